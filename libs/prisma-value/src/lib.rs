@@ -131,6 +131,14 @@ impl TryFrom<serde_json::Value> for PrismaValue {
 
                     decode_bytes(value).map(PrismaValue::Bytes)
                 }
+                Some("enum") => {
+                    let value = obj
+                        .get("prisma__value")
+                        .and_then(|v| v.as_str())
+                        .ok_or_else(|| ConversionFailure::new("JSON enum value", "PrismaValue"))?;
+
+                    Ok(PrismaValue::Enum(value.to_owned()))
+                }
 
                 _ => Ok(PrismaValue::Json(serde_json::to_string(&obj).unwrap())),
             },
@@ -335,3 +335,13 @@ pub struct ExternalError {
     /// id of the error in external system, which would allow to retrieve it later
     pub id: i32,
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2037",
+    message = "The record was modified by another operation between the time it was read and the time it was updated or deleted. Expected {model} to be at version {expected_version}, but it no longer matched."
+)]
+pub struct OptimisticLockError {
+    pub model: String,
+    pub expected_version: String,
+}
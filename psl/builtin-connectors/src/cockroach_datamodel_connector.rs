@@ -57,7 +57,8 @@ const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Connector
     MultiSchema |
     FilteredInlineChildNestedToOneDisconnect |
     InsertReturning |
-    UpdateReturning
+    UpdateReturning |
+    UpdateFromJoin
 });
 
 const SCALAR_TYPE_DEFAULTS: &[(ScalarType, CockroachType)] = &[
@@ -316,6 +317,7 @@ pub struct SequenceFunction {
     pub min_value: Option<i64>,
     pub max_value: Option<i64>,
     pub start: Option<i64>,
+    pub cycle: Option<bool>,
 }
 
 impl SequenceFunction {
@@ -334,6 +336,7 @@ impl SequenceFunction {
                 Some("minValue") => this.min_value = coerce::integer(&arg.value, diagnostics),
                 Some("maxValue") => this.max_value = coerce::integer(&arg.value, diagnostics),
                 Some("start") => this.start = coerce::integer(&arg.value, diagnostics),
+                Some("cycle") => this.cycle = coerce::boolean(&arg.value, diagnostics),
                 Some(_) | None => diagnostics.push_error(DatamodelError::new_static(
                     "Unexpected argument in `sequence()` function call",
                     arg.span,
@@ -52,7 +52,10 @@ const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Connector
     SupportsTxIsolationReadCommitted |
     SupportsTxIsolationRepeatableRead |
     SupportsTxIsolationSerializable |
-    SupportsTxIsolationSnapshot
+    SupportsTxIsolationSnapshot |
+    UpdateFromJoin |
+    InsensitiveFilters |
+    NativeUpsert
 });
 
 pub(crate) struct MsSqlDatamodelConnector;
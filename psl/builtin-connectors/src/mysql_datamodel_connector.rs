@@ -58,7 +58,9 @@ const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Connector
     SupportsTxIsolationReadUncommitted |
     SupportsTxIsolationReadCommitted |
     SupportsTxIsolationRepeatableRead |
-    SupportsTxIsolationSerializable
+    SupportsTxIsolationSerializable |
+    UpdateFromJoin |
+    InsensitiveFilters
 });
 
 const CONSTRAINT_SCOPES: &[ConstraintScope] = &[ConstraintScope::GlobalForeignKey, ConstraintScope::ModelKeyIndex];
@@ -225,6 +227,7 @@ impl Connector for MySqlDatamodelConnector {
     fn validate_model(&self, model: walkers::ModelWalker<'_>, relation_mode: RelationMode, errors: &mut Diagnostics) {
         for index in model.indexes() {
             validations::field_types_can_be_used_in_an_index(self, index, errors);
+            validations::fulltext_columns_must_be_string_typed(index, errors);
         }
 
         if let Some(pk) = model.primary_key() {
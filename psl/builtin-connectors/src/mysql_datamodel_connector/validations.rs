@@ -1,7 +1,7 @@
 use indoc::formatdoc;
-use psl_core::diagnostics::{DatamodelWarning, Span};
+use psl_core::diagnostics::{DatamodelError, DatamodelWarning, Span};
 use psl_core::parser_database::ast::WithSpan;
-use psl_core::parser_database::ReferentialAction;
+use psl_core::parser_database::{ReferentialAction, ScalarType};
 use psl_core::{
     datamodel_connector::{walker_ext_traits::ScalarFieldWalkerExt, Connector},
     diagnostics::Diagnostics,
@@ -62,6 +62,35 @@ pub(crate) fn field_types_can_be_used_in_an_index(
     }
 }
 
+/// `@@fulltext` indexes are only valid on `String` columns (`CHAR`, `VARCHAR` and the `TEXT`
+/// family): MySQL cannot build a `FULLTEXT` index over any other column type.
+pub(crate) fn fulltext_columns_must_be_string_typed(index: IndexWalker<'_>, errors: &mut Diagnostics) {
+    if !index.is_fulltext() {
+        return;
+    }
+
+    for field in index.scalar_field_attributes() {
+        let index_field = field.as_index_field();
+
+        if index_field.scalar_field_type().as_builtin_scalar() == Some(ScalarType::String) {
+            continue;
+        }
+
+        let message = format!(
+            "The field `{}` cannot be part of a `@@fulltext` index. MySQL only supports fulltext indexes on `String` columns.",
+            index_field.name()
+        );
+
+        errors.push_error(DatamodelError::new_attribute_validation_error(
+            &message,
+            index.attribute_name(),
+            index.ast_attribute().span,
+        ));
+
+        break;
+    }
+}
+
 pub(crate) fn field_types_can_be_used_in_a_primary_key(
     connector: &dyn Connector,
     primary_key: PrimaryKeyWalker<'_>,
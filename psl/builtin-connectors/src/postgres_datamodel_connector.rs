@@ -64,7 +64,8 @@ const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Connector
     SupportsTxIsolationSerializable |
     NativeUpsert |
     InsertReturning |
-    UpdateReturning
+    UpdateReturning |
+    UpdateFromJoin
 });
 
 pub struct PostgresDatamodelConnector;
@@ -584,6 +585,13 @@ fn allowed_index_operator_classes(algo: IndexAlgorithm, field: walkers::ScalarFi
             classes.push(OperatorClass::JsonbOps);
             classes.push(OperatorClass::JsonbPathOps);
         }
+        (IndexAlgorithm::Gin, Some(ScalarType::String), None | Some("Text") | Some("VarChar") | Some("Char")) => {
+            classes.push(OperatorClass::GinTrgmOps);
+        }
+
+        (IndexAlgorithm::Gist, Some(ScalarType::String), None | Some("Text") | Some("VarChar") | Some("Char")) => {
+            classes.push(OperatorClass::GistTrgmOps);
+        }
 
         (IndexAlgorithm::SpGist, _, Some("Inet")) => {
             classes.push(OperatorClass::InetOps);
@@ -141,6 +141,11 @@ pub(super) fn generalized_index_validations(
             match (&native_type, opclass) {
                 // Inet / InetOps
                 (Some(PostgresType::Inet), Some(InetOps)) => (),
+
+                // Text / GistTrgmOps (requires the pg_trgm extension)
+                (None, Some(GistTrgmOps)) if r#type.is_string() => (),
+                (Some(PostgresType::Text | PostgresType::VarChar(_) | PostgresType::Char(_)), Some(GistTrgmOps)) => (),
+
                 _ => err_f(native_type_name, opclass),
             }
         } else if algo.is_gin() {
@@ -148,6 +153,10 @@ pub(super) fn generalized_index_validations(
                 // Jsonb / JsonbOps + JsonbPathOps
                 (None, None) if r#type.is_json() => (),
 
+                // Text / GinTrgmOps (requires the pg_trgm extension)
+                (None, Some(GinTrgmOps)) if r#type.is_string() => (),
+                (Some(PostgresType::Text | PostgresType::VarChar(_) | PostgresType::Char(_)), Some(GinTrgmOps)) => (),
+
                 // Array fields + ArrayOps
                 (_, None) if field.as_index_field().is_list() => (),
 
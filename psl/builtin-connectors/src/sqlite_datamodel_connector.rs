@@ -24,10 +24,19 @@ const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Connector
     OrderByNullsFirstLast |
     SupportsTxIsolationSerializable |
     NativeUpsert |
-    FilteredInlineChildNestedToOneDisconnect
+    FilteredInlineChildNestedToOneDisconnect |
+    CreateMany |
+    // SQLite renders `skipDuplicates` as `INSERT OR IGNORE` (see `quaint::visitor::Sqlite::visit_insert`).
+    CreateSkipDuplicates |
+    // Emulated via `LOWER(column) LIKE LOWER(pattern)` (see `sql-query-connector`'s
+    // `insensitive_scalar_filter`), since SQLite has no native case-insensitive operator.
+    InsensitiveFilters
     // InsertReturning - While SQLite does support RETURNING, it does not return column information on the way back from the database.
     // This column type information is necessary in order to preserve consistency for some data types such as int, where values could overflow.
     // Since we care to stay consistent with reads, it is not enabled.
+    // UpdateFromJoin - SQLite only gained `UPDATE ... FROM` in 3.33.0. Whether it's usable depends on the
+    // linked library version, not just the flavour, so quaint's SQLite visitor checks it at render time
+    // (see `quaint::visitor::Sqlite::visit_update`) instead of it being a static connector capability.
 });
 
 pub struct SqliteDatamodelConnector;
@@ -9,8 +9,9 @@ use crate::{
     coerce, coerce_array,
     context::Context,
     types::{
-        CompositeTypeField, EnumAttributes, FieldWithArgs, IndexAlgorithm, IndexAttribute, IndexFieldPath, IndexType,
-        ModelAttributes, OperatorClassStore, RelationField, ScalarField, ScalarFieldType, SortOrder,
+        CompositeTypeField, EnumAttributes, FieldWithArgs, GrantAttribute, IndexAlgorithm, IndexAttribute,
+        IndexFieldPath, IndexType, ModelAttributes, OperatorClassStore, RelationField, ScalarField, ScalarFieldType,
+        SortOrder,
     },
     walkers::RelationFieldId,
     DatamodelError, ScalarFieldId, StringId,
@@ -167,6 +168,12 @@ fn resolve_model_attributes(model_id: ast::ModelId, ctx: &mut Context<'_>) {
         ctx.validate_visited_arguments();
     }
 
+    // @@grant
+    while ctx.visit_repeated_attr("grant") {
+        model_grant(&mut model_attributes, ctx);
+        ctx.validate_visited_arguments();
+    }
+
     // Model-global validations
     id::validate_id_field_arities(model_id, &model_attributes, ctx);
 
@@ -443,6 +450,41 @@ fn model_fulltext(data: &mut ModelAttributes, model_id: ast::ModelId, ctx: &mut
     data.ast_indexes.push((ctx.current_attribute_id(), index_attribute));
 }
 
+/// Validate @@grant on models.
+///
+/// ```ignore
+/// @@grant(role: "app_user", privileges: ["select", "insert"])
+/// ```
+fn model_grant(data: &mut ModelAttributes, ctx: &mut Context<'_>) {
+    let role = match ctx
+        .visit_default_arg("role")
+        .map(|value| coerce::string(value, ctx.diagnostics))
+    {
+        Ok(Some(role)) => role,
+        Ok(None) => return,
+        Err(err) => return ctx.push_error(err),
+    };
+
+    let privileges = match ctx
+        .visit_default_arg("privileges")
+        .map(|value| coerce_array(value, &coerce::string, ctx.diagnostics))
+    {
+        Ok(Some(privileges)) => privileges,
+        Ok(None) => return,
+        Err(err) => return ctx.push_error(err),
+    };
+
+    if privileges.is_empty() {
+        ctx.push_attribute_validation_error("`@@grant` needs at least one privilege.");
+        return;
+    }
+
+    data.ast_grants.push(GrantAttribute {
+        role: ctx.interner.intern(role),
+        privileges: privileges.into_iter().map(|p| ctx.interner.intern(p)).collect(),
+    });
+}
+
 /// Validate @@index on models.
 fn model_index(data: &mut ModelAttributes, model_id: ast::ModelId, ctx: &mut Context<'_>) {
     let mut index_attribute = IndexAttribute {
@@ -335,6 +335,18 @@ pub(crate) struct ModelAttributes {
     ///          ^^^^^^^^
     /// ```
     pub(crate) schema: Option<(StringId, ast::Span)>,
+    /// @@grant, one entry per repetition of the attribute.
+    pub(crate) ast_grants: Vec<GrantAttribute>,
+}
+
+/// ```ignore
+/// @@grant(role: "app_user", privileges: ["select", "insert"])
+///                ^^^^^^^^^             ^^^^^^^^^^^^^^^^^^^^^
+/// ```
+#[derive(Debug)]
+pub(crate) struct GrantAttribute {
+    pub(crate) role: StringId,
+    pub(crate) privileges: Vec<StringId>,
 }
 
 /// A type of index as defined by the `type: ...` argument on an index attribute.
@@ -763,6 +775,26 @@ pub enum OperatorClass {
     /// - `<@ (anyarray,anyarray)`
     /// - `= (anyarray,anyarray)`
     ArrayOps,
+    /// An operator class for `Gin` index and `text` type, requiring the
+    /// `pg_trgm` extension. Enables trigram similarity and pattern-matching
+    /// operators to use the index.
+    ///
+    /// # Indexable Operators
+    ///
+    /// - `% (text,text)`
+    /// - `LIKE (text,text)`
+    /// - `~~ (text,text)`
+    GinTrgmOps,
+    /// An operator class for `Gist` index and `text` type, requiring the
+    /// `pg_trgm` extension. Enables trigram similarity and pattern-matching
+    /// operators to use the index.
+    ///
+    /// # Indexable Operators
+    ///
+    /// - `% (text,text)`
+    /// - `LIKE (text,text)`
+    /// - `~~ (text,text)`
+    GistTrgmOps,
     /// An operator class for `SpGist` index and `text`, `char` and
     /// `varchar` types.
     ///
@@ -1232,6 +1264,8 @@ impl OperatorClass {
             Self::JsonbOps => matches!(algo, IndexAlgorithm::Gin),
             Self::JsonbPathOps => matches!(algo, IndexAlgorithm::Gin),
             Self::ArrayOps => matches!(algo, IndexAlgorithm::Gin),
+            Self::GinTrgmOps => matches!(algo, IndexAlgorithm::Gin),
+            Self::GistTrgmOps => matches!(algo, IndexAlgorithm::Gist),
             Self::TextOps => matches!(algo, IndexAlgorithm::SpGist),
             Self::BitMinMaxOps => matches!(algo, IndexAlgorithm::Brin),
             Self::VarBitMinMaxOps => matches!(algo, IndexAlgorithm::Brin),
@@ -1295,6 +1329,8 @@ impl fmt::Display for OperatorClass {
             Self::JsonbOps => f.write_str("JsonbOps"),
             Self::JsonbPathOps => f.write_str("JsonbPathOps"),
             Self::ArrayOps => f.write_str("ArrayOps"),
+            Self::GinTrgmOps => f.write_str("GinTrgmOps"),
+            Self::GistTrgmOps => f.write_str("GistTrgmOps"),
             Self::TextOps => f.write_str("TextOps"),
             Self::BitMinMaxOps => f.write_str("BitMinMaxOps"),
             Self::VarBitMinMaxOps => f.write_str("VarBitMinMaxOps"),
@@ -94,6 +94,10 @@ fn field_args<'a>(args: &'a [ast::Argument], diagnostics: &mut diagnostics::Diag
                 "JsonbOps" => Some(OperatorClass::from(crate::OperatorClass::JsonbOps)),
                 "JsonbPathOps" => Some(OperatorClass::from(crate::OperatorClass::JsonbPathOps)),
                 "ArrayOps" => Some(OperatorClass::from(crate::OperatorClass::ArrayOps)),
+                "GinTrgmOps" => Some(OperatorClass::from(crate::OperatorClass::GinTrgmOps)),
+
+                // gist
+                "GistTrgmOps" => Some(OperatorClass::from(crate::OperatorClass::GistTrgmOps)),
 
                 // sp-gist
                 "TextOps" => Some(OperatorClass::from(crate::OperatorClass::TextOps)),
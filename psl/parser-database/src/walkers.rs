@@ -9,6 +9,7 @@
 mod composite_type;
 mod r#enum;
 mod field;
+mod grant;
 mod index;
 mod model;
 mod relation;
@@ -18,6 +19,7 @@ mod scalar_field;
 pub use crate::types::RelationFieldId;
 pub use composite_type::*;
 pub use field::*;
+pub use grant::*;
 pub use index::*;
 pub use model::*;
 pub use r#enum::*;
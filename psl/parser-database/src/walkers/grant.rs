@@ -0,0 +1,26 @@
+use crate::{types::GrantAttribute, walkers::ModelWalker, ParserDatabase};
+
+/// A single `@@grant(role: ..., privileges: [...])` attribute on a model.
+#[derive(Copy, Clone)]
+pub struct GrantWalker<'db> {
+    pub(crate) model_id: crate::ast::ModelId,
+    pub(crate) db: &'db ParserDatabase,
+    pub(crate) grant: &'db GrantAttribute,
+}
+
+impl<'db> GrantWalker<'db> {
+    /// The role the grant applies to.
+    pub fn role(self) -> &'db str {
+        &self.db[self.grant.role]
+    }
+
+    /// The privileges granted to the role, e.g. `["select", "insert"]`.
+    pub fn privileges(self) -> impl ExactSizeIterator<Item = &'db str> {
+        self.grant.privileges.iter().map(move |id| &self.db[*id])
+    }
+
+    /// The model the grant is defined on.
+    pub fn model(self) -> ModelWalker<'db> {
+        self.db.walk(self.model_id)
+    }
+}
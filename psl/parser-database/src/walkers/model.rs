@@ -6,8 +6,8 @@ pub use primary_key::*;
 pub(crate) use unique_criteria::*;
 
 use super::{
-    CompleteInlineRelationWalker, FieldWalker, IndexWalker, InlineRelationWalker, RelationFieldWalker, RelationWalker,
-    ScalarFieldWalker,
+    CompleteInlineRelationWalker, FieldWalker, GrantWalker, IndexWalker, InlineRelationWalker, RelationFieldWalker,
+    RelationWalker, ScalarFieldWalker,
 };
 use crate::{
     ast::{self, WithName},
@@ -145,6 +145,17 @@ impl<'db> ModelWalker<'db> {
             .filter(|walker| !walker.fields().any(|field| field.is_optional()))
     }
 
+    /// Iterate all the `@@grant` attributes on the model in the order they were defined.
+    pub fn grants(self) -> impl Iterator<Item = GrantWalker<'db>> {
+        let model_id = self.id;
+        let db = self.db;
+
+        self.attributes()
+            .ast_grants
+            .iter()
+            .map(move |grant| GrantWalker { model_id, db, grant })
+    }
+
     /// Iterate all the indexes in the model in the order they were
     /// defined.
     pub fn indexes(self) -> impl Iterator<Item = IndexWalker<'db>> {
@@ -103,6 +103,7 @@ capabilities!(
     NativeUpsert,
     InsertReturning,
     UpdateReturning,
+    UpdateFromJoin, // Connector can express an UPDATE whose SET/WHERE clauses read from a second table (Postgres/MSSQL FROM, MySQL JOIN).
 );
 
 /// Contains all capabilities that the connector is able to serve.
@@ -91,13 +91,38 @@ fn default_sequence_with_all_arguments_is_valid_on_cockroachdb() {
         }
 
         model Test {
-            id Int @id @default(sequence(virtual: true, cache: 10, increment: 3, minValue: 10, maxValue: 100, start: 12))
+            id Int @id @default(sequence(virtual: true, cache: 10, increment: 3, minValue: 10, maxValue: 100, start: 12, cycle: true))
         }
     "#;
 
     assert_valid(schema);
 }
 
+#[test]
+fn default_sequence_cycle_argument_of_the_wrong_type_on_cockroachdb() {
+    let schema = r#"
+        datasource db {
+            provider = "cockroachdb"
+            url = env("TEST_DATABASE_URL")
+        }
+
+        model Test {
+            id Int @id @default(sequence(cycle: 1))
+        }
+    "#;
+
+    let expected = expect![[r#"
+        [1;91merror[0m: [1mExpected a boolean value, but received literal value `1`.[0m
+          [1;94m-->[0m  [4mschema.prisma:8[0m
+        [1;94m   | [0m
+        [1;94m 7 | [0m        model Test {
+        [1;94m 8 | [0m            id Int @id @default(sequence(cycle: [1;91m1[0m))
+        [1;94m   | [0m
+    "#]];
+
+    expect_error(schema, &expected);
+}
+
 #[test]
 fn default_sequence_with_unknown_argument() {
     let schema = r#"
@@ -441,6 +441,33 @@ fn fulltext_index_length_attribute() {
     expectation.assert_eq(&error)
 }
 
+#[test]
+fn fulltext_index_on_non_string_column() {
+    let dml = indoc! {r#"
+        model A {
+          id Int @id
+          a  String
+          b  Int
+
+          @@fulltext([a, b])
+        }
+    "#};
+
+    let schema = with_header(dml, Provider::Mysql, &["fullTextIndex"]);
+    let error = parse_unwrap_err(&schema);
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mError parsing attribute "@@fulltext": The field `b` cannot be part of a `@@fulltext` index. MySQL only supports fulltext indexes on `String` columns.[0m
+          [1;94m-->[0m  [4mschema.prisma:16[0m
+        [1;94m   | [0m
+        [1;94m15 | [0m
+        [1;94m16 | [0m  [1;91m@@fulltext([a, b])[0m
+        [1;94m   | [0m
+    "#]];
+
+    expectation.assert_eq(&error)
+}
+
 #[test]
 fn hash_index_doesnt_work_on_sqlite() {
     let dml = indoc! {r#"
@@ -401,3 +401,23 @@ fn gin_raw_ops_to_supported_type() {
         .assert_field("data")
         .assert_raw_ops("gin_trgm_ops");
 }
+
+#[test]
+fn gin_trgm_ops_named() {
+    let dm = r#"
+        model A {
+          id   Int     @id @default(autoincrement())
+          data String? @test.VarChar
+
+          @@index([data(ops: GinTrgmOps)], type: Gin)
+        }
+    "#;
+
+    psl::parse_schema(with_header(dm, Provider::Postgres, &[]))
+        .unwrap()
+        .assert_has_model("A")
+        .assert_index_on_fields(&["data"])
+        .assert_type(IndexAlgorithm::Gin)
+        .assert_field("data")
+        .assert_ops(OperatorClass::GinTrgmOps);
+}
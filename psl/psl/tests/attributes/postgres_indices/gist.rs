@@ -214,3 +214,23 @@ fn wrong_ops_no_native_type() {
 
     expectation.assert_eq(&error)
 }
+
+#[test]
+fn gist_trgm_ops() {
+    let dml = indoc! {r#"
+        model A {
+          id   Int    @id
+          data String
+
+          @@index([data(ops: GistTrgmOps)], type: Gist)
+        }
+    "#};
+
+    psl::parse_schema(with_header(dml, Provider::Postgres, &[]))
+        .unwrap()
+        .assert_has_model("A")
+        .assert_index_on_fields(&["data"])
+        .assert_type(IndexAlgorithm::Gist)
+        .assert_field("data")
+        .assert_ops(OperatorClass::GistTrgmOps);
+}
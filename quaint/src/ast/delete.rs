@@ -7,6 +7,7 @@ pub struct Delete<'a> {
     pub(crate) table: Table<'a>,
     pub(crate) conditions: Option<ConditionTree<'a>>,
     pub(crate) comment: Option<Cow<'a, str>>,
+    pub(crate) returning: Option<Vec<Column<'a>>>,
 }
 
 impl<'a> From<Delete<'a>> for Query<'a> {
@@ -36,6 +37,7 @@ impl<'a> Delete<'a> {
             table: table.into(),
             conditions: None,
             comment: None,
+            returning: None,
         }
     }
 
@@ -77,4 +79,27 @@ impl<'a> Delete<'a> {
         self.conditions = Some(conditions.into());
         self
     }
+
+    /// Sets the returned columns.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let delete = Delete::from_table("users").so_that("bar".equals(false));
+    /// let delete = delete.returning(vec!["id"]);
+    /// let (sql, _) = Postgres::build(delete)?;
+    ///
+    /// assert_eq!("DELETE FROM \"users\" WHERE \"bar\" = $1 RETURNING \"id\"", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mssql"))]
+    pub fn returning<K, I>(mut self, columns: I) -> Self
+    where
+        K: Into<Column<'a>>,
+        I: IntoIterator<Item = K>,
+    {
+        self.returning = Some(columns.into_iter().map(|k| k.into()).collect());
+        self
+    }
 }
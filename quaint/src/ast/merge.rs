@@ -11,6 +11,7 @@ pub struct Merge<'a> {
     pub(crate) table: Table<'a>,
     pub(crate) using: Using<'a>,
     pub(crate) when_not_matched: Option<Query<'a>>,
+    pub(crate) when_matched_update: Option<Vec<(Column<'a>, Expression<'a>)>>,
     pub(crate) returning: Option<Vec<Column<'a>>>,
 }
 
@@ -24,6 +25,7 @@ impl<'a> Merge<'a> {
             table: table.into(),
             using: using.into(),
             when_not_matched: None,
+            when_matched_update: None,
             returning: None,
         }
     }
@@ -36,6 +38,14 @@ impl<'a> Merge<'a> {
         self
     }
 
+    /// Adds a `WHEN MATCHED THEN UPDATE SET ...` clause, used to turn a `MERGE` produced from an
+    /// `INSERT ... ON CONFLICT DO UPDATE` into a native upsert on connectors (MSSQL) that have no
+    /// `ON CONFLICT` syntax of their own.
+    pub(crate) fn when_matched_update(mut self, assignments: Vec<(Column<'a>, Expression<'a>)>) -> Self {
+        self.when_matched_update = Some(assignments);
+        self
+    }
+
     pub(crate) fn returning<K, I>(mut self, columns: I) -> Self
     where
         K: Into<Column<'a>>,
@@ -159,3 +169,17 @@ impl<'a> TryFrom<Insert<'a>> for Merge<'a> {
         Ok(merge)
     }
 }
+
+impl<'a> Merge<'a> {
+    /// Like the `TryFrom<Insert>` conversion above, but for an `INSERT ... ON CONFLICT DO UPDATE`:
+    /// the resulting `MERGE` additionally gets a `WHEN MATCHED THEN UPDATE SET ...` clause built
+    /// from `update`'s column assignments. The `update`'s own conditions are dropped: the row to
+    /// match is already pinned down by the `USING ... ON` join on the table's unique indexes, same
+    /// as for the `WHEN NOT MATCHED` insert branch.
+    pub(crate) fn try_from_insert_on_conflict_update(insert: Insert<'a>, update: Update<'a>) -> crate::Result<Self> {
+        let assignments: Vec<_> = update.columns.into_iter().zip(update.values).collect();
+        let merge = Self::try_from(insert)?.when_matched_update(assignments);
+
+        Ok(merge)
+    }
+}
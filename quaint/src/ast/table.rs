@@ -31,6 +31,20 @@ pub struct Table<'a> {
     pub alias: Option<Cow<'a, str>>,
     pub database: Option<Cow<'a, str>>,
     pub(crate) index_definitions: Vec<IndexDefinition<'a>>,
+    pub(crate) index_hint: Option<IndexHint<'a>>,
+}
+
+/// An index hint attached to a table reference, steering the query planner towards a specific
+/// index instead of leaving the choice up to it. Rendered as `FORCE INDEX` on MySQL and `WITH
+/// (INDEX(...))` on MSSQL; connectors without an equivalent (Postgres, SQLite) ignore it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndexHint<'a>(pub(crate) Cow<'a, str>);
+
+impl<'a> IndexHint<'a> {
+    /// The database name of the index this hint forces the connector to use.
+    pub fn index_name(&self) -> &str {
+        &self.0
+    }
 }
 
 impl<'a> PartialEq for Table<'a> {
@@ -57,6 +71,17 @@ impl<'a> Table<'a> {
         }
     }
 
+    /// Force the connector to use a specific index when reading from this table, where the
+    /// connector supports rendering index hints. Connectors without an equivalent silently ignore
+    /// the hint.
+    pub fn force_index<T>(mut self, index_name: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.index_hint = Some(IndexHint(index_name.into()));
+        self
+    }
+
     /// Add unique index definition.
     pub fn add_unique_index(mut self, i: impl Into<IndexDefinition<'a>>) -> Self {
         let definition = i.into();
@@ -87,6 +112,7 @@ impl<'a> Table<'a> {
             alias: self.alias.clone(),
             database: self.database.clone(),
             index_definitions: Vec::new(),
+            index_hint: None,
         };
 
         self.index_definitions.push(definition.set_table(table));
@@ -367,6 +393,7 @@ impl<'a> From<&'a str> for Table<'a> {
             alias: None,
             database: None,
             index_definitions: Vec::new(),
+            index_hint: None,
         }
     }
 }
@@ -378,6 +405,7 @@ impl<'a> From<&'a String> for Table<'a> {
             alias: None,
             database: None,
             index_definitions: Vec::new(),
+            index_hint: None,
         }
     }
 }
@@ -417,6 +445,7 @@ impl<'a> From<String> for Table<'a> {
             alias: None,
             database: None,
             index_definitions: Vec::new(),
+            index_hint: None,
         }
     }
 }
@@ -434,6 +463,7 @@ impl<'a> From<Values<'a>> for Table<'a> {
             alias: None,
             database: None,
             index_definitions: Vec::new(),
+            index_hint: None,
         }
     }
 }
@@ -452,6 +482,7 @@ impl<'a> From<Select<'a>> for Table<'a> {
             alias: None,
             database: None,
             index_definitions: Vec::new(),
+            index_hint: None,
         }
     }
 }
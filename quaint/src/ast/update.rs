@@ -10,6 +10,17 @@ pub struct Update<'a> {
     pub(crate) conditions: Option<ConditionTree<'a>>,
     pub(crate) comment: Option<Cow<'a, str>>,
     pub(crate) returning: Option<Vec<Column<'a>>>,
+    pub(crate) from: Option<UpdateFrom<'a>>,
+}
+
+/// A second table an [`Update`](struct.Update.html) reads from, so the `SET` values can
+/// reference its columns. Renders as `UPDATE ... FROM ... WHERE <join>` on Postgres, SQLite and
+/// MSSQL, and is folded into an `UPDATE a JOIN b ON <join>` on MySQL, which has no `FROM` clause
+/// for `UPDATE`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct UpdateFrom<'a> {
+    pub(crate) table: Table<'a>,
+    pub(crate) conditions: ConditionTree<'a>,
 }
 
 impl<'a> From<Update<'a>> for Query<'a> {
@@ -31,6 +42,7 @@ impl<'a> Update<'a> {
             conditions: None,
             comment: None,
             returning: None,
+            from: None,
         }
     }
 
@@ -136,6 +148,38 @@ impl<'a> Update<'a> {
         self
     }
 
+    /// Joins another table into the update, so `SET` values and `WHERE` conditions can reference
+    /// its columns. Not every connector can express a cross-table update; the PSL-level
+    /// `ConnectorCapability::UpdateFromJoin` capability tracks which ones can.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Update::table("users")
+    ///     .set("name", Column::from(("accounts", "name")))
+    ///     .from_table("accounts", "users".equals(Column::from(("accounts", "user_id"))));
+    /// let (sql, _) = Postgres::build(query)?;
+    ///
+    /// assert_eq!(
+    ///     "UPDATE \"users\" SET \"name\" = \"accounts\".\"name\" FROM \"accounts\" WHERE \"users\" = \"accounts\".\"user_id\"",
+    ///     sql
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_table<T, C>(mut self, table: T, on: C) -> Self
+    where
+        T: Into<Table<'a>>,
+        C: Into<ConditionTree<'a>>,
+    {
+        self.from = Some(UpdateFrom {
+            table: table.into(),
+            conditions: on.into(),
+        });
+
+        self
+    }
+
     /// Sets the returned columns.
     ///
     /// ```rust
@@ -149,7 +193,7 @@ impl<'a> Update<'a> {
     /// # Ok(())
     /// # }
     /// ```
-    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mssql"))]
     pub fn returning<K, I>(mut self, columns: I) -> Self
     where
         K: Into<Column<'a>>,
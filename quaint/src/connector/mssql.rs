@@ -71,6 +71,16 @@ impl FromStr for EncryptMode {
 }
 
 #[derive(Debug, Clone)]
+// synth-539 asked for a `statementCacheSize` connection string option to cap a prepared-statement
+// cache for MSSQL, matching the ones Postgres/MySQL keep. Closing as infeasible with the pinned
+// `tiberius` version: its `Query`/`execute` methods already send every statement through SQL
+// Server's parameterized RPC call (the sp_executesql-equivalent under the TDS wire protocol), so
+// plan reuse already happens server-side, keyed by statement text, via SQL Server's own plan
+// cache. There's no tokio_postgres::Statement / mysql_async::Statement-shaped prepared handle in
+// tiberius's API to key a client-side LRU on the way the Postgres/MySQL connectors do; driving
+// sp_prepare/sp_execute's OUTPUT handle parameter by hand would need a lower-level API tiberius
+// doesn't expose here. Revisit only if a future tiberius version exposes prepared statement
+// handles directly.
 pub(crate) struct MssqlQueryParams {
     encrypt: EncryptMode,
     port: Option<u16>,
@@ -97,6 +107,7 @@ impl TransactionCapable for Mssql {
     async fn start_transaction<'a>(
         &'a self,
         isolation: Option<IsolationLevel>,
+        session_context: &[(String, String)],
     ) -> crate::Result<Box<dyn Transaction + 'a>> {
         // Isolation levels in SQL Server are set on the connection and live until they're changed.
         // Always explicitly setting the isolation level each time a tx is started (either to the given value
@@ -106,7 +117,8 @@ impl TransactionCapable for Mssql {
             .or(self.url.query_params.transaction_isolation_level)
             .or(Some(SQL_SERVER_DEFAULT_ISOLATION));
 
-        let opts = TransactionOptions::new(isolation, self.requires_isolation_first());
+        let opts = TransactionOptions::new(isolation, self.requires_isolation_first())
+            .with_session_context(session_context.to_vec());
 
         Ok(Box::new(
             DefaultTransaction::new(self, self.begin_statement(), opts).await?,
@@ -273,6 +285,10 @@ pub struct Mssql {
     url: MssqlUrl,
     socket_timeout: Option<Duration>,
     is_healthy: AtomicBool,
+    /// The quoted table names `NOCHECK`ed by `disable_foreign_keys`, re-checked by the matching
+    /// `enable_and_validate_foreign_keys` call. SQL Server has no session-wide switch for this:
+    /// constraints are disabled per table.
+    nochecked_tables: Mutex<Vec<String>>,
 }
 
 impl Mssql {
@@ -304,6 +320,7 @@ impl Mssql {
             url,
             socket_timeout,
             is_healthy: AtomicBool::new(true),
+            nochecked_tables: Mutex::new(Vec::new()),
         };
 
         if let Some(isolation) = this.url.transaction_isolation_level() {
@@ -450,6 +467,81 @@ impl Queryable for Mssql {
     fn requires_isolation_first(&self) -> bool {
         true
     }
+
+    fn create_savepoint_statement(&self, name: &str) -> String {
+        format!("SAVE TRANSACTION {name}")
+    }
+
+    fn rollback_to_savepoint_statement(&self, name: &str) -> String {
+        format!("ROLLBACK TRANSACTION {name}")
+    }
+
+    fn release_savepoint_statement(&self, _name: &str) -> Option<String> {
+        // SQL Server has no equivalent to RELEASE SAVEPOINT: a savepoint is implicitly discarded
+        // once the transaction it was created in commits or rolls back.
+        None
+    }
+
+    async fn disable_foreign_keys(&self) -> crate::Result<()> {
+        // Regular tables in the current database, plus global temp tables, which live in
+        // `tempdb` but can be referenced directly by name from any database context.
+        let tables = self
+            .query_raw(
+                "SELECT QUOTENAME(s.name) + '.' + QUOTENAME(t.name) \
+                 FROM sys.tables t INNER JOIN sys.schemas s ON t.schema_id = s.schema_id \
+                 UNION ALL \
+                 SELECT QUOTENAME(t.name) FROM tempdb.sys.tables t WHERE t.name LIKE '##%'",
+                &[],
+            )
+            .await?;
+
+        let mut nochecked = Vec::new();
+
+        for row in tables.into_iter() {
+            let table = row.at(0).and_then(|v| v.to_string()).unwrap_or_default();
+
+            self.raw_cmd(&format!("ALTER TABLE {table} NOCHECK CONSTRAINT ALL")).await?;
+
+            nochecked.push(table);
+        }
+
+        *self.nochecked_tables.lock().await = nochecked;
+
+        Ok(())
+    }
+
+    async fn enable_and_validate_foreign_keys(&self) -> crate::Result<()> {
+        // `WITH CHECK CHECK CONSTRAINT` re-enables enforcement for the table and validates its
+        // existing rows in the same statement, raising an error if a constraint is violated.
+        let nochecked = std::mem::take(&mut *self.nochecked_tables.lock().await);
+        let mut violations = Vec::new();
+
+        for table in nochecked {
+            let result = self
+                .raw_cmd(&format!("ALTER TABLE {table} WITH CHECK CHECK CONSTRAINT ALL"))
+                .await;
+
+            if let Err(e) = result {
+                violations.push(format!("{table}: {e}"));
+            }
+        }
+
+        if !violations.is_empty() {
+            return Err(Error::builder(ErrorKind::foreign_key_checks_failed(violations)).build());
+        }
+
+        Ok(())
+    }
+
+    async fn set_session_context_value(&self, key: &str, value: &str) -> crate::Result<()> {
+        self.execute_raw(
+            "EXEC sp_set_session_context @key = @P1, @value = @P2",
+            &[Value::from(key.to_owned()), Value::from(value.to_owned())],
+        )
+        .await?;
+
+        Ok(())
+    }
 }
 
 impl MssqlUrl {
@@ -598,6 +690,7 @@ impl MssqlUrl {
 
 #[cfg(test)]
 mod tests {
+    use super::MssqlUrl;
     use crate::tests::test_api::mssql::CONN_STR;
     use crate::{error::*, single::Quaint};
 
@@ -39,6 +39,9 @@ pub struct Mysql {
     socket_timeout: Option<Duration>,
     is_healthy: AtomicBool,
     statement_cache: Mutex<LruCache<String, my::Statement>>,
+    /// The value of `@@SESSION.foreign_key_checks` captured by `disable_foreign_keys`, restored
+    /// by the matching `enable_and_validate_foreign_keys` call.
+    foreign_key_checks_before_disable: Mutex<Option<bool>>,
 }
 
 /// Wraps a connection url and exposes the parsing logic used by quaint, including default values.
@@ -374,6 +377,7 @@ impl Mysql {
             statement_cache: Mutex::new(url.cache()),
             url,
             is_healthy: AtomicBool::new(true),
+            foreign_key_checks_before_disable: Mutex::new(None),
         })
     }
 
@@ -581,6 +585,81 @@ impl Queryable for Mysql {
     fn requires_isolation_first(&self) -> bool {
         true
     }
+
+    async fn disable_foreign_keys(&self) -> crate::Result<()> {
+        let previous = self.query_raw("SELECT @@SESSION.foreign_key_checks", &[]).await?;
+        let was_enabled = previous
+            .get(0)
+            .and_then(|row| row.at(0).and_then(|v| v.as_bool()))
+            .unwrap_or(true);
+
+        *self.foreign_key_checks_before_disable.lock().await = Some(was_enabled);
+
+        self.raw_cmd("SET SESSION foreign_key_checks=0").await
+    }
+
+    async fn enable_and_validate_foreign_keys(&self) -> crate::Result<()> {
+        // Unlike other connectors, MySQL does not revalidate existing rows when
+        // `foreign_key_checks` is turned back on: it only starts enforcing the constraints on
+        // writes from that point onward. To honor the same "report violations" contract, we look
+        // up every foreign key on the current database and check it for orphaned rows ourselves.
+        let foreign_keys = self
+            .query_raw(
+                r#"
+                SELECT tc.TABLE_NAME, kcu.COLUMN_NAME, kcu.REFERENCED_TABLE_NAME, kcu.REFERENCED_COLUMN_NAME
+                FROM information_schema.TABLE_CONSTRAINTS tc
+                INNER JOIN information_schema.KEY_COLUMN_USAGE kcu
+                    ON tc.CONSTRAINT_NAME = kcu.CONSTRAINT_NAME AND tc.TABLE_SCHEMA = kcu.TABLE_SCHEMA
+                WHERE tc.CONSTRAINT_TYPE = 'FOREIGN KEY' AND tc.TABLE_SCHEMA = DATABASE()
+                "#,
+                &[],
+            )
+            .await?;
+
+        let mut violations = Vec::new();
+
+        for fk in foreign_keys.into_iter() {
+            let table = fk.at(0).and_then(|v| v.to_string()).unwrap_or_default();
+            let column = fk.at(1).and_then(|v| v.to_string()).unwrap_or_default();
+            let referenced_table = fk.at(2).and_then(|v| v.to_string()).unwrap_or_default();
+            let referenced_column = fk.at(3).and_then(|v| v.to_string()).unwrap_or_default();
+
+            let check = format!(
+                "SELECT COUNT(*) FROM `{table}` WHERE `{column}` IS NOT NULL \
+                 AND `{column}` NOT IN (SELECT `{referenced_column}` FROM `{referenced_table}`)"
+            );
+
+            let orphans = self
+                .query_raw(&check, &[])
+                .await?
+                .get(0)
+                .and_then(|row| row.at(0).and_then(|v| v.as_i64()))
+                .unwrap_or(0);
+
+            if orphans > 0 {
+                violations.push(format!(
+                    "{orphans} row(s) in {table}.{column} reference a missing row in {referenced_table}.{referenced_column}"
+                ));
+            }
+        }
+
+        let was_enabled = self.foreign_key_checks_before_disable.lock().await.take().unwrap_or(true);
+        self.raw_cmd(&format!("SET SESSION foreign_key_checks={}", was_enabled as u8))
+            .await?;
+
+        if !violations.is_empty() {
+            return Err(Error::builder(ErrorKind::foreign_key_checks_failed(violations)).build());
+        }
+
+        Ok(())
+    }
+
+    fn statement_timeout_statements(&self, timeout: Duration) -> Option<(String, String)> {
+        Some((
+            format!("SET SESSION MAX_EXECUTION_TIME={}", timeout.as_millis()),
+            "SET SESSION MAX_EXECUTION_TIME=0".to_owned(),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -61,6 +61,9 @@ pub struct PostgreSql {
     socket_timeout: Option<Duration>,
     statement_cache: Mutex<LruCache<String, Statement>>,
     is_healthy: AtomicBool,
+    /// The value of `session_replication_role` captured by `disable_foreign_keys`, restored by
+    /// the matching `enable_and_validate_foreign_keys` call.
+    replication_role_before_disable: Mutex<Option<String>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -650,6 +653,7 @@ impl PostgreSql {
             pg_bouncer: url.query_params.pg_bouncer,
             statement_cache: Mutex::new(url.cache()),
             is_healthy: AtomicBool::new(true),
+            replication_role_before_disable: Mutex::new(None),
         })
     }
 
@@ -930,6 +934,99 @@ impl Queryable for PostgreSql {
     fn requires_isolation_first(&self) -> bool {
         false
     }
+
+    async fn disable_foreign_keys(&self) -> crate::Result<()> {
+        let previous = self.query_raw("SHOW session_replication_role", &[]).await?;
+        let previous_role = previous
+            .get(0)
+            .and_then(|row| row.at(0).and_then(|v| v.to_string()))
+            .unwrap_or_else(|| "origin".to_owned());
+
+        *self.replication_role_before_disable.lock().await = Some(previous_role);
+
+        self.raw_cmd("SET session_replication_role = 'replica'").await
+    }
+
+    async fn enable_and_validate_foreign_keys(&self) -> crate::Result<()> {
+        // Restoring `session_replication_role` only re-enables enforcement for future writes: it
+        // does not revalidate rows loaded while it was off. We look up every foreign key on the
+        // current schema and check it for orphaned rows ourselves to honor the same "report
+        // violations" contract as connectors with native support for this (e.g. SQLite).
+        let foreign_keys = self
+            .query_raw(
+                r#"
+                SELECT tc.table_name, kcu.column_name, ccu.table_name AS referenced_table, ccu.column_name AS referenced_column
+                FROM information_schema.table_constraints tc
+                INNER JOIN information_schema.key_column_usage kcu
+                    ON tc.constraint_name = kcu.constraint_name AND tc.table_schema = kcu.table_schema
+                INNER JOIN information_schema.constraint_column_usage ccu
+                    ON tc.constraint_name = ccu.constraint_name AND tc.table_schema = ccu.table_schema
+                WHERE tc.constraint_type = 'FOREIGN KEY' AND tc.table_schema = current_schema()
+                "#,
+                &[],
+            )
+            .await?;
+
+        let mut violations = Vec::new();
+
+        for fk in foreign_keys.into_iter() {
+            let table = fk.at(0).and_then(|v| v.to_string()).unwrap_or_default();
+            let column = fk.at(1).and_then(|v| v.to_string()).unwrap_or_default();
+            let referenced_table = fk.at(2).and_then(|v| v.to_string()).unwrap_or_default();
+            let referenced_column = fk.at(3).and_then(|v| v.to_string()).unwrap_or_default();
+
+            let check = format!(
+                "SELECT COUNT(*) FROM \"{table}\" WHERE \"{column}\" IS NOT NULL \
+                 AND \"{column}\" NOT IN (SELECT \"{referenced_column}\" FROM \"{referenced_table}\")"
+            );
+
+            let orphans = self
+                .query_raw(&check, &[])
+                .await?
+                .get(0)
+                .and_then(|row| row.at(0).and_then(|v| v.as_i64()))
+                .unwrap_or(0);
+
+            if orphans > 0 {
+                violations.push(format!(
+                    "{orphans} row(s) in {table}.{column} reference a missing row in {referenced_table}.{referenced_column}"
+                ));
+            }
+        }
+
+        let previous_role = self
+            .replication_role_before_disable
+            .lock()
+            .await
+            .take()
+            .unwrap_or_else(|| "origin".to_owned());
+
+        self.raw_cmd(&format!("SET session_replication_role = '{previous_role}'"))
+            .await?;
+
+        if !violations.is_empty() {
+            return Err(Error::builder(ErrorKind::foreign_key_checks_failed(violations)).build());
+        }
+
+        Ok(())
+    }
+
+    fn statement_timeout_statements(&self, timeout: Duration) -> Option<(String, String)> {
+        Some((
+            format!("SET statement_timeout = {}", timeout.as_millis()),
+            "SET statement_timeout = 0".to_owned(),
+        ))
+    }
+
+    async fn set_session_context_value(&self, key: &str, value: &str) -> crate::Result<()> {
+        self.execute_raw(
+            "SELECT set_config($1, $2, true)",
+            &[Value::from(key.to_owned()), Value::from(value.to_owned())],
+        )
+        .await?;
+
+        Ok(())
+    }
 }
 
 /// Sorted list of CockroachDB's reserved keywords.
@@ -1,6 +1,7 @@
 use super::{IsolationLevel, ResultSet, Transaction};
 use crate::ast::*;
 use async_trait::async_trait;
+use std::time::Duration;
 
 pub trait GetRow {
     fn get_result_row(&self) -> crate::Result<Vec<Value<'static>>>;
@@ -91,21 +92,94 @@ pub trait Queryable: Send + Sync {
         "BEGIN"
     }
 
+    /// Statement to create a savepoint with the given name inside the current transaction, so a
+    /// later, failed part of the transaction can be undone with
+    /// [`Queryable::rollback_to_savepoint_statement`] without rolling back the whole transaction.
+    /// `name` is expected to already be a valid, unquoted SQL identifier.
+    fn create_savepoint_statement(&self, name: &str) -> String {
+        format!("SAVEPOINT {name}")
+    }
+
+    /// Statement to undo everything done since the matching [`Queryable::create_savepoint_statement`]
+    /// call, without rolling back the rest of the transaction. `name` is expected to already be a
+    /// valid, unquoted SQL identifier.
+    fn rollback_to_savepoint_statement(&self, name: &str) -> String {
+        format!("ROLLBACK TO SAVEPOINT {name}")
+    }
+
+    /// Statement to discard a savepoint once it's no longer needed, freeing the resources it holds
+    /// for the rest of the transaction. `None` for connectors that don't support releasing a
+    /// savepoint explicitly (e.g. SQL Server) — there, the savepoint is implicitly discarded when
+    /// the transaction it was created in ends. `name` is expected to already be a valid, unquoted
+    /// SQL identifier.
+    fn release_savepoint_statement(&self, name: &str) -> Option<String> {
+        Some(format!("RELEASE SAVEPOINT {name}"))
+    }
+
     /// Sets the transaction isolation level to given value.
     /// Implementers have to make sure that the passed isolation level is valid for the underlying database.
     async fn set_tx_isolation_level(&self, isolation_level: IsolationLevel) -> crate::Result<()>;
 
     /// Signals if the isolation level SET needs to happen before or after the tx BEGIN.
     fn requires_isolation_first(&self) -> bool;
+
+    /// Disables foreign key constraint checking on this connection, so that rows can be
+    /// bulk-loaded out of referential order. Always pair with a call to
+    /// [`Queryable::enable_and_validate_foreign_keys`] once the load is done.
+    ///
+    /// The scope is the current session/connection only; enforcement is unaffected for other
+    /// connections. The default implementation is a no-op, for connectors that don't support it.
+    async fn disable_foreign_keys(&self) -> crate::Result<()> {
+        Ok(())
+    }
+
+    /// Restores foreign key constraint checking to whatever it was before the matching
+    /// [`Queryable::disable_foreign_keys`] call, and validates that nothing loaded in the
+    /// meantime actually violates a constraint. Returns an error describing the violations found,
+    /// if any.
+    ///
+    /// The default implementation is a no-op, for connectors that don't support it.
+    async fn enable_and_validate_foreign_keys(&self) -> crate::Result<()> {
+        Ok(())
+    }
+
+    /// Sets a single session-scoped context variable, read back by row-level security policies to
+    /// scope query results to e.g. the current tenant (Postgres' `set_config`, MSSQL's
+    /// `sp_set_session_context`). The setting is local to this connection and does not outlive it.
+    ///
+    /// Called directly for a plain (non-transactional) connection; [`TransactionCapable::start_transaction`]
+    /// applies a whole batch of these right after `BEGIN` for a transaction, via
+    /// [`TransactionOptions::with_session_context`].
+    ///
+    /// The default implementation is a no-op, for connectors (SQLite, MySQL) with no native
+    /// session context mechanism to translate this to.
+    async fn set_session_context_value(&self, _key: &str, _value: &str) -> crate::Result<()> {
+        Ok(())
+    }
+
+    /// SQL to set a server-side, per-session statement timeout for the given duration, paired with
+    /// the SQL to reset it back to the default afterwards, for connectors that have a session-level
+    /// equivalent (`SET statement_timeout` on Postgres, the `MAX_EXECUTION_TIME` session variable on
+    /// MySQL). `None` for connectors (SQLite, MSSQL) with nothing to translate this to, in which
+    /// case callers can't enforce the timeout at the database level.
+    fn statement_timeout_statements(&self, _timeout: Duration) -> Option<(String, String)> {
+        None
+    }
 }
 
 /// A thing that can start a new transaction.
 #[async_trait]
 pub trait TransactionCapable: Queryable {
-    /// Starts a new transaction
+    /// Starts a new transaction.
+    ///
+    /// `session_context` is applied via [`Queryable::set_session_context_value`] once the
+    /// transaction has begun, in order, before the caller gets to run anything else on it — see
+    /// that method's doc comment for what it's for. Connectors with no session context mechanism
+    /// ignore it, same as [`Queryable::set_session_context_value`]'s default implementation does.
     async fn start_transaction<'a>(
         &'a self,
         isolation: Option<IsolationLevel>,
+        session_context: &[(String, String)],
     ) -> crate::Result<Box<dyn Transaction + 'a>>;
 }
 
@@ -116,8 +190,10 @@ macro_rules! impl_default_TransactionCapable {
             async fn start_transaction<'a>(
                 &'a self,
                 isolation: Option<IsolationLevel>,
+                session_context: &[(String, String)],
             ) -> crate::Result<Box<dyn crate::connector::Transaction + 'a>> {
-                let opts = crate::connector::TransactionOptions::new(isolation, self.requires_isolation_first());
+                let opts = crate::connector::TransactionOptions::new(isolation, self.requires_isolation_first())
+                    .with_session_context(session_context.to_vec());
 
                 Ok(Box::new(
                     crate::connector::DefaultTransaction::new(self, self.begin_statement(), opts).await?,
@@ -23,6 +23,9 @@ pub use rusqlite;
 /// A connector interface for the SQLite database
 pub struct Sqlite {
     pub(crate) client: Mutex<rusqlite::Connection>,
+    /// The value of the `foreign_keys` pragma captured by `disable_foreign_keys`, restored by
+    /// the matching `enable_and_validate_foreign_keys` call.
+    foreign_keys_before_disable: Mutex<Option<bool>>,
 }
 
 /// Wraps a connection url and exposes the parsing logic used by Quaint,
@@ -139,7 +142,10 @@ impl TryFrom<&str> for Sqlite {
 
         let client = Mutex::new(conn);
 
-        Ok(Sqlite { client })
+        Ok(Sqlite {
+            client,
+            foreign_keys_before_disable: Mutex::new(None),
+        })
     }
 }
 
@@ -154,6 +160,7 @@ impl Sqlite {
 
         Ok(Sqlite {
             client: Mutex::new(client),
+            foreign_keys_before_disable: Mutex::new(None),
         })
     }
 
@@ -250,6 +257,45 @@ impl Queryable for Sqlite {
     fn requires_isolation_first(&self) -> bool {
         false
     }
+
+    async fn disable_foreign_keys(&self) -> crate::Result<()> {
+        let previous = self.query_raw("PRAGMA foreign_keys", &[]).await?;
+        let was_enabled = previous
+            .get(0)
+            .and_then(|row| row.at(0).and_then(|v| v.as_bool()))
+            .unwrap_or(true);
+
+        *self.foreign_keys_before_disable.lock().await = Some(was_enabled);
+
+        self.raw_cmd("PRAGMA foreign_keys = OFF").await
+    }
+
+    async fn enable_and_validate_foreign_keys(&self) -> crate::Result<()> {
+        // `foreign_key_check` reports violations regardless of the `foreign_keys` pragma, so we
+        // can run it before restoring enforcement.
+        let violations = self.query_raw("PRAGMA foreign_key_check", &[]).await?;
+
+        let was_enabled = self.foreign_keys_before_disable.lock().await.take().unwrap_or(true);
+        self.raw_cmd(&format!("PRAGMA foreign_keys = {}", if was_enabled { "ON" } else { "OFF" }))
+            .await?;
+
+        if !violations.is_empty() {
+            let messages = violations
+                .into_iter()
+                .map(|row| {
+                    let table = row.at(0).and_then(|v| v.to_string()).unwrap_or_default();
+                    let rowid = row.at(1).and_then(|v| v.as_i64()).map(|i| i.to_string()).unwrap_or_default();
+                    let parent = row.at(2).and_then(|v| v.to_string()).unwrap_or_default();
+
+                    format!("row {rowid} in {table} references missing row in {parent}")
+                })
+                .collect();
+
+            return Err(Error::builder(ErrorKind::foreign_key_checks_failed(messages)).build());
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
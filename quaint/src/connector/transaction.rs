@@ -17,6 +17,29 @@ pub trait Transaction: Queryable {
     /// Rolls back the changes to the database.
     async fn rollback(&self) -> crate::Result<()>;
 
+    /// Creates a savepoint with the given name inside this transaction, so a later, failed part
+    /// of the transaction can be undone with [`Transaction::rollback_to_savepoint`] without
+    /// rolling back (and thereby poisoning) the whole transaction. `name` must already be a
+    /// valid, unquoted SQL identifier — callers control it, it's never derived from user input.
+    async fn create_savepoint(&self, name: &str) -> crate::Result<()> {
+        self.raw_cmd(&self.create_savepoint_statement(name)).await
+    }
+
+    /// Undoes everything done since the matching [`Transaction::create_savepoint`] call, without
+    /// rolling back the rest of the transaction.
+    async fn rollback_to_savepoint(&self, name: &str) -> crate::Result<()> {
+        self.raw_cmd(&self.rollback_to_savepoint_statement(name)).await
+    }
+
+    /// Discards a savepoint once it's no longer needed. A no-op on connectors that don't support
+    /// releasing a savepoint explicitly (see [`Queryable::release_savepoint_statement`]).
+    async fn release_savepoint(&self, name: &str) -> crate::Result<()> {
+        match self.release_savepoint_statement(name) {
+            Some(stmt) => self.raw_cmd(&stmt).await,
+            None => Ok(()),
+        }
+    }
+
     /// workaround for lack of upcasting between traits https://github.com/rust-lang/rust/issues/65991
     fn as_queryable(&self) -> &dyn Queryable;
 }
@@ -27,6 +50,10 @@ pub(crate) struct TransactionOptions {
 
     /// Whether or not to put the isolation level `SET` before or after the `BEGIN`.
     pub(crate) isolation_first: bool,
+
+    /// Session-scoped context values (see [`Queryable::set_session_context_value`]) to set right
+    /// after the transaction begins, e.g. for row-level security policies to key off of.
+    pub(crate) session_context: Vec<(String, String)>,
 }
 
 /// A default representation of an SQL database transaction. If not commited, a
@@ -60,6 +87,13 @@ impl<'a> DefaultTransaction<'a> {
             }
         }
 
+        // Applied inside the transaction, right after BEGIN, so it's visible to every statement
+        // the caller runs on it and (on connectors like Postgres whose implementation scopes the
+        // setting to the current transaction) is cleared automatically when the transaction ends.
+        for (key, value) in &tx_opts.session_context {
+            inner.set_session_context_value(key, value).await?;
+        }
+
         inner.server_reset_query(&this).await?;
 
         increment_gauge!("prisma_client_queries_active", 1.0);
@@ -135,6 +169,18 @@ impl<'a> Queryable for DefaultTransaction<'a> {
     fn requires_isolation_first(&self) -> bool {
         self.inner.requires_isolation_first()
     }
+
+    fn create_savepoint_statement(&self, name: &str) -> String {
+        self.inner.create_savepoint_statement(name)
+    }
+
+    fn rollback_to_savepoint_statement(&self, name: &str) -> String {
+        self.inner.rollback_to_savepoint_statement(name)
+    }
+
+    fn release_savepoint_statement(&self, name: &str) -> Option<String> {
+        self.inner.release_savepoint_statement(name)
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -194,6 +240,12 @@ impl TransactionOptions {
         Self {
             isolation_level,
             isolation_first,
+            session_context: Vec::new(),
         }
     }
+
+    pub fn with_session_context(mut self, session_context: Vec<(String, String)>) -> Self {
+        self.session_context = session_context;
+        self
+    }
 }
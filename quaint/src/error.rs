@@ -277,6 +277,12 @@ pub enum ErrorKind {
 
     #[error("External error id#{}", _0)]
     ExternalError(i32),
+
+    #[error("Failed to initialize a new connection: {}", _0)]
+    ConnectionInitializationFailed(String),
+
+    #[error("Foreign key constraints violated while checks were disabled: {}", violations.join("; "))]
+    ForeignKeyChecksFailed { violations: Vec<String> },
 }
 
 impl ErrorKind {
@@ -306,6 +312,10 @@ impl ErrorKind {
     pub fn invalid_isolation_level(isolation_level: &IsolationLevel) -> Self {
         Self::InvalidIsolationLevel(isolation_level.to_string())
     }
+
+    pub(crate) fn foreign_key_checks_failed(violations: Vec<String>) -> Self {
+        Self::ForeignKeyChecksFailed { violations }
+    }
 }
 
 impl From<Error> for ErrorKind {
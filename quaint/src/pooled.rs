@@ -307,11 +307,24 @@ impl Builder {
             url.set_flavour(flavour);
         }
 
-        if let QuaintManager::Postgres { ref mut url } = self.manager {
+        if let QuaintManager::Postgres { ref mut url, .. } = self.manager {
             url.set_flavour(flavour);
         }
     }
 
+    /// Statements to run against every connection immediately after it is opened, before it is
+    /// handed out to a caller, in the order given. Useful for session-scoped settings that aren't
+    /// exposed as connection string parameters (e.g. `SET search_path`, PRAGMAs, ...).
+    ///
+    /// If any statement fails, the connection is discarded and acquiring it (`check_out`, or the
+    /// first query on a fresh pool) returns the error instead of handing out a misconfigured
+    /// connection.
+    ///
+    /// - Defaults to an empty list, meaning no statements are run.
+    pub fn set_init_sql(&mut self, statements: Vec<String>) {
+        self.manager.set_init_sql(statements);
+    }
+
     /// Consume the builder and create a new instance of a pool.
     pub fn build(self) -> Quaint {
         let connection_info = Arc::new(self.connection_info);
@@ -361,6 +374,7 @@ impl Quaint {
                 let manager = QuaintManager::Sqlite {
                     url: s.to_string(),
                     db_name: params.db_name,
+                    init_sql: Vec::new(),
                 };
 
                 let mut builder = Builder::new(s, manager)?;
@@ -387,7 +401,10 @@ impl Quaint {
                 let max_connection_lifetime = url.max_connection_lifetime();
                 let max_idle_connection_lifetime = url.max_idle_connection_lifetime();
 
-                let manager = QuaintManager::Mysql { url };
+                let manager = QuaintManager::Mysql {
+                    url,
+                    init_sql: Vec::new(),
+                };
                 let mut builder = Builder::new(s, manager)?;
 
                 if let Some(limit) = connection_limit {
@@ -416,7 +433,10 @@ impl Quaint {
                 let max_connection_lifetime = url.max_connection_lifetime();
                 let max_idle_connection_lifetime = url.max_idle_connection_lifetime();
 
-                let manager = QuaintManager::Postgres { url };
+                let manager = QuaintManager::Postgres {
+                    url,
+                    init_sql: Vec::new(),
+                };
                 let mut builder = Builder::new(s, manager)?;
 
                 if let Some(limit) = connection_limit {
@@ -445,7 +465,10 @@ impl Quaint {
                 let max_connection_lifetime = url.max_connection_lifetime();
                 let max_idle_connection_lifetime = url.max_idle_connection_lifetime();
 
-                let manager = QuaintManager::Mssql { url };
+                let manager = QuaintManager::Mssql {
+                    url,
+                    init_sql: Vec::new(),
+                };
                 let mut builder = Builder::new(s, manager)?;
 
                 if let Some(limit) = connection_limit {
@@ -78,16 +78,62 @@ impl Queryable for PooledConnection {
 #[doc(hidden)]
 pub enum QuaintManager {
     #[cfg(feature = "mysql")]
-    Mysql { url: MysqlUrl },
+    Mysql { url: MysqlUrl, init_sql: Vec<String> },
 
     #[cfg(feature = "postgresql")]
-    Postgres { url: PostgresUrl },
+    Postgres { url: PostgresUrl, init_sql: Vec<String> },
 
     #[cfg(feature = "sqlite")]
-    Sqlite { url: String, db_name: String },
+    Sqlite {
+        url: String,
+        db_name: String,
+        init_sql: Vec<String>,
+    },
 
     #[cfg(feature = "mssql")]
-    Mssql { url: MssqlUrl },
+    Mssql { url: MssqlUrl, init_sql: Vec<String> },
+}
+
+impl QuaintManager {
+    /// The statements to run against every freshly opened connection, in order, before it is
+    /// handed out to a caller. Set through [`Builder::set_init_sql`].
+    ///
+    /// [`Builder::set_init_sql`]: crate::pooled::Builder::set_init_sql
+    fn init_sql(&self) -> &[String] {
+        match self {
+            #[cfg(feature = "mysql")]
+            QuaintManager::Mysql { init_sql, .. } => init_sql,
+
+            #[cfg(feature = "postgresql")]
+            QuaintManager::Postgres { init_sql, .. } => init_sql,
+
+            #[cfg(feature = "sqlite")]
+            QuaintManager::Sqlite { init_sql, .. } => init_sql,
+
+            #[cfg(feature = "mssql")]
+            QuaintManager::Mssql { init_sql, .. } => init_sql,
+        }
+    }
+
+    /// Sets the statements to run against every freshly opened connection. See
+    /// [`Builder::set_init_sql`].
+    ///
+    /// [`Builder::set_init_sql`]: crate::pooled::Builder::set_init_sql
+    pub(crate) fn set_init_sql(&mut self, statements: Vec<String>) {
+        match self {
+            #[cfg(feature = "mysql")]
+            QuaintManager::Mysql { init_sql, .. } => *init_sql = statements,
+
+            #[cfg(feature = "postgresql")]
+            QuaintManager::Postgres { init_sql, .. } => *init_sql = statements,
+
+            #[cfg(feature = "sqlite")]
+            QuaintManager::Sqlite { init_sql, .. } => *init_sql = statements,
+
+            #[cfg(feature = "mssql")]
+            QuaintManager::Mssql { init_sql, .. } => *init_sql = statements,
+        }
+    }
 }
 
 #[async_trait]
@@ -107,19 +153,19 @@ impl Manager for QuaintManager {
             }
 
             #[cfg(feature = "mysql")]
-            QuaintManager::Mysql { url } => {
+            QuaintManager::Mysql { url, .. } => {
                 use crate::connector::Mysql;
                 Ok(Box::new(Mysql::new(url.clone()).await?) as Self::Connection)
             }
 
             #[cfg(feature = "postgresql")]
-            QuaintManager::Postgres { url } => {
+            QuaintManager::Postgres { url, .. } => {
                 use crate::connector::PostgreSql;
                 Ok(Box::new(PostgreSql::new(url.clone()).await?) as Self::Connection)
             }
 
             #[cfg(feature = "mssql")]
-            QuaintManager::Mssql { url } => {
+            QuaintManager::Mssql { url, .. } => {
                 use crate::connector::Mssql;
                 Ok(Box::new(Mssql::new(url.clone()).await?) as Self::Connection)
             }
@@ -128,7 +174,18 @@ impl Manager for QuaintManager {
         conn.iter()
             .for_each(|_| tracing::debug!("Acquired database connection."));
 
-        conn
+        let conn = conn?;
+
+        for statement in self.init_sql() {
+            conn.raw_cmd(statement).await.map_err(|err| {
+                Error::builder(crate::error::ErrorKind::ConnectionInitializationFailed(
+                    err.to_string(),
+                ))
+                .build()
+            })?;
+        }
+
+        Ok(conn)
     }
 
     async fn check(&self, conn: Self::Connection) -> crate::Result<Self::Connection> {
@@ -231,4 +288,34 @@ mod tests {
 
         assert_eq!(10, pool.capacity().await as usize);
     }
+
+    #[tokio::test]
+    #[cfg(feature = "sqlite")]
+    async fn init_sql_runs_on_every_fresh_connection() {
+        let conn_string = "file:db/test.db".to_string();
+        let mut builder = Quaint::builder(&conn_string).unwrap();
+
+        builder.set_init_sql(vec!["PRAGMA foreign_keys = OFF".to_string()]);
+
+        let pool = builder.build();
+        let conn = pool.check_out().await.unwrap();
+
+        let result = conn.query_raw("PRAGMA foreign_keys", &[]).await.unwrap();
+        let row = result.into_single().unwrap();
+
+        assert_eq!(Some(0), row[0].as_i64());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "sqlite")]
+    async fn failing_init_sql_fails_the_check_out() {
+        let conn_string = "file:db/test.db".to_string();
+        let mut builder = Quaint::builder(&conn_string).unwrap();
+
+        builder.set_init_sql(vec!["SELECT * FROM this_table_does_not_exist".to_string()]);
+
+        let pool = builder.build();
+
+        assert!(pool.check_out().await.is_err());
+    }
 }
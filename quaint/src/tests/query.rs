@@ -64,7 +64,7 @@ async fn select_star_from(api: &mut dyn TestApi) -> crate::Result<()> {
 async fn transactions(api: &mut dyn TestApi) -> crate::Result<()> {
     let table = api.create_temp_table("value int").await?;
 
-    let tx = api.conn().start_transaction(None).await?;
+    let tx = api.conn().start_transaction(None, &[]).await?;
     let insert = Insert::single_into(&table).value("value", 10);
 
     let rows_affected = tx.execute(insert.into()).await?;
@@ -89,25 +89,25 @@ async fn transactions(api: &mut dyn TestApi) -> crate::Result<()> {
 async fn transactions_with_isolation_works(api: &mut dyn TestApi) -> crate::Result<()> {
     // This test only tests that the SET isolation level statements are accepted.
     api.conn()
-        .start_transaction(Some(IsolationLevel::ReadUncommitted))
+        .start_transaction(Some(IsolationLevel::ReadUncommitted), &[])
         .await?
         .commit()
         .await?;
 
     api.conn()
-        .start_transaction(Some(IsolationLevel::ReadCommitted))
+        .start_transaction(Some(IsolationLevel::ReadCommitted), &[])
         .await?
         .commit()
         .await?;
 
     api.conn()
-        .start_transaction(Some(IsolationLevel::RepeatableRead))
+        .start_transaction(Some(IsolationLevel::RepeatableRead), &[])
         .await?
         .commit()
         .await?;
 
     api.conn()
-        .start_transaction(Some(IsolationLevel::Serializable))
+        .start_transaction(Some(IsolationLevel::Serializable), &[])
         .await?
         .commit()
         .await?;
@@ -119,7 +119,7 @@ async fn transactions_with_isolation_works(api: &mut dyn TestApi) -> crate::Resu
 #[test_each_connector(tags("sqlite"))]
 async fn sqlite_serializable_tx(api: &mut dyn TestApi) -> crate::Result<()> {
     api.conn()
-        .start_transaction(Some(IsolationLevel::Serializable))
+        .start_transaction(Some(IsolationLevel::Serializable), &[])
         .await?
         .commit()
         .await?;
@@ -131,7 +131,7 @@ async fn sqlite_serializable_tx(api: &mut dyn TestApi) -> crate::Result<()> {
 #[test_each_connector(tags("mssql"))]
 async fn mssql_snapshot_tx(api: &mut dyn TestApi) -> crate::Result<()> {
     api.conn()
-        .start_transaction(Some(IsolationLevel::Snapshot))
+        .start_transaction(Some(IsolationLevel::Snapshot), &[])
         .await?
         .commit()
         .await?;
@@ -139,6 +139,122 @@ async fn mssql_snapshot_tx(api: &mut dyn TestApi) -> crate::Result<()> {
     Ok(())
 }
 
+#[test_each_connector(tags("postgresql"))]
+async fn set_session_context_value_is_readable_back_on_postgres(api: &mut dyn TestApi) -> crate::Result<()> {
+    api.conn()
+        .set_session_context_value("app.current_tenant", "acme")
+        .await?;
+
+    let row = api
+        .conn()
+        .query_raw("SELECT current_setting('app.current_tenant') AS value", &[])
+        .await?
+        .into_single()?;
+
+    assert_eq!(Some("acme"), row["value"].as_str());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mssql"))]
+async fn set_session_context_value_is_readable_back_on_mssql(api: &mut dyn TestApi) -> crate::Result<()> {
+    api.conn()
+        .set_session_context_value("app.current_tenant", "acme")
+        .await?;
+
+    let row = api
+        .conn()
+        .query_raw(
+            "SELECT CAST(SESSION_CONTEXT(N'app.current_tenant') AS NVARCHAR(MAX)) AS value",
+            &[],
+        )
+        .await?
+        .into_single()?;
+
+    assert_eq!(Some("acme"), row["value"].as_str());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgresql"))]
+async fn start_transaction_applies_session_context_on_postgres(api: &mut dyn TestApi) -> crate::Result<()> {
+    let session_context = vec![("app.current_tenant".to_owned(), "acme".to_owned())];
+    let tx = api.conn().start_transaction(None, &session_context).await?;
+
+    let row = tx
+        .query_raw("SELECT current_setting('app.current_tenant') AS value", &[])
+        .await?
+        .into_single()?;
+
+    assert_eq!(Some("acme"), row["value"].as_str());
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mssql"))]
+async fn start_transaction_applies_session_context_on_mssql(api: &mut dyn TestApi) -> crate::Result<()> {
+    let session_context = vec![("app.current_tenant".to_owned(), "acme".to_owned())];
+    let tx = api.conn().start_transaction(None, &session_context).await?;
+
+    let row = tx
+        .query_raw(
+            "SELECT CAST(SESSION_CONTEXT(N'app.current_tenant') AS NVARCHAR(MAX)) AS value",
+            &[],
+        )
+        .await?
+        .into_single()?;
+
+    assert_eq!(Some("acme"), row["value"].as_str());
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn disabling_foreign_keys_allows_loading_rows_out_of_order(api: &mut dyn TestApi) -> crate::Result<()> {
+    let id_column = api.autogen_id("id");
+    let parent = api.create_temp_table(&id_column).await?;
+    let fk = api.foreign_key(&parent, "id", "parent_id");
+    let child = api.create_temp_table(&format!("id int, parent_id int, {fk}")).await?;
+
+    api.conn().disable_foreign_keys().await?;
+
+    // This would fail with a foreign key violation if checks were still enabled: no row with
+    // `id = 1` exists yet in the parent table.
+    let insert = Insert::single_into(&child).value("id", 1).value("parent_id", 1);
+    api.conn().execute(insert.into()).await?;
+
+    let insert = Insert::single_into(&parent).value("id", 1);
+    api.conn().execute(insert.into()).await?;
+
+    api.conn().enable_and_validate_foreign_keys().await?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn reenabling_foreign_keys_reports_violations_left_by_the_bulk_load(api: &mut dyn TestApi) -> crate::Result<()> {
+    let id_column = api.autogen_id("id");
+    let parent = api.create_temp_table(&id_column).await?;
+    let fk = api.foreign_key(&parent, "id", "parent_id");
+    let child = api.create_temp_table(&format!("id int, parent_id int, {fk}")).await?;
+
+    api.conn().disable_foreign_keys().await?;
+
+    // The referenced parent row is never inserted, so this is left dangling.
+    let insert = Insert::single_into(&child).value("id", 1).value("parent_id", 999);
+    api.conn().execute(insert.into()).await?;
+
+    let err = api.conn().enable_and_validate_foreign_keys().await.unwrap_err();
+
+    assert!(matches!(err.kind(), ErrorKind::ForeignKeyChecksFailed { .. }));
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn in_values_singular(api: &mut dyn TestApi) -> crate::Result<()> {
     let table = api.create_temp_table("id int, id2 int").await?;
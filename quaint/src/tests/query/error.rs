@@ -425,7 +425,7 @@ async fn array_into_scalar_should_fail(api: &mut dyn TestApi) -> crate::Result<(
 async fn sqlite_isolation_error(api: &mut dyn TestApi) -> crate::Result<()> {
     let res = api
         .conn()
-        .start_transaction(Some(IsolationLevel::ReadUncommitted))
+        .start_transaction(Some(IsolationLevel::ReadUncommitted), &[])
         .await;
 
     let err = res.err().expect("SQLite must fail on isolation != SERIALIZABLE");
@@ -437,7 +437,7 @@ async fn sqlite_isolation_error(api: &mut dyn TestApi) -> crate::Result<()> {
 // Postgres and MySQL error on Snapshot.
 #[test_each_connector(tags("postgresql", "mysql"))]
 async fn snapshot_isolation_error(api: &mut dyn TestApi) -> crate::Result<()> {
-    let res = api.conn().start_transaction(Some(IsolationLevel::Snapshot)).await;
+    let res = api.conn().start_transaction(Some(IsolationLevel::Snapshot), &[]).await;
 
     let err = res.err().expect("Postgres/MySQL must fail on isolation SNAPSHOT");
     assert_eq!(err.to_string(), "Invalid isolation level: SNAPSHOT");
@@ -457,8 +457,8 @@ async fn concurrent_transaction_conflict(api: &mut dyn TestApi) -> crate::Result
     let conn1 = api.create_additional_connection().await?;
     let conn2 = api.create_additional_connection().await?;
 
-    let tx1 = conn1.start_transaction(Some(IsolationLevel::Serializable)).await?;
-    let tx2 = conn2.start_transaction(Some(IsolationLevel::Serializable)).await?;
+    let tx1 = conn1.start_transaction(Some(IsolationLevel::Serializable), &[]).await?;
+    let tx2 = conn2.start_transaction(Some(IsolationLevel::Serializable), &[]).await?;
 
     tx1.query(Select::from_table(&table).into()).await?;
     tx2.query(Select::from_table(&table).into()).await?;
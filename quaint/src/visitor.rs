@@ -336,8 +336,19 @@ pub trait Visitor<'a> {
         Ok(())
     }
 
-    /// A walk through an `UPDATE` statement
+    /// A walk through an `UPDATE` statement. Connectors whose `UPDATE` syntax diverges from the
+    /// ANSI-ish `UPDATE ... SET ... FROM ... WHERE ...` shape rendered here (MySQL's join-based
+    /// `UPDATE`, or a connector version too old to support `FROM` at all) override this method;
+    /// [`Self::render_update`] holds the shared rendering so such overrides can still reuse it for
+    /// the parts they don't need to change.
     fn visit_update(&mut self, update: Update<'a>) -> Result {
+        self.render_update(update)
+    }
+
+    /// The default `UPDATE` rendering, factored out of [`Self::visit_update`] so connectors that
+    /// override it for one reason (e.g. gating `FROM` behind a minimum version) can still fall
+    /// back to it instead of duplicating the whole statement.
+    fn render_update(&mut self, update: Update<'a>) -> Result {
         self.write("UPDATE ")?;
         self.visit_table(update.table, true)?;
 
@@ -357,7 +368,18 @@ pub trait Visitor<'a> {
             }
         }
 
-        if let Some(conditions) = update.conditions {
+        if let Some(from) = update.from {
+            self.write(" FROM ")?;
+            self.visit_table(from.table, true)?;
+
+            let conditions = match update.conditions {
+                Some(conditions) => from.conditions.and(conditions),
+                None => from.conditions,
+            };
+
+            self.write(" WHERE ")?;
+            self.visit_conditions(conditions)?;
+        } else if let Some(conditions) = update.conditions {
             self.write(" WHERE ")?;
             self.visit_conditions(conditions)?;
         }
@@ -409,8 +431,19 @@ pub trait Visitor<'a> {
         Ok(())
     }
 
-    /// A walk through an `DELETE` statement
+    /// A walk through an `DELETE` statement. Connectors whose `DELETE` can't take the ANSI-ish
+    /// `DELETE FROM ... WHERE ... RETURNING ...` shape rendered here (e.g. MSSQL, where a returned
+    /// selection has to be an `OUTPUT` clause between the table and `WHERE`) override this method;
+    /// [`Self::render_delete`] holds the shared rendering so such overrides can still reuse it for
+    /// the parts they don't need to change.
     fn visit_delete(&mut self, delete: Delete<'a>) -> Result {
+        self.render_delete(delete)
+    }
+
+    /// The default `DELETE` rendering, factored out of [`Self::visit_delete`] so connectors that
+    /// override it for one reason (e.g. rejecting an unsupported `returning`) can still fall back
+    /// to it instead of duplicating the whole statement.
+    fn render_delete(&mut self, delete: Delete<'a>) -> Result {
         self.write("DELETE FROM ")?;
         self.visit_table(delete.table, true)?;
 
@@ -419,6 +452,14 @@ pub trait Visitor<'a> {
             self.visit_conditions(conditions)?;
         }
 
+        if let Some(returning) = delete.returning {
+            if !returning.is_empty() {
+                let values = returning.into_iter().map(|r| r.into()).collect();
+                self.write(" RETURNING ")?;
+                self.visit_columns(values)?;
+            }
+        }
+
         if let Some(comment) = delete.comment {
             self.write(" ")?;
             self.visit_comment(comment)?;
@@ -642,6 +683,17 @@ pub trait Visitor<'a> {
             };
         }
 
+        if let Some(hint) = table.index_hint {
+            self.visit_index_hint(hint)?;
+        }
+
+        Ok(())
+    }
+
+    /// Render an index hint attached to a table reference (see [`IndexHint`]). Connectors that
+    /// have no equivalent syntax silently ignore it; the caller is responsible for deciding
+    /// whether that silence deserves a warning.
+    fn visit_index_hint(&mut self, _hint: IndexHint<'a>) -> Result {
         Ok(())
     }
 
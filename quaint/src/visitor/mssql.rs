@@ -3,8 +3,8 @@ use super::Visitor;
 use crate::prelude::{JsonExtract, JsonType, JsonUnquote};
 use crate::{
     ast::{
-        Column, Comparable, Expression, ExpressionKind, Insert, IntoRaw, Join, JoinData, Joinable, Merge, OnConflict,
-        Order, Ordering, Row, Table, TypeDataLength, TypeFamily, Values,
+        Column, Comparable, Delete, Expression, ExpressionKind, IndexHint, Insert, IntoRaw, Join, JoinData, Joinable,
+        Merge, OnConflict, Order, Ordering, Row, Table, TypeDataLength, TypeFamily, Update, Values,
     },
     error::{Error, ErrorKind},
     prelude::{Aliasable, Average, Query},
@@ -45,6 +45,32 @@ impl<'a> Mssql<'a> {
         Ok(())
     }
 
+    /// Renders an `OUTPUT` clause that reads straight off the row(s) affected by an `UPDATE` or
+    /// `DELETE`, using MSSQL's `Inserted`/`Deleted` pseudo-tables. Unlike [`Self::visit_returning`]
+    /// (used for `INSERT`/`MERGE`), this doesn't route through the `@generated_keys` table
+    /// variable: that trick joins the output back onto the target table by its returned columns to
+    /// work around triggers on the table, which only works because the row still exists there
+    /// afterwards. For a `DELETE` the row is gone by the time we'd join back to it, so we read the
+    /// `Inserted`/`Deleted` pseudo-tables directly instead. This does mean a table with an `AFTER`
+    /// trigger can't combine `UPDATE`/`DELETE` with a returned selection on MSSQL, which is a
+    /// documented MSSQL limitation, not something we can work around here.
+    fn visit_output(&mut self, columns: Vec<Column<'a>>, pseudo_table: &'static str) -> visitor::Result {
+        let cols: Vec<_> = columns.into_iter().map(|c| c.table(pseudo_table)).collect();
+
+        self.write(" OUTPUT ")?;
+
+        let len = cols.len();
+        for (i, value) in cols.into_iter().enumerate() {
+            self.visit_column(value)?;
+
+            if i < (len - 1) {
+                self.write(",")?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn visit_type_family(&mut self, type_family: TypeFamily) -> visitor::Result {
         match type_family {
             TypeFamily::Text(len) => {
@@ -203,6 +229,92 @@ impl<'a> Visitor<'a> for Mssql<'a> {
         Ok(())
     }
 
+    fn visit_index_hint(&mut self, hint: IndexHint<'a>) -> visitor::Result {
+        self.write(" WITH (INDEX(")?;
+        self.delimited_identifiers(&[hint.index_name()])?;
+        self.write("))")
+    }
+
+    fn visit_update(&mut self, mut update: Update<'a>) -> visitor::Result {
+        // The default `render_update` appends `RETURNING` at the end of the statement, which isn't
+        // valid T-SQL. When there's nothing to return we can just fall back to it unchanged; when
+        // there is, `OUTPUT Inserted.col` has to go between the `SET` clause and `FROM`/`WHERE`.
+        let Some(returning) = update.returning.take() else {
+            return self.render_update(update);
+        };
+
+        self.write("UPDATE ")?;
+        self.visit_table(update.table, true)?;
+        self.write(" SET ")?;
+
+        let pairs = update.columns.into_iter().zip(update.values);
+        let len = pairs.len();
+
+        for (i, (key, value)) in pairs.enumerate() {
+            self.visit_column(key)?;
+            self.write(" = ")?;
+            self.visit_expression(value)?;
+
+            if i < (len - 1) {
+                self.write(", ")?;
+            }
+        }
+
+        if !returning.is_empty() {
+            self.visit_output(returning, "Inserted")?;
+        }
+
+        if let Some(from) = update.from {
+            self.write(" FROM ")?;
+            self.visit_table(from.table, true)?;
+
+            let conditions = match update.conditions {
+                Some(conditions) => from.conditions.and(conditions),
+                None => from.conditions,
+            };
+
+            self.write(" WHERE ")?;
+            self.visit_conditions(conditions)?;
+        } else if let Some(conditions) = update.conditions {
+            self.write(" WHERE ")?;
+            self.visit_conditions(conditions)?;
+        }
+
+        if let Some(comment) = update.comment {
+            self.write(" ")?;
+            self.visit_comment(comment)?;
+        }
+
+        Ok(())
+    }
+
+    fn visit_delete(&mut self, mut delete: Delete<'a>) -> visitor::Result {
+        // Same reasoning as `visit_update`: `OUTPUT Deleted.col` has to sit between the table name
+        // and `WHERE`, not trail the statement like `RETURNING` does in the default renderer.
+        let Some(returning) = delete.returning.take() else {
+            return self.render_delete(delete);
+        };
+
+        self.write("DELETE FROM ")?;
+        self.visit_table(delete.table, true)?;
+
+        if !returning.is_empty() {
+            self.visit_output(returning, "Deleted")?;
+        }
+
+        if let Some(conditions) = delete.conditions {
+            self.write(" WHERE ")?;
+            self.visit_conditions(conditions)?;
+        }
+
+        if let Some(comment) = delete.comment {
+            self.write(" ")?;
+            self.visit_comment(comment)?;
+        }
+
+        Ok(())
+    }
+
     fn add_parameter(&mut self, value: Value<'a>) {
         self.parameters.push(value)
     }
@@ -217,10 +329,14 @@ impl<'a> Visitor<'a> for Mssql<'a> {
                 .convert_tuple_selects_to_ctes(true, &mut 0)
                 .expect_left("Top-level query was right")
                 .into(),
-            // Replacing the `ON CONFLICT DO NOTHING` clause with a `MERGE` statement.
-            Query::Insert(insert) => match insert.on_conflict {
+            // Replacing an `ON CONFLICT` clause with a `MERGE` statement, MSSQL's only way to
+            // express "do nothing" or "do update" on a conflicting insert.
+            Query::Insert(mut insert) => match insert.on_conflict.take() {
                 Some(OnConflict::DoNothing) => Merge::try_from(*insert).unwrap().into(),
-                _ => Query::Insert(insert),
+                Some(OnConflict::Update(update, _constraints)) => {
+                    Merge::try_from_insert_on_conflict_update(*insert, update).unwrap().into()
+                }
+                None => Query::Insert(insert),
             },
             _ => query,
         }
@@ -507,6 +623,21 @@ impl<'a> Visitor<'a> for Mssql<'a> {
         self.write(" ON ")?;
         self.visit_conditions(merge.using.on_conditions)?;
 
+        if let Some(assignments) = merge.when_matched_update {
+            self.write(" WHEN MATCHED THEN UPDATE SET ")?;
+
+            let len = assignments.len();
+            for (i, (column, value)) in assignments.into_iter().enumerate() {
+                self.visit_column(column)?;
+                self.write(" = ")?;
+                self.visit_expression(value)?;
+
+                if i < (len - 1) {
+                    self.write(", ")?;
+                }
+            }
+        }
+
         if let Some(query) = merge.when_not_matched {
             self.write(" WHEN NOT MATCHED THEN ")?;
             self.visit_query(query)?;
@@ -765,6 +896,16 @@ mod tests {
         assert_eq!(default_params(vec![]), params);
     }
 
+    #[test]
+    fn test_select_with_forced_index() {
+        let expected_sql = "SELECT [musti].* FROM [musti] WITH (INDEX([musti_name_idx]))";
+        let query = Select::from_table(Table::from("musti").force_index("musti_name_idx"));
+        let (sql, params) = Mssql::build(query).unwrap();
+
+        assert_eq!(expected_sql, sql);
+        assert_eq!(default_params(vec![]), params);
+    }
+
     #[test]
     fn test_in_values() {
         use crate::{col, values};
@@ -1349,6 +1490,82 @@ mod tests {
         assert_eq!(vec![Value::from("lol"), Value::from("meow")], params);
     }
 
+    #[test]
+    fn test_single_insert_conflict_do_update_single_unique() {
+        let table = Table::from("foo").add_unique_index("bar");
+
+        let insert: Insert<'_> = Insert::single_into(table)
+            .value(("foo", "bar"), "lol")
+            .value(("foo", "wtf"), "meow")
+            .into();
+
+        let update = Update::table("foo").set("wtf", "purr");
+        let query = insert.on_conflict(OnConflict::Update(update, Vec::from(["bar".into()])));
+
+        let (sql, params) = Mssql::build(query).unwrap();
+
+        let expected_sql = indoc!(
+            "
+            MERGE INTO [foo]
+            USING (SELECT @P1 AS [bar], @P2 AS [wtf]) AS [dual] ([bar],[wtf])
+            ON [dual].[bar] = [foo].[bar]
+            WHEN MATCHED THEN UPDATE SET [wtf] = @P3
+            WHEN NOT MATCHED THEN
+            INSERT ([bar],[wtf]) VALUES ([dual].[bar],[dual].[wtf]);
+        "
+        );
+
+        assert_eq!(expected_sql.replace('\n', " ").trim(), sql);
+        assert_eq!(
+            vec![Value::from("lol"), Value::from("meow"), Value::from("purr")],
+            params
+        );
+    }
+
+    #[test]
+    fn test_single_insert_conflict_update_with_returning_clause() {
+        let table = Table::from("foo").add_unique_index("bar");
+
+        let insert: Insert<'_> = Insert::single_into(table)
+            .value(("foo", "bar"), "lol")
+            .value(("foo", "wtf"), "meow")
+            .into();
+
+        let update = Update::table("foo").set("wtf", "purr");
+        let query = insert
+            .on_conflict(OnConflict::Update(update, Vec::from(["bar".into()])))
+            .returning(vec![("foo", "bar"), ("foo", "wtf")]);
+
+        let (sql, params) = Mssql::build(query).unwrap();
+
+        // The `OUTPUT` clause has to apply regardless of which branch of the `MERGE` fires: it
+        // reads `Inserted`, MSSQL's pseudo-table holding the row's post-image for both an `INSERT`
+        // and an `UPDATE`, so the matched (update) branch is returned correctly, not just the
+        // not-matched (insert) one.
+        let expected_sql = indoc!(
+            "
+            DECLARE @generated_keys table([bar] NVARCHAR(255),[wtf] NVARCHAR(255))
+            MERGE INTO [foo]
+            USING (SELECT @P1 AS [bar], @P2 AS [wtf]) AS [dual] ([bar],[wtf])
+            ON [dual].[bar] = [foo].[bar]
+            WHEN MATCHED THEN UPDATE SET [wtf] = @P3
+            WHEN NOT MATCHED THEN
+            INSERT ([bar],[wtf]) VALUES ([dual].[bar],[dual].[wtf])
+            OUTPUT [Inserted].[bar],[Inserted].[wtf] INTO @generated_keys;
+            SELECT [t].[bar],[t].[wtf] FROM @generated_keys AS g
+            INNER JOIN [foo] AS [t]
+            ON ([t].[bar] = [g].[bar] AND [t].[wtf] = [g].[wtf])
+            WHERE @@ROWCOUNT > 0
+        "
+        );
+
+        assert_eq!(expected_sql.replace('\n', " ").trim(), sql);
+        assert_eq!(
+            vec![Value::from("lol"), Value::from("meow"), Value::from("purr")],
+            params
+        );
+    }
+
     #[test]
     fn test_single_insert_conflict_do_nothing_single_unique_with_default() {
         let unique_column = Column::from("bar").default("purr");
@@ -1638,6 +1855,23 @@ mod tests {
         assert_eq!(expected_sql, sql);
     }
 
+    #[test]
+    fn test_update_from_join() {
+        let expected_sql =
+            "UPDATE [users] SET [name] = [accounts].[name] FROM [accounts] WHERE [users].[account_id] = [accounts].[id]";
+
+        let query = Update::table("users")
+            .set("name", Column::from(("accounts", "name")))
+            .from_table(
+                "accounts",
+                Column::from(("users", "account_id")).equals(Column::from(("accounts", "id"))),
+            );
+
+        let (sql, _) = Mssql::build(query).unwrap();
+
+        assert_eq!(expected_sql, sql);
+    }
+
     #[test]
     fn test_comment_insert() {
         let expected_sql = "INSERT INTO [users] DEFAULT VALUES /* trace_id='5bd66ef5095369c7b0d1f8f4bd33716a', parent_id='c532cb4098ac3dd2' */";
@@ -1802,4 +2036,30 @@ mod tests {
             sql
         );
     }
+
+    #[test]
+    fn test_returning_update() {
+        let update = Update::table("users")
+            .set("foo", 10)
+            .so_that("id".equals(1))
+            .returning(vec!["id", "foo"]);
+
+        let (sql, params) = Mssql::build(update).unwrap();
+
+        assert_eq!(
+            "UPDATE [users] SET [foo] = @P1 OUTPUT [Inserted].[id],[Inserted].[foo] WHERE [id] = @P2",
+            sql
+        );
+        assert_eq!(vec![Value::from(10), Value::from(1)], params);
+    }
+
+    #[test]
+    fn test_returning_delete() {
+        let delete = Delete::from_table("users").so_that("id".equals(1)).returning(vec!["id"]);
+
+        let (sql, params) = Mssql::build(delete).unwrap();
+
+        assert_eq!("DELETE FROM [users] OUTPUT [Deleted].[id] WHERE [id] = @P1", sql);
+        assert_eq!(vec![Value::from(1)], params);
+    }
 }
@@ -118,6 +118,12 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         Ok(())
     }
 
+    fn visit_index_hint(&mut self, hint: IndexHint<'a>) -> visitor::Result {
+        self.write(" FORCE INDEX (")?;
+        self.delimited_identifiers(&[hint.index_name()])?;
+        self.write(")")
+    }
+
     fn visit_raw_value(&mut self, value: Value<'a>) -> visitor::Result {
         let res = match &value.typed {
             ValueType::Int32(i) => i.map(|i| self.write(i)),
@@ -248,6 +254,64 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         unimplemented!("Upsert not supported for the underlying database.")
     }
 
+    /// MySQL has no `FROM` clause on `UPDATE`. A second table is instead joined right after the
+    /// updated table, and the join predicate lives in the `ON` clause rather than being folded
+    /// into `WHERE`:
+    ///
+    /// `UPDATE a JOIN b ON <join predicate> SET ... WHERE ...`
+    /// MySQL has no `RETURNING` clause, so `update.returning` (if set) is silently dropped here,
+    /// same as `visit_insert` does for `insert.returning`.
+    fn visit_update(&mut self, update: Update<'a>) -> visitor::Result {
+        self.write("UPDATE ")?;
+        self.visit_table(update.table, true)?;
+
+        if let Some(from) = &update.from {
+            self.write(" JOIN ")?;
+            self.visit_table(from.table.clone(), true)?;
+            self.write(" ON ")?;
+            self.visit_conditions(from.conditions.clone())?;
+        }
+
+        self.write(" SET ")?;
+        let pairs = update.columns.into_iter().zip(update.values);
+        let len = pairs.len();
+
+        for (i, (key, value)) in pairs.enumerate() {
+            self.visit_column(key)?;
+            self.write(" = ")?;
+            self.visit_expression(value)?;
+
+            if i < (len - 1) {
+                self.write(", ")?;
+            }
+        }
+
+        if let Some(conditions) = update.conditions {
+            self.write(" WHERE ")?;
+            self.visit_conditions(conditions)?;
+        }
+
+        if let Some(comment) = update.comment {
+            self.write(" ")?;
+            self.visit_comment(comment)?;
+        }
+
+        Ok(())
+    }
+
+    /// MySQL has no `RETURNING` clause for `DELETE`. Unlike `visit_update`, which silently drops
+    /// `update.returning`, this errors instead: `Delete::returning` is a new, narrower surface (only
+    /// meant for connectors that can actually honor it), so a caller reaching this code path is a
+    /// bug worth surfacing rather than a value we should quietly ignore.
+    fn visit_delete(&mut self, delete: Delete<'a>) -> visitor::Result {
+        if delete.returning.as_ref().is_some_and(|r| !r.is_empty()) {
+            let kind = ErrorKind::QueryInvalidInput("MySQL doesn't support RETURNING in DELETE".to_owned());
+            return Err(Error::builder(kind).build());
+        }
+
+        self.render_delete(delete)
+    }
+
     /// MySql will error if a `Update` or `Delete` query has a subselect
     /// that references a table that is being updated or deleted
     /// to get around that, we need to wrap the table in a tmp table name
@@ -502,6 +566,10 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         })
     }
 
+    /// Boolean mode is used for both filtering and relevance ordering (see
+    /// `visit_text_search_relevance` below) rather than natural language mode, so that a search
+    /// containing operators like `+required -excluded` behaves consistently whether it's used to
+    /// filter rows or to rank them.
     fn visit_matches(&mut self, left: Expression<'a>, right: std::borrow::Cow<'a, str>, not: bool) -> visitor::Result {
         if not {
             self.write("(NOT ")?;
@@ -620,7 +688,7 @@ fn get_target_table(query: Query<'_>) -> Option<Table<'_>> {
 
 #[cfg(test)]
 mod tests {
-    use crate::visitor::*;
+    use crate::{error::ErrorKind, visitor::*};
 
     fn expected_values<'a, T>(sql: &'static str, params: Vec<T>) -> (String, Vec<Value<'a>>)
     where
@@ -704,6 +772,16 @@ mod tests {
         assert_eq!(expected.1, params);
     }
 
+    #[test]
+    fn test_select_with_forced_index() {
+        let expected = expected_values("SELECT `users`.* FROM `users` FORCE INDEX (`users_email_idx`)", vec![]);
+        let query = Select::from_table(Table::from("users").force_index("users_email_idx"));
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
     #[test]
     fn test_in_values_2_tuple() {
         use crate::{col, values};
@@ -838,6 +916,42 @@ mod tests {
         assert_eq!(expected_sql, sql);
     }
 
+    #[test]
+    fn test_update_from_join() {
+        let expected_sql =
+            "UPDATE `users` JOIN `accounts` ON `users`.`account_id` = `accounts`.`id` SET `name` = `accounts`.`name`";
+
+        let query = Update::table("users")
+            .set("name", Column::from(("accounts", "name")))
+            .from_table(
+                "accounts",
+                Column::from(("users", "account_id")).equals(Column::from(("accounts", "id"))),
+            );
+
+        let (sql, _) = Mysql::build(query).unwrap();
+
+        assert_eq!(expected_sql, sql);
+    }
+
+    #[test]
+    fn test_update_from_join_keeps_where_separate() {
+        let expected_sql =
+            "UPDATE `users` JOIN `accounts` ON `users`.`account_id` = `accounts`.`id` SET `name` = `accounts`.`name` WHERE `users`.`active` = ?";
+
+        let query = Update::table("users")
+            .set("name", Column::from(("accounts", "name")))
+            .from_table(
+                "accounts",
+                Column::from(("users", "account_id")).equals(Column::from(("accounts", "id"))),
+            )
+            .so_that(Column::from(("users", "active")).equals(true));
+
+        let (sql, params) = Mysql::build(query).unwrap();
+
+        assert_eq!(expected_sql, sql);
+        assert_eq!(vec![Value::from(true)], params);
+    }
+
     #[test]
     fn test_comment_insert() {
         let expected_sql = "INSERT INTO `users` () VALUES () /* trace_id='5bd66ef5095369c7b0d1f8f4bd33716a', parent_id='c532cb4098ac3dd2' */";
@@ -970,4 +1084,13 @@ mod tests {
             sql
         );
     }
+
+    #[test]
+    fn test_delete_returning_is_not_supported() {
+        let delete = Delete::from_table("users").so_that("id".equals(1)).returning(vec!["id"]);
+
+        let err = Mysql::build(delete).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::QueryInvalidInput(_)));
+    }
 }
@@ -735,6 +735,16 @@ mod tests {
         assert_eq!(expected.1, params);
     }
 
+    #[test]
+    fn test_select_with_forced_index_is_ignored() {
+        let expected = expected_values("SELECT \"users\".* FROM \"users\"", Vec::<i64>::new());
+        let query = Select::from_table(Table::from("users").force_index("users_email_idx"));
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
     #[test]
     fn test_limit_and_offset_when_only_offset_is_set() {
         let expected = expected_values("SELECT \"users\".* FROM \"users\" OFFSET $1", vec![10_i64]);
@@ -825,6 +835,41 @@ mod tests {
         assert_eq!(expected_sql, sql);
     }
 
+    #[test]
+    fn test_update_from_join() {
+        let expected_sql =
+            "UPDATE \"users\" SET \"name\" = \"accounts\".\"name\" FROM \"accounts\" WHERE \"users\".\"account_id\" = \"accounts\".\"id\"";
+
+        let query = Update::table("users")
+            .set("name", Column::from(("accounts", "name")))
+            .from_table(
+                "accounts",
+                Column::from(("users", "account_id")).equals(Column::from(("accounts", "id"))),
+            );
+
+        let (sql, _) = Postgres::build(query).unwrap();
+
+        assert_eq!(expected_sql, sql);
+    }
+
+    #[test]
+    fn test_update_from_join_combines_with_where() {
+        let expected_sql =
+            "UPDATE \"users\" SET \"name\" = \"accounts\".\"name\" FROM \"accounts\" WHERE (\"users\".\"account_id\" = \"accounts\".\"id\" AND \"users\".\"active\" = $1)";
+
+        let query = Update::table("users")
+            .set("name", Column::from(("accounts", "name")))
+            .from_table(
+                "accounts",
+                Column::from(("users", "account_id")).equals(Column::from(("accounts", "id"))),
+            )
+            .so_that(Column::from(("users", "active")).equals(true));
+
+        let (sql, _) = Postgres::build(query).unwrap();
+
+        assert_eq!(expected_sql, sql);
+    }
+
     #[test]
     fn test_comment_delete() {
         let expected_sql =
@@ -224,6 +224,24 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
         Ok(())
     }
 
+    /// `UPDATE ... FROM` was only added in SQLite 3.33.0. Older linked versions don't understand
+    /// the clause at all, so we fail fast with a clear error instead of sending SQL the engine
+    /// would reject anyway.
+    fn visit_update(&mut self, update: Update<'a>) -> visitor::Result {
+        const MIN_SQLITE_VERSION_WITH_UPDATE_FROM: i32 = 3_033_000;
+
+        if update.from.is_some() && ::rusqlite::version_number() < MIN_SQLITE_VERSION_WITH_UPDATE_FROM {
+            let kind = ErrorKind::QueryInvalidInput(format!(
+                "UPDATE ... FROM requires SQLite 3.33.0 or newer, the linked version is {}",
+                ::rusqlite::version()
+            ));
+
+            return Err(Error::builder(kind).build());
+        }
+
+        self.render_update(update)
+    }
+
     fn parameter_substitution(&mut self) -> visitor::Result {
         self.write("?")
     }
@@ -805,6 +823,28 @@ mod tests {
         assert_eq!(expected_sql, sql);
     }
 
+    #[test]
+    fn test_update_from_join() {
+        let query = Update::table("users")
+            .set("name", Column::from(("accounts", "name")))
+            .from_table(
+                "accounts",
+                Column::from(("users", "account_id")).equals(Column::from(("accounts", "id"))),
+            );
+
+        let result = Sqlite::build(query);
+
+        if ::rusqlite::version_number() >= 3_033_000 {
+            let (sql, _) = result.unwrap();
+            assert_eq!(
+                "UPDATE `users` SET `name` = `accounts`.`name` FROM `accounts` WHERE `users`.`account_id` = `accounts`.`id`",
+                sql
+            );
+        } else {
+            result.unwrap_err();
+        }
+    }
+
     #[cfg(feature = "sqlite")]
     fn sqlite_harness() -> ::rusqlite::Connection {
         let conn = ::rusqlite::Connection::open_in_memory().unwrap();
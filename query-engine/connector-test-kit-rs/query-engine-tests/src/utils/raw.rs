@@ -31,6 +31,7 @@ pub enum RawParam {
     Bytes(Vec<u8>),
     BigInt(i64),
     Decimal(String),
+    Enum(String),
     Array(Vec<RawParam>),
     Primitive(serde_json::Value),
     Null,
@@ -55,6 +56,10 @@ impl RawParam {
         Self::Decimal(dec.to_owned())
     }
 
+    pub fn enum_value(variant: &str) -> Self {
+        Self::Enum(variant.to_owned())
+    }
+
     pub fn array(arr: Vec<impl Into<RawParam>>) -> Self {
         let arr: Vec<_> = arr.into_iter().map(Into::into).collect();
 
@@ -91,6 +96,7 @@ impl From<RawParam> for serde_json::Value {
             RawParam::Bytes(bytes) => scalar_type("bytes", encode_bytes(&bytes)),
             RawParam::BigInt(b_int) => scalar_type("bigint", b_int.to_string()),
             RawParam::Decimal(dec) => scalar_type("decimal", dec.as_str()),
+            RawParam::Enum(variant) => scalar_type("enum", variant.as_str()),
             RawParam::Array(values) => {
                 let json_values: Vec<_> = values.into_iter().map(serde_json::Value::from).collect();
 
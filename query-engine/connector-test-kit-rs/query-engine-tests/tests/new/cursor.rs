@@ -48,3 +48,55 @@ mod bigint_cursor {
         Ok(())
     }
 }
+
+/// Cursor pagination must remain stable even when the requested `orderBy` is not unique, by
+/// implicitly appending the primary identifier as a trailing tie-breaker.
+#[test_suite(schema(non_unique_order_by_schema))]
+mod non_unique_order_by_cursor {
+    use indoc::indoc;
+    use query_engine_tests::run_query;
+
+    fn non_unique_order_by_schema() -> String {
+        let schema = indoc! {"
+            model TestModel {
+                #id(id, Int, @id)
+                counter Int
+            }
+        "};
+
+        schema.to_owned()
+    }
+
+    #[connector_test]
+    async fn tie_broken_by_id(runner: Runner) -> TestResult<()> {
+        test_data(&runner).await?;
+
+        // All rows tie on `counter`, so without an implicit `id` tie-breaker the cursor could
+        // return duplicate or skipped rows across pages. `id` ascending is a stable sub-order.
+        insta::assert_snapshot!(
+            run_query!(&runner, "query { findManyTestModel(orderBy: { counter: asc }, cursor: { id: 2 }, take: 2){ id }}"),
+            @r###"{"data":{"findManyTestModel":[{"id":2},{"id":3}]}}"###
+        );
+
+        Ok(())
+    }
+
+    async fn test_data(runner: &Runner) -> TestResult<()> {
+        runner
+            .query(r#"mutation { createOneTestModel(data: { id: 1, counter: 1 }) { id }}"#)
+            .await?
+            .assert_success();
+
+        runner
+            .query(r#"mutation { createOneTestModel(data: { id: 2, counter: 1 }) { id }}"#)
+            .await?
+            .assert_success();
+
+        runner
+            .query(r#"mutation { createOneTestModel(data: { id: 3, counter: 1 }) { id }}"#)
+            .await?
+            .assert_success();
+
+        Ok(())
+    }
+}
@@ -37,6 +37,12 @@ mod metrics {
         }
 
         assert_eq!(total_operations, 2);
+
+        let create_one_operations = get_labelled_counter(&json, PRISMA_CLIENT_QUERIES_TOTAL, "operation", "createOne");
+        let update_one_operations = get_labelled_counter(&json, PRISMA_CLIENT_QUERIES_TOTAL, "operation", "updateOne");
+        assert_eq!(create_one_operations, 1);
+        assert_eq!(update_one_operations, 1);
+
         Ok(())
     }
 
@@ -78,25 +84,39 @@ mod metrics {
         Ok(())
     }
 
+    // `name` can be recorded as several distinct label combinations (e.g. `PRISMA_CLIENT_QUERIES_TOTAL`
+    // is split by `operation`/`model`), so the total is the sum of every entry sharing that name.
     fn get_counter(json: &Value, name: &str) -> u64 {
-        let metric_value = get_metric_value(json, "counters", name);
-        metric_value.as_u64().unwrap()
+        get_metrics(json, "counters", name)
+            .map(|metric| metric.get("value").unwrap().as_u64().unwrap())
+            .sum()
     }
 
     fn get_gauge(json: &Value, name: &str) -> f64 {
-        let metric_value = get_metric_value(json, "gauges", name);
-        metric_value.as_f64().unwrap()
+        get_metrics(json, "gauges", name)
+            .map(|metric| metric.get("value").unwrap().as_f64().unwrap())
+            .sum()
     }
 
-    fn get_metric_value(json: &Value, metric_type: &str, name: &str) -> serde_json::Value {
-        let metrics = json.get(metric_type).unwrap().as_array().unwrap();
-        let metric = metrics
-            .iter()
-            .find(|metric| metric.get("key").unwrap().as_str() == Some(name))
-            .unwrap()
-            .as_object()
-            .unwrap();
+    fn get_labelled_counter(json: &Value, name: &str, label_key: &str, label_value: &str) -> u64 {
+        get_metrics(json, "counters", name)
+            .filter(|metric| {
+                metric
+                    .get("labels")
+                    .and_then(|labels| labels.get(label_key))
+                    .and_then(Value::as_str)
+                    == Some(label_value)
+            })
+            .map(|metric| metric.get("value").unwrap().as_u64().unwrap())
+            .sum()
+    }
 
-        metric.get("value").unwrap().clone()
+    fn get_metrics<'a>(json: &'a Value, metric_type: &str, name: &str) -> impl Iterator<Item = &'a Value> {
+        json.get(metric_type)
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(move |metric| metric.get("key").unwrap().as_str() == Some(name))
     }
 }
@@ -514,6 +514,30 @@ mod ext_rel_filters {
         Ok(())
     }
 
+    #[connector_test]
+    async fn is_empty_true(runner: Runner) -> TestResult<()> {
+        test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{ findManyGenre(where: { Tracks: { isEmpty: true } }) { Name }}"#),
+          @r###"{"data":{"findManyGenre":[{"Name":"GenreThatIsNotUsed"}]}}"###
+        );
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn is_empty_false(runner: Runner) -> TestResult<()> {
+        test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{ findManyGenre(where: { Tracks: { isEmpty: false } }, orderBy: { Name: asc }) { Name }}"#),
+          @r###"{"data":{"findManyGenre":[{"Name":"Genre1"},{"Name":"Genre2"},{"Name":"Genre3"}]}}"###
+        );
+
+        Ok(())
+    }
+
     async fn test_data(runner: &Runner) -> TestResult<()> {
         runner
             .query(r#"mutation { createOneGenre(data: { Name: "Genre1", GenreId: 1}) { Name }}"#)
@@ -946,6 +946,44 @@ mod nested_pagination {
         Ok(())
     }
 
+    /*******************************************
+     * Per-parent `take` pushdown (ROW_NUMBER). *
+     ******************************************/
+
+    // On connectors that support pushing a nested `take` down to the database (currently
+    // Postgres/CockroachDb, via `ROW_NUMBER() OVER (PARTITION BY ...)`), the per-parent limit
+    // must still produce exactly the same results as the in-memory fallback used elsewhere.
+    #[connector_test(only(Postgres, CockroachDb))]
+    async fn mid_lvl_take_pushed_down_on_postgres(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{
+            findManyTop{t, middles(take: 2, orderBy: { m: asc }){m}}
+          }"#),
+          @r###"{"data":{"findManyTop":[{"t":"T1","middles":[{"m":"M11"},{"m":"M12"}]},{"t":"T2","middles":[{"m":"M21"},{"m":"M22"}]},{"t":"T3","middles":[{"m":"M31"},{"m":"M32"}]}]}}"###
+        );
+
+        Ok(())
+    }
+
+    // Same query as `mid_lvl_take_pushed_down_on_postgres`, but on connectors without pushdown
+    // support: `ReadOperations::supports_relation_load_strategy_pushdown` defaults to `false`
+    // there, so the results are still trimmed to two per parent, just via the in-memory processor.
+    #[connector_test(exclude(Postgres, CockroachDb))]
+    async fn mid_lvl_take_falls_back_without_pushdown(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{
+            findManyTop{t, middles(take: 2, orderBy: { m: asc }){m}}
+          }"#),
+          @r###"{"data":{"findManyTop":[{"t":"T1","middles":[{"m":"M11"},{"m":"M12"}]},{"t":"T2","middles":[{"m":"M21"},{"m":"M22"}]},{"t":"T3","middles":[{"m":"M31"},{"m":"M32"}]}]}}"###
+        );
+
+        Ok(())
+    }
+
     async fn create_test_data(runner: &Runner) -> TestResult<()> {
         create_row(
             runner,
@@ -61,4 +61,41 @@ mod input_coercion {
 
         Ok(())
     }
+
+    fn schema_with_enum() -> String {
+        let schema = indoc! {
+            r#"model TestModel {
+                #id(id, Int, @id)
+                optEnum MyEnum?
+            }
+
+            enum MyEnum {
+                A
+                B
+            }"#
+        };
+
+        schema.to_owned()
+    }
+
+    // Checks that a raw query parameter explicitly tagged as an enum is bound as the enum
+    // variant rather than being guessed from its (otherwise indistinguishable from a plain
+    // string) JSON shape.
+    #[connector_test(schema(schema_with_enum))]
+    async fn enum_input_correctly_coerced(runner: Runner) -> TestResult<()> {
+        run_query!(
+            &runner,
+            fmt_execute_raw(
+                r#"INSERT INTO "TestModel" ("id", "optEnum") VALUES ($1, $2);"#,
+                vec![RawParam::from(1), RawParam::enum_value("A")],
+            )
+        );
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"{ findManyTestModel { id optEnum } }"#),
+          @r###"{"data":{"findManyTestModel":[{"id":1,"optEnum":"A"}]}}"###
+        );
+
+        Ok(())
+    }
 }
@@ -308,6 +308,51 @@ mod basic_types {
         Ok(())
     }
 
+    // "An Update Mutation that unshifts onto some scalar lists" should "prepend the new item(s)"
+    // Skipped for CockroachDB as enum array concatenation is not supported (https://github.com/cockroachdb/cockroach/issues/71388).
+    #[connector_test(exclude(CockroachDb))]
+    async fn update_mut_unshift_scalar_list(runner: Runner) -> TestResult<()> {
+        create_row(
+            &runner,
+            r#"{
+              id: 1,
+              strings:   { set: ["future"] }
+              ints:      { set: [15] }
+              floats:    { set: [2] }
+              booleans:  { set: [true] }
+              enums:     { set: [A] }
+              dateTimes: { set: ["2019-07-31T23:59:01.000Z"] }
+              bytes:     { set: ["dGVzdA=="] }
+            }"#,
+        )
+        .await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation {
+            updateOneScalarModel(where: { id: 1 }, data: {
+              strings:   { unshift: "past" }
+              ints:      { unshift: 14 }
+              floats:    { unshift: 1 }
+              booleans:  { unshift: false }
+              enums:     { unshift: B }
+              dateTimes: { unshift: "2018-07-31T23:59:01.000Z" }
+              bytes:     { unshift: "dA==" }
+            }) {
+              strings
+              ints
+              floats
+              booleans
+              enums
+              dateTimes
+              bytes
+            }
+          }"#),
+          @r###"{"data":{"updateOneScalarModel":{"strings":["past","future"],"ints":[14,15],"floats":[1.0,2.0],"booleans":[false,true],"enums":["B","A"],"dateTimes":["2018-07-31T23:59:01.000Z","2019-07-31T23:59:01.000Z"],"bytes":["dA==","dGVzdA=="]}}}"###
+        );
+
+        Ok(())
+    }
+
     // Test that Cockroach will not work with enum push
     #[connector_test(only(CockroachDb))]
     async fn cockroachdb_doesnot_support_enum_push(runner: Runner) -> TestResult<()> {
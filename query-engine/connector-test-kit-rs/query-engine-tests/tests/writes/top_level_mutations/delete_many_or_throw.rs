@@ -0,0 +1,65 @@
+use query_engine_tests::*;
+
+#[test_suite(schema(schema))]
+mod delete_many_or_throw {
+    use indoc::indoc;
+    use query_engine_tests::run_query;
+
+    fn schema() -> String {
+        let schema = indoc! {
+            r#"model Todo {
+              #id(id, Int, @id)
+              title String
+            }"#
+        };
+
+        schema.to_owned()
+    }
+
+    // "The deleteManyOrThrow Mutation" should "delete the items matching the where clause"
+    #[connector_test]
+    async fn should_delete_items(runner: Runner) -> TestResult<()> {
+        create_row(&runner, r#"{ id: 1, title: "title1" }"#).await?;
+        create_row(&runner, r#"{ id: 2, title: "title2" }"#).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation {
+            deleteManyTodoOrThrow(
+              where: { title: { equals: "title1" }}
+            ){
+              count
+            }
+          }"#),
+          @r###"{"data":{"deleteManyTodoOrThrow":{"count":1}}}"###
+        );
+
+        Ok(())
+    }
+
+    // "The deleteManyOrThrow Mutation" should "error instead of returning a count of 0 when nothing matched"
+    #[connector_test]
+    async fn should_throw_when_nothing_matched(runner: Runner) -> TestResult<()> {
+        create_row(&runner, r#"{ id: 1, title: "title1" }"#).await?;
+
+        assert_error!(
+            &runner,
+            r#"mutation {
+              deleteManyTodoOrThrow(where: { title: { equals: "doesNotExist" }}){
+                count
+              }
+            }"#,
+            2025,
+            "An operation failed because it depends on one or more records that were required but not found. Expected a record, found none."
+        );
+
+        Ok(())
+    }
+
+    async fn create_row(runner: &Runner, data: &str) -> TestResult<()> {
+        runner
+            .query(format!("mutation {{ createOneTodo(data: {data}) {{ id }} }}"))
+            .await?
+            .assert_success();
+        Ok(())
+    }
+}
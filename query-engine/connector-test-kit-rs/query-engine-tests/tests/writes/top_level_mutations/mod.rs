@@ -4,10 +4,12 @@ mod create_many;
 mod default_value;
 mod delete;
 mod delete_many;
+mod delete_many_or_throw;
 mod delete_many_relations;
 mod delete_mutation_relations;
 mod insert_null_in_required_field;
 mod non_embedded_upsert;
 mod update;
 mod update_many;
+mod update_many_or_throw;
 mod upsert;
@@ -0,0 +1,68 @@
+use query_engine_tests::*;
+
+#[test_suite(schema(schema))]
+mod update_many_or_throw {
+    use indoc::indoc;
+    use query_engine_tests::run_query;
+
+    fn schema() -> String {
+        let schema = indoc! {
+            r#"model TestModel {
+              #id(id, Int, @id)
+              optStr String?
+            }"#
+        };
+
+        schema.to_owned()
+    }
+
+    // "An updateManyOrThrow mutation" should "update the records matching the where clause"
+    #[connector_test]
+    async fn update_recs_matching_where(runner: Runner) -> TestResult<()> {
+        create_row(&runner, r#"{ id: 1, optStr: "str1" }"#).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation {
+            updateManyTestModelOrThrow(
+              where: { optStr: { equals: "str1" } }
+              data: { optStr: { set: "str1new" } }
+            ) {
+              count
+            }
+          }"#),
+          @r###"{"data":{"updateManyTestModelOrThrow":{"count":1}}}"###
+        );
+
+        Ok(())
+    }
+
+    // "An updateManyOrThrow mutation" should "error instead of returning a count of 0 when nothing matched"
+    #[connector_test]
+    async fn should_throw_when_nothing_matched(runner: Runner) -> TestResult<()> {
+        create_row(&runner, r#"{ id: 1, optStr: "str1" }"#).await?;
+
+        assert_error!(
+            &runner,
+            r#"mutation {
+              updateManyTestModelOrThrow(
+                where: { optStr: { equals: "doesNotExist" } }
+                data: { optStr: { set: "irrelevant" } }
+              ) {
+                count
+              }
+            }"#,
+            2025,
+            "An operation failed because it depends on one or more records that were required but not found. Expected a record, found none."
+        );
+
+        Ok(())
+    }
+
+    async fn create_row(runner: &Runner, data: &str) -> TestResult<()> {
+        runner
+            .query(format!("mutation {{ createOneTestModel(data: {data}) {{ id }} }}"))
+            .await?
+            .assert_success();
+        Ok(())
+    }
+}
@@ -28,6 +28,7 @@ impl Connection for MongoDbConnection {
     async fn start_transaction<'a>(
         &'a mut self,
         isolation_level: Option<String>,
+        session_context: &[(String, String)],
     ) -> connector_interface::Result<Box<dyn connector_interface::Transaction + 'a>> {
         if isolation_level.is_some() {
             return Err(MongoError::Unsupported(
@@ -36,6 +37,13 @@ impl Connection for MongoDbConnection {
             .into_connector_error());
         }
 
+        if !session_context.is_empty() {
+            return Err(MongoError::Unsupported(
+                "Mongo does not support setting session context values.".to_owned(),
+            )
+            .into_connector_error());
+        }
+
         let tx = Box::new(MongoDbTransaction::new(self).await?);
 
         Ok(tx as Box<dyn Transaction>)
@@ -190,6 +198,7 @@ impl ReadOperations for MongoDbConnection {
         filter: &connector_interface::Filter,
         selected_fields: &FieldSelection,
         aggr_selections: &[RelAggregationSelection],
+        _index_hint: Option<&str>,
         _trace_id: Option<String>,
     ) -> connector_interface::Result<Option<SingleRecord>> {
         catch(async move {
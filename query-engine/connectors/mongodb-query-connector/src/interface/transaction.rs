@@ -255,6 +255,7 @@ impl<'conn> ReadOperations for MongoDbTransaction<'conn> {
         filter: &connector_interface::Filter,
         selected_fields: &FieldSelection,
         aggr_selections: &[RelAggregationSelection],
+        _index_hint: Option<&str>,
         _trace_id: Option<String>,
     ) -> connector_interface::Result<Option<SingleRecord>> {
         catch(async move {
@@ -374,6 +374,14 @@ impl MongoReadQueryBuilder {
                         filter,
                     }
                 }
+                RelAggregationSelection::Sum(_, _, _)
+                | RelAggregationSelection::Average(_, _, _)
+                | RelAggregationSelection::Min(_, _, _)
+                | RelAggregationSelection::Max(_, _, _) => {
+                    return Err(crate::error::MongoError::Unsupported(
+                        "Relation aggregations other than _count are not yet supported on MongoDB".to_string(),
+                    ))
+                }
             };
 
             let projection = doc! {
@@ -24,6 +24,10 @@ impl IntoUpdateOperation for ScalarWriteOperation {
 
         let doc = match self {
             ScalarWriteOperation::Add(rhs) if field.is_list() => Some(render_push_update_doc(rhs, field, field_path)?),
+            ScalarWriteOperation::Prepend(rhs) if field.is_list() => {
+                Some(render_unshift_update_doc(rhs, field, field_path)?)
+            }
+            ScalarWriteOperation::Prepend(_) => unreachable!("Prepend is only supported on scalar list fields"),
             // We use $literal to enable the set of empty object, which is otherwise considered a syntax error
             ScalarWriteOperation::Set(rhs) => Some(UpdateOperation::generic(
                 field_path,
@@ -127,9 +131,7 @@ impl IntoUpdateOperation for CompositeWriteOperation {
     }
 }
 
-fn render_push_update_doc(rhs: PrismaValue, field: &Field, field_path: FieldPath) -> crate::Result<UpdateOperation> {
-    let dollar_field_path = field_path.dollar_path(true);
-
+fn push_values_as_bson_array(rhs: PrismaValue, field: &Field) -> crate::Result<Bson> {
     let values = match rhs {
         PrismaValue::List(vals) => {
             vals.into_iter()
@@ -157,7 +159,12 @@ fn render_push_update_doc(rhs: PrismaValue, field: &Field, field_path: FieldPath
         },
     };
 
-    let bson_array = Bson::Array(values);
+    Ok(Bson::Array(values))
+}
+
+fn render_push_update_doc(rhs: PrismaValue, field: &Field, field_path: FieldPath) -> crate::Result<UpdateOperation> {
+    let dollar_field_path = field_path.dollar_path(true);
+    let bson_array = push_values_as_bson_array(rhs, field)?;
 
     Ok(UpdateOperation::generic(
         field_path,
@@ -169,3 +176,20 @@ fn render_push_update_doc(rhs: PrismaValue, field: &Field, field_path: FieldPath
         },
     ))
 }
+
+/// Like [`render_push_update_doc`], but prepends the new values to the start of the list
+/// (`unshift`) rather than appending them to the end.
+fn render_unshift_update_doc(rhs: PrismaValue, field: &Field, field_path: FieldPath) -> crate::Result<UpdateOperation> {
+    let dollar_field_path = field_path.dollar_path(true);
+    let bson_array = push_values_as_bson_array(rhs, field)?;
+
+    Ok(UpdateOperation::generic(
+        field_path,
+        doc! {
+            "$ifNull": [
+                { "$concatArrays": [bson_array.clone(), dollar_field_path] },
+                bson_array
+            ]
+        },
+    ))
+}
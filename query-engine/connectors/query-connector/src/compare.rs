@@ -91,6 +91,9 @@ pub trait RelationCompare {
         T: Into<Filter>;
 
     fn one_relation_is_null(&self) -> Filter;
+
+    /// To-many relation only - `true` for no related records, `false` for at least one.
+    fn is_empty(&self, b: bool) -> Filter;
 }
 
 /// Comparison methods for scalar list fields.
@@ -3,6 +3,11 @@ use crate::filter::Filter;
 use prisma_models::{CompositeFieldRef, PrismaValue};
 // use std::sync::Arc;
 
+// Composite traversal (`is`, `isNot` on to-one composites, `every`/`some`/`none` on embedded
+// lists) is implemented here and compiled by the MongoDB connector, since composites are
+// currently a MongoDB-only concept -- no SQL connector declares composite-type support, so there
+// is no JSON path compilation target for this filter tree to compile to yet.
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CompositeFilter {
     /// Starting field of the Composite traversal.
@@ -14,6 +19,58 @@ pub struct CompositeFilter {
     pub condition: Box<CompositeCondition>,
 }
 
+impl CompositeFilter {
+    /// Approximate number of bind parameters this filter contributes to a query.
+    pub fn approximate_param_count(&self) -> usize {
+        match self.condition.as_ref() {
+            CompositeCondition::Every(f) | CompositeCondition::Some(f) | CompositeCondition::None(f) => {
+                f.approximate_param_count()
+            }
+            CompositeCondition::Is(f) | CompositeCondition::IsNot(f) => f.approximate_param_count(),
+            CompositeCondition::Equals(_) => 1,
+            CompositeCondition::Empty(_) | CompositeCondition::IsSet(_) => 0,
+        }
+    }
+
+    pub(crate) fn normalize(self) -> Self {
+        let condition = match *self.condition {
+            CompositeCondition::Every(f) => CompositeCondition::Every(f.normalize()),
+            CompositeCondition::Some(f) => CompositeCondition::Some(f.normalize()),
+            CompositeCondition::None(f) => CompositeCondition::None(f.normalize()),
+            CompositeCondition::Is(f) => CompositeCondition::Is(f.normalize()),
+            CompositeCondition::IsNot(f) => CompositeCondition::IsNot(f.normalize()),
+            other => other,
+        };
+
+        Self {
+            condition: Box::new(condition),
+            ..self
+        }
+    }
+
+    pub(crate) fn simplify(self) -> Self {
+        let condition = match *self.condition {
+            CompositeCondition::Every(f) => CompositeCondition::Every(f.simplify()),
+            CompositeCondition::Some(f) => CompositeCondition::Some(f.simplify()),
+            CompositeCondition::None(f) => CompositeCondition::None(f.simplify()),
+            CompositeCondition::Is(f) => CompositeCondition::Is(f.simplify()),
+            CompositeCondition::IsNot(f) => CompositeCondition::IsNot(f.simplify()),
+            other => other,
+        };
+
+        Self {
+            condition: Box::new(condition),
+            ..self
+        }
+    }
+
+    /// Structural equality that ignores literal `Equals` values but requires the same field and
+    /// nested filter shape (see [`CompositeCondition::structurally_eq`]).
+    pub(crate) fn structurally_eq(&self, other: &Self) -> bool {
+        self.field == other.field && self.condition.structurally_eq(&other.condition)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum CompositeCondition {
     /// Every composite in the list needs to fulfill a condition.
@@ -41,6 +98,24 @@ pub enum CompositeCondition {
     IsSet(bool),
 }
 
+impl CompositeCondition {
+    /// Structural equality that ignores the literal value of `Equals`, since only the fact that
+    /// an equality check is being made is significant here, not what it's being checked against.
+    pub(crate) fn structurally_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Every(a), Self::Every(b))
+            | (Self::Some(a), Self::Some(b))
+            | (Self::None(a), Self::None(b))
+            | (Self::Is(a), Self::Is(b))
+            | (Self::IsNot(a), Self::IsNot(b)) => a.structurally_eq(b),
+            (Self::Empty(a), Self::Empty(b)) => a == b,
+            (Self::Equals(_), Self::Equals(_)) => true,
+            (Self::IsSet(a), Self::IsSet(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
 impl CompositeCompare for CompositeFieldRef {
     fn every<T>(&self, filter: T) -> Filter
     where
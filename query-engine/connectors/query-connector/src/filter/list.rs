@@ -13,6 +13,22 @@ impl ScalarListFilter {
     pub fn as_field_ref(&self) -> Option<&ScalarFieldRef> {
         self.condition.as_field_ref()
     }
+
+    /// Approximate number of bind parameters this filter contributes to a query.
+    pub fn approximate_param_count(&self) -> usize {
+        match &self.condition {
+            ScalarListCondition::ContainsEvery(v) => v.len(),
+            ScalarListCondition::ContainsSome(v) => v.len(),
+            ScalarListCondition::Contains(_) => 1,
+            ScalarListCondition::IsEmpty(_) => 0,
+        }
+    }
+
+    /// Structural equality that ignores literal operand values (see
+    /// [`ScalarCondition::structurally_eq`]).
+    pub(crate) fn structurally_eq(&self, other: &Self) -> bool {
+        self.field == other.field && self.condition.structurally_eq(&other.condition)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -40,6 +56,19 @@ impl ScalarListCondition {
             ScalarListCondition::IsEmpty(_) => None,
         }
     }
+
+    /// Structural equality that ignores literal operand values (see
+    /// [`ConditionValue::structurally_eq`] / [`ConditionListValue::structurally_eq`]).
+    pub(crate) fn structurally_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Contains(a), Self::Contains(b)) => a.structurally_eq(b),
+            (Self::ContainsEvery(a), Self::ContainsEvery(b)) | (Self::ContainsSome(a), Self::ContainsSome(b)) => {
+                a.structurally_eq(b)
+            }
+            (Self::IsEmpty(a), Self::IsEmpty(b)) => a == b,
+            _ => false,
+        }
+    }
 }
 
 #[allow(warnings)]
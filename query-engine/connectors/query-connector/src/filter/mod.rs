@@ -54,6 +54,40 @@ impl AggregationFilter {
             AggregationFilter::Max(f) => f,
         }
     }
+
+    fn normalize(self) -> Self {
+        match self {
+            Self::Count(f) => Self::Count(Box::new(f.normalize())),
+            Self::Average(f) => Self::Average(Box::new(f.normalize())),
+            Self::Sum(f) => Self::Sum(Box::new(f.normalize())),
+            Self::Min(f) => Self::Min(Box::new(f.normalize())),
+            Self::Max(f) => Self::Max(Box::new(f.normalize())),
+        }
+    }
+
+    fn simplify(self) -> Self {
+        match self {
+            Self::Count(f) => Self::Count(Box::new(f.simplify())),
+            Self::Average(f) => Self::Average(Box::new(f.simplify())),
+            Self::Sum(f) => Self::Sum(Box::new(f.simplify())),
+            Self::Min(f) => Self::Min(Box::new(f.simplify())),
+            Self::Max(f) => Self::Max(Box::new(f.simplify())),
+        }
+    }
+
+    /// Structural equality that ignores literal filter values (see [`Filter::structurally_eq`]).
+    /// Requires the same aggregation kind, since `Count` and `Sum` filters mean different things
+    /// even if their inner filter happens to match.
+    fn structurally_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Count(a), Self::Count(b))
+            | (Self::Average(a), Self::Average(b))
+            | (Self::Sum(a), Self::Sum(b))
+            | (Self::Min(a), Self::Min(b))
+            | (Self::Max(a), Self::Max(b)) => a.structurally_eq(b),
+            _ => false,
+        }
+    }
 }
 
 impl Filter {
@@ -73,6 +107,115 @@ impl Filter {
         Filter::Empty
     }
 
+    /// Canonicalizes the operand order of commutative filter nodes (`AND`/`OR`) and of `IN`/`NOT IN`
+    /// value lists, so that logically-equivalent filters normalize to the same structure regardless
+    /// of the order they were built in (e.g. `a AND b` and `b AND a`). Intended to run ahead of a
+    /// structural hash of the filter, so that a permuted-but-equivalent filter still hits the cache.
+    /// Recurses into nested filters (`NOT`, relation, composite, aggregation) without reordering
+    /// their own operand lists, since those orderings are either significant or already fixed by
+    /// construction.
+    pub fn normalize(self) -> Self {
+        match self {
+            Self::And(filters) => Self::And(Self::normalize_commutative(filters)),
+            Self::Or(filters) => Self::Or(Self::normalize_commutative(filters)),
+            Self::Not(filters) => Self::Not(filters.into_iter().map(Filter::normalize).collect()),
+            Self::Scalar(sf) => Self::Scalar(sf.normalize()),
+            Self::Aggregation(agg) => Self::Aggregation(agg.normalize()),
+            Self::Relation(mut rf) => {
+                rf.nested_filter = Box::new(rf.nested_filter.normalize());
+                Self::Relation(rf)
+            }
+            Self::Composite(cf) => Self::Composite(cf.normalize()),
+            other => other,
+        }
+    }
+
+    fn normalize_commutative(filters: Vec<Filter>) -> Vec<Filter> {
+        let mut filters: Vec<Filter> = filters.into_iter().map(Filter::normalize).collect();
+        filters.sort_by(|a, b| Self::sort_key(a).cmp(&Self::sort_key(b)));
+        filters
+    }
+
+    /// Rewrites filters that are constant regardless of the data they run against into an
+    /// equivalent [`Filter::BoolFilter`], so connectors never have to special-case them while
+    /// generating a query. Currently only handles an empty `IN`/`NOT IN` list: `field IN []` can
+    /// never match a row (`BoolFilter(false)`), and `field NOT IN []` always matches (`BoolFilter(true)`).
+    /// Recurses into nested filters (`AND`/`OR`/`NOT`, relation, composite, aggregation).
+    pub fn simplify(self) -> Self {
+        match self {
+            Self::And(filters) => Self::And(filters.into_iter().map(Filter::simplify).collect()),
+            Self::Or(filters) => Self::Or(filters.into_iter().map(Filter::simplify).collect()),
+            Self::Not(filters) => Self::Not(filters.into_iter().map(Filter::simplify).collect()),
+            Self::Scalar(sf) if sf.is_empty() => match sf.condition {
+                ScalarCondition::In(_) => Self::BoolFilter(false),
+                ScalarCondition::NotIn(_) => Self::BoolFilter(true),
+                _ => Self::Scalar(sf),
+            },
+            Self::Relation(mut rf) => {
+                rf.nested_filter = Box::new(rf.nested_filter.simplify());
+                Self::Relation(rf)
+            }
+            Self::Composite(cf) => Self::Composite(cf.simplify()),
+            Self::Aggregation(af) => Self::Aggregation(af.simplify()),
+            other => other,
+        }
+    }
+
+    /// Stable sort key for ordering commutative filter operands. Scalar filters sort by field name,
+    /// then operator/operand; everything else falls back to its (deterministic) `Debug` form.
+    fn sort_key(filter: &Filter) -> String {
+        match filter {
+            Self::Scalar(sf) => sf.sort_key(),
+            other => format!("{other:?}"),
+        }
+    }
+
+    /// Whether `self` and `other` describe the same filter tree modulo the literal values being
+    /// compared against: same fields, same operators, same nesting and the same `FieldRef`
+    /// operands, but two `Equals(1)` and `Equals(2)` filters (for example) are considered equal.
+    /// `AND`/`OR` operands are compared as an unordered multiset, matching how [`Filter::normalize`]
+    /// treats them as commutative; every other variant's structure is compared position-by-position.
+    pub fn structurally_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::And(a), Self::And(b)) | (Self::Or(a), Self::Or(b)) => Self::structurally_eq_multiset(a, b),
+            (Self::Not(a), Self::Not(b)) => {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| x.structurally_eq(y))
+            }
+            (Self::Scalar(a), Self::Scalar(b)) => a.structurally_eq(b),
+            (Self::ScalarList(a), Self::ScalarList(b)) => a.structurally_eq(b),
+            (Self::OneRelationIsNull(a), Self::OneRelationIsNull(b)) => a.field == b.field,
+            (Self::Relation(a), Self::Relation(b)) => {
+                a.field == b.field && a.condition == b.condition && a.nested_filter.structurally_eq(&b.nested_filter)
+            }
+            (Self::Composite(a), Self::Composite(b)) => a.structurally_eq(b),
+            (Self::BoolFilter(a), Self::BoolFilter(b)) => a == b,
+            (Self::Aggregation(a), Self::Aggregation(b)) => a.structurally_eq(b),
+            (Self::Empty, Self::Empty) => true,
+            _ => false,
+        }
+    }
+
+    /// Order-independent comparison of two operand lists via `structurally_eq`, used for the
+    /// commutative `AND`/`OR` filter nodes.
+    fn structurally_eq_multiset(a: &[Filter], b: &[Filter]) -> bool {
+        if a.len() != b.len() {
+            return false;
+        }
+
+        let mut used = vec![false; b.len()];
+
+        a.iter().all(|x| {
+            b.iter().enumerate().any(|(i, y)| {
+                if used[i] || !x.structurally_eq(y) {
+                    false
+                } else {
+                    used[i] = true;
+                    true
+                }
+            })
+        })
+    }
+
     /// Returns the size of the topmost filter elements (does not recursively compute the size).
     pub fn size(&self) -> usize {
         match self {
@@ -84,6 +227,36 @@ impl Filter {
         }
     }
 
+    /// A heuristic, coarse estimate in `[0, 1]` of how selective this filter is: how large a
+    /// fraction of a table's rows it's expected to eliminate. `1.0` means "expect this to match
+    /// very few rows" (an equality comparison), `0.0` means "no filtering at all". This is not a
+    /// real cardinality estimate — it doesn't look at table statistics or row counts, just the
+    /// shape of the filter — and exists so a join-ordering pass can pick a reasonable
+    /// most-selective-first order without needing one.
+    pub fn selectivity_hint(&self) -> f64 {
+        match self {
+            Self::Empty => 0.0,
+            Self::BoolFilter(matches_everything) => {
+                if *matches_everything {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+            // `AND` can only narrow down what its most selective operand already narrows down to.
+            Self::And(filters) => filters.iter().map(Filter::selectivity_hint).fold(0.0, f64::max),
+            // `OR` can only be as selective as its least selective operand.
+            Self::Or(filters) => filters.iter().map(Filter::selectivity_hint).fold(1.0, f64::min),
+            Self::Not(filters) => filters.iter().map(Filter::selectivity_hint).fold(0.0, f64::max),
+            Self::Scalar(sf) => sf.selectivity_hint(),
+            Self::ScalarList(_) => 0.4,
+            Self::OneRelationIsNull(_) => 0.3,
+            Self::Relation(_) => 0.4,
+            Self::Composite(_) => 0.3,
+            Self::Aggregation(af) => af.filter().selectivity_hint(),
+        }
+    }
+
     pub fn should_batch(&self, chunk_size: usize) -> bool {
         match self {
             Self::Scalar(sf) => sf.should_batch(chunk_size),
@@ -223,6 +396,40 @@ impl Filter {
         uniques
     }
 
+    /// Whether this filter provably restricts the result set to at most one record via an
+    /// equality check on a field covered by a unique constraint. Conservative on purpose: it only
+    /// looks through top-level `AND`s and a bare scalar filter, so it never misfires on `OR`/`NOT`
+    /// branches, or on partial comparisons (`IN`, ranges, ...) that could still match more than
+    /// one row even on a unique field.
+    pub fn is_unique_restriction(&self) -> bool {
+        match self {
+            Filter::Scalar(sf) => sf.is_unique() && matches!(sf.condition, ScalarCondition::Equals(_)),
+            Filter::And(inner) => inner.iter().any(|f| f.is_unique_restriction()),
+            _ => false,
+        }
+    }
+
+    /// Approximate number of bind parameters this filter contributes to a query, counting each
+    /// `IN`/`NOT IN` element and comparison operand and recursing into nested filters. Deliberately
+    /// rounds up rather than down (e.g. a `FieldRef` comparison binds no parameter at all, but we
+    /// still count it as one) so callers can use it to guard against exceeding a driver's bind
+    /// parameter limit without risking an undercount.
+    pub fn approximate_param_count(&self) -> usize {
+        match self {
+            Filter::And(inner) | Filter::Or(inner) | Filter::Not(inner) => {
+                inner.iter().map(|f| f.approximate_param_count()).sum()
+            }
+            Filter::Scalar(sf) => sf.approximate_param_count(),
+            Filter::ScalarList(slf) => slf.approximate_param_count(),
+            Filter::OneRelationIsNull(_) => 0,
+            Filter::Relation(rf) => rf.nested_filter.approximate_param_count(),
+            Filter::Composite(cf) => cf.approximate_param_count(),
+            Filter::BoolFilter(_) => 0,
+            Filter::Aggregation(af) => af.filter().approximate_param_count(),
+            Filter::Empty => 0,
+        }
+    }
+
     fn filter_and_collect_scalars(
         filter: &Filter,
         filter_check: fn(&ScalarFilter) -> bool,
@@ -141,4 +141,15 @@ impl RelationCompare for RelationField {
     fn one_relation_is_null(&self) -> Filter {
         Filter::from(OneRelationIsNullFilter { field: self.clone() })
     }
+
+    /// To-many relation only - whether the list of related records is empty. Reuses the existing
+    /// `none`/`some` machinery with an always-true nested filter, so it compiles identically to an
+    /// explicit `none: {}` / `some: {}`.
+    fn is_empty(&self, b: bool) -> Filter {
+        if b {
+            self.no_related(Filter::empty())
+        } else {
+            self.at_least_one_related(Filter::empty())
+        }
+    }
 }
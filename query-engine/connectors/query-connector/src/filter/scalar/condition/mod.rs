@@ -93,4 +93,51 @@ impl ScalarCondition {
             ScalarCondition::IsSet(_) => None,
         }
     }
+
+    /// See [`crate::Filter::selectivity_hint`]. Based purely on the comparison operator: it has no
+    /// way to know whether the field being compared is unique, which is why callers that do know
+    /// (e.g. the query engine's `ReadQuery::selectivity_hint`) special-case an equality filter on
+    /// a model's unique criteria before falling back to this.
+    pub fn selectivity_hint(&self) -> f64 {
+        match self {
+            Self::Equals(_) => 0.7,
+            Self::NotEquals(_) => 0.3,
+            Self::In(_) => 0.5,
+            Self::NotIn(_) => 0.2,
+            Self::LessThan(_) | Self::LessThanOrEquals(_) | Self::GreaterThan(_) | Self::GreaterThanOrEquals(_) => 0.4,
+            Self::Contains(_) | Self::StartsWith(_) | Self::EndsWith(_) | Self::Search(..) => 0.3,
+            Self::NotContains(_) | Self::NotStartsWith(_) | Self::NotEndsWith(_) | Self::NotSearch(..) => 0.2,
+            Self::JsonCompare(json_cond) => json_cond.condition.selectivity_hint() * 0.8,
+            Self::IsSet(_) => 0.2,
+        }
+    }
+
+    /// Structural equality that requires the same operator and, for `FieldRef` operands, the same
+    /// referenced field, but treats any two literal operands as interchangeable (see
+    /// [`ConditionValue::structurally_eq`]).
+    pub(crate) fn structurally_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Equals(a), Self::Equals(b))
+            | (Self::NotEquals(a), Self::NotEquals(b))
+            | (Self::Contains(a), Self::Contains(b))
+            | (Self::NotContains(a), Self::NotContains(b))
+            | (Self::StartsWith(a), Self::StartsWith(b))
+            | (Self::NotStartsWith(a), Self::NotStartsWith(b))
+            | (Self::EndsWith(a), Self::EndsWith(b))
+            | (Self::NotEndsWith(a), Self::NotEndsWith(b))
+            | (Self::LessThan(a), Self::LessThan(b))
+            | (Self::LessThanOrEquals(a), Self::LessThanOrEquals(b))
+            | (Self::GreaterThan(a), Self::GreaterThan(b))
+            | (Self::GreaterThanOrEquals(a), Self::GreaterThanOrEquals(b)) => a.structurally_eq(b),
+            (Self::In(a), Self::In(b)) | (Self::NotIn(a), Self::NotIn(b)) => a.structurally_eq(b),
+            (Self::JsonCompare(a), Self::JsonCompare(b)) => {
+                a.path == b.path && a.target_type == b.target_type && a.condition.structurally_eq(&b.condition)
+            }
+            (Self::Search(a, fa), Self::Search(b, fb)) | (Self::NotSearch(a, fa), Self::NotSearch(b, fb)) => {
+                a.structurally_eq(b) && fa == fb
+            }
+            (Self::IsSet(a), Self::IsSet(b)) => a == b,
+            _ => false,
+        }
+    }
 }
@@ -46,6 +46,18 @@ impl ConditionValue {
             None
         }
     }
+
+    /// Structural equality that treats any two literals as equivalent regardless of their actual
+    /// value, since only the shape of the comparison (literal vs. field reference) is significant
+    /// for this purpose. `FieldRef` operands still need genuine equality, since which field is
+    /// referenced changes what the filter means.
+    pub(crate) fn structurally_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Value(_), Self::Value(_)) => true,
+            (Self::FieldRef(a), Self::FieldRef(b)) => a == b,
+            _ => false,
+        }
+    }
 }
 
 impl From<PrismaValue> for ConditionValue {
@@ -102,6 +114,16 @@ impl ConditionListValue {
             None
         }
     }
+
+    /// Structural equality that treats any two literal lists as equivalent regardless of their
+    /// contents (see [`ConditionValue::structurally_eq`]).
+    pub(crate) fn structurally_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::List(_), Self::List(_)) => true,
+            (Self::FieldRef(a), Self::FieldRef(b)) => a == b,
+            _ => false,
+        }
+    }
 }
 
 impl From<PrismaListValue> for ConditionListValue {
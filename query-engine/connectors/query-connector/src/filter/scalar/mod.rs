@@ -48,6 +48,29 @@ impl ScalarFilter {
         self.len() == 0
     }
 
+    /// Approximate number of bind parameters this filter contributes to a query. Mirrors `len()`
+    /// for `IN`/`NOT IN`, recurses into `JsonCompare`, and counts every other condition as one
+    /// parameter (even a `FieldRef` comparison, which binds none, to keep this an over-estimate).
+    pub fn approximate_param_count(&self) -> usize {
+        match &self.condition {
+            ScalarCondition::JsonCompare(json_cond) => {
+                let inner = ScalarFilter {
+                    projection: self.projection.clone(),
+                    condition: (*json_cond.condition).clone(),
+                    mode: self.mode.clone(),
+                };
+
+                inner.approximate_param_count()
+            }
+            _ => self.len(),
+        }
+    }
+
+    /// See [`crate::Filter::selectivity_hint`].
+    pub fn selectivity_hint(&self) -> f64 {
+        self.condition.selectivity_hint()
+    }
+
     /// If `true`, the filter should be split into smaller filters executed in
     /// separate queries.
     pub fn should_batch(&self, chunk_size: usize) -> bool {
@@ -145,4 +168,42 @@ impl ScalarFilter {
     pub fn scalar_ref(&self) -> Option<&ScalarFieldRef> {
         self.projection.as_single()
     }
+
+    /// Canonicalizes `IN`/`NOT IN` operand order so that filters differing only in list order are
+    /// otherwise structurally identical.
+    pub fn normalize(self) -> Self {
+        let condition = match self.condition {
+            ScalarCondition::In(ConditionListValue::List(mut list)) => {
+                list.sort();
+                ScalarCondition::In(ConditionListValue::List(list))
+            }
+            ScalarCondition::NotIn(ConditionListValue::List(mut list)) => {
+                list.sort();
+                ScalarCondition::NotIn(ConditionListValue::List(list))
+            }
+            other => other,
+        };
+
+        Self { condition, ..self }
+    }
+
+    /// Structural equality that ignores the actual literal values being compared against, but
+    /// requires the same field(s), the same `QueryMode`, and the same operator/`FieldRef` shape
+    /// (see [`ScalarCondition::structurally_eq`]).
+    pub(crate) fn structurally_eq(&self, other: &Self) -> bool {
+        self.projection == other.projection && self.mode == other.mode && self.condition.structurally_eq(&other.condition)
+    }
+
+    /// Stable key used to order commutative filter operands: field name(s) first, then the
+    /// condition (whose `Debug` representation already carries the operator and operand).
+    pub(crate) fn sort_key(&self) -> String {
+        let fields = self
+            .scalar_fields()
+            .iter()
+            .map(|f| f.db_name().to_owned())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!("{fields}:{:?}", self.condition)
+    }
 }
@@ -1,4 +1,8 @@
-use crate::{coerce_null_to_zero_value, Filter, NativeUpsert, QueryArguments, WriteArgs};
+use crate::{
+    coerce_null_to_zero_value,
+    error::{ConnectorError, ErrorKind},
+    Filter, NativeUpsert, QueryArguments, WriteArgs,
+};
 use async_trait::async_trait;
 use prisma_models::{ast::FieldArity, *};
 use prisma_value::PrismaValue;
@@ -9,6 +13,18 @@ pub trait Connector {
     /// Returns a connection to a data source.
     async fn get_connection(&self) -> crate::Result<Box<dyn Connection + Send + Sync>>;
 
+    /// Returns a connection suitable for a read-only query, which a connector configured with
+    /// read replicas may route to one of them instead of the primary. `force_primary` overrides
+    /// that routing to guarantee read-after-write consistency (e.g. reading back a record right
+    /// after writing it in the same request, before a replica may have caught up).
+    ///
+    /// Defaults to [`Connector::get_connection`] — the primary — for connectors that don't
+    /// maintain replicas.
+    async fn get_read_connection(&self, force_primary: bool) -> crate::Result<Box<dyn Connection + Send + Sync>> {
+        let _ = force_primary;
+        self.get_connection().await
+    }
+
     /// Returns the name of the connector.
     fn name(&self) -> &'static str;
 
@@ -19,9 +35,16 @@ pub trait Connector {
 
 #[async_trait]
 pub trait Connection: ConnectionLike {
+    /// `session_context` is a set of key/value pairs the client asked to have applied on this
+    /// transaction's pinned connection before it runs anything else, e.g. `app.current_tenant`
+    /// for a Postgres row-level security policy to key off of. Connectors that support it apply
+    /// each pair with [`quaint::connector::Queryable::set_session_context_value`]; connectors
+    /// without an equivalent mechanism (Mongo) reject it if non-empty rather than silently
+    /// dropping a setting the client may be relying on for security purposes.
     async fn start_transaction<'a>(
         &'a mut self,
         isolation_level: Option<String>,
+        session_context: &[(String, String)],
     ) -> crate::Result<Box<dyn Transaction + 'a>>;
 
     /// Explicit upcast.
@@ -38,9 +61,41 @@ pub trait Transaction: ConnectionLike {
     fn as_connection_like(&mut self) -> &mut dyn ConnectionLike;
 }
 
+fn unsupported_savepoints_error() -> ConnectorError {
+    ConnectorError::from_kind(ErrorKind::UnsupportedFeature(
+        "Savepoints are not supported by this connector".to_owned(),
+    ))
+}
+
 /// Marker trait required by the query core executor to abstract connections and
 /// transactions into something that can is capable of writing to or reading from the database.
-pub trait ConnectionLike: ReadOperations + WriteOperations + Send + Sync {}
+#[async_trait]
+pub trait ConnectionLike: ReadOperations + WriteOperations + Send + Sync {
+    /// Creates a savepoint with `name` inside the current transaction, so a later, failed part of
+    /// the transaction can be undone with [`ConnectionLike::rollback_to_savepoint`] without rolling
+    /// back (and thereby poisoning) the whole transaction. `name` must already be a valid, unquoted
+    /// SQL identifier — callers control it, it's never derived from user input.
+    ///
+    /// Defaults to an [`ErrorKind::UnsupportedFeature`] error, for connectors (e.g. MongoDB, which
+    /// has no equivalent concept) and plain, non-transactional connections that don't override it.
+    async fn create_savepoint(&mut self, name: &str) -> crate::Result<()> {
+        let _ = name;
+        Err(unsupported_savepoints_error())
+    }
+
+    /// Undoes everything done since the matching [`ConnectionLike::create_savepoint`] call, without
+    /// rolling back the rest of the transaction.
+    async fn rollback_to_savepoint(&mut self, name: &str) -> crate::Result<()> {
+        let _ = name;
+        Err(unsupported_savepoints_error())
+    }
+
+    /// Discards a savepoint once it's no longer needed.
+    async fn release_savepoint(&mut self, name: &str) -> crate::Result<()> {
+        let _ = name;
+        Err(unsupported_savepoints_error())
+    }
+}
 
 /// A wrapper struct allowing to either filter for records or for the core to
 /// communicate already known record selectors to connectors.
@@ -176,10 +231,21 @@ pub enum AggregationResult {
     Max(ScalarFieldRef, PrismaValue),
 }
 
+/// A relation aggregation to compute alongside a `RecordQuery`/`ManyRecordsQuery`, over the
+/// records of a to-many relation. `Count` needs no target field (`count(*)`); `Sum`/`Average`/
+/// `Min`/`Max` aggregate one scalar field of the related model.
+///
+/// Note: only `Count` is currently reachable from the client API (via `_count` in `select`/
+/// `include`) — the query graph builder and schema output types for `_sum`/`_avg`/`_min`/`_max`
+/// on relations are follow-up work. The variants below are wired all the way through the SQL and
+/// Mongo connectors and the interpreter so that work has a connector-level contract to build on.
 #[derive(Debug, Clone)]
 pub enum RelAggregationSelection {
-    // Always a count(*) for now
     Count(RelationFieldRef, Option<Filter>),
+    Sum(RelationFieldRef, ScalarFieldRef, Option<Filter>),
+    Average(RelationFieldRef, ScalarFieldRef, Option<Filter>),
+    Min(RelationFieldRef, ScalarFieldRef, Option<Filter>),
+    Max(RelationFieldRef, ScalarFieldRef, Option<Filter>),
 }
 
 pub type RelAggregationRow = Vec<RelAggregationResult>;
@@ -187,50 +253,104 @@ pub type RelAggregationRow = Vec<RelAggregationResult>;
 #[derive(Debug, Clone)]
 pub enum RelAggregationResult {
     Count(RelationFieldRef, PrismaValue),
+    Sum(RelationFieldRef, ScalarFieldRef, PrismaValue),
+    Average(RelationFieldRef, ScalarFieldRef, PrismaValue),
+    Min(RelationFieldRef, ScalarFieldRef, PrismaValue),
+    Max(RelationFieldRef, ScalarFieldRef, PrismaValue),
 }
 
 impl RelAggregationSelection {
     pub fn db_alias(&self) -> String {
         match self {
-            RelAggregationSelection::Count(rf, _) => {
-                format!("_aggr_count_{}", rf.name())
-            }
+            RelAggregationSelection::Count(rf, _) => format!("_aggr_count_{}", rf.name()),
+            RelAggregationSelection::Sum(rf, sf, _) => format!("_aggr_sum_{}_{}", rf.name(), sf.name()),
+            RelAggregationSelection::Average(rf, sf, _) => format!("_aggr_avg_{}_{}", rf.name(), sf.name()),
+            RelAggregationSelection::Min(rf, sf, _) => format!("_aggr_min_{}_{}", rf.name(), sf.name()),
+            RelAggregationSelection::Max(rf, sf, _) => format!("_aggr_max_{}_{}", rf.name(), sf.name()),
         }
     }
 
     pub fn field_name(&self) -> &str {
         match self {
             RelAggregationSelection::Count(rf, _) => rf.name(),
+            RelAggregationSelection::Sum(rf, _, _)
+            | RelAggregationSelection::Average(rf, _, _)
+            | RelAggregationSelection::Min(rf, _, _)
+            | RelAggregationSelection::Max(rf, _, _) => rf.name(),
         }
     }
 
     pub fn type_identifier_with_arity(&self) -> (TypeIdentifier, FieldArity) {
         match self {
             RelAggregationSelection::Count(_, _) => (TypeIdentifier::Int, FieldArity::Required),
+            RelAggregationSelection::Sum(_, sf, _) => (sf.type_identifier(), FieldArity::Required),
+            // Empty relations have no average/minimum/maximum, so these are nullable even when
+            // the aggregated field itself is required.
+            RelAggregationSelection::Average(_, sf, _)
+            | RelAggregationSelection::Min(_, sf, _)
+            | RelAggregationSelection::Max(_, sf, _) => (sf.type_identifier(), FieldArity::Nullable),
         }
     }
 
     pub fn into_result(self, val: PrismaValue) -> RelAggregationResult {
         match self {
             RelAggregationSelection::Count(rf, _) => RelAggregationResult::Count(rf, coerce_null_to_zero_value(val)),
+            RelAggregationSelection::Sum(rf, sf, _) => {
+                RelAggregationResult::Sum(rf, sf, coerce_null_to_zero_value(val))
+            }
+            RelAggregationSelection::Average(rf, sf, _) => RelAggregationResult::Average(rf, sf, val),
+            RelAggregationSelection::Min(rf, sf, _) => RelAggregationResult::Min(rf, sf, val),
+            RelAggregationSelection::Max(rf, sf, _) => RelAggregationResult::Max(rf, sf, val),
         }
     }
 }
 
 #[async_trait]
 pub trait ReadOperations {
+    /// Whether this connector can honor `QueryArguments::take_per_group`, i.e. render a per-group
+    /// row limit in the query itself (e.g. via `ROW_NUMBER() OVER (PARTITION BY ...)`) instead of
+    /// requiring the core to over-fetch and trim every group in memory.
+    ///
+    /// Defaults to `false`; connectors that support it override this to `true`.
+    fn supports_relation_load_strategy_pushdown(&self) -> bool {
+        false
+    }
+
+    /// Whether this connector can compile a nested relation load into the parent's query via a
+    /// join instead of running it as its own query (see [`crate::RelationLoadStrategy::Join`]).
+    ///
+    /// Defaults to `false`. No connector overrides this yet: it's the capability check a future
+    /// LATERAL-join/JSON-aggregation implementation would flip to `true` once it exists.
+    fn supports_relation_join_strategy(&self) -> bool {
+        false
+    }
+
+    /// Whether this connector can honor `QueryArguments::distinct` itself (e.g. via `ROW_NUMBER()
+    /// OVER (PARTITION BY ...)`) for query shapes where `QueryArguments::can_push_down_distinct`
+    /// returns `true`, instead of requiring the core to fetch every row and deduplicate in
+    /// memory.
+    ///
+    /// Defaults to `false`; connectors that support it override this to `true`.
+    fn supports_distinct_pushdown(&self) -> bool {
+        false
+    }
+
     /// Gets a single record or `None` back from the database.
     ///
     /// - The `ModelRef` represents the datamodel and its relations.
     /// - The `Filter` defines what item we want back and is guaranteed to be
     ///   defined to filter at most one item by the core.
     /// - The `FieldSelection` defines the values to be returned.
+    /// - The `index_hint` names an index on `model` to force the connector to use where it
+    ///   supports rendering such a hint. Connectors without an equivalent ignore it.
+    #[allow(clippy::too_many_arguments)]
     async fn get_single_record(
         &mut self,
         model: &Model,
         filter: &Filter,
         selected_fields: &FieldSelection,
         aggregation_selections: &[RelAggregationSelection],
+        index_hint: Option<&str>,
         trace_id: Option<String>,
     ) -> crate::Result<Option<SingleRecord>>;
 
@@ -249,6 +369,104 @@ pub trait ReadOperations {
         trace_id: Option<String>,
     ) -> crate::Result<ManyRecords>;
 
+    /// Like [`Self::get_many_records`], but pages through the result in chunks of at most
+    /// `chunk_size` records instead of loading the whole result set into memory at once, invoking
+    /// `on_chunk` with each page as it arrives. Intended for `findMany` queries expected to return
+    /// a very large number of records, where materializing everything before serialization would
+    /// otherwise blow up memory usage.
+    ///
+    /// Paging reuses the same cursor-based pagination (`QueryArguments::cursor` + `skip`) that
+    /// Prisma clients already use to page through results themselves, so it works uniformly across
+    /// every connector without any extra support from it. This still runs one `get_many_records`
+    /// call per page rather than opening a real server-side cursor at the database level (e.g.
+    /// Postgres `DECLARE CURSOR`), which would need the query to run against a still-open
+    /// transaction plus a per-connector streaming implementation; that's left as follow-up work,
+    /// as is surfacing this as HTTP chunked transfer or a node-api async iterator, both of which
+    /// live above the connector layer.
+    ///
+    /// Requires `query_arguments.order_by` to order by the model's primary identifier (or another
+    /// unique, fully ordered field selection) so that cursors reliably identify a page boundary;
+    /// callers that need results sorted some other way should sort within each chunk instead.
+    /// Enforced with [`QueryArguments::is_stable_ordering`]: an `order_by` that doesn't satisfy it
+    /// returns [`ErrorKind::QueryInvalidInput`] rather than silently paging on an unstable cursor.
+    ///
+    /// Called from the top-level `findMany` read path (`query_interpreters::read::read_many`)
+    /// whenever `PRISMA_QUERY_CHUNK_SIZE` is configured and the query doesn't already need
+    /// in-memory reprocessing; every other read still goes through [`Self::get_many_records`].
+    #[allow(clippy::too_many_arguments)]
+    async fn get_many_records_chunked(
+        &mut self,
+        model: &Model,
+        mut query_arguments: QueryArguments,
+        selected_fields: &FieldSelection,
+        aggregation_selections: &[RelAggregationSelection],
+        chunk_size: usize,
+        trace_id: Option<String>,
+        on_chunk: &mut (dyn FnMut(ManyRecords) -> crate::Result<()> + Send),
+    ) -> crate::Result<usize> {
+        if !query_arguments.is_stable_ordering() {
+            return Err(ConnectorError::from_kind(ErrorKind::QueryInvalidInput(format!(
+                "get_many_records_chunked requires `order_by` to order by the model's primary \
+                 identifier or another unique, fully ordered field selection, so that a page's last \
+                 record can be used as a reliable cursor for the next one; got order_by: {:?}",
+                query_arguments.order_by
+            ))));
+        }
+
+        let chunk_size = chunk_size.max(1) as i64;
+        let cursor_selection = model.primary_identifier();
+        let overall_take = query_arguments.take;
+        let mut fetched: i64 = 0;
+
+        loop {
+            let page_take = match overall_take {
+                Some(take) => {
+                    let remaining = take - fetched;
+                    if remaining <= 0 {
+                        break;
+                    }
+                    remaining.min(chunk_size)
+                }
+                None => chunk_size,
+            };
+
+            query_arguments.take = Some(page_take);
+
+            let page = self
+                .get_many_records(
+                    model,
+                    query_arguments.clone(),
+                    selected_fields,
+                    aggregation_selections,
+                    trace_id.clone(),
+                )
+                .await?;
+
+            let page_len = page.records.len() as i64;
+            let last_cursor = page
+                .records
+                .last()
+                .map(|record| record.extract_selection_result(&page.field_names, &cursor_selection))
+                .transpose()?;
+
+            fetched += page_len;
+            let is_last_page = page_len < page_take;
+
+            on_chunk(page)?;
+
+            if is_last_page {
+                break;
+            }
+
+            let Some(cursor) = last_cursor else { break };
+
+            query_arguments.cursor = Some(cursor);
+            query_arguments.skip = Some(1);
+        }
+
+        Ok(fetched as usize)
+    }
+
     /// Retrieves pairs of IDs that belong together from a intermediate join
     /// table.
     ///
@@ -1,5 +1,6 @@
 use crate::filter::Filter;
 use prisma_models::*;
+use std::time::Duration;
 
 /// `QueryArguments` define various constraints queried data should fulfill:
 /// - `cursor`, `take`, `skip` page through the data.
@@ -13,7 +14,7 @@ use prisma_models::*;
 /// A query argument struct is always valid over a single model only, meaning that all
 /// data referenced in a single query argument instance is always refering to data of
 /// a single model (e.g. the cursor projection, distinct projection, orderby, ...).
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub struct QueryArguments {
     pub model: Model,
     pub cursor: Option<SelectionResult>,
@@ -24,6 +25,43 @@ pub struct QueryArguments {
     pub distinct: Option<FieldSelection>,
     pub ignore_skip: bool,
     pub ignore_take: bool,
+    /// The database name of an index to force the connector to use when reading, where the
+    /// connector supports rendering such a hint. Connectors without an equivalent ignore it.
+    pub index_hint: Option<String>,
+    /// When set, `take` is applied per distinct value of these fields instead of to the result
+    /// set as a whole: at most `take` rows are returned for every group of records that share the
+    /// same values for this selection, ordered by `order_by` within each group. Used to push a
+    /// nested relation's `take` down to the database (see `RelatedRecordsQuery`) instead of
+    /// fetching every related record and trimming each parent's slice in memory. Connectors that
+    /// can't express this fall back to treating `take` as a plain result-set-wide limit.
+    pub take_per_group: Option<FieldSelection>,
+    /// The strategy requested for resolving a nested relation load these arguments belong to. See
+    /// [`RelationLoadStrategy`] for what's actually implemented; use
+    /// [`Self::effective_relation_load_strategy`] to resolve it against what the executing
+    /// connector supports rather than reading this field directly.
+    pub relation_load_strategy: RelationLoadStrategy,
+    /// A per-query statement timeout, translated by the executing connector into a server-side
+    /// setting where one exists (`SET statement_timeout` on Postgres, `MAX_EXECUTION_TIME` on
+    /// MySQL) so a single slow query can't hold a pooled connection indefinitely. Connectors
+    /// without a session-level equivalent ignore it.
+    pub timeout: Option<Duration>,
+}
+
+/// How the executor should resolve a nested (`include`d) relation load: as its own query against
+/// the parent's already-fetched keys (`Query`, today's only implemented strategy), or compiled into
+/// the parent's query via a join (`Join`).
+///
+/// `Join` is accepted as a request throughout the core and connector interfaces, but no connector
+/// implements it yet — every [`crate::ReadOperations::supports_relation_join_strategy`] currently
+/// returns `false`, so [`QueryArguments::effective_relation_load_strategy`] always falls back to
+/// `Query`. Compiling nested reads into a single LATERAL join / JSON aggregation statement on
+/// Postgres and MySQL is tracked as follow-up work; this type and the capability check are the
+/// extension point for it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RelationLoadStrategy {
+    #[default]
+    Query,
+    Join,
 }
 
 impl std::fmt::Debug for QueryArguments {
@@ -38,6 +76,10 @@ impl std::fmt::Debug for QueryArguments {
             .field("distinct", &self.distinct)
             .field("ignore_skip", &self.ignore_skip)
             .field("ignore_take", &self.ignore_take)
+            .field("index_hint", &self.index_hint)
+            .field("take_per_group", &self.take_per_group)
+            .field("relation_load_strategy", &self.relation_load_strategy)
+            .field("timeout", &self.timeout)
             .finish()
     }
 }
@@ -54,6 +96,20 @@ impl QueryArguments {
             distinct: None,
             ignore_take: false,
             ignore_skip: false,
+            index_hint: None,
+            take_per_group: None,
+            relation_load_strategy: RelationLoadStrategy::default(),
+            timeout: None,
+        }
+    }
+
+    /// Resolves [`Self::relation_load_strategy`] against whether the connector executing this
+    /// nested load actually supports it, falling back to [`RelationLoadStrategy::Query`] whenever
+    /// [`RelationLoadStrategy::Join`] was requested but isn't supported.
+    pub fn effective_relation_load_strategy(&self, connector_supports_join: bool) -> RelationLoadStrategy {
+        match self.relation_load_strategy {
+            RelationLoadStrategy::Join if connector_supports_join => RelationLoadStrategy::Join,
+            _ => RelationLoadStrategy::Query,
         }
     }
 
@@ -74,6 +130,23 @@ impl QueryArguments {
         self.distinct.is_some() || self.contains_unstable_cursor() || self.contains_null_cursor()
     }
 
+    /// Whether `distinct` is shaped so it *could* be pushed down to the database (as a
+    /// `ROW_NUMBER()`-partitioned query, see `sql-query-connector`'s `get_records_distinct`)
+    /// instead of being applied in-memory, if the executing connector supports it (see
+    /// [`crate::ReadOperations::supports_distinct_pushdown`]). We only consider pushing down when
+    /// there's no cursor to interact with, since cursor-based pagination combined with distinct
+    /// isn't implemented, and every orderBy is a plain scalar ordering on the model itself, since
+    /// the row numbering only has access to columns of the model being queried, not of a joined
+    /// relation.
+    pub fn can_push_down_distinct(&self) -> bool {
+        self.distinct.is_some()
+            && self.cursor.is_none()
+            && self
+                .order_by
+                .iter()
+                .all(|o| matches!(o, OrderBy::Scalar(o) if o.path.is_empty()))
+    }
+
     /// An unstable cursor is a cursor that is used in conjunction with an unstable (non-unique) combination of orderBys.
     pub fn contains_unstable_cursor(&self) -> bool {
         self.cursor.is_some() && !self.is_stable_ordering()
@@ -200,6 +273,10 @@ impl QueryArguments {
                 let distinct = self.distinct;
                 let ignore_skip = self.ignore_skip;
                 let ignore_take = self.ignore_take;
+                let index_hint = self.index_hint;
+                let take_per_group = self.take_per_group;
+                let relation_load_strategy = self.relation_load_strategy;
+                let timeout = self.timeout;
 
                 filter
                     .batched(chunk_size)
@@ -214,6 +291,10 @@ impl QueryArguments {
                         distinct: distinct.clone(),
                         ignore_skip,
                         ignore_take,
+                        index_hint: index_hint.clone(),
+                        take_per_group: take_per_group.clone(),
+                        relation_load_strategy,
+                        timeout,
                     })
                     .collect()
             }
@@ -224,6 +305,21 @@ impl QueryArguments {
     pub fn model(&self) -> &Model {
         &self.model
     }
+
+    /// Approximate number of bind parameters these arguments contribute to a query: the filter's
+    /// parameters plus one per cursor field (cursor-based pagination binds each field of the
+    /// cursor's selection). Over-estimates rather than under-estimates.
+    pub fn approximate_param_count(&self) -> usize {
+        let filter_params = self
+            .filter
+            .as_ref()
+            .map(|filter| filter.approximate_param_count())
+            .unwrap_or(0);
+
+        let cursor_params = self.cursor.as_ref().map(|c| c.pairs.len()).unwrap_or(0);
+
+        filter_params + cursor_params
+    }
 }
 
 impl<T> From<(Model, T)> for QueryArguments
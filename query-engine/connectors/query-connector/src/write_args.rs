@@ -68,6 +68,10 @@ impl WriteOperation {
         Self::Scalar(ScalarWriteOperation::Add(pv))
     }
 
+    pub fn scalar_prepend(pv: PrismaValue) -> Self {
+        Self::Scalar(ScalarWriteOperation::Prepend(pv))
+    }
+
     pub fn scalar_substract(pv: PrismaValue) -> Self {
         Self::Scalar(ScalarWriteOperation::Substract(pv))
     }
@@ -158,9 +162,14 @@ pub enum ScalarWriteOperation {
     /// Unsets a field (only for MongoDB for now)
     Unset(bool),
 
-    /// Add value to field.
+    /// Add value to field. For a scalar list field, appends the value(s) to the end of the list
+    /// (`push`).
     Add(PrismaValue),
 
+    /// Prepend value(s) to the start of a scalar list field (`unshift`). Only valid for scalar
+    /// list fields.
+    Prepend(PrismaValue),
+
     /// Substract value from field
     Substract(PrismaValue),
 
@@ -488,5 +497,6 @@ pub fn apply_expression(val: PrismaValue, scalar_write: ScalarWriteOperation) ->
         ScalarWriteOperation::Multiply(rhs) => val * rhs,
         ScalarWriteOperation::Divide(rhs) => val / rhs,
         ScalarWriteOperation::Unset(_) => unimplemented!(),
+        ScalarWriteOperation::Prepend(_) => unimplemented!(),
     }
 }
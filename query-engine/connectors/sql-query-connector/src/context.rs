@@ -1,4 +1,5 @@
-use quaint::prelude::ConnectionInfo;
+use prisma_models::NullsOrder;
+use quaint::prelude::{ConnectionInfo, SqlFamily};
 
 pub(super) struct Context<'a> {
     connection_info: &'a ConnectionInfo,
@@ -9,6 +10,11 @@ pub(super) struct Context<'a> {
     /// Maximum number of bind parameters allowed for a single query.
     /// None is unlimited.
     pub(crate) max_bind_values: Option<usize>,
+    /// Null ordering applied to order terms that don't set one explicitly. `None` (the
+    /// `DatabaseDefault` setting) leaves the underlying database's native null ordering
+    /// untouched, which is why identical `orderBy`s can otherwise return differently
+    /// ordered results across MySQL and Postgres.
+    null_ordering: Option<NullsOrder>,
 }
 
 impl<'a> Context<'a> {
@@ -25,12 +31,24 @@ impl<'a> Context<'a> {
             trace_id,
             max_rows,
             max_bind_values: get_batch_size(default_batch_size),
+            null_ordering: get_null_ordering(),
         }
     }
 
     pub(crate) fn schema_name(&self) -> &str {
         self.connection_info.schema_name()
     }
+
+    pub(crate) fn sql_family(&self) -> SqlFamily {
+        self.connection_info.sql_family()
+    }
+
+    /// Resolves the null ordering to use for an order term: `explicit` wins when the term
+    /// sets its own `nulls` placement, otherwise falls back to the connector's configured
+    /// `null_ordering` (which is `None`/`DatabaseDefault` unless overridden).
+    pub(crate) fn resolve_nulls_order(&self, explicit: Option<&NullsOrder>) -> Option<NullsOrder> {
+        explicit.cloned().or_else(|| self.null_ordering.clone())
+    }
 }
 
 fn get_batch_size(default: usize) -> Option<usize> {
@@ -48,3 +66,64 @@ fn get_batch_size(default: usize) -> Option<usize> {
     });
     (*BATCH_SIZE_OVERRIDE).or(Some(default))
 }
+
+fn get_null_ordering() -> Option<NullsOrder> {
+    use once_cell::sync::Lazy;
+
+    /// Overrides the connector's default null ordering for order terms that don't set one
+    /// explicitly (`DatabaseDefault` otherwise, i.e. no override). Accepts `nulls_first` or
+    /// `nulls_last`; set with the `QUERY_NULL_ORDERING` environment variable so cross-database
+    /// tests and clients that need consistent ordering don't have to rely on each database's
+    /// native (and differing) default null placement.
+    static NULL_ORDERING_OVERRIDE: Lazy<Option<NullsOrder>> = Lazy::new(|| {
+        std::env::var("QUERY_NULL_ORDERING")
+            .ok()
+            .and_then(|value| match value.to_lowercase().as_str() {
+                "nulls_first" => Some(NullsOrder::First),
+                "nulls_last" => Some(NullsOrder::Last),
+                _ => None,
+            })
+    });
+    (*NULL_ORDERING_OVERRIDE).clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use once_cell::sync::Lazy;
+
+    static CONNECTION_INFO: Lazy<ConnectionInfo> = Lazy::new(|| ConnectionInfo::InMemorySqlite {
+        db_name: "test".to_owned(),
+    });
+
+    fn ctx_with_null_ordering(null_ordering: Option<NullsOrder>) -> Context<'static> {
+        Context {
+            connection_info: &CONNECTION_INFO,
+            trace_id: None,
+            max_rows: None,
+            max_bind_values: None,
+            null_ordering,
+        }
+    }
+
+    #[test]
+    fn explicit_nulls_order_wins_over_connector_default() {
+        let ctx = ctx_with_null_ordering(Some(NullsOrder::Last));
+
+        assert_eq!(ctx.resolve_nulls_order(Some(&NullsOrder::First)), Some(NullsOrder::First));
+    }
+
+    #[test]
+    fn connector_default_fills_in_an_unset_nulls_order() {
+        let ctx = ctx_with_null_ordering(Some(NullsOrder::Last));
+
+        assert_eq!(ctx.resolve_nulls_order(None), Some(NullsOrder::Last));
+    }
+
+    #[test]
+    fn database_default_leaves_an_unset_nulls_order_unset() {
+        let ctx = ctx_with_null_ordering(None);
+
+        assert_eq!(ctx.resolve_nulls_order(None), None);
+    }
+}
@@ -45,6 +45,7 @@ where
     async fn start_transaction<'a>(
         &'a mut self,
         isolation_level: Option<String>,
+        session_context: &[(String, String)],
     ) -> connector::Result<Box<dyn Transaction + 'a>> {
         let connection_info = &self.connection_info;
         let features = self.features;
@@ -59,7 +60,7 @@ where
             None => None,
         };
 
-        let fut_tx = self.inner.start_transaction(isolation_level);
+        let fut_tx = self.inner.start_transaction(isolation_level, session_context);
 
         catch(self.connection_info.clone(), async move {
             let tx = fut_tx.await.map_err(SqlError::from)?;
@@ -79,12 +80,21 @@ impl<C> ReadOperations for SqlConnection<C>
 where
     C: Queryable + Send + Sync + 'static,
 {
+    fn supports_relation_load_strategy_pushdown(&self) -> bool {
+        self.connection_info.sql_family().is_postgres()
+    }
+
+    fn supports_distinct_pushdown(&self) -> bool {
+        self.connection_info.sql_family().is_postgres()
+    }
+
     async fn get_single_record(
         &mut self,
         model: &Model,
         filter: &Filter,
         selected_fields: &FieldSelection,
         aggr_selections: &[RelAggregationSelection],
+        index_hint: Option<&str>,
         trace_id: Option<String>,
     ) -> connector::Result<Option<SingleRecord>> {
         // [Composites] todo: FieldSelection -> ModelProjection conversion
@@ -96,6 +106,7 @@ where
                 filter,
                 &selected_fields.into(),
                 aggr_selections,
+                index_hint,
                 &ctx,
             )
             .await
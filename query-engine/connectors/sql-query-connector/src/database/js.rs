@@ -163,7 +163,8 @@ impl TransactionCapable for DriverAdapter {
     async fn start_transaction<'a>(
         &'a self,
         isolation: Option<IsolationLevel>,
+        session_context: &[(String, String)],
     ) -> quaint::Result<Box<dyn Transaction + 'a>> {
-        self.connector.start_transaction(isolation).await
+        self.connector.start_transaction(isolation, session_context).await
     }
 }
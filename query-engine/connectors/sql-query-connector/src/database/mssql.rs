@@ -74,6 +74,7 @@ impl Connector for Mssql {
     }
 
     fn should_retry_on_transient_error(&self) -> bool {
-        false
+        // MSSQL's deadlock victim error (1205) is mapped to a transient `TransactionWriteConflict`.
+        true
     }
 }
@@ -81,6 +81,7 @@ impl Connector for Mysql {
     }
 
     fn should_retry_on_transient_error(&self) -> bool {
-        false
+        // MySQL's ER_LOCK_DEADLOCK (1213) is mapped to a transient `TransactionWriteConflict`.
+        true
     }
 }
@@ -8,7 +8,20 @@ use crate::{
 use connector_interface::*;
 use futures::stream::{FuturesUnordered, StreamExt};
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
+
+/// MySQL and MSSQL render index hints natively; other connectors silently drop them at the SQL
+/// level, so we surface that as a warning here instead of failing the query outright.
+fn warn_on_unsupported_index_hint(index_hint: Option<&str>, ctx: &Context<'_>) {
+    if let Some(index_name) = index_hint {
+        if !matches!(ctx.sql_family(), SqlFamily::Mysql | SqlFamily::Mssql) {
+            tracing::warn!(
+                "Ignoring index hint `{index_name}`: {} does not support forcing an index.",
+                ctx.sql_family()
+            );
+        }
+    }
+}
 
 pub(crate) async fn get_single_record(
     conn: &dyn Queryable,
@@ -16,9 +29,15 @@ pub(crate) async fn get_single_record(
     filter: &Filter,
     selected_fields: &ModelProjection,
     aggr_selections: &[RelAggregationSelection],
+    index_hint: Option<&str>,
     ctx: &Context<'_>,
 ) -> crate::Result<Option<SingleRecord>> {
-    let query = read::get_records(model, selected_fields.as_columns(ctx), aggr_selections, filter, ctx);
+    warn_on_unsupported_index_hint(index_hint, ctx);
+
+    let mut args = QueryArguments::from((model.clone(), filter.clone()));
+    args.index_hint = index_hint.map(ToOwned::to_owned);
+
+    let query = read::get_records(model, selected_fields.as_columns(ctx), aggr_selections, args, ctx);
 
     let mut field_names: Vec<_> = selected_fields.db_names().collect();
     let mut aggr_field_names: Vec<_> = aggr_selections.iter().map(|aggr_sel| aggr_sel.db_alias()).collect();
@@ -48,6 +67,32 @@ pub(crate) async fn get_single_record(
 }
 
 pub(crate) async fn get_many_records(
+    conn: &dyn Queryable,
+    model: &Model,
+    query_arguments: QueryArguments,
+    selected_fields: &ModelProjection,
+    aggr_selections: &[RelAggregationSelection],
+    ctx: &Context<'_>,
+) -> crate::Result<ManyRecords> {
+    // The SET/RESET pair wraps the whole call, including any batching `get_many_records_inner`
+    // does internally, so a query split into several statements because of parameter limits still
+    // counts as a single timed-out operation rather than one timeout per batch.
+    match query_arguments
+        .timeout
+        .and_then(|timeout| conn.statement_timeout_statements(timeout))
+    {
+        Some((set, reset)) => {
+            conn.raw_cmd(&set).await?;
+            let result =
+                get_many_records_inner(conn, model, query_arguments, selected_fields, aggr_selections, ctx).await;
+            conn.raw_cmd(&reset).await?;
+            result
+        }
+        None => get_many_records_inner(conn, model, query_arguments, selected_fields, aggr_selections, ctx).await,
+    }
+}
+
+async fn get_many_records_inner(
     conn: &dyn Queryable,
     model: &Model,
     mut query_arguments: QueryArguments,
@@ -55,6 +100,8 @@ pub(crate) async fn get_many_records(
     aggr_selections: &[RelAggregationSelection],
     ctx: &Context<'_>,
 ) -> crate::Result<ManyRecords> {
+    warn_on_unsupported_index_hint(query_arguments.index_hint.as_deref(), ctx);
+
     let reversed = query_arguments.needs_reversed_order();
 
     let mut field_names: Vec<_> = selected_fields.db_names().collect();
@@ -78,6 +125,47 @@ pub(crate) async fn get_many_records(
         return Ok(records);
     };
 
+    if let Some(partition_by) = query_arguments.take_per_group.clone() {
+        let query = read::get_records_partitioned_by(
+            model,
+            selected_fields.as_columns(ctx),
+            &partition_by.into(),
+            query_arguments,
+            ctx,
+        );
+
+        for item in conn.filter(query.into(), meta.as_slice(), ctx).await?.into_iter() {
+            records.push(Record::from(item))
+        }
+
+        if reversed {
+            records.reverse();
+        }
+
+        return Ok(records);
+    }
+
+    if query_arguments.can_push_down_distinct() {
+        let distinct_by = query_arguments.distinct.clone().unwrap();
+        let query = read::get_records_distinct(
+            model,
+            selected_fields.as_columns(ctx),
+            &distinct_by.into(),
+            query_arguments,
+            ctx,
+        );
+
+        for item in conn.filter(query.into(), meta.as_slice(), ctx).await?.into_iter() {
+            records.push(Record::from(item))
+        }
+
+        if reversed {
+            records.reverse();
+        }
+
+        return Ok(records);
+    }
+
     // Todo: This can't work for all cases. Cursor-based pagination will not work, because it relies on the ordering
     // to determine the right queries to fire, and will default to incorrect orderings if no ordering is found.
     // The should_batch has been adjusted to reflect that as a band-aid, but deeper investigation is necessary.
@@ -28,7 +28,7 @@ pub(crate) async fn update_one_with_selection(
     if args.args.is_empty() {
         let filter = build_update_one_filter(record_filter);
 
-        return get_single_record(conn, model, &filter, &selected_fields, &[], ctx).await;
+        return get_single_record(conn, model, &filter, &selected_fields, &[], None, ctx).await;
     }
 
     let cond = FilterBuilder::without_top_level_joins().visit_filter(build_update_one_filter(record_filter), ctx);
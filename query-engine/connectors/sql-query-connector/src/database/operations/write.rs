@@ -213,7 +213,35 @@ async fn create_many_nonempty(
     affected_fields: HashSet<ScalarFieldRef>,
     ctx: &Context<'_>,
 ) -> crate::Result<usize> {
-    let batches = if let Some(max_params) = ctx.max_bind_values {
+    let partitioned_batches = partition_into_batches(args, ctx.max_bind_values, ctx.max_rows);
+
+    trace!("Total of {} batches to be executed.", partitioned_batches.len());
+    trace!(
+        "Batch sizes: {:?}",
+        partitioned_batches.iter().map(|b| b.len()).collect_vec()
+    );
+
+    let mut count = 0;
+    for batch in partitioned_batches {
+        let stmt = write::create_records_nonempty(model, batch, skip_duplicates, &affected_fields, ctx);
+        count += conn.execute(stmt.into()).await?;
+    }
+
+    Ok(count as usize)
+}
+
+/// Splits a `createMany`'s rows into batches of at most `max_bind_values` bind parameters and at
+/// most `max_rows` rows each (`None` in either means unlimited), so a single bulk insert never
+/// produces a statement the flavour can't accept (e.g. SQLite's 999/32766 variable limit).
+/// Horizontal partitioning is by row count, vertical by bind parameter count; a row that doesn't
+/// fit in the current batch starts a new one.
+#[allow(clippy::mutable_key_type)]
+fn partition_into_batches(
+    args: Vec<WriteArgs>,
+    max_bind_values: Option<usize>,
+    max_rows: Option<usize>,
+) -> Vec<Vec<WriteArgs>> {
+    let batches = if let Some(max_params) = max_bind_values {
         // We need to split inserts if they are above a parameter threshold, as well as split based on number of rows.
         // -> Horizontal partitioning by row number, vertical by number of args.
         args.into_iter()
@@ -254,7 +282,7 @@ async fn create_many_nonempty(
         vec![args]
     };
 
-    let partitioned_batches = if let Some(max_rows) = ctx.max_rows {
+    if let Some(max_rows) = max_rows {
         let capacity = batches.len();
         batches
             .into_iter()
@@ -275,21 +303,7 @@ async fn create_many_nonempty(
             })
     } else {
         batches
-    };
-
-    trace!("Total of {} batches to be executed.", partitioned_batches.len());
-    trace!(
-        "Batch sizes: {:?}",
-        partitioned_batches.iter().map(|b| b.len()).collect_vec()
-    );
-
-    let mut count = 0;
-    for batch in partitioned_batches {
-        let stmt = write::create_records_nonempty(model, batch, skip_duplicates, &affected_fields, ctx);
-        count += conn.execute(stmt.into()).await?;
     }
-
-    Ok(count as usize)
 }
 
 /// Creates many empty (all default values) rows.
@@ -431,3 +445,53 @@ pub(crate) async fn query_raw(
 ) -> crate::Result<serde_json::Value> {
     Ok(conn.raw_json(inputs).await?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A row with `field_count` bind parameters, i.e. `field_count` args set on it.
+    fn row_with_fields(field_count: usize) -> WriteArgs {
+        let mut args = WriteArgs::new_empty(PrismaValue::Null);
+
+        for i in 0..field_count {
+            let field = DatasourceFieldName(format!("field{i}"));
+            args.insert(field, WriteOperation::scalar_set(PrismaValue::Int(i as i64)));
+        }
+
+        args
+    }
+
+    #[test]
+    fn partition_into_batches_splits_by_configured_bind_value_limit() {
+        // 10 rows of 3 bind params each, batched at a limit of 10: at most 3 rows per batch.
+        let args = (0..10).map(|_| row_with_fields(3)).collect_vec();
+
+        let batches = partition_into_batches(args, Some(10), None);
+
+        assert_eq!(batches.len(), 4);
+        assert_eq!(batches.iter().map(|b| b.len()).collect_vec(), vec![3, 3, 3, 1]);
+    }
+
+    #[test]
+    fn partition_into_batches_also_splits_by_configured_row_limit() {
+        // 10 single-param rows: the bind value limit alone would fit them all in one batch, but a
+        // row limit of 4 must still split it further.
+        let args = (0..10).map(|_| row_with_fields(1)).collect_vec();
+
+        let batches = partition_into_batches(args, Some(1000), Some(4));
+
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches.iter().map(|b| b.len()).collect_vec(), vec![4, 4, 2]);
+    }
+
+    #[test]
+    fn partition_into_batches_is_a_single_batch_when_unlimited() {
+        let args = (0..25).map(|_| row_with_fields(5)).collect_vec();
+
+        let batches = partition_into_batches(args, None, None);
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].len(), 25);
+    }
+}
@@ -7,12 +7,20 @@ use connector_interface::{
 };
 use psl::builtin_connectors::COCKROACH;
 use quaint::{connector::PostgresFlavour, pooled::Quaint, prelude::ConnectionInfo};
-use std::time::Duration;
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
 
 pub struct PostgreSql {
     pool: Quaint,
     connection_info: ConnectionInfo,
     features: psl::PreviewFeatures,
+    /// Read-only replicas reads are routed to unless the caller forces the primary. Empty unless
+    /// [`PostgreSql::from_source_with_replicas`] was given replica URLs.
+    replicas: Vec<Quaint>,
+    /// Round-robins `replicas` so consecutive replica reads spread across all of them.
+    next_replica: AtomicUsize,
 }
 
 impl PostgreSql {
@@ -20,22 +28,20 @@ impl PostgreSql {
     pub fn features(&self) -> psl::PreviewFeatures {
         self.features
     }
-}
 
-#[async_trait]
-impl FromSource for PostgreSql {
-    async fn from_source(
-        source: &psl::Datasource,
-        url: &str,
-        features: psl::PreviewFeatures,
-    ) -> connector_interface::Result<Self> {
-        let database_str = url;
+    fn flavour(source: &psl::Datasource) -> PostgresFlavour {
+        if COCKROACH.is_provider(source.active_provider) {
+            PostgresFlavour::Cockroach
+        } else {
+            PostgresFlavour::Postgres
+        }
+    }
 
-        // This connection info is only used for error rendering. It does not matter that the flavour is not set.
+    fn build_pool(url: &str, flavour: PostgresFlavour) -> connector_interface::Result<Quaint> {
         let err_conn_info = ConnectionInfo::from_url(url).map_err(|err| {
             ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
                 details: err.to_string(),
-                url: database_str.to_string(),
+                url: url.to_string(),
             })
         })?;
 
@@ -43,27 +49,59 @@ impl FromSource for PostgreSql {
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&err_conn_info))?;
 
-        let flavour = if COCKROACH.is_provider(source.active_provider) {
-            PostgresFlavour::Cockroach
-        } else {
-            PostgresFlavour::Postgres
-        };
-
         // The postgres flavour is set in order to avoid a network roundtrip when connecting to the database.
         builder.set_postgres_flavour(flavour);
         builder.health_check_interval(Duration::from_secs(15));
         builder.test_on_check_out(true);
 
-        let pool = builder.build();
+        Ok(builder.build())
+    }
+
+    /// Like [`FromSource::from_source`], but additionally configures `replica_urls` as read-only
+    /// replicas: [`Connector::get_read_connection`] routes to one of them (round-robin) unless
+    /// asked to force the primary. Writes and transactions always use the primary pool, since
+    /// [`Connector::get_connection`] is unaffected by this.
+    ///
+    /// There is currently no way to configure replica URLs from the Prisma schema itself — that
+    /// would need a new datasource property and accompanying parser/validation support in `psl`,
+    /// which is out of scope here. Callers wire replica URLs in from their own configuration.
+    pub async fn from_source_with_replicas(
+        source: &psl::Datasource,
+        url: &str,
+        replica_urls: &[String],
+        features: psl::PreviewFeatures,
+    ) -> connector_interface::Result<Self> {
+        let flavour = Self::flavour(source);
+
+        let pool = Self::build_pool(url, flavour)?;
         let connection_info = pool.connection_info().to_owned();
+
+        let replicas = replica_urls
+            .iter()
+            .map(|replica_url| Self::build_pool(replica_url, flavour))
+            .collect::<connector_interface::Result<Vec<_>>>()?;
+
         Ok(PostgreSql {
             pool,
             connection_info,
             features,
+            replicas,
+            next_replica: AtomicUsize::new(0),
         })
     }
 }
 
+#[async_trait]
+impl FromSource for PostgreSql {
+    async fn from_source(
+        source: &psl::Datasource,
+        url: &str,
+        features: psl::PreviewFeatures,
+    ) -> connector_interface::Result<Self> {
+        Self::from_source_with_replicas(source, url, &[], features).await
+    }
+}
+
 #[async_trait]
 impl Connector for PostgreSql {
     async fn get_connection<'a>(&'a self) -> connector_interface::Result<Box<dyn Connection + Send + Sync + 'static>> {
@@ -75,11 +113,58 @@ impl Connector for PostgreSql {
         .await
     }
 
+    async fn get_read_connection<'a>(
+        &'a self,
+        force_primary: bool,
+    ) -> connector_interface::Result<Box<dyn Connection + Send + Sync + 'static>> {
+        if force_primary || self.replicas.is_empty() {
+            return self.get_connection().await;
+        }
+
+        let replica = &self.replicas[self.next_replica.fetch_add(1, Ordering::Relaxed) % self.replicas.len()];
+        let connection_info = replica.connection_info().to_owned();
+
+        super::catch(connection_info.clone(), async move {
+            let conn = replica.check_out().await.map_err(SqlError::from)?;
+            let conn = SqlConnection::new(conn, &connection_info, self.features);
+            Ok(Box::new(conn) as Box<dyn Connection + Send + Sync + 'static>)
+        })
+        .await
+    }
+
     fn name(&self) -> &'static str {
         "postgres"
     }
 
     fn should_retry_on_transient_error(&self) -> bool {
-        false
+        // Postgres reports both deadlocks and serialization failures under SQLSTATE 40001, which
+        // we map to a transient `TransactionWriteConflict`.
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Mirrors `PostgreSql::get_read_connection`'s index selection without needing a live pool.
+    fn next_index(counter: &AtomicUsize, len: usize) -> usize {
+        counter.fetch_add(1, Ordering::Relaxed) % len
+    }
+
+    #[test]
+    fn round_robins_across_replicas() {
+        let counter = AtomicUsize::new(0);
+        let picks: Vec<usize> = (0..5).map(|_| next_index(&counter, 3)).collect();
+
+        assert_eq!(picks, vec![0, 1, 2, 0, 1]);
+    }
+
+    #[test]
+    fn wraps_around_a_single_replica() {
+        let counter = AtomicUsize::new(0);
+        let picks: Vec<usize> = (0..3).map(|_| next_index(&counter, 1)).collect();
+
+        assert_eq!(picks, vec![0, 0, 0]);
     }
 }
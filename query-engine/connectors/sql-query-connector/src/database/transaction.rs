@@ -33,7 +33,29 @@ impl<'tx> SqlConnectorTransaction<'tx> {
     }
 }
 
-impl<'tx> ConnectionLike for SqlConnectorTransaction<'tx> {}
+#[async_trait]
+impl<'tx> ConnectionLike for SqlConnectorTransaction<'tx> {
+    async fn create_savepoint(&mut self, name: &str) -> connector::Result<()> {
+        catch(self.connection_info.clone(), async move {
+            self.inner.create_savepoint(name).await.map_err(SqlError::from)
+        })
+        .await
+    }
+
+    async fn rollback_to_savepoint(&mut self, name: &str) -> connector::Result<()> {
+        catch(self.connection_info.clone(), async move {
+            self.inner.rollback_to_savepoint(name).await.map_err(SqlError::from)
+        })
+        .await
+    }
+
+    async fn release_savepoint(&mut self, name: &str) -> connector::Result<()> {
+        catch(self.connection_info.clone(), async move {
+            self.inner.release_savepoint(name).await.map_err(SqlError::from)
+        })
+        .await
+    }
+}
 
 #[async_trait]
 impl<'tx> Transaction for SqlConnectorTransaction<'tx> {
@@ -63,12 +85,21 @@ impl<'tx> Transaction for SqlConnectorTransaction<'tx> {
 
 #[async_trait]
 impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
+    fn supports_relation_load_strategy_pushdown(&self) -> bool {
+        self.connection_info.sql_family().is_postgres()
+    }
+
+    fn supports_distinct_pushdown(&self) -> bool {
+        self.connection_info.sql_family().is_postgres()
+    }
+
     async fn get_single_record(
         &mut self,
         model: &Model,
         filter: &Filter,
         selected_fields: &FieldSelection,
         aggr_selections: &[RelAggregationSelection],
+        index_hint: Option<&str>,
         trace_id: Option<String>,
     ) -> connector::Result<Option<SingleRecord>> {
         catch(self.connection_info.clone(), async move {
@@ -79,6 +110,7 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
                 filter,
                 &selected_fields.into(),
                 aggr_selections,
+                index_hint,
                 &ctx,
             )
             .await
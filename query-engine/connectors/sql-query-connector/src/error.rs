@@ -255,7 +255,14 @@ impl SqlError {
             SqlError::TransactionAlreadyClosed(message) => {
                 ConnectorError::from_kind(ErrorKind::TransactionAlreadyClosed { message })
             }
-            SqlError::TransactionWriteConflict => ConnectorError::from_kind(ErrorKind::TransactionWriteConflict),
+            SqlError::TransactionWriteConflict => {
+                // Deadlocks and serialization failures are transient: retrying the same
+                // transaction from scratch commonly succeeds once the conflicting transaction
+                // has released its locks.
+                let mut err = ConnectorError::from_kind(ErrorKind::TransactionWriteConflict);
+                err.set_transient(true);
+                err
+            }
             SqlError::RollbackWithoutBegin => ConnectorError::from_kind(ErrorKind::RollbackWithoutBegin),
             SqlError::QueryParameterLimitExceeded(e) => {
                 ConnectorError::from_kind(ErrorKind::QueryParameterLimitExceeded(e))
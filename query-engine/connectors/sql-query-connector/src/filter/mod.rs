@@ -28,14 +28,14 @@ impl FilterBuilderWithJoins {
         filter: Filter,
         ctx: &Context,
     ) -> (ConditionTree<'static>, Option<Vec<AliasedJoin>>) {
-        FilterVisitor::with_top_level_joins().visit_filter(filter, ctx)
+        FilterVisitor::with_top_level_joins().visit_filter(filter.simplify(), ctx)
     }
 }
 
 impl FilterBuilderWithoutJoins {
     /// Visits a filter without any top-level joins. Can be safely used in any context.
     pub(crate) fn visit_filter(&self, filter: Filter, ctx: &Context) -> ConditionTree<'static> {
-        let (cond, _) = FilterVisitor::without_top_level_joins().visit_filter(filter, ctx);
+        let (cond, _) = FilterVisitor::without_top_level_joins().visit_filter(filter.simplify(), ctx);
 
         cond
     }
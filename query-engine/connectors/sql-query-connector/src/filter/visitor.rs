@@ -6,6 +6,7 @@ use connector_interface::filter::*;
 use prisma_models::prelude::*;
 use quaint::ast::concat;
 use quaint::ast::*;
+use quaint::prelude::SqlFamily;
 use std::convert::TryInto;
 
 pub(crate) trait FilterVisitorExt {
@@ -410,6 +411,10 @@ impl FilterVisitorExt for FilterVisitor {
         }
     }
 
+    /// Compiles `is: null` (and, inverted, `isNot: null`) on a to-one relation. When the relation
+    /// is inlined or joins can be rendered, this compiles directly to a null check on the
+    /// (joined) linking columns, i.e. an anti-join -- the `NOT IN` subselect further down is only
+    /// a fallback for contexts where joins can't be rendered.
     fn visit_one_relation_is_null_filter(
         &mut self,
         filter: OneRelationIsNullFilter,
@@ -652,6 +657,13 @@ fn convert_scalar_filter(
     }
 }
 
+/// Renders a filter on a value nested inside a Json column. `json_condition.path` (a
+/// `JsonFilterPath::String` for Postgres-style dotted paths or `::Array` for a pre-split segment
+/// list) picks out the nested value via `json_extract` before `condition` is compared against it;
+/// without a path, the condition applies to the whole Json value instead. `target_type` tells the
+/// per-connector `JsonCompare` impls (below) whether the extracted value should be treated as a
+/// Json-typed or already-unquoted string/array value, since Postgres and MySQL extract those
+/// differently (`jsonb_path_query`/`->>` vs `JSON_EXTRACT`).
 fn convert_json_filter(
     comparable: Expression<'static>,
     json_condition: JsonCondition,
@@ -911,69 +923,84 @@ fn insensitive_scalar_filter(
     is_parent_aggregation: bool,
     ctx: &Context<'_>,
 ) -> ConditionTree<'static> {
-    // Current workaround: We assume we can use ILIKE when we see `mode: insensitive`, because postgres is the only DB that has
-    // insensitive. We need a connector context for filter building that is unexpectedly complicated to integrate.
+    // Postgres has a native case-insensitive `ILIKE`/`NOT ILIKE` operator, so we use it directly
+    // there. The other connectors don't, so we fold both sides through `LOWER()` and compare with
+    // the regular (case-sensitive) `LIKE`/`NOT LIKE` instead. This means `mode: insensitive`
+    // prevents those connectors from using a native index on the column (see `LOWER()`-based
+    // functional indexes as a workaround), which is why we surface a warning below.
     let condition = match cond {
         ScalarCondition::Equals(ConditionValue::Value(PrismaValue::Null)) => comparable.is_null(),
         ScalarCondition::Equals(value) => match value {
-            ConditionValue::Value(value) => comparable.compare_raw("ILIKE", format!("{value}")),
-            ConditionValue::FieldRef(field_ref) => comparable.compare_raw("ILIKE", field_ref.aliased_col(alias, ctx)),
+            ConditionValue::Value(value) => ilike(comparable, format!("{value}"), false, ctx),
+            ConditionValue::FieldRef(field_ref) => {
+                ilike(comparable, field_ref.aliased_col(alias, ctx), false, ctx)
+            }
         },
         ScalarCondition::NotEquals(ConditionValue::Value(PrismaValue::Null)) => comparable.is_not_null(),
         ScalarCondition::NotEquals(value) => match value {
-            ConditionValue::Value(value) => comparable.compare_raw("NOT ILIKE", format!("{value}")),
-            ConditionValue::FieldRef(field_ref) => {
-                comparable.compare_raw("NOT ILIKE", field_ref.aliased_col(alias, ctx))
-            }
+            ConditionValue::Value(value) => ilike(comparable, format!("{value}"), true, ctx),
+            ConditionValue::FieldRef(field_ref) => ilike(comparable, field_ref.aliased_col(alias, ctx), true, ctx),
         },
         ScalarCondition::Contains(value) => match value {
-            ConditionValue::Value(value) => comparable.compare_raw("ILIKE", format!("%{value}%")),
-            ConditionValue::FieldRef(field_ref) => comparable.compare_raw(
-                "ILIKE",
+            ConditionValue::Value(value) => ilike(comparable, format!("%{value}%"), false, ctx),
+            ConditionValue::FieldRef(field_ref) => ilike(
+                comparable,
                 concat::<'_, Expression<'_>>(vec![
                     Value::text("%").into(),
                     field_ref.aliased_col(alias, ctx).into(),
                     Value::text("%").into(),
                 ]),
+                false,
+                ctx,
             ),
         },
         ScalarCondition::NotContains(value) => match value {
-            ConditionValue::Value(value) => comparable.compare_raw("NOT ILIKE", format!("%{value}%")),
-            ConditionValue::FieldRef(field_ref) => comparable.compare_raw(
-                "NOT ILIKE",
+            ConditionValue::Value(value) => ilike(comparable, format!("%{value}%"), true, ctx),
+            ConditionValue::FieldRef(field_ref) => ilike(
+                comparable,
                 concat::<'_, Expression<'_>>(vec![
                     Value::text("%").into(),
                     field_ref.aliased_col(alias, ctx).into(),
                     Value::text("%").into(),
                 ]),
+                true,
+                ctx,
             ),
         },
         ScalarCondition::StartsWith(value) => match value {
-            ConditionValue::Value(value) => comparable.compare_raw("ILIKE", format!("{value}%")),
-            ConditionValue::FieldRef(field_ref) => comparable.compare_raw(
-                "ILIKE",
+            ConditionValue::Value(value) => ilike(comparable, format!("{value}%"), false, ctx),
+            ConditionValue::FieldRef(field_ref) => ilike(
+                comparable,
                 concat::<'_, Expression<'_>>(vec![field_ref.aliased_col(alias, ctx).into(), Value::text("%").into()]),
+                false,
+                ctx,
             ),
         },
         ScalarCondition::NotStartsWith(value) => match value {
-            ConditionValue::Value(value) => comparable.compare_raw("NOT ILIKE", format!("{value}%")),
-            ConditionValue::FieldRef(field_ref) => comparable.compare_raw(
-                "NOT ILIKE",
+            ConditionValue::Value(value) => ilike(comparable, format!("{value}%"), true, ctx),
+            ConditionValue::FieldRef(field_ref) => ilike(
+                comparable,
                 concat::<'_, Expression<'_>>(vec![field_ref.aliased_col(alias, ctx).into(), Value::text("%").into()]),
+                true,
+                ctx,
             ),
         },
         ScalarCondition::EndsWith(value) => match value {
-            ConditionValue::Value(value) => comparable.compare_raw("ILIKE", format!("%{value}")),
-            ConditionValue::FieldRef(field_ref) => comparable.compare_raw(
-                "ILIKE",
+            ConditionValue::Value(value) => ilike(comparable, format!("%{value}"), false, ctx),
+            ConditionValue::FieldRef(field_ref) => ilike(
+                comparable,
                 concat::<'_, Expression<'_>>(vec![Value::text("%").into(), field_ref.aliased_col(alias, ctx).into()]),
+                false,
+                ctx,
             ),
         },
         ScalarCondition::NotEndsWith(value) => match value {
-            ConditionValue::Value(value) => comparable.compare_raw("NOT ILIKE", format!("%{value}")),
-            ConditionValue::FieldRef(field_ref) => comparable.compare_raw(
-                "NOT ILIKE",
+            ConditionValue::Value(value) => ilike(comparable, format!("%{value}"), true, ctx),
+            ConditionValue::FieldRef(field_ref) => ilike(
+                comparable,
                 concat::<'_, Expression<'_>>(vec![Value::text("%").into(), field_ref.aliased_col(alias, ctx).into()]),
+                true,
+                ctx,
             ),
         },
         ScalarCondition::LessThan(value) => {
@@ -1083,6 +1110,43 @@ fn insensitive_scalar_filter(
     ConditionTree::single(condition)
 }
 
+/// Renders a case-insensitive (in)equality/pattern comparison. Postgres has a native `ILIKE` /
+/// `NOT ILIKE` operator, so we use it directly there. Every other connector folds both sides
+/// through `LOWER()` and falls back to the regular `LIKE` / `NOT LIKE`, which prevents the
+/// database from using a plain index on the column, hence the warning.
+fn ilike<T>(comparable: Expression<'static>, pattern: T, negate: bool, ctx: &Context<'_>) -> Compare<'static>
+where
+    T: Into<Expression<'static>>,
+{
+    if matches!(ctx.sql_family(), SqlFamily::Postgres) {
+        let raw_comparator = if negate { "NOT ILIKE" } else { "ILIKE" };
+
+        comparable.compare_raw(raw_comparator, pattern)
+    } else {
+        warn_on_insensitive_filter_without_native_support(ctx);
+
+        let comparable: Expression = lower(comparable).into();
+        let pattern: Expression = lower(pattern).into();
+
+        if negate {
+            comparable.not_like(pattern)
+        } else {
+            comparable.like(pattern)
+        }
+    }
+}
+
+/// `mode: insensitive` is emulated on non-Postgres connectors via `LOWER(column) LIKE
+/// LOWER(pattern)`, which means the database can't use a regular index on `column` to satisfy the
+/// filter. Callers who need this to stay fast should add a computed/expression index on
+/// `LOWER(column)` themselves.
+fn warn_on_insensitive_filter_without_native_support(ctx: &Context<'_>) {
+    tracing::warn!(
+        "Using `mode: insensitive` on {} emulates case-insensitivity via `LOWER()`, which prevents the database from using a regular index on the filtered column.",
+        ctx.sql_family()
+    );
+}
+
 fn lower_if(expr: Expression<'_>, cond: bool) -> Expression<'_> {
     if cond {
         lower(expr).into()
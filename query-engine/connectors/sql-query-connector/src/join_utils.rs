@@ -14,6 +14,10 @@ pub(crate) struct AliasedJoin {
 #[derive(Debug, Clone)]
 pub(crate) enum AggregationType {
     Count,
+    Sum(ScalarFieldRef),
+    Average(ScalarFieldRef),
+    Min(ScalarFieldRef),
+    Max(ScalarFieldRef),
 }
 
 pub(crate) fn compute_aggr_join(
@@ -87,6 +91,10 @@ fn compute_aggr_join_one2m(
         .so_that(conditions);
     let aggr_expr = match aggregation {
         AggregationType::Count => count(asterisk()),
+        AggregationType::Sum(sf) => sum(sf.as_column(ctx)),
+        AggregationType::Average(sf) => avg(sf.as_column(ctx)),
+        AggregationType::Min(sf) => min(sf.as_column(ctx)),
+        AggregationType::Max(sf) => max(sf.as_column(ctx)),
     };
 
     // SELECT Child.<fk>,
@@ -178,6 +186,10 @@ fn compute_aggr_join_m2m(
 
     let aggr_expr = match aggregation {
         AggregationType::Count => count(m2m_child_columns.clone()),
+        AggregationType::Sum(sf) => sum(sf.as_column(ctx)),
+        AggregationType::Average(sf) => avg(sf.as_column(ctx)),
+        AggregationType::Min(sf) => min(sf.as_column(ctx)),
+        AggregationType::Max(sf) => max(sf.as_column(ctx)),
     };
 
     // SELECT _ParentToChild.ChildId,
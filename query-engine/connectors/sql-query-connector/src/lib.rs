@@ -15,6 +15,7 @@ mod query_arguments_ext;
 mod query_builder;
 mod query_ext;
 mod row;
+mod sql_preview;
 mod sql_trace;
 mod value;
 mod value_ext;
@@ -26,5 +27,6 @@ use quaint::prelude::Queryable;
 pub use database::{activate_driver_adapter, Js};
 pub use database::{FromSource, Mssql, Mysql, PostgreSql, Sqlite};
 pub use error::SqlError;
+pub use sql_preview::{preview, ReadPreview, SqlPreviewStatement};
 
 type Result<T> = std::result::Result<T, error::SqlError>;
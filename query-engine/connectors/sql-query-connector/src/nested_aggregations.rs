@@ -18,24 +18,29 @@ pub(crate) fn build(aggr_selections: &[RelAggregationSelection], ctx: &Context<'
     let mut columns: Vec<Expression<'static>> = vec![];
 
     for (index, selection) in aggr_selections.iter().enumerate() {
-        match selection {
-            RelAggregationSelection::Count(rf, filter) => {
-                let join_alias = format!("aggr_selection_{index}");
-                let aggregator_alias = selection.db_alias();
-                let join = compute_aggr_join(
-                    rf,
-                    AggregationType::Count,
-                    filter.clone(),
-                    aggregator_alias.as_str(),
-                    join_alias.as_str(),
-                    None,
-                    ctx,
-                );
+        let join_alias = format!("aggr_selection_{index}");
+        let aggregator_alias = selection.db_alias();
 
-                columns.push(Column::from((join.alias.clone(), aggregator_alias)).into());
-                joins.push(join);
-            }
-        }
+        let (rf, aggregation, filter) = match selection {
+            RelAggregationSelection::Count(rf, filter) => (rf, AggregationType::Count, filter),
+            RelAggregationSelection::Sum(rf, sf, filter) => (rf, AggregationType::Sum(sf.clone()), filter),
+            RelAggregationSelection::Average(rf, sf, filter) => (rf, AggregationType::Average(sf.clone()), filter),
+            RelAggregationSelection::Min(rf, sf, filter) => (rf, AggregationType::Min(sf.clone()), filter),
+            RelAggregationSelection::Max(rf, sf, filter) => (rf, AggregationType::Max(sf.clone()), filter),
+        };
+
+        let join = compute_aggr_join(
+            rf,
+            aggregation,
+            filter.clone(),
+            aggregator_alias.as_str(),
+            join_alias.as_str(),
+            None,
+            ctx,
+        );
+
+        columns.push(Column::from((join.alias.clone(), aggregator_alias)).into());
+        joins.push(join);
     }
 
     RelAggregationJoins { joins, columns }
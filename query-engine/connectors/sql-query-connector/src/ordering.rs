@@ -50,11 +50,8 @@ impl OrderByBuilder {
         ctx: &Context<'_>,
     ) -> OrderByDefinition {
         let (joins, order_column) = self.compute_joins_scalar(order_by, ctx);
-        let order: Option<Order> = Some(into_order(
-            &order_by.sort_order,
-            order_by.nulls_order.as_ref(),
-            needs_reversed_order,
-        ));
+        let nulls_order = ctx.resolve_nulls_order(order_by.nulls_order.as_ref());
+        let order: Option<Order> = Some(into_order(&order_by.sort_order, nulls_order.as_ref(), needs_reversed_order));
         let order_definition: OrderDefinition = (order_column.clone().into(), order);
 
         OrderByDefinition {
@@ -72,7 +69,8 @@ impl OrderByBuilder {
     ) -> OrderByDefinition {
         let columns: Vec<Expression> = order_by.fields.iter().map(|sf| sf.as_column(ctx).into()).collect();
         let order_column: Expression = text_search_relevance(&columns, order_by.search.clone()).into();
-        let order: Option<Order> = Some(into_order(&order_by.sort_order, None, needs_reversed_order));
+        let nulls_order = ctx.resolve_nulls_order(None);
+        let order: Option<Order> = Some(into_order(&order_by.sort_order, nulls_order.as_ref(), needs_reversed_order));
         let order_definition: OrderDefinition = (order_column.clone(), order);
 
         OrderByDefinition {
@@ -88,7 +86,8 @@ impl OrderByBuilder {
         needs_reversed_order: bool,
         ctx: &Context<'_>,
     ) -> OrderByDefinition {
-        let order: Option<Order> = Some(into_order(&order_by.sort_order, None, needs_reversed_order));
+        let nulls_order = ctx.resolve_nulls_order(None);
+        let order: Option<Order> = Some(into_order(&order_by.sort_order, nulls_order.as_ref(), needs_reversed_order));
         let order_column = order_by.field.as_column(ctx);
         let order_definition: OrderDefinition = match order_by.sort_aggregation {
             SortAggregation::Count => (count(order_column.clone()).into(), order),
@@ -111,17 +110,23 @@ impl OrderByBuilder {
         needs_reversed_order: bool,
         ctx: &Context<'_>,
     ) -> OrderByDefinition {
-        let order: Option<Order> = Some(into_order(&order_by.sort_order, None, needs_reversed_order));
+        let nulls_order = ctx.resolve_nulls_order(None);
+        let order: Option<Order> = Some(into_order(&order_by.sort_order, nulls_order.as_ref(), needs_reversed_order));
         let (joins, order_column) = self.compute_joins_aggregation(order_by, ctx);
         let order_definition: OrderDefinition = match order_by.sort_aggregation {
-            SortAggregation::Count => {
+            SortAggregation::Count | SortAggregation::Sum => {
                 let exprs: Vec<Expression> = vec![order_column.clone().into(), Value::int32(0).into()];
 
                 // We coalesce the order by expr to 0 so that if there's no relation,
-                // `COALESCE(NULL, 0)` will return `0`, thus preserving the order
+                // `COALESCE(NULL, 0)` will return `0`, thus preserving the order. `Sum` behaves the
+                // same as `Count` here: an empty relation sums to `0`, not `NULL`.
                 (coalesce(exprs).into(), order)
             }
-            _ => unreachable!("Order by relation aggregation other than count are not supported"),
+            // Empty relations have no average/minimum/maximum, so we leave those `NULL` rather than
+            // coalescing to `0`.
+            SortAggregation::Avg | SortAggregation::Min | SortAggregation::Max => {
+                (order_column.clone().into(), order)
+            }
         };
 
         OrderByDefinition {
@@ -154,7 +159,10 @@ impl OrderByBuilder {
 
         let aggregation_type = match order_by.sort_aggregation {
             SortAggregation::Count => AggregationType::Count,
-            _ => unreachable!("Order by relation aggregation other than count are not supported"),
+            SortAggregation::Avg => AggregationType::Average(non_count_aggregation_field(order_by)),
+            SortAggregation::Sum => AggregationType::Sum(non_count_aggregation_field(order_by)),
+            SortAggregation::Min => AggregationType::Min(non_count_aggregation_field(order_by)),
+            SortAggregation::Max => AggregationType::Max(non_count_aggregation_field(order_by)),
         };
 
         let previous_alias = joins.last().map(|j| j.alias.as_str());
@@ -215,6 +223,25 @@ impl OrderByBuilder {
     }
 }
 
+/// The scalar field targeted by a non-`Count` to-many relation aggregation orderBy, e.g. the
+/// `views` in `orderBy: { posts: { _sum: { views: asc } } }`.
+///
+/// The schema doesn't expose this yet (see `order_by_to_many_aggregate_object_type`), so
+/// `order_by.field` is always `None` in practice today; this just documents the invariant that a
+/// non-`Count` aggregation always carries its target field once the schema grows one.
+fn non_count_aggregation_field(order_by: &OrderByToManyAggregation) -> ScalarFieldRef {
+    order_by
+        .field
+        .clone()
+        .expect("Order by relation aggregation other than count must have a target scalar field")
+}
+
+/// Translates a Prisma sort order and (optional, explicit or connector-default) nulls placement
+/// into the quaint `Order` to render. When `reverse` is set (e.g. a negative `take` walks the
+/// result set backwards), both the sort direction _and_ the nulls placement are flipped: nulls
+/// that would sort last in the forward direction must sort first once the whole order is
+/// reversed, or they'd end up on the wrong end of the page after `take` re-reverses the rows back
+/// to their requested order.
 pub fn into_order(prisma_order: &SortOrder, nulls_order: Option<&NullsOrder>, reverse: bool) -> Order {
     match (prisma_order, nulls_order, reverse) {
         // Without NULLS order
@@ -1,6 +1,11 @@
 use crate::{
-    cursor_condition, filter::FilterBuilder, model_extensions::*, nested_aggregations, ordering::OrderByBuilder,
-    sql_trace::SqlTraceComment, Context,
+    cursor_condition,
+    filter::FilterBuilder,
+    model_extensions::*,
+    nested_aggregations,
+    ordering::{into_order, OrderByBuilder},
+    sql_trace::SqlTraceComment,
+    Context,
 };
 use connector_interface::{filter::Filter, AggregationSelection, QueryArguments, RelAggregationSelection};
 use itertools::Itertools;
@@ -8,6 +13,10 @@ use prisma_models::*;
 use quaint::ast::*;
 use tracing::Span;
 
+/// Alias of the `ROW_NUMBER()` column added by [`get_records_partitioned_by`] to the inner
+/// (unfiltered) query, so the outer query can filter on it.
+const ROW_NUMBER_ALIAS: &str = "__prisma_row_number";
+
 pub(crate) trait SelectDefinition {
     fn into_select(
         self,
@@ -64,6 +73,7 @@ impl SelectDefinition for QueryArguments {
 
         let limit = if self.ignore_take { None } else { self.take_abs() };
         let skip = if self.ignore_skip { 0 } else { self.skip.unwrap_or(0) };
+        let index_hint = self.index_hint.clone();
 
         let (filter, filter_joins) = self
             .filter
@@ -76,11 +86,16 @@ impl SelectDefinition for QueryArguments {
             (filter, cursor) => ConditionTree::and(filter, cursor),
         };
 
+        let base_table = match index_hint {
+            Some(index_name) => model.as_table(ctx).force_index(index_name),
+            None => model.as_table(ctx),
+        };
+
         // Add joins necessary to the ordering
         let joined_table = order_by_definitions
             .iter()
             .flat_map(|j| &j.joins)
-            .fold(model.as_table(ctx), |acc, join| acc.join(join.clone().data));
+            .fold(base_table, |acc, join| acc.join(join.clone().data));
 
         // Add joins necessary to the nested aggregations
         let joined_table = aggregation_joins
@@ -135,6 +150,217 @@ where
         .fold(select, |acc, col| acc.value(col))
 }
 
+/// Like [`get_records`], but returns at most `args.take` rows per distinct value of
+/// `partition_by` instead of `args.take` rows overall, ordered by `args.order_by` within each
+/// partition. Used to push a nested relation's `take` down to the database instead of fetching
+/// every related record across all parents and trimming each parent's slice in memory.
+///
+/// Implemented by wrapping the ordinarily filtered query in `ROW_NUMBER() OVER (PARTITION BY
+/// ... ORDER BY ...)` and keeping only the rows numbered at or below the limit:
+/// ```sql
+/// SELECT <columns> FROM (
+///     SELECT <columns>, ROW_NUMBER() OVER (PARTITION BY <partition_by> ORDER BY <order_by>) AS __prisma_row_number
+///     FROM <table> WHERE <filter>
+/// ) AS t
+/// WHERE __prisma_row_number <= <take>
+/// ORDER BY <order_by>
+/// ```
+///
+/// Callers must only use this when `args.take` is set and `args.order_by` contains no relation
+/// hops: the partitioning and re-ordering only have access to columns of `model` itself, since
+/// the joins required for a relation hop don't exist outside the inner query.
+pub(crate) fn get_records_partitioned_by(
+    model: &Model,
+    columns: impl Iterator<Item = Column<'static>>,
+    partition_by: &ModelProjection,
+    args: QueryArguments,
+    ctx: &Context<'_>,
+) -> Select<'static> {
+    let limit = args
+        .take_abs()
+        .expect("get_records_partitioned_by requires `args.take` to be set");
+
+    debug_assert!(
+        args.order_by.iter().all(|o| matches!(o, OrderBy::Scalar(o) if o.path.is_empty())),
+        "get_records_partitioned_by only supports ordering by scalar fields of the model itself"
+    );
+
+    let needs_reversed_order = args.needs_reversed_order();
+    let columns: Vec<_> = columns.map(|c| c.set_is_selected(true)).collect();
+
+    let order: Vec<(Column<'static>, Option<Order>)> = args
+        .order_by
+        .iter()
+        .filter_map(|o| match o {
+            OrderBy::Scalar(o) if o.path.is_empty() => Some((
+                o.field.as_column(ctx),
+                Some(into_order(&o.sort_order, o.nulls_order.as_ref(), needs_reversed_order)),
+            )),
+            _ => None,
+        })
+        .collect();
+
+    let row_number = partition_by
+        .as_columns(ctx)
+        .fold(row_number(), |rn, col| rn.partition_by(col));
+    let row_number = order
+        .iter()
+        .cloned()
+        .fold(row_number, |rn, (col, ord)| rn.order_by(col.order(ord)));
+
+    let (filter, filter_joins) = args
+        .filter
+        .map(|f| FilterBuilder::with_top_level_joins().visit_filter(f, ctx))
+        .unwrap_or((ConditionTree::NoCondition, None));
+
+    let joined_table = if let Some(filter_joins) = filter_joins {
+        filter_joins
+            .into_iter()
+            .fold(model.as_table(ctx), |acc, join| acc.join(join.data))
+    } else {
+        model.as_table(ctx)
+    };
+
+    let inner = columns
+        .iter()
+        .cloned()
+        .fold(Select::from_table(joined_table).so_that(filter), |acc, col| {
+            acc.column(col)
+        })
+        .value(Function::from(row_number).alias(ROW_NUMBER_ALIAS))
+        .append_trace(&Span::current())
+        .add_trace_id(ctx.trace_id);
+
+    // The derived table below re-exposes the same (unqualified) column names as the inner query,
+    // so the outer query can select and order by them without needing the inner joins in scope.
+    let outer_columns: Vec<_> = columns
+        .iter()
+        .map(|c| Column::from(c.name.clone().into_owned()).set_is_selected(true))
+        .collect();
+
+    let outer = outer_columns
+        .into_iter()
+        .fold(Select::from_table(Table::from(inner).alias("t")), |acc, col| {
+            acc.column(col)
+        })
+        .so_that(Column::from(ROW_NUMBER_ALIAS).less_than_or_equals(limit));
+
+    order
+        .into_iter()
+        .fold(outer, |acc, (col, order)| {
+            acc.order_by(Column::from(col.name.into_owned()).order(order))
+        })
+        .append_trace(&Span::current())
+        .add_trace_id(ctx.trace_id)
+}
+
+/// Pushes `distinct` down to the database instead of fetching every row and deduplicating
+/// in-memory: keeps only the first row (by `args.order_by`) of each group of rows sharing the
+/// same `distinct_by` values, using the same `ROW_NUMBER() OVER (PARTITION BY ...)` technique as
+/// [`get_records_partitioned_by`], just keeping the first row of each partition (`= 1` instead of
+/// `<= take`) and applying `take`/`skip` on the deduplicated set afterwards instead of on the raw
+/// rows:
+/// ```sql
+/// SELECT <columns> FROM (
+///     SELECT <columns>, ROW_NUMBER() OVER (PARTITION BY <distinct_by> ORDER BY <order_by>) AS __prisma_row_number
+///     FROM <table> WHERE <filter>
+/// ) AS t
+/// WHERE __prisma_row_number = 1
+/// ORDER BY <order_by>
+/// LIMIT <take> OFFSET <skip>
+/// ```
+///
+/// Callers must only use this when `args.cursor` is `None` (cursor-based pagination combined with
+/// distinct isn't implemented) and `args.order_by` contains no relation hops, same as
+/// [`get_records_partitioned_by`]: the row numbering only has access to columns of `model` itself.
+/// [`QueryArguments::can_push_down_distinct`] checks both.
+pub(crate) fn get_records_distinct(
+    model: &Model,
+    columns: impl Iterator<Item = Column<'static>>,
+    distinct_by: &ModelProjection,
+    args: QueryArguments,
+    ctx: &Context<'_>,
+) -> Select<'static> {
+    debug_assert!(args.can_push_down_distinct(), "get_records_distinct requires `args.can_push_down_distinct()`");
+
+    let needs_reversed_order = args.needs_reversed_order();
+    let limit = if args.ignore_take { None } else { args.take_abs() };
+    let skip = if args.ignore_skip { 0 } else { args.skip.unwrap_or(0) };
+    let columns: Vec<_> = columns.map(|c| c.set_is_selected(true)).collect();
+
+    let order: Vec<(Column<'static>, Option<Order>)> = args
+        .order_by
+        .iter()
+        .filter_map(|o| match o {
+            OrderBy::Scalar(o) if o.path.is_empty() => Some((
+                o.field.as_column(ctx),
+                Some(into_order(&o.sort_order, o.nulls_order.as_ref(), needs_reversed_order)),
+            )),
+            _ => None,
+        })
+        .collect();
+
+    let row_number = distinct_by
+        .as_columns(ctx)
+        .fold(row_number(), |rn, col| rn.partition_by(col));
+    let row_number = order
+        .iter()
+        .cloned()
+        .fold(row_number, |rn, (col, ord)| rn.order_by(col.order(ord)));
+
+    let (filter, filter_joins) = args
+        .filter
+        .map(|f| FilterBuilder::with_top_level_joins().visit_filter(f, ctx))
+        .unwrap_or((ConditionTree::NoCondition, None));
+
+    let joined_table = if let Some(filter_joins) = filter_joins {
+        filter_joins
+            .into_iter()
+            .fold(model.as_table(ctx), |acc, join| acc.join(join.data))
+    } else {
+        model.as_table(ctx)
+    };
+
+    let inner = columns
+        .iter()
+        .cloned()
+        .fold(Select::from_table(joined_table).so_that(filter), |acc, col| {
+            acc.column(col)
+        })
+        .value(Function::from(row_number).alias(ROW_NUMBER_ALIAS))
+        .append_trace(&Span::current())
+        .add_trace_id(ctx.trace_id);
+
+    // The derived table below re-exposes the same (unqualified) column names as the inner query,
+    // so the outer query can select, re-order and paginate them without needing the inner joins in
+    // scope.
+    let outer_columns: Vec<_> = columns
+        .iter()
+        .map(|c| Column::from(c.name.clone().into_owned()).set_is_selected(true))
+        .collect();
+
+    let outer = outer_columns
+        .into_iter()
+        .fold(Select::from_table(Table::from(inner).alias("t")), |acc, col| {
+            acc.column(col)
+        })
+        .so_that(Column::from(ROW_NUMBER_ALIAS).equals(1))
+        .offset(skip as usize);
+
+    let outer = order
+        .into_iter()
+        .fold(outer, |acc, (col, order)| {
+            acc.order_by(Column::from(col.name.into_owned()).order(order))
+        })
+        .append_trace(&Span::current())
+        .add_trace_id(ctx.trace_id);
+
+    match limit {
+        Some(limit) => outer.limit(limit as usize),
+        None => outer,
+    }
+}
+
 /// Generates a query of the form:
 /// ```sql
 /// SELECT
@@ -135,6 +135,18 @@ pub(crate) fn build_update_and_set_query(
                     // Postgres only
                     e.compare_raw("||", Value::array(vals)).into()
                 }
+                ScalarWriteOperation::Prepend(rhs) if field.is_list() => {
+                    let e: Expression = Column::from((table.clone(), name.clone())).into();
+                    let vals: Vec<_> = match rhs {
+                        PrismaValue::List(vals) => vals.into_iter().map(|val| field.value(val, ctx)).collect(),
+                        _ => vec![field.value(rhs, ctx)],
+                    };
+                    let prepended: Expression = Value::array(vals).into();
+
+                    // Postgres only: prepend by concatenating the new values before the column (`unshift`).
+                    prepended.compare_raw("||", e).into()
+                }
+                ScalarWriteOperation::Prepend(_) => unreachable!("Prepend is only supported on scalar list fields"),
                 ScalarWriteOperation::Add(rhs) => {
                     let e: Expression<'_> = Column::from((table.clone(), name.clone())).into();
                     e + field.value(rhs, ctx).into()
@@ -0,0 +1,237 @@
+//! Offline SQL preview for read queries: renders the SQL a read (and any nested relation loads
+//! it carries, e.g. from an `include`) would generate against a given connection flavour,
+//! without executing anything or requiring a live connection. Meant for developer tooling (a
+//! schema explorer, say) that wants to show a user the SQL behind a query given only the
+//! datamodel: a [`ConnectionInfo`] can be built straight from a connection string via
+//! [`ConnectionInfo::from_url`], which never dials the database, so [`preview`] never performs
+//! any I/O.
+//!
+//! This engine loads relations by issuing one additional, separately batched statement per
+//! nested read (see `nested_read::one2m`/`m2m` in `query-engine-core`), filtered by the parent
+//! rows' link values once the parent statement has actually run. A preview can't know those
+//! values ahead of time, so nested statements are rendered with a single placeholder row of
+//! `NULL`s in their `IN (...)` filter instead - everything else about the statement, including
+//! its parameter count, matches what would run.
+use crate::{context::Context, model_extensions::AsColumns, query_builder::read, SqlError};
+use connector_interface::{filter::Filter, ConditionListValue, QueryArguments, RelAggregationSelection, ScalarCompare};
+use prisma_models::*;
+use quaint::{
+    prelude::ConnectionInfo,
+    visitor::{self, Visitor},
+};
+
+/// One SQL statement produced by [`preview`]: its parameterized text plus its bound parameters,
+/// rendered as debug strings since callers only need them for display, not execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqlPreviewStatement {
+    pub sql: String,
+    pub params: Vec<String>,
+}
+
+/// A read to preview, mirroring the shape the query engine builds for a `findMany`/`findFirst`
+/// with an `include`: a top-level selection plus, for every relation to load, the field that
+/// carries it on `model` and the nested read to run for it.
+pub struct ReadPreview {
+    pub model: Model,
+    pub selected_fields: ModelProjection,
+    pub args: QueryArguments,
+    pub aggregation_selections: Vec<RelAggregationSelection>,
+    pub nested: Vec<(RelationFieldRef, ReadPreview)>,
+}
+
+impl ReadPreview {
+    pub fn new(model: Model, selected_fields: ModelProjection, args: QueryArguments) -> Self {
+        Self {
+            model,
+            selected_fields,
+            args,
+            aggregation_selections: Vec::new(),
+            nested: Vec::new(),
+        }
+    }
+
+    /// Attaches a relation to load as a nested statement, keyed by the relation field on
+    /// [`Self::model`] that carries it.
+    pub fn with_nested(mut self, parent_field: RelationFieldRef, nested: ReadPreview) -> Self {
+        self.nested.push((parent_field, nested));
+        self
+    }
+}
+
+/// Renders the SQL statement(s) `node` (and its nested reads) would generate against
+/// `connection_info`'s flavour. See the module docs for how nested statements' join filters are
+/// approximated.
+pub fn preview(node: &ReadPreview, connection_info: &ConnectionInfo) -> Result<Vec<SqlPreviewStatement>, SqlError> {
+    let ctx = Context::new(connection_info, None);
+    let mut statements = Vec::new();
+
+    render_node(node, None, &ctx, &mut statements)?;
+
+    Ok(statements)
+}
+
+fn render_node(
+    node: &ReadPreview,
+    child_link_id: Option<&FieldSelection>,
+    ctx: &Context<'_>,
+    statements: &mut Vec<SqlPreviewStatement>,
+) -> Result<(), SqlError> {
+    let mut args = node.args.clone();
+
+    if let Some(child_link_id) = child_link_id {
+        let placeholder_row: Vec<PrismaValue> = child_link_id
+            .as_scalar_fields()
+            .expect("relation link fields must be scalar")
+            .iter()
+            .map(|_| PrismaValue::Null)
+            .collect();
+        let join_filter = child_link_id.is_in(ConditionListValue::list(vec![placeholder_row]));
+
+        args.filter = match args.filter {
+            Some(existing) => Some(Filter::and(vec![existing, join_filter])),
+            None => Some(join_filter),
+        };
+    }
+
+    let select = read::get_records(
+        &node.model,
+        node.selected_fields.as_columns(ctx),
+        &node.aggregation_selections,
+        args,
+        ctx,
+    );
+
+    statements.push(render_select(select, ctx)?);
+
+    for (parent_field, nested) in &node.nested {
+        let child_link_id = parent_field.related_field().linking_fields();
+        render_node(nested, Some(&child_link_id), ctx, statements)?;
+    }
+
+    Ok(())
+}
+
+fn render_select(select: quaint::ast::Select<'static>, ctx: &Context<'_>) -> Result<SqlPreviewStatement, SqlError> {
+    let query = quaint::ast::Query::from(select);
+
+    let (sql, params) = match ctx.sql_family() {
+        quaint::prelude::SqlFamily::Postgres => visitor::Postgres::build(query),
+        quaint::prelude::SqlFamily::Mysql => visitor::Mysql::build(query),
+        quaint::prelude::SqlFamily::Sqlite => visitor::Sqlite::build(query),
+        quaint::prelude::SqlFamily::Mssql => visitor::Mssql::build(query),
+    }
+    .map_err(SqlError::from)?;
+
+    Ok(SqlPreviewStatement {
+        sql,
+        params: params.iter().map(|p| format!("{p:?}")).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn test_models() -> (Model, Model) {
+        let schema = psl::parse_schema(
+            r#"
+            datasource db {
+              provider = "postgresql"
+              url      = "postgres://"
+            }
+
+            model User {
+              id    Int    @id
+              name  String
+              posts Post[]
+            }
+
+            model Post {
+              id       Int    @id
+              title    String
+              userId   Int
+              user     User   @relation(fields: [userId], references: [id])
+            }
+            "#,
+        )
+        .unwrap();
+
+        let dm = prisma_models::convert(Arc::new(schema));
+        (dm.find_model("User").unwrap(), dm.find_model("Post").unwrap())
+    }
+
+    #[test]
+    fn previews_a_top_level_query() {
+        let (user, _) = test_models();
+        let args = QueryArguments::new(user.clone());
+        let node = ReadPreview::new(user.clone(), ModelProjection::from(user.primary_identifier()), args);
+
+        let connection_info = ConnectionInfo::from_url("postgresql://localhost/db").unwrap();
+        let statements = preview(&node, &connection_info).unwrap();
+
+        assert_eq!(statements.len(), 1);
+        assert!(statements[0].sql.starts_with("SELECT"));
+        assert!(statements[0].sql.contains("\"User\""));
+    }
+
+    #[test]
+    fn previews_a_nested_include_query() {
+        let (user, post) = test_models();
+        let posts_field = user.fields().find_from_relation_fields("posts").unwrap();
+
+        let user_args = QueryArguments::new(user.clone());
+        let post_args = QueryArguments::new(post.clone());
+
+        let nested = ReadPreview::new(post.clone(), ModelProjection::from(post.primary_identifier()), post_args);
+        let node = ReadPreview::new(user.clone(), ModelProjection::from(user.primary_identifier()), user_args)
+            .with_nested(posts_field, nested);
+
+        let connection_info = ConnectionInfo::from_url("postgresql://localhost/db").unwrap();
+        let statements = preview(&node, &connection_info).unwrap();
+
+        assert_eq!(statements.len(), 2);
+        assert!(statements[0].sql.contains("\"User\""));
+
+        // The nested statement filters on the child link column with a single placeholder row.
+        assert!(statements[1].sql.contains("\"Post\""));
+        assert!(statements[1].sql.contains("\"userId\""));
+        assert_eq!(statements[1].params.len(), 1);
+    }
+
+    #[test]
+    fn previews_an_empty_in_filter_as_constant_false() {
+        let (user, _) = test_models();
+        let name = user.fields().find_from_scalar("name").unwrap();
+
+        let mut args = QueryArguments::new(user.clone());
+        args.filter = Some(name.is_in(ConditionListValue::list(Vec::<PrismaValue>::new())));
+
+        let node = ReadPreview::new(user.clone(), ModelProjection::from(user.primary_identifier()), args);
+        let connection_info = ConnectionInfo::from_url("postgresql://localhost/db").unwrap();
+        let statements = preview(&node, &connection_info).unwrap();
+
+        assert_eq!(statements.len(), 1);
+        assert!(statements[0].sql.contains("1=0"));
+        assert!(!statements[0].sql.contains("IN ("));
+        assert!(statements[0].params.is_empty());
+    }
+
+    #[test]
+    fn previews_an_empty_not_in_filter_as_constant_true() {
+        let (user, _) = test_models();
+        let name = user.fields().find_from_scalar("name").unwrap();
+
+        let mut args = QueryArguments::new(user.clone());
+        args.filter = Some(name.not_in(ConditionListValue::list(Vec::<PrismaValue>::new())));
+
+        let node = ReadPreview::new(user.clone(), ModelProjection::from(user.primary_identifier()), args);
+        let connection_info = ConnectionInfo::from_url("postgresql://localhost/db").unwrap();
+        let statements = preview(&node, &connection_info).unwrap();
+
+        assert_eq!(statements.len(), 1);
+        assert!(statements[0].sql.contains("1=1"));
+        assert!(!statements[0].sql.contains("NOT IN ("));
+        assert!(statements[0].params.is_empty());
+    }
+}
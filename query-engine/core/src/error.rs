@@ -48,6 +48,9 @@ pub enum CoreError {
     #[error("{}", _0)]
     SerializationError(String),
 
+    #[error("{}", _0)]
+    QueryLimitExceeded(String),
+
     #[error("{}", _0)]
     InterpreterError(InterpreterError),
 
@@ -158,6 +161,18 @@ impl From<CoreError> for user_facing_errors::Error {
             })
             .into(),
 
+            CoreError::QueryGraphBuilderError(QueryGraphBuilderError::RecordNotFoundForOptimisticLock {
+                model_name,
+                conditions,
+            })
+            | CoreError::InterpreterError(InterpreterError::QueryGraphBuilderError(
+                QueryGraphBuilderError::RecordNotFoundForOptimisticLock { model_name, conditions },
+            )) => user_facing_errors::KnownError::new(user_facing_errors::query_engine::OptimisticLockError {
+                model: model_name,
+                expected_version: conditions.join(", "),
+            })
+            .into(),
+
             CoreError::QueryGraphBuilderError(QueryGraphBuilderError::InputError(details)) => {
                 user_facing_errors::KnownError::new(user_facing_errors::query_engine::InputError { details }).into()
             }
@@ -170,6 +185,14 @@ impl From<CoreError> for user_facing_errors::Error {
                         )
                         .into()
                     }
+                    InterpreterError::QueryGraphBuilderError(QueryGraphBuilderError::RecordNotFoundForOptimisticLock {
+                        model_name,
+                        conditions,
+                    }) => user_facing_errors::KnownError::new(user_facing_errors::query_engine::OptimisticLockError {
+                        model: model_name.clone(),
+                        expected_version: conditions.join(", "),
+                    })
+                    .into(),
                     InterpreterError::QueryGraphBuilderError(QueryGraphBuilderError::RelationViolation(
                         RelationViolation {
                             relation_name,
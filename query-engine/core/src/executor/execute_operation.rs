@@ -5,14 +5,36 @@ use crate::{
 };
 use connector::{Connection, ConnectionLike, Connector};
 use futures::future;
+use once_cell::sync::Lazy;
 use query_engine_metrics::{
     histogram, increment_counter, metrics, PRISMA_CLIENT_QUERIES_DURATION_HISTOGRAM_MS, PRISMA_CLIENT_QUERIES_TOTAL,
 };
-use schema::{QuerySchema, QuerySchemaRef};
+use schema::{OutputField, QuerySchema, QuerySchemaRef};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
 use tracing::Instrument;
 use tracing_futures::WithSubscriber;
 
+/// Value for the `operation` label on the query-count metrics, e.g. `findMany`, `createOne`. Falls
+/// back to `unknown` for fields with no [`schema::QueryTag`] (there currently are none, but the
+/// counter must never panic on a schema shape we didn't anticipate).
+fn operation_label(output_field: &OutputField<'_>) -> String {
+    output_field
+        .query_tag()
+        .map(|tag| tag.to_string())
+        .unwrap_or_else(|| "unknown".to_owned())
+}
+
+/// Value for the `model` label on the query-count metrics. Empty for operations that aren't scoped
+/// to a single model (e.g. a raw query, or a transaction-level operation).
+fn model_label(query_schema: &QuerySchema, output_field: &OutputField<'_>) -> String {
+    output_field
+        .model()
+        .map(|model_id| query_schema.internal_data_model.find_model_by_id(model_id).name().to_owned())
+        .unwrap_or_default()
+}
+
 pub async fn execute_single_operation(
     query_schema: QuerySchemaRef,
     conn: &mut dyn ConnectionLike,
@@ -73,7 +95,9 @@ pub async fn execute_single_self_contained<C: Connector + Send + Sync>(
         user_facing = true,
         "db.type" = connector.name()
     );
-    let conn = connector.get_connection().instrument(conn_span).await?;
+    let conn = get_connection_for(connector, &operation, force_transactions)
+        .instrument(conn_span)
+        .await?;
 
     execute_self_contained(
         conn,
@@ -86,6 +110,37 @@ pub async fn execute_single_self_contained<C: Connector + Send + Sync>(
     .await
 }
 
+/// Picks the connection an operation should run on: a read that isn't forced into a transaction
+/// can be routed to a read replica (see [`Connector::get_read_connection`]); anything else — a
+/// write, or a read that `force_transactions` will wrap in one — always goes to the primary, so
+/// the whole transaction sees a single, consistent connection.
+///
+/// There's no way yet for a caller to force a specific read onto the primary for
+/// read-after-write consistency (the `force_primary` a future version of this would pass through
+/// from the request) — that needs a new field on [`Operation`]/the wire protocol, which is out of
+/// scope here.
+async fn get_connection_for<C: Connector + Send + Sync + ?Sized>(
+    connector: &C,
+    operation: &Operation,
+    force_transactions: bool,
+) -> crate::Result<Box<dyn Connection + Send + Sync>> {
+    if !force_transactions && operation.as_read().is_some() {
+        Ok(connector.get_read_connection(false).await?)
+    } else {
+        Ok(connector.get_connection().await?)
+    }
+}
+
+/// Caps how many operations of a single non-transactional batch hold a pooled connection and
+/// execute concurrently (see [`execute_many_self_contained`]). Independent operations already fan
+/// out onto separate connections instead of running one after another; without a cap, a batch with
+/// hundreds of operations would grab hundreds of connections from the pool at once. Overridable for
+/// deployments whose pool is sized to allow more (or less) fan-out.
+static BATCH_PARALLELISM_LIMIT: Lazy<usize> = Lazy::new(|| match std::env::var("BATCH_PARALLELISM_LIMIT") {
+    Ok(limit) => limit.parse().unwrap_or(10),
+    Err(_) => 10,
+});
+
 pub async fn execute_many_self_contained<C: Connector + Send + Sync>(
     connector: &C,
     query_schema: QuerySchemaRef,
@@ -95,30 +150,44 @@ pub async fn execute_many_self_contained<C: Connector + Send + Sync>(
     engine_protocol: EngineProtocol,
 ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
     let mut futures = Vec::with_capacity(operations.len());
+    let semaphore = Arc::new(Semaphore::new(*BATCH_PARALLELISM_LIMIT));
 
     let dispatcher = crate::get_current_dispatcher();
     for op in operations {
-        increment_counter!(PRISMA_CLIENT_QUERIES_TOTAL);
+        // Acquired before the connection itself and held for the whole spawned task below, so a
+        // batch larger than the limit acquires its connections in waves instead of all at once.
+        let permit = semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("semaphore is never closed");
 
         let conn_span = info_span!(
             "prisma:engine:connection",
             user_facing = true,
             "db.type" = connector.name(),
         );
-        let conn = connector.get_connection().instrument(conn_span).await?;
+        let conn = get_connection_for(connector, op, force_transactions)
+            .instrument(conn_span)
+            .await?;
+
+        let task = request_context::with_request_context(
+            engine_protocol,
+            execute_self_contained(
+                conn,
+                query_schema.clone(),
+                op.clone(),
+                force_transactions,
+                connector.should_retry_on_transient_error(),
+                trace_id.clone(),
+            ),
+        );
 
         futures.push(tokio::spawn(
-            request_context::with_request_context(
-                engine_protocol,
-                execute_self_contained(
-                    conn,
-                    query_schema.clone(),
-                    op.clone(),
-                    force_transactions,
-                    connector.should_retry_on_transient_error(),
-                    trace_id.clone(),
-                ),
-            )
+            async move {
+                let _permit = permit;
+                task.await
+            }
             .with_subscriber(dispatcher.clone()),
         ));
     }
@@ -181,10 +250,27 @@ async fn execute_self_contained_without_retry<'a>(
 // As suggested by the MongoDB documentation
 // https://github.com/mongodb/specifications/blob/master/source/transactions-convenient-api/transactions-convenient-api.rst#pseudo-code
 const MAX_TX_TIMEOUT_RETRY_LIMIT: Duration = Duration::from_secs(12);
-const TX_RETRY_BACKOFF: Duration = Duration::from_millis(5);
 
-// MongoDB-specific transient transaction error retry logic.
-// Hack: This should ideally live in MongoDb's connector but our current architecture doesn't allow us to easily do that.
+/// Maximum number of retries for a transient error (a MongoDB transient transaction error, or a
+/// SQL deadlock/serialization failure) before giving up and returning the underlying error.
+/// Overridable for testing and for deployments that see contention patterns needing more headroom.
+static TRANSACTION_RETRY_LIMIT: Lazy<u32> = Lazy::new(|| match std::env::var("TRANSACTION_RETRY_LIMIT") {
+    Ok(limit) => limit.parse().unwrap_or(20),
+    Err(_) => 20,
+});
+
+/// Delay between transient-error retries of a self-contained transaction.
+static TRANSACTION_RETRY_BACKOFF: Lazy<Duration> = Lazy::new(|| match std::env::var("TRANSACTION_RETRY_BACKOFF_MS") {
+    Ok(ms) => Duration::from_millis(ms.parse().unwrap_or(5)),
+    Err(_) => Duration::from_millis(5),
+});
+
+// Transient error retry logic for self-contained (engine-generated) transactions: MongoDB
+// transient transaction errors, and SQL deadlocks/serialization failures (Postgres, MySQL,
+// MSSQL). Safe only because these transactions have no side effects outside of the queries the
+// engine itself issues between BEGIN and COMMIT/ROLLBACK, so replaying the whole transaction from
+// scratch is equivalent to the failed attempt never having happened.
+// Hack: This should ideally live in each connector, but our current architecture doesn't allow us to easily do that.
 async fn execute_self_contained_with_retry(
     conn: &mut Box<dyn Connection>,
     query_schema: QuerySchemaRef,
@@ -196,23 +282,11 @@ async fn execute_self_contained_with_retry(
     let (graph, serializer) = build_graph(&query_schema, operation.clone())?;
 
     if force_transactions || graph.needs_transaction() {
-        let res = execute_in_tx(conn, graph, serializer, query_schema.as_ref(), trace_id.clone()).await;
-
-        if !is_transient_error(&res) {
-            return res;
-        }
-
-        loop {
+        retry_on_transient_error(retry_timeout, || async {
             let (graph, serializer) = build_graph(&query_schema, operation.clone())?;
-            let res = execute_in_tx(conn, graph, serializer, query_schema.as_ref(), trace_id.clone()).await;
-
-            if is_transient_error(&res) && retry_timeout.elapsed() < MAX_TX_TIMEOUT_RETRY_LIMIT {
-                tokio::time::sleep(TX_RETRY_BACKOFF).await;
-                continue;
-            } else {
-                return res;
-            }
-        }
+            execute_in_tx(conn, graph, serializer, query_schema.as_ref(), trace_id.clone()).await
+        })
+        .await
     } else {
         execute_on(
             conn.as_connection_like(),
@@ -225,6 +299,38 @@ async fn execute_self_contained_with_retry(
     }
 }
 
+/// Retries `op` for as long as it keeps returning a transient error, up to `TRANSACTION_RETRY_LIMIT`
+/// attempts or `MAX_TX_TIMEOUT_RETRY_LIMIT` elapsed since `retry_timeout`, whichever comes first,
+/// sleeping `TRANSACTION_RETRY_BACKOFF` between attempts. Returns as soon as `op` succeeds or fails
+/// with a non-transient error, or the last result once retries are exhausted.
+async fn retry_on_transient_error<F, Fut>(retry_timeout: Instant, mut op: F) -> crate::Result<ResponseData>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = crate::Result<ResponseData>>,
+{
+    let mut attempt: u32 = 0;
+
+    loop {
+        let res = op().await;
+
+        if !is_transient_error(&res) {
+            return res;
+        }
+
+        if attempt >= *TRANSACTION_RETRY_LIMIT || retry_timeout.elapsed() >= MAX_TX_TIMEOUT_RETRY_LIMIT {
+            debug!(
+                "Giving up retrying transient transaction error after {} attempt(s): {:?}",
+                attempt + 1,
+                res.as_ref().err()
+            );
+            return res;
+        }
+
+        attempt += 1;
+        tokio::time::sleep(*TRANSACTION_RETRY_BACKOFF).await;
+    }
+}
+
 async fn execute_in_tx<'a>(
     conn: &mut Box<dyn Connection>,
     graph: QueryGraph,
@@ -232,7 +338,7 @@ async fn execute_in_tx<'a>(
     query_schema: &'a QuerySchema,
     trace_id: Option<String>,
 ) -> crate::Result<ResponseData> {
-    let mut tx = conn.start_transaction(None).await?;
+    let mut tx = conn.start_transaction(None, &[]).await?;
     let result = execute_on(
         tx.as_connection_like(),
         graph,
@@ -259,7 +365,9 @@ async fn execute_on<'a>(
     query_schema: &'a QuerySchema,
     trace_id: Option<String>,
 ) -> crate::Result<ResponseData> {
-    increment_counter!(PRISMA_CLIENT_QUERIES_TOTAL);
+    let operation = operation_label(&serializer.output_field);
+    let model = model_label(query_schema, &serializer.output_field);
+    increment_counter!(PRISMA_CLIENT_QUERIES_TOTAL, "operation" => operation, "model" => model);
 
     let interpreter = QueryInterpreter::new(conn);
     QueryPipeline::new(graph, interpreter, serializer)
@@ -279,3 +387,72 @@ fn is_transient_error<T>(res: &Result<T, CoreError>) -> bool {
         Err(err) => err.is_transient(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response_ir::Item;
+    use connector::error::{ConnectorError, ErrorKind};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn ok_response() -> crate::Result<ResponseData> {
+        Ok(ResponseData::new("data".to_owned(), Item::null()))
+    }
+
+    fn transient_error() -> crate::Result<ResponseData> {
+        let mut err = ConnectorError::from_kind(ErrorKind::TransactionWriteConflict);
+        err.set_transient(true);
+        Err(CoreError::from(err))
+    }
+
+    fn permanent_error() -> crate::Result<ResponseData> {
+        Err(CoreError::from(ConnectorError::from_kind(ErrorKind::TransactionWriteConflict)))
+    }
+
+    // A deadlock error N times, then success, models a connection whose transaction keeps losing
+    // the deadlock race until contention clears up.
+    #[tokio::test]
+    async fn retries_transient_error_until_success() {
+        let attempts = AtomicU32::new(0);
+
+        let result = retry_on_transient_error(Instant::now(), || {
+            let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+            async move { if attempt < 3 { transient_error() } else { ok_response() } }
+        })
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_non_transient_error() {
+        let attempts = AtomicU32::new(0);
+
+        let result = retry_on_transient_error(Instant::now(), || {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async move { permanent_error() }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    // A connection that never recovers must eventually give up rather than retry forever, once the
+    // retry limit (or the overall timeout, whichever hits first) is exhausted.
+    #[tokio::test]
+    async fn gives_up_after_exhausting_retry_limit() {
+        let attempts = AtomicU32::new(0);
+
+        let result = retry_on_transient_error(Instant::now(), || {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async move { transient_error() }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert!(is_transient_error(&result));
+        assert_eq!(attempts.load(Ordering::SeqCst), *TRANSACTION_RETRY_LIMIT + 1);
+    }
+}
@@ -1,13 +1,15 @@
 use super::execute_operation::{execute_many_operations, execute_many_self_contained, execute_single_self_contained};
 use super::request_context;
 use crate::{
-    protocol::EngineProtocol, BatchDocumentTransaction, CoreError, Operation, QueryExecutor, ResponseData,
-    TransactionActorManager, TransactionError, TransactionManager, TransactionOptions, TxId,
+    protocol::EngineProtocol, BatchDocumentTransaction, CoreError, Operation, QueryExecutor, ResponseCache,
+    ResponseCacheConfig, ResponseData, TransactionActorManager, TransactionError, TransactionManager,
+    TransactionOptions, TxId,
 };
 
 use async_trait::async_trait;
 use connector::Connector;
-use schema::QuerySchemaRef;
+use schema::{QuerySchema, QuerySchemaRef};
+use tokio::sync::RwLock;
 use tokio::time::{self, Duration};
 use tracing_futures::Instrument;
 
@@ -21,6 +23,11 @@ pub struct InterpretingExecutor<C> {
     /// Flag that forces individual operations to run in a transaction.
     /// Does _not_ force batches to use transactions.
     force_transactions: bool,
+
+    /// Opt-in read cache for single, non-transactional operations (see [`ResponseCache`]). `None`
+    /// (the default) disables it entirely, matching this struct's original behavior before it
+    /// existed.
+    response_cache: Option<RwLock<ResponseCache>>,
 }
 
 impl<C> InterpretingExecutor<C>
@@ -32,8 +39,77 @@ where
             connector,
             force_transactions,
             itx_manager: TransactionActorManager::new(),
+            response_cache: None,
         }
     }
+
+    /// Enables the response cache described by `config`. Only single, non-transactional
+    /// operations (i.e. `execute` called with `tx_id: None`) are ever cached: batches and
+    /// interactive transactions are left alone, since caching there would need to reason about
+    /// writes that are still in flight within the same transaction.
+    pub fn with_response_cache(mut self, config: ResponseCacheConfig) -> Self {
+        self.response_cache = Some(RwLock::new(ResponseCache::new(config)));
+        self
+    }
+
+    /// Runs `operation` through the response cache: serves a cached response for a cache-enabled
+    /// read without touching the connector, and otherwise executes normally, then either caches a
+    /// successful cache-enabled read or invalidates a successful cache-enabled write's model.
+    async fn execute_with_response_cache(
+        &self,
+        cache: &RwLock<ResponseCache>,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        trace_id: Option<String>,
+        engine_protocol: EngineProtocol,
+    ) -> crate::Result<ResponseData> {
+        let model = cacheable_model(&query_schema, &operation);
+
+        if let (Operation::Read(_), Some(model)) = (&operation, model.as_deref()) {
+            if let Some(cached) = cache.write().await.get(model, &operation) {
+                return Ok(cached);
+            }
+        }
+
+        let exec_operation = operation.clone();
+        let result = request_context::with_request_context(engine_protocol, async move {
+            execute_single_self_contained(
+                &self.connector,
+                query_schema,
+                exec_operation,
+                trace_id,
+                self.force_transactions,
+            )
+            .await
+        })
+        .await;
+
+        if let (Ok(response), Some(model)) = (&result, model.as_deref()) {
+            let mut cache = cache.write().await;
+
+            match &operation {
+                Operation::Read(_) => cache.insert(model, &operation, response.clone()),
+                Operation::Write(_) => cache.invalidate_model(model),
+            }
+        }
+
+        result
+    }
+}
+
+/// The model `operation` reads from or writes to, if it's scoped to a single model. `None` for
+/// anything else (e.g. a raw query or a transaction-level operation), so the caller can skip cache
+/// bookkeeping entirely. Whether that model actually has a configured TTL is [`ResponseCache`]'s
+/// concern, not this function's.
+fn cacheable_model(query_schema: &QuerySchema, operation: &Operation) -> Option<String> {
+    let output_field = match operation {
+        Operation::Read(_) => query_schema.find_query_field(operation.name()),
+        Operation::Write(_) => query_schema.find_mutation_field(operation.name()),
+    }?;
+
+    output_field
+        .model()
+        .map(|model_id| query_schema.internal_data_model.find_model_by_id(model_id).name().to_owned())
 }
 
 #[async_trait]
@@ -53,6 +129,9 @@ where
         // If a Tx id is provided, execute on that one. Else execute normally as a single operation.
         if let Some(tx_id) = tx_id {
             self.itx_manager.execute(&tx_id, operation, trace_id).await
+        } else if let Some(cache) = &self.response_cache {
+            self.execute_with_response_cache(cache, operation, query_schema, trace_id, engine_protocol)
+                .await
         } else {
             request_context::with_request_context(engine_protocol, async move {
                 execute_single_self_contained(
@@ -104,7 +183,7 @@ where
                 "db.type" = self.connector.name(),
             );
             let mut conn = self.connector.get_connection().instrument(conn_span).await?;
-            let mut tx = conn.start_transaction(transaction.isolation_level()).await?;
+            let mut tx = conn.start_transaction(transaction.isolation_level(), &[]).await?;
 
             let results = request_context::with_request_context(
                 engine_protocol,
@@ -153,7 +232,9 @@ where
     ) -> crate::Result<TxId> {
         super::with_request_context(engine_protocol, async move {
             let isolation_level = tx_opts.isolation_level;
+            let session_context = tx_opts.session_context;
             let valid_for_millis = tx_opts.valid_for_millis;
+            let idle_timeout = tx_opts.idle_timeout_millis.map(Duration::from_millis);
             let id = tx_opts.new_tx_id.unwrap_or_default();
 
             trace!("[{}] Starting...", id);
@@ -177,7 +258,9 @@ where
                     id.clone(),
                     conn,
                     isolation_level,
+                    session_context,
                     Duration::from_millis(valid_for_millis),
+                    idle_timeout,
                     engine_protocol,
                 )
                 .await?;
@@ -67,9 +67,37 @@ pub struct TransactionOptions {
     #[serde(rename = "timeout")]
     pub valid_for_millis: u64,
 
-    /// Isolation level to use for the transaction.
+    /// Isolation level to use for the transaction, parsed case-insensitively by the connector
+    /// (e.g. `quaint::connector::IsolationLevel::from_str`) into one of `ReadUncommitted`,
+    /// `ReadCommitted`, `RepeatableRead`, `Snapshot` or `Serializable`. `None` leaves the
+    /// connection's default isolation level untouched.
+    ///
+    /// Support and rendering (`SET TRANSACTION ISOLATION LEVEL ...`, before or after `BEGIN`
+    /// depending on the connector) is implemented per-connector; an unsupported level for the
+    /// active connector (e.g. `Snapshot` on Postgres/MySQL, anything but `Serializable` on SQLite,
+    /// any level at all on MongoDB) is rejected with a connector error rather than silently ignored.
     pub isolation_level: Option<String>,
 
+    /// Time in milliseconds of inactivity after which the transaction rolls back automatically,
+    /// separately from (and typically shorter than) `valid_for_millis`. Unlike `valid_for_millis`,
+    /// this deadline is pushed back every time an operation is executed on the transaction, so a
+    /// transaction that is actually being used doesn't time out just because it's long-running.
+    /// `None` (the default when the field is absent from the request) disables idle tracking, so
+    /// only `valid_for_millis` bounds the transaction's lifetime, matching this struct's original
+    /// behavior before this field existed.
+    #[serde(default)]
+    pub idle_timeout_millis: Option<u64>,
+
+    /// Key/value session settings (e.g. `app.current_tenant`) applied on the transaction's pinned
+    /// connection right after it's opened, before any query in the transaction runs, so a
+    /// Postgres row-level security policy or MSSQL `SESSION_CONTEXT`-based one can scope every
+    /// query the client makes on it. SQL connectors without a session context mechanism ignore it
+    /// (see `quaint::connector::Queryable::set_session_context_value`); the MongoDB connector
+    /// rejects a non-empty one with an error. Empty by default, matching
+    /// this struct's original behavior before this field existed.
+    #[serde(default)]
+    pub session_context: Vec<(String, String)>,
+
     /// An optional pre-defined transaction id. Some value might be provided in case we want to generate
     /// a new id at the beginning of the transaction
     #[serde(skip)]
@@ -82,6 +110,8 @@ impl TransactionOptions {
             max_acquisition_millis,
             valid_for_millis,
             isolation_level,
+            idle_timeout_millis: None,
+            session_context: Vec::new(),
             new_tx_id: None,
         }
     }
@@ -99,7 +129,8 @@ pub trait TransactionManager {
     /// Starts a new transaction.
     /// Returns ID of newly opened transaction.
     /// Expected to throw an error if no transaction could be opened for `opts.max_acquisition_millis` milliseconds.
-    /// The new transaction must only live for `opts.valid_for_millis` milliseconds before it automatically rolls back.
+    /// The new transaction must only live for `opts.valid_for_millis` milliseconds before it automatically rolls back,
+    /// or for `opts.idle_timeout_millis` milliseconds without an operation being executed on it, whichever comes first.
     /// This rollback mechanism is an implementation detail of the trait implementer.
     async fn start_tx(
         &self,
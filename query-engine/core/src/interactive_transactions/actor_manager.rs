@@ -64,13 +64,16 @@ impl TransactionActorManager {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) async fn create_tx(
         &self,
         query_schema: QuerySchemaRef,
         tx_id: TxId,
         conn: Box<dyn Connection + Send + Sync>,
         isolation_level: Option<String>,
+        session_context: Vec<(String, String)>,
         timeout: Duration,
+        idle_timeout: Option<Duration>,
         engine_protocol: EngineProtocol,
     ) -> crate::Result<()> {
         let client = spawn_itx_actor(
@@ -78,7 +81,9 @@ impl TransactionActorManager {
             tx_id.clone(),
             conn,
             isolation_level,
+            session_context,
             timeout,
+            idle_timeout,
             CHANNEL_SIZE,
             self.send_done.clone(),
             engine_protocol,
@@ -28,15 +28,20 @@ pub struct ITXServer<'a> {
     id: TxId,
     pub cached_tx: CachedTx<'a>,
     pub timeout: Duration,
+    /// See [`crate::TransactionOptions::idle_timeout_millis`]. `None` disables idle tracking, so
+    /// only `timeout` bounds the transaction's lifetime.
+    pub idle_timeout: Option<Duration>,
     receive: Receiver<TxOpRequest>,
     query_schema: QuerySchemaRef,
 }
 
 impl<'a> ITXServer<'a> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: TxId,
         tx: CachedTx<'a>,
         timeout: Duration,
+        idle_timeout: Option<Duration>,
         receive: Receiver<TxOpRequest>,
         query_schema: QuerySchemaRef,
     ) -> Self {
@@ -44,6 +49,7 @@ impl<'a> ITXServer<'a> {
             id,
             cached_tx: tx,
             timeout,
+            idle_timeout,
             receive,
             query_schema,
         }
@@ -250,7 +256,9 @@ pub(crate) async fn spawn_itx_actor(
     tx_id: TxId,
     mut conn: Box<dyn Connection + Send + Sync>,
     isolation_level: Option<String>,
+    session_context: Vec<(String, String)>,
     timeout: Duration,
+    idle_timeout: Option<Duration>,
     channel_size: usize,
     send_done: Sender<(TxId, Option<ClosedTx>)>,
     engine_protocol: EngineProtocol,
@@ -272,7 +280,7 @@ pub(crate) async fn spawn_itx_actor(
             // We match on the result in order to send the error to the parent task and abort this
             // task, on error. This is a separate task (actor), not a function where we can just bubble up the
             // result.
-            let c_tx = match conn.start_transaction(isolation_level).await {
+            let c_tx = match conn.start_transaction(isolation_level, &session_context).await {
                 Ok(c_tx) => {
                     open_transaction_send.send(Ok(())).unwrap();
                     c_tx
@@ -287,6 +295,7 @@ pub(crate) async fn spawn_itx_actor(
                 tx_id.clone(),
                 CachedTx::Open(c_tx),
                 timeout,
+                idle_timeout,
                 rx_from_client,
                 query_schema,
             );
@@ -295,6 +304,11 @@ pub(crate) async fn spawn_itx_actor(
             let sleep = time::sleep(timeout);
             tokio::pin!(sleep);
 
+            // Only ever rolled back to if `server.idle_timeout` is `Some`, so its initial duration
+            // doesn't matter when it's `None`: the select arm below is disabled in that case.
+            let idle_sleep = time::sleep(idle_timeout.unwrap_or(Duration::ZERO));
+            tokio::pin!(idle_sleep);
+
             loop {
                 tokio::select! {
                     _ = &mut sleep => {
@@ -302,8 +316,17 @@ pub(crate) async fn spawn_itx_actor(
                         let _ = server.rollback(true).await;
                         break;
                     }
+                    _ = &mut idle_sleep, if server.idle_timeout.is_some() => {
+                        trace!("[{}] interactive transaction timed out due to inactivity", server.id.to_string());
+                        let _ = server.rollback(true).await;
+                        break;
+                    }
                     msg = server.receive.recv() => {
                         if let Some(op) = msg {
+                            if let Some(idle_timeout) = server.idle_timeout {
+                                idle_sleep.as_mut().reset(Instant::now() + idle_timeout);
+                            }
+
                             let run_state = server.process_msg(op).await;
 
                             if run_state == RunState::Finished {
@@ -1,7 +1,23 @@
 use super::{Env, ExpressionResult, InterpretationResult};
 use crate::Query;
 
+// synth-537 asked for independent subgraphs within an `Expression::Sequence` (e.g. two sibling
+// nested `include`s with no data dependency) to run concurrently on separate pooled connections
+// instead of one after another. Won't do as requested, needs redesign: every node in a query
+// graph shares the same `&mut dyn ConnectionLike` (see `QueryInterpreter`), so the whole graph
+// runs on one connection and, when wrapped in one, one transaction. Splitting siblings across
+// connections would break that guarantee — a partial failure in one sibling could no longer roll
+// back the others, since they'd no longer share a transaction to roll back on. Doing this safely
+// needs per-node savepoints plus reworking `QueryInterpreter` to hand out connections instead of
+// holding one; that's a redesign of the interpreter's connection model, not a scheduling tweak.
 pub(crate) enum Expression {
+    /// Runs `seq` in order on the interpreter's single connection, even when two of its elements
+    /// have no data dependency on each other (e.g. two independent nested `include`s).
+    ///
+    /// The only concurrency this engine performs is one level up, across whole *operations* in a
+    /// non-transactional batch (see `crate::executor::execute_many_self_contained`): those
+    /// operations never shared a connection or transaction to begin with, so running them
+    /// concurrently doesn't run into the problem described below.
     Sequence {
         seq: Vec<Expression>,
     },
@@ -36,6 +52,18 @@ pub(crate) enum Expression {
     Return {
         result: Box<ExpressionResult>,
     },
+
+    /// Runs `seq` as a [`Expression::Sequence`] wrapped in a database savepoint named `name`, so
+    /// that if it fails, only its own effects are undone (via a rollback to the savepoint) instead
+    /// of poisoning the whole surrounding transaction. Used for optional sub-graphs — e.g. the
+    /// "create" branch of a nested upsert — whose failure the rest of the transaction should be
+    /// able to recover from. On connectors that don't support savepoints (see
+    /// [`connector::ConnectionLike::create_savepoint`]), `seq` runs unprotected, exactly as if it
+    /// hadn't been wrapped.
+    Savepoint {
+        name: String,
+        seq: Vec<Expression>,
+    },
 }
 
 pub(crate) struct Binding {
@@ -236,12 +236,30 @@ impl Expressionista {
             .then
             .expect("Expected if-node to always have a then edge to another node.");
 
-        // Build expressions for both arms.
+        // Build expressions for both arms. Each arm is an optional sub-graph: only one of them
+        // actually runs, so wrap each in its own savepoint (named after the branch's node id) to
+        // let a failure inside the arm be rolled back without poisoning the rest of the enclosing
+        // transaction. On connectors that don't support savepoints, this is a no-op wrapper (see
+        // `Expression::Savepoint`).
+        let then_id = then_pair.1.id();
         let then_expr = Self::build_expression(graph, &then_pair.1, graph.incoming_edges(&then_pair.1))?;
+        let then_expr = Expression::Savepoint {
+            name: format!("sp_{then_id}"),
+            seq: vec![then_expr],
+        };
+
         let else_expr = if_node_info
             ._else
             .into_iter()
-            .map(|(_, node)| Self::build_expression(graph, &node, graph.incoming_edges(&node)))
+            .map(|(_, node)| {
+                let else_id = node.id();
+                let expr = Self::build_expression(graph, &node, graph.incoming_edges(&node))?;
+
+                Ok(Expression::Savepoint {
+                    name: format!("sp_{else_id}"),
+                    seq: vec![expr],
+                })
+            })
             .collect::<InterpretationResult<Vec<_>>>()?;
 
         let child_expressions = Self::process_children(graph, if_node_info.other)?;
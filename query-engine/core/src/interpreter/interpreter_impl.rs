@@ -229,7 +229,7 @@ impl<'conn> QueryInterpreter<'conn> {
                 match *query {
                     Query::Read(read) => {
                         self.log_line(level, || format!("READ {read}"));
-                        let span = info_span!("prisma:engine:read-execute");
+                        let span = info_span!("prisma:engine:read-execute", "query" = %read);
                         Ok(read::execute(self.conn, read, None, trace_id)
                             .instrument(span)
                             .await
@@ -238,7 +238,7 @@ impl<'conn> QueryInterpreter<'conn> {
 
                     Query::Write(write) => {
                         self.log_line(level, || format!("WRITE {write}"));
-                        let span = info_span!("prisma:engine:write-execute");
+                        let span = info_span!("prisma:engine:write-execute", "query" = %write);
                         Ok(write::execute(self.conn, write, trace_id)
                             .instrument(span)
                             .await
@@ -284,6 +284,37 @@ impl<'conn> QueryInterpreter<'conn> {
                 self.log_line(level, || "RETURN");
                 Ok(*result)
             }),
+
+            Expression::Savepoint { name, seq } => Box::pin(async move {
+                self.log_line(level, || format!("SAVEPOINT {name}"));
+
+                let inner = Expression::Sequence { seq };
+
+                if let Err(err) = self.conn.create_savepoint(&name).await {
+                    return if matches!(err.kind, connector::error::ErrorKind::UnsupportedFeature(_)) {
+                        // The connector (or the current connection, if it's not actually inside a
+                        // transaction) doesn't support savepoints. Run unprotected, exactly as if
+                        // this expression hadn't been wrapped in a savepoint at all.
+                        self.interpret(inner, env, level + 1, trace_id).await
+                    } else {
+                        // A real failure (dropped connection, syntax error, exhausted resources, ...)
+                        // creating the savepoint. Propagate it instead of silently running the rest
+                        // unprotected, which would defeat the point of the savepoint.
+                        Err(err.into())
+                    };
+                }
+
+                match self.interpret(inner, env, level + 1, trace_id).await {
+                    Ok(result) => {
+                        self.conn.release_savepoint(&name).await?;
+                        Ok(result)
+                    }
+                    Err(err) => {
+                        self.conn.rollback_to_savepoint(&name).await?;
+                        Err(err)
+                    }
+                }
+            }),
         }
     }
 
@@ -4,7 +4,7 @@ use connector::{
     self, filter::Filter, ConditionListValue, ConnectionLike, QueryArguments, RelAggregationRow,
     RelAggregationSelection, ScalarCompare,
 };
-use prisma_models::{FieldSelection, ManyRecords, PrismaValue, Record, RelationFieldRef, SelectionResult};
+use prisma_models::{FieldSelection, ManyRecords, OrderBy, PrismaValue, Record, RelationFieldRef, SelectionResult};
 use std::collections::HashMap;
 
 pub(crate) async fn m2m(
@@ -191,9 +191,24 @@ pub async fn one2m(
 
     // If we're fetching related records from a single parent, then we can apply normal pagination instead of in-memory processing.
     // However, we can't just apply a LIMIT/OFFSET for multiple parents as we need N related records PER parent.
-    // We could use ROW_NUMBER() but it requires further refactoring so we're still using in-memory processing for now.
+    // If the connector supports it, we push a per-parent LIMIT down via `take_per_group` (rendered as
+    // ROW_NUMBER() OVER (PARTITION BY ...) on the SQL side) instead of over-fetching and trimming in memory.
+    let can_push_down_take_per_group = uniq_selections.len() > 1
+        && query_args.take.is_some()
+        && query_args.skip.is_none()
+        && !query_args.requires_inmemory_processing()
+        && aggr_selections.is_empty()
+        && query_args
+            .order_by
+            .iter()
+            .all(|o| matches!(o, OrderBy::Scalar(o) if o.path.is_empty()))
+        && tx.supports_relation_load_strategy_pushdown();
+
     let processor = if uniq_selections.len() == 1 && !query_args.requires_inmemory_processing() {
         None
+    } else if can_push_down_take_per_group {
+        query_args.take_per_group = Some(child_link_id.clone());
+        None
     } else {
         Some(InMemoryRecordProcessor::new_from_query_args(&mut query_args))
     };
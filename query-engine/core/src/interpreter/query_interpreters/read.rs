@@ -1,12 +1,27 @@
 use super::*;
 use crate::{interpreter::InterpretationResult, query_ast::*, result_ast::*};
-use connector::{self, error::ConnectorError, ConnectionLike, RelAggregationRow, RelAggregationSelection};
+use connector::{
+    self, error::ConnectorError, ConnectionLike, RelAggregationRow, RelAggregationSelection, RelationLoadStrategy,
+};
 use futures::future::{BoxFuture, FutureExt};
 use inmemory_record_processor::InMemoryRecordProcessor;
+use once_cell::sync::Lazy;
 use prisma_models::ManyRecords;
 use std::collections::HashMap;
 use user_facing_errors::KnownError;
 
+/// Page size used to page a top-level `findMany` through
+/// [`connector::ReadOperations::get_many_records_chunked`] instead of fetching it as one
+/// unbounded query, overridable with `PRISMA_QUERY_CHUNK_SIZE`. Unset, or set to `0`, disables
+/// chunking and keeps using a single [`connector::ReadOperations::get_many_records`] call, matching
+/// this crate's original behavior.
+static QUERY_CHUNK_SIZE: Lazy<Option<usize>> = Lazy::new(|| {
+    std::env::var("PRISMA_QUERY_CHUNK_SIZE")
+        .ok()
+        .and_then(|size| size.parse().ok())
+        .filter(|size| *size > 0)
+});
+
 pub(crate) fn execute<'conn>(
     tx: &'conn mut dyn ConnectionLike,
     query: ReadQuery,
@@ -34,12 +49,14 @@ fn read_one(
     let fut = async move {
         let model = query.model;
         let filter = query.filter.expect("Expected filter to be set for ReadOne query.");
+        let index_hint = query.index_hint.as_ref().map(|hint| hint.index_name());
         let scalars = tx
             .get_single_record(
                 &model,
                 &filter,
                 &query.selected_fields,
                 &query.aggregation_selections,
+                index_hint,
                 trace_id,
             )
             .await?;
@@ -79,32 +96,84 @@ fn read_one(
 }
 
 /// Queries a set of records.
-/// If the query specifies distinct, we need to lift up pagination (and distinct) processing to the core with in-memory record processing.
-/// -> Distinct can't be processed in the DB with our current query API model.
-///    We need to select IDs / uniques alongside the distincts, which doesn't work in SQL, as all records
-///    are distinct by definition if a unique is in the selection set.
+/// If the query specifies distinct, we generally need to lift up pagination (and distinct)
+/// processing to the core with in-memory record processing.
+/// -> Distinct usually can't be processed on the DB with our current query API model: we need to
+///    select IDs / uniques alongside the distincts, which doesn't work with a plain SQL `DISTINCT`,
+///    as all records are distinct by definition if a unique is in the selection set. Connectors
+///    that support `QueryArguments::can_push_down_distinct` (see `ReadOperations::supports_distinct_pushdown`)
+///    work around that with a `ROW_NUMBER() OVER (PARTITION BY <distinct fields> ...)` query instead, which doesn't have
+///    this problem since it only partitions by the distinct fields, not the whole selection.
 /// -> Unstable cursors can't reliably be fetched by the underlying datasource, so we need to process part of it in-memory.
+///
+/// When [`QUERY_CHUNK_SIZE`] is configured and neither of the above forces in-memory processing,
+/// this pages the fetch itself through [`connector::ReadOperations::get_many_records_chunked`]
+/// instead of one unbounded [`connector::ReadOperations::get_many_records`] call, merging the
+/// pages back into one [`ManyRecords`] before returning. The result and its memory profile are
+/// otherwise the same as the unchunked path -- the whole set is still materialized here before
+/// serialization -- since streaming the response itself over HTTP chunked transfer or a node-api
+/// async iterator needs its own pipeline above the connector layer and is follow-up work.
 fn read_many(
     tx: &mut dyn ConnectionLike,
     mut query: ManyRecordsQuery,
     trace_id: Option<String>,
 ) -> BoxFuture<'_, InterpretationResult<QueryResult>> {
-    let processor = if query.args.requires_inmemory_processing() {
+    let can_push_down_distinct = query.args.can_push_down_distinct() && tx.supports_distinct_pushdown();
+
+    let processor = if can_push_down_distinct {
+        None
+    } else if query.args.requires_inmemory_processing() {
         Some(InMemoryRecordProcessor::new_from_query_args(&mut query.args))
     } else {
         None
     };
 
+    query.args.index_hint = query.index_hint.map(|hint| hint.index_name().to_owned());
+
+    // Chunked paging needs a cursor that reliably identifies a page boundary, and no further
+    // in-memory reprocessing of the whole result set -- both already ruled out above whenever
+    // `processor` is set (distinct pushdown not available, or an unstable cursor).
+    let chunk_size = (*QUERY_CHUNK_SIZE).filter(|_| processor.is_none() && query.args.is_stable_ordering());
+
     let fut = async move {
-        let scalars = tx
-            .get_many_records(
-                &query.model,
-                query.args.clone(),
-                &query.selected_fields,
-                &query.aggregation_selections,
-                trace_id,
-            )
-            .await?;
+        let scalars = match chunk_size {
+            Some(chunk_size) => {
+                let mut chunks: Vec<ManyRecords> = Vec::new();
+
+                tx.get_many_records_chunked(
+                    &query.model,
+                    query.args.clone(),
+                    &query.selected_fields,
+                    &query.aggregation_selections,
+                    chunk_size,
+                    trace_id,
+                    &mut |chunk| {
+                        chunks.push(chunk);
+                        Ok(())
+                    },
+                )
+                .await?;
+
+                let field_names = chunks
+                    .first()
+                    .map(|chunk| chunk.field_names.clone())
+                    .unwrap_or_else(|| query.selected_fields.prisma_names().collect());
+
+                let mut merged = ManyRecords::new(field_names);
+                merged.records.extend(chunks.into_iter().flat_map(|chunk| chunk.records));
+                merged
+            }
+            None => {
+                tx.get_many_records(
+                    &query.model,
+                    query.args.clone(),
+                    &query.selected_fields,
+                    &query.aggregation_selections,
+                    trace_id,
+                )
+                .await?
+            }
+        };
 
         let scalars = if let Some(p) = processor {
             p.apply(scalars)
@@ -143,6 +212,17 @@ fn read_related<'conn>(
     let fut = async move {
         let relation = query.parent_field.relation();
 
+        // See `RelationLoadStrategy`: `Join` is accepted here but never actually resolved to,
+        // since no connector currently overrides `supports_relation_join_strategy`. Once one does,
+        // its join-compiled load goes in a new arm below instead of falling through to today's
+        // per-relation query.
+        match query.args.effective_relation_load_strategy(tx.supports_relation_join_strategy()) {
+            RelationLoadStrategy::Join => {
+                unreachable!("no connector currently supports RelationLoadStrategy::Join")
+            }
+            RelationLoadStrategy::Query => (),
+        }
+
         let (scalars, aggregation_rows) = if relation.is_many_to_many() {
             nested_read::m2m(tx, &mut query, parent_result, trace_id).await?
         } else {
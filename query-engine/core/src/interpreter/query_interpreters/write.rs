@@ -3,7 +3,8 @@ use crate::{
     query_ast::*,
     QueryResult, RecordSelection,
 };
-use connector::{ConnectionLike, NativeUpsert};
+use connector::{error::ConnectorError, ConnectionLike, NativeUpsert};
+use user_facing_errors::KnownError;
 
 pub(crate) async fn execute(
     tx: &mut dyn ConnectionLike,
@@ -154,9 +155,14 @@ async fn update_many(
     q: UpdateManyRecords,
     trace_id: Option<String>,
 ) -> InterpretationResult<QueryResult> {
+    let throw_on_empty = q.options.contains(QueryOption::ThrowOnEmpty);
     let res = tx.update_records(&q.model, q.record_filter, q.args, trace_id).await?;
 
-    Ok(QueryResult::Count(res))
+    if res == 0 && throw_on_empty {
+        record_not_found()
+    } else {
+        Ok(QueryResult::Count(res))
+    }
 }
 
 async fn delete_many(
@@ -164,9 +170,29 @@ async fn delete_many(
     q: DeleteManyRecords,
     trace_id: Option<String>,
 ) -> InterpretationResult<QueryResult> {
+    let throw_on_empty = q.options.contains(QueryOption::ThrowOnEmpty);
     let res = tx.delete_records(&q.model, q.record_filter, trace_id).await?;
 
-    Ok(QueryResult::Count(res))
+    if res == 0 && throw_on_empty {
+        record_not_found()
+    } else {
+        Ok(QueryResult::Count(res))
+    }
+}
+
+/// Mirrors the `findXOrThrow`/`updateOne`/`deleteOne` "record not found" error, for the
+/// `OrThrow` variants of the batch write operations (see `QueryOption::ThrowOnEmpty`).
+fn record_not_found() -> InterpretationResult<QueryResult> {
+    Err(ConnectorError {
+        user_facing_error: Some(KnownError::new(
+            user_facing_errors::query_engine::RecordRequiredButNotFound {
+                cause: "Expected a record, found none.".to_owned(),
+            },
+        )),
+        kind: connector::error::ErrorKind::RecordDoesNotExist,
+        transient: false,
+    }
+    .into())
 }
 
 async fn connect(
@@ -15,7 +15,10 @@ pub use self::{
     error::{CoreError, FieldConversionError},
     executor::{QueryExecutor, TransactionOptions},
     interactive_transactions::{ExtendedTransactionUserFacingError, TransactionError, TxId},
+    plan_cache::PlanCache,
     query_document::*,
+    query_graph::{DebugEdge, DebugGraph, DebugNode, QueryGraph, ToGraphviz},
+    response_cache::{ResponseCache, ResponseCacheConfig},
     telemetry::*,
 };
 pub use connector::{
@@ -26,8 +29,10 @@ pub use connector::{
 mod error;
 mod interactive_transactions;
 mod interpreter;
+mod plan_cache;
 mod query_ast;
 mod query_graph;
+mod response_cache;
 mod result_ast;
 
 use self::{
@@ -0,0 +1,106 @@
+use lru::LruCache;
+use once_cell::sync::Lazy;
+
+/// Default capacity for a [`PlanCache`] when none is given explicitly, overridable with the
+/// `QUERY_PLAN_CACHE_SIZE` environment variable. Mirrors `CLOSED_TX_CACHE_SIZE`'s env-var pattern.
+pub static DEFAULT_PLAN_CACHE_SIZE: Lazy<usize> = Lazy::new(|| match std::env::var("QUERY_PLAN_CACHE_SIZE") {
+    Ok(size) => size.parse().unwrap_or(1000),
+    Err(_) => 1000,
+});
+
+/// A bounded, least-recently-used cache keyed on [`crate::Operation::shape_key`].
+///
+/// This is the storage primitive a document-shape-keyed query plan cache needs, but it does not
+/// itself compile or reuse a `QueryGraph`: today's `QueryGraph` bakes each operation's literal
+/// argument values directly into its nodes (e.g. as concrete `Filter`s), so a cached graph can't be
+/// replayed against a different request's values without a parameter-substitution mechanism that
+/// doesn't exist yet. Wiring this into `execute_operation`'s `build_graph` call is future work once
+/// `QueryGraph` (or an IR beneath it) supports rebinding literals after the fact; until then, this
+/// type exists as the eviction-policy building block for that integration, and can already be used
+/// standalone to cache anything else keyed on document shape (e.g. validation results).
+pub struct PlanCache<V> {
+    cache: LruCache<String, V>,
+}
+
+impl<V> PlanCache<V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            cache: LruCache::new(capacity),
+        }
+    }
+
+    /// Looks up `shape_key` (see [`crate::Operation::shape_key`]), marking it most-recently-used
+    /// on a hit.
+    pub fn get(&mut self, shape_key: &str) -> Option<&V> {
+        self.cache.get(shape_key)
+    }
+
+    /// Inserts `value` under `shape_key`, evicting the least-recently-used entry first if the
+    /// cache is already at capacity.
+    pub fn insert(&mut self, shape_key: String, value: V) {
+        self.cache.put(shape_key, value);
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+impl<V> Default for PlanCache<V> {
+    fn default() -> Self {
+        Self::new(*DEFAULT_PLAN_CACHE_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_returns_none_for_a_missing_key() {
+        let mut cache: PlanCache<i32> = PlanCache::new(2);
+
+        assert_eq!(cache.get("missing"), None);
+    }
+
+    #[test]
+    fn insert_then_get_returns_the_value() {
+        let mut cache = PlanCache::new(2);
+        cache.insert("a".to_owned(), 1);
+
+        assert_eq!(cache.get("a"), Some(&1));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn insert_evicts_the_least_recently_used_entry_once_full() {
+        let mut cache = PlanCache::new(2);
+        cache.insert("a".to_owned(), 1);
+        cache.insert("b".to_owned(), 2);
+        cache.insert("c".to_owned(), 3);
+
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.get("b"), Some(&2));
+        assert_eq!(cache.get("c"), Some(&3));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn get_refreshes_an_entrys_recency() {
+        let mut cache = PlanCache::new(2);
+        cache.insert("a".to_owned(), 1);
+        cache.insert("b".to_owned(), 2);
+
+        // Touch "a" so "b" becomes the least recently used entry.
+        cache.get("a");
+        cache.insert("c".to_owned(), 3);
+
+        assert_eq!(cache.get("a"), Some(&1));
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("c"), Some(&3));
+    }
+}
@@ -1,10 +1,13 @@
 //! Prisma read query AST
 use super::FilteredQuery;
-use crate::ToGraphviz;
-use connector::{filter::Filter, AggregationSelection, QueryArguments, RelAggregationSelection};
+use crate::{
+    query_graph_builder::{QueryGraphBuilderError, QueryGraphBuilderResult},
+    ToGraphviz,
+};
+use connector::{filter::Filter, AggregationSelection, QueryArguments, RelAggregationSelection, ScalarCondition};
 use enumflags2::BitFlags;
 use prisma_models::prelude::*;
-use std::fmt::Display;
+use std::{collections::HashSet, fmt::Display};
 
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone)]
@@ -21,6 +24,261 @@ impl ReadQuery {
         self.returns().map(|sel| sel.is_superset_of(expected)).unwrap_or(false)
     }
 
+    /// Whether `field_name` is among the fields selected on this node, checking
+    /// `selection_order` rather than `selected_fields` so it also covers relation-aggregation
+    /// pseudo-fields. Always `false` for `AggregateRecordsQuery`, which has no `selection_order`
+    /// of field names.
+    pub fn selects_field(&self, field_name: &str) -> bool {
+        match self {
+            ReadQuery::RecordQuery(x) => x.selection_order.iter().any(|f| f == field_name),
+            ReadQuery::ManyRecordsQuery(x) => x.selection_order.iter().any(|f| f == field_name),
+            ReadQuery::RelatedRecordsQuery(x) => x.selection_order.iter().any(|f| f == field_name),
+            ReadQuery::AggregateRecordsQuery(_) => false,
+        }
+    }
+
+    /// Returns `Some` if this is a `RecordQuery`, `None` otherwise.
+    pub fn as_record_query(&self) -> Option<&RecordQuery> {
+        match self {
+            ReadQuery::RecordQuery(q) => Some(q),
+            _ => None,
+        }
+    }
+
+    /// Returns `Some` if this is a `RecordQuery`, `None` otherwise.
+    pub fn as_record_query_mut(&mut self) -> Option<&mut RecordQuery> {
+        match self {
+            ReadQuery::RecordQuery(q) => Some(q),
+            _ => None,
+        }
+    }
+
+    /// Returns `Some` if this is a `ManyRecordsQuery`, `None` otherwise.
+    pub fn as_many_query(&self) -> Option<&ManyRecordsQuery> {
+        match self {
+            ReadQuery::ManyRecordsQuery(q) => Some(q),
+            _ => None,
+        }
+    }
+
+    /// Returns `Some` if this is a `ManyRecordsQuery`, `None` otherwise.
+    pub fn as_many_query_mut(&mut self) -> Option<&mut ManyRecordsQuery> {
+        match self {
+            ReadQuery::ManyRecordsQuery(q) => Some(q),
+            _ => None,
+        }
+    }
+
+    /// Returns `Some` if this is a `RelatedRecordsQuery`, `None` otherwise.
+    pub fn as_related_query(&self) -> Option<&RelatedRecordsQuery> {
+        match self {
+            ReadQuery::RelatedRecordsQuery(q) => Some(q),
+            _ => None,
+        }
+    }
+
+    /// Returns `Some` if this is a `RelatedRecordsQuery`, `None` otherwise.
+    pub fn as_related_query_mut(&mut self) -> Option<&mut RelatedRecordsQuery> {
+        match self {
+            ReadQuery::RelatedRecordsQuery(q) => Some(q),
+            _ => None,
+        }
+    }
+
+    /// Returns `Some` if this is an `AggregateRecordsQuery`, `None` otherwise.
+    pub fn as_aggregate_query(&self) -> Option<&AggregateRecordsQuery> {
+        match self {
+            ReadQuery::AggregateRecordsQuery(q) => Some(q),
+            _ => None,
+        }
+    }
+
+    /// Returns `Some` if this is an `AggregateRecordsQuery`, `None` otherwise.
+    pub fn as_aggregate_query_mut(&mut self) -> Option<&mut AggregateRecordsQuery> {
+        match self {
+            ReadQuery::AggregateRecordsQuery(q) => Some(q),
+            _ => None,
+        }
+    }
+
+    /// Removes structurally-identical duplicate children from this node's `nested`, keeping the
+    /// first occurrence of each. Fragment expansion can emit the same nested `include` more than
+    /// once under a parent, which would otherwise load the same relation twice and duplicate
+    /// result keys. This is a plain dedup: it drops exact duplicates rather than reconciling
+    /// them, which is what [`ReadQuery::satisfy_dependency`]'s selection merging is for when the
+    /// duplicates differ in what they select. Recurses into the kept children.
+    pub fn dedup_nested(&mut self) {
+        let nested = match self {
+            ReadQuery::RecordQuery(x) => &mut x.nested,
+            ReadQuery::ManyRecordsQuery(x) => &mut x.nested,
+            ReadQuery::RelatedRecordsQuery(x) => &mut x.nested,
+            ReadQuery::AggregateRecordsQuery(_) => return,
+        };
+
+        let mut deduped: Vec<ReadQuery> = Vec::with_capacity(nested.len());
+
+        for child in nested.drain(..) {
+            if !deduped.iter().any(|kept| kept.is_duplicate_of(&child)) {
+                deduped.push(child);
+            }
+        }
+
+        *nested = deduped;
+
+        for child in nested.iter_mut() {
+            child.dedup_nested();
+        }
+    }
+
+    /// Whether `self` and `other` are structurally identical nested reads: same `parent_field`,
+    /// `args` and `selected_fields`, with recursively identical `nested` children. Only
+    /// `RelatedRecordsQuery` nodes are compared this way, since that's the only kind fragment
+    /// expansion produces as a nested `include`.
+    fn is_duplicate_of(&self, other: &ReadQuery) -> bool {
+        match (self, other) {
+            (ReadQuery::RelatedRecordsQuery(a), ReadQuery::RelatedRecordsQuery(b)) => {
+                a.parent_field == b.parent_field
+                    && a.args == b.args
+                    && a.selected_fields == b.selected_fields
+                    && a.nested.len() == b.nested.len()
+                    && a.nested.iter().zip(b.nested.iter()).all(|(x, y)| x.is_duplicate_of(y))
+            }
+            _ => false,
+        }
+    }
+
+    /// Folds together nested to-one relation loads that go through the same `parent_field`: a
+    /// to-one load returns the same target row no matter what it selects, so two `include`s of
+    /// the same to-one relation under one parent are loading the identical row and can share a
+    /// single load. Their field selections and nested reads are merged into the first occurrence;
+    /// later duplicates are dropped. To-many relations are left alone, since two nested loads
+    /// there can return different rows (e.g. differing `take`/`skip`/filters), so row identity
+    /// can't be assumed from `parent_field` alone. Recurses into the kept children.
+    pub fn fold_redundant_to_one_includes(&mut self) {
+        let nested = match self {
+            ReadQuery::RecordQuery(x) => &mut x.nested,
+            ReadQuery::ManyRecordsQuery(x) => &mut x.nested,
+            ReadQuery::RelatedRecordsQuery(x) => &mut x.nested,
+            ReadQuery::AggregateRecordsQuery(_) => return,
+        };
+
+        let mut folded: Vec<ReadQuery> = Vec::with_capacity(nested.len());
+
+        for child in nested.drain(..) {
+            let shares_load_with = match &child {
+                ReadQuery::RelatedRecordsQuery(child_rrq) if !child_rrq.parent_field.is_list() => {
+                    folded.iter().position(|kept| match kept {
+                        ReadQuery::RelatedRecordsQuery(kept_rrq) => {
+                            kept_rrq.parent_field == child_rrq.parent_field && kept_rrq.args == child_rrq.args
+                        }
+                        _ => false,
+                    })
+                }
+                _ => None,
+            };
+
+            match (shares_load_with, child) {
+                (Some(idx), ReadQuery::RelatedRecordsQuery(child_rrq)) => {
+                    if let ReadQuery::RelatedRecordsQuery(kept_rrq) = &mut folded[idx] {
+                        kept_rrq.selected_fields = kept_rrq.selected_fields.clone().merge(child_rrq.selected_fields);
+                        kept_rrq.nested.extend(child_rrq.nested);
+                        kept_rrq.aggregation_selections.extend(child_rrq.aggregation_selections);
+
+                        for field_name in child_rrq.selection_order {
+                            if !kept_rrq.selection_order.contains(&field_name) {
+                                kept_rrq.selection_order.push(field_name);
+                            }
+                        }
+                    }
+                }
+                (_, child) => folded.push(child),
+            }
+        }
+
+        *nested = folded;
+
+        for child in nested.iter_mut() {
+            child.fold_redundant_to_one_includes();
+        }
+    }
+
+    /// Whether `self` and `other` are the same query shape modulo the literal values embedded in
+    /// their filters: same model/parent field, the same selection (by field name and order), the
+    /// same nesting, the same pagination presence (whether `take`/`skip`/`cursor` are set, not
+    /// their actual values), and structurally-equal filter trees (see [`Filter::structurally_eq`]).
+    /// Two queries that only differ in which literal a `WHERE` clause compares against are
+    /// structurally equal; two queries with a different selection, nesting, or operator are not.
+    pub fn structurally_eq(&self, other: &ReadQuery) -> bool {
+        fn filters_eq(a: Option<&Filter>, b: Option<&Filter>) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => a.structurally_eq(b),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+
+        fn pagination_matches(a: &QueryArguments, b: &QueryArguments) -> bool {
+            a.take.is_some() == b.take.is_some()
+                && a.skip.is_some() == b.skip.is_some()
+                && a.cursor.is_some() == b.cursor.is_some()
+        }
+
+        fn nested_eq(a: &[ReadQuery], b: &[ReadQuery]) -> bool {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| x.structurally_eq(y))
+        }
+
+        fn aggregation_selection_eq(a: &AggregationSelection, b: &AggregationSelection) -> bool {
+            match (a, b) {
+                (AggregationSelection::Field(a), AggregationSelection::Field(b)) => a == b,
+                (
+                    AggregationSelection::Count { all: aa, fields: af },
+                    AggregationSelection::Count { all: ba, fields: bf },
+                ) => aa == ba && af == bf,
+                (AggregationSelection::Average(a), AggregationSelection::Average(b))
+                | (AggregationSelection::Sum(a), AggregationSelection::Sum(b))
+                | (AggregationSelection::Min(a), AggregationSelection::Min(b))
+                | (AggregationSelection::Max(a), AggregationSelection::Max(b)) => a == b,
+                _ => false,
+            }
+        }
+
+        match (self, other) {
+            (ReadQuery::RecordQuery(a), ReadQuery::RecordQuery(b)) => {
+                a.model == b.model
+                    && a.selection_order == b.selection_order
+                    && filters_eq(a.filter.as_ref(), b.filter.as_ref())
+                    && nested_eq(&a.nested, &b.nested)
+            }
+            (ReadQuery::ManyRecordsQuery(a), ReadQuery::ManyRecordsQuery(b)) => {
+                a.model == b.model
+                    && a.selection_order == b.selection_order
+                    && pagination_matches(&a.args, &b.args)
+                    && filters_eq(a.args.filter.as_ref(), b.args.filter.as_ref())
+                    && nested_eq(&a.nested, &b.nested)
+            }
+            (ReadQuery::RelatedRecordsQuery(a), ReadQuery::RelatedRecordsQuery(b)) => {
+                a.parent_field == b.parent_field
+                    && a.selection_order == b.selection_order
+                    && pagination_matches(&a.args, &b.args)
+                    && filters_eq(a.args.filter.as_ref(), b.args.filter.as_ref())
+                    && nested_eq(&a.nested, &b.nested)
+            }
+            (ReadQuery::AggregateRecordsQuery(a), ReadQuery::AggregateRecordsQuery(b)) => {
+                a.model == b.model
+                    && pagination_matches(&a.args, &b.args)
+                    && filters_eq(a.args.filter.as_ref(), b.args.filter.as_ref())
+                    && a.group_by == b.group_by
+                    && a.selectors.len() == b.selectors.len()
+                    && a.selectors
+                        .iter()
+                        .zip(b.selectors.iter())
+                        .all(|(x, y)| aggregation_selection_eq(x, y))
+                    && filters_eq(a.having.as_ref(), b.having.as_ref())
+            }
+            _ => false,
+        }
+    }
+
     /// Returns the field selection of a read query.
     fn returns(&self) -> Option<&FieldSelection> {
         match self {
@@ -47,6 +305,54 @@ impl ReadQuery {
         }
     }
 
+    /// Adds `field_name` to this node's selection, keeping `selected_fields` and
+    /// `selection_order` consistent with each other. A no-op if the field is already selected.
+    /// The inverse of trimming a selection down: useful for composing a query from multiple
+    /// sources (e.g. a base selection plus per-plugin additions) that union fields in without
+    /// worrying about duplicates. Errors if `field_name` isn't a scalar field on this node's
+    /// model. A no-op for `AggregateRecordsQuery`, which has no field selection to add to.
+    pub fn add_selected_field(&mut self, field_name: &str) -> QueryGraphBuilderResult<()> {
+        if self.selects_field(field_name) {
+            return Ok(());
+        }
+
+        let model = self.model();
+        let scalar_field = model.fields().find_from_scalar(field_name)?;
+        let addition = FieldSelection::from(vec![scalar_field]);
+
+        match self {
+            ReadQuery::RecordQuery(x) => {
+                x.selected_fields = x.selected_fields.clone().merge(addition);
+                x.selection_order.push(field_name.to_owned());
+            }
+            ReadQuery::ManyRecordsQuery(x) => {
+                x.selected_fields = x.selected_fields.clone().merge(addition);
+                x.selection_order.push(field_name.to_owned());
+            }
+            ReadQuery::RelatedRecordsQuery(x) => {
+                x.selected_fields = x.selected_fields.clone().merge(addition);
+                x.selection_order.push(field_name.to_owned());
+            }
+            ReadQuery::AggregateRecordsQuery(_) => (),
+        }
+
+        Ok(())
+    }
+
+    /// Bulk form of [`Self::add_selected_field`]: adds every field in `field_names`, stopping at
+    /// (and returning) the first one that doesn't exist on this node's model. Fields added before
+    /// the error are not rolled back.
+    pub fn add_selected_fields<'a>(
+        &mut self,
+        field_names: impl IntoIterator<Item = &'a str>,
+    ) -> QueryGraphBuilderResult<()> {
+        for field_name in field_names {
+            self.add_selected_field(field_name)?;
+        }
+
+        Ok(())
+    }
+
     pub fn model(&self) -> Model {
         match self {
             ReadQuery::RecordQuery(x) => x.model.clone(),
@@ -55,6 +361,495 @@ impl ReadQuery {
             ReadQuery::AggregateRecordsQuery(x) => x.model.clone(),
         }
     }
+
+    /// The name this node is addressed by, e.g. in [`ReadQuery::dependencies`]'s edges.
+    fn name(&self) -> &str {
+        match self {
+            ReadQuery::RecordQuery(x) => &x.name,
+            ReadQuery::ManyRecordsQuery(x) => &x.name,
+            ReadQuery::RelatedRecordsQuery(x) => &x.name,
+            ReadQuery::AggregateRecordsQuery(x) => &x.name,
+        }
+    }
+
+    /// The nested reads this node carries, e.g. from an `include`. Empty for aggregates, which
+    /// can't be nested under another read.
+    fn nested(&self) -> &[ReadQuery] {
+        match self {
+            ReadQuery::RecordQuery(x) => &x.nested,
+            ReadQuery::ManyRecordsQuery(x) => &x.nested,
+            ReadQuery::RelatedRecordsQuery(x) => &x.nested,
+            ReadQuery::AggregateRecordsQuery(_) => &[],
+        }
+    }
+
+    /// Mutable access to the nested reads this node carries. Empty for aggregates, which can't be
+    /// nested under another read.
+    fn nested_mut(&mut self) -> &mut [ReadQuery] {
+        match self {
+            ReadQuery::RecordQuery(x) => &mut x.nested,
+            ReadQuery::ManyRecordsQuery(x) => &mut x.nested,
+            ReadQuery::RelatedRecordsQuery(x) => &mut x.nested,
+            ReadQuery::AggregateRecordsQuery(_) => &mut [],
+        }
+    }
+
+    /// The alias this node's result should be keyed by, falling back to `name()` when none was
+    /// set explicitly.
+    fn effective_alias(&self) -> &str {
+        match self {
+            ReadQuery::RecordQuery(x) => x.alias.as_deref().unwrap_or(&x.name),
+            ReadQuery::ManyRecordsQuery(x) => x.alias.as_deref().unwrap_or(&x.name),
+            ReadQuery::RelatedRecordsQuery(x) => x.alias.as_deref().unwrap_or(&x.name),
+            ReadQuery::AggregateRecordsQuery(x) => x.alias.as_deref().unwrap_or(&x.name),
+        }
+    }
+
+    fn alias_mut(&mut self) -> &mut Option<String> {
+        match self {
+            ReadQuery::RecordQuery(x) => &mut x.alias,
+            ReadQuery::ManyRecordsQuery(x) => &mut x.alias,
+            ReadQuery::RelatedRecordsQuery(x) => &mut x.alias,
+            ReadQuery::AggregateRecordsQuery(x) => &mut x.alias,
+        }
+    }
+
+    /// Prepends `prefix` to this node's alias (setting it from `name()` first if it wasn't set
+    /// already), and recurses into every nested read. Used when merging multiple client batches
+    /// into a single execution, so aliases from different batches can't collide in the merged
+    /// result map.
+    pub(crate) fn prefix_aliases(&mut self, prefix: &str) {
+        let prefixed = format!("{prefix}{}", self.effective_alias());
+        *self.alias_mut() = Some(prefixed);
+
+        for nested in self.nested_mut() {
+            nested.prefix_aliases(prefix);
+        }
+    }
+
+    /// The inverse of [`ReadQuery::prefix_aliases`]: removes `prefix` from this node's alias, and
+    /// recursively from every nested read, to demultiplex a merged batch's results back onto their
+    /// original per-batch keys.
+    pub(crate) fn strip_prefix(&mut self, prefix: &str) {
+        if let Some(alias) = self.alias_mut() {
+            if let Some(stripped) = alias.strip_prefix(prefix) {
+                *alias = stripped.to_owned();
+            }
+        }
+
+        for nested in self.nested_mut() {
+            nested.strip_prefix(prefix);
+        }
+    }
+
+    /// Whether this node needs another node's output before it can run: a `RelatedRecordsQuery`
+    /// whose `parent_results` haven't been resolved yet needs the interpreter to feed it its
+    /// parent's selected rows, and one wired up from a write result (`depends_on_write`) needs
+    /// that write to have completed first.
+    fn needs_predecessor(&self) -> bool {
+        match self {
+            ReadQuery::RelatedRecordsQuery(x) => x.parent_results.is_none() || x.depends_on_write,
+            _ => false,
+        }
+    }
+
+    /// Computes the dependency edges among a batch of top-level `ReadQuery` nodes and their
+    /// nested reads, so an external scheduler can build a DAG instead of relying on the
+    /// interpreter's implicit parent-then-nested-children walk. An edge means the `dependent`
+    /// cannot run until `depends_on` (its immediate parent in the batch) has produced results.
+    /// Nodes that don't need a predecessor's output (e.g. independent top-level siblings) have no
+    /// incoming edges.
+    pub fn dependencies(nodes: &[ReadQuery]) -> Vec<ReadDependency> {
+        let mut edges = Vec::new();
+
+        for node in nodes {
+            collect_dependencies(node, &mut edges);
+        }
+
+        edges
+    }
+
+    /// Whether this read has to be executed on a connection that's inside an active transaction,
+    /// as opposed to a plain connection. This is the case when the node (or any of its nested
+    /// reads) locks rows it selects, depends on the output of a write earlier in the query graph,
+    /// or requires a specific isolation level. Pure, independent reads return `false`, letting the
+    /// executor route them over the cheaper non-transactional path.
+    pub fn requires_transaction(&self) -> bool {
+        match self {
+            ReadQuery::RecordQuery(x) => {
+                x.options.contains(QueryOption::ForUpdate) || x.nested.iter().any(|q| q.requires_transaction())
+            }
+            ReadQuery::ManyRecordsQuery(x) => {
+                x.options.contains(QueryOption::ForUpdate) || x.nested.iter().any(|q| q.requires_transaction())
+            }
+            ReadQuery::RelatedRecordsQuery(x) => {
+                x.depends_on_write || x.nested.iter().any(|q| q.requires_transaction())
+            }
+            ReadQuery::AggregateRecordsQuery(_) => false,
+        }
+    }
+
+    /// Approximate number of bind parameters this query (and its nested reads) would produce,
+    /// counting each `IN` element and comparison operand. Used by the executor to decide whether a
+    /// query needs to be split or rejected before it can overflow a driver's bind parameter limit
+    /// (Postgres 65535, SQLite 32766/999, ...). Always rounds up, never down.
+    pub fn approximate_param_count(&self) -> usize {
+        match self {
+            ReadQuery::RecordQuery(x) => {
+                let own = x.filter.as_ref().map(|f| f.approximate_param_count()).unwrap_or(0);
+                own + nested_param_count(&x.nested)
+            }
+            ReadQuery::ManyRecordsQuery(x) => {
+                let own = x.args.approximate_param_count();
+                own + nested_param_count(&x.nested)
+            }
+            ReadQuery::RelatedRecordsQuery(x) => {
+                let own = x.args.approximate_param_count();
+                own + nested_param_count(&x.nested)
+            }
+            ReadQuery::AggregateRecordsQuery(x) => {
+                let filter_params = x.args.approximate_param_count();
+                let having_params = x.having.as_ref().map(|f| f.approximate_param_count()).unwrap_or(0);
+
+                filter_params + having_params
+            }
+        }
+    }
+
+    /// Returns `true` if this query's filter provably restricts the result to at most one row:
+    /// an equality constraint (ANDed at the top level, including through compound-unique ANDs)
+    /// is present for every field of some unique constraint or the primary key. Used to decide
+    /// whether a query can skip an `ORDER BY`/take a `LIMIT 1` shortcut, or have its "not found"
+    /// case mapped to a unique-lookup error.
+    ///
+    /// Conservative by design: anything that isn't a plain top-level `AND` of equalities (an
+    /// `OR`, a `NOT`, a partial composite-unique filter, a non-equality condition, ...) returns
+    /// `false` rather than trying to prove uniqueness through it. `RelatedRecordsQuery` always
+    /// returns `false`, because whatever uniqueness it has comes from the parent relation, not
+    /// from its own filter.
+    pub fn filter_targets_unique(&self) -> bool {
+        let filter = match self {
+            ReadQuery::RecordQuery(x) => x.filter.as_ref(),
+            ReadQuery::ManyRecordsQuery(x) => x.args.filter.as_ref(),
+            ReadQuery::RelatedRecordsQuery(_) => return false,
+            ReadQuery::AggregateRecordsQuery(_) => return false,
+        };
+
+        let Some(equality_fields) = filter.and_then(top_level_equality_fields) else {
+            return false;
+        };
+
+        self.model()
+            .unique_criteria_field_names()
+            .iter()
+            .any(|criteria| criteria.iter().all(|field| equality_fields.contains(field)))
+    }
+
+    /// Whether this specific node (ignoring its nested reads) must run inside the same
+    /// transaction/connection as its parent: it locks the rows it selects, or it's a relation
+    /// load that depends on a write earlier in the same transaction. Unlike
+    /// [`ReadQuery::requires_transaction`], this doesn't look at `nested` — it's the local
+    /// constraint [`ReadQuery::tag_connections`] uses to decide whether a node may be routed
+    /// independently of its parent.
+    fn pinned_to_parent_connection(&self) -> bool {
+        match self {
+            ReadQuery::RecordQuery(x) => x.options.contains(QueryOption::ForUpdate),
+            ReadQuery::ManyRecordsQuery(x) => x.options.contains(QueryOption::ForUpdate),
+            ReadQuery::RelatedRecordsQuery(x) => x.depends_on_write,
+            ReadQuery::AggregateRecordsQuery(_) => false,
+        }
+    }
+
+    /// Tags every node in this read's tree with the connection it should be executed against,
+    /// for read-replica routing. Each node's model is passed through `router` to pick its
+    /// connection independently, EXCEPT nodes that [`ReadQuery::pinned_to_parent_connection`]
+    /// (a locked read, or a relation load depending on an earlier write): those inherit their
+    /// parent's tag instead, so a transactional subtree never splits across connections.
+    /// Non-transactional reads, including siblings and nested reads of a node that itself isn't
+    /// pinned, are free to route to different connections.
+    pub fn tag_connections(&self, router: &dyn Fn(&Model) -> ConnectionTag) -> ConnectionPlan {
+        let tag = router(&self.model());
+        self.tag_connections_inner(tag, router)
+    }
+
+    fn tag_connections_inner(&self, parent_tag: ConnectionTag, router: &dyn Fn(&Model) -> ConnectionTag) -> ConnectionPlan {
+        let tag = if self.pinned_to_parent_connection() {
+            parent_tag
+        } else {
+            router(&self.model())
+        };
+
+        let nested = self
+            .nested()
+            .iter()
+            .map(|child| child.tag_connections_inner(tag.clone(), router))
+            .collect();
+
+        ConnectionPlan {
+            node_name: self.name().to_owned(),
+            tag,
+            nested,
+        }
+    }
+
+    /// This node's own row bound, ignoring `nested`: how many rows a single invocation of this
+    /// node can return. `None` if it's unbounded (e.g. a list read with no `take`).
+    fn own_row_bound(&self) -> Option<u64> {
+        match self {
+            ReadQuery::RecordQuery(_) => Some(1),
+            ReadQuery::ManyRecordsQuery(x) => x.args.take_abs().map(|take| take as u64),
+            // A to-one relation load returns at most one row per parent row regardless of `take`;
+            // a to-many load is bounded the same way a top-level list read is.
+            ReadQuery::RelatedRecordsQuery(x) => {
+                if x.parent_field.is_list() {
+                    x.args.take_abs().map(|take| take as u64)
+                } else {
+                    Some(1)
+                }
+            }
+            ReadQuery::AggregateRecordsQuery(_) => Some(1),
+        }
+    }
+
+    /// An upper bound on the total number of rows this read (including all of its nested reads)
+    /// can return, for resource estimation. Every nested read runs once per row its parent
+    /// returns, so its contribution is the parent's bound times its own bound; siblings add up.
+    /// Returns `None` as soon as any node in the tree is unbounded, since no finite bound can be
+    /// computed in that case. The arithmetic saturates at `u64::MAX` instead of overflowing.
+    pub fn max_result_rows(&self) -> Option<u64> {
+        let own = self.own_row_bound()?;
+
+        self.nested().iter().try_fold(own, |total, child| {
+            let child_bound = child.max_result_rows()?;
+
+            Some(total.saturating_add(own.saturating_mul(child_bound)))
+        })
+    }
+
+    /// A heuristic, coarse `[0, 1]` estimate of how selective this node's own filter is (ignoring
+    /// `nested`), meant as a join-order hint: connectors that render nested relations as SQL joins
+    /// can sort their joins most-selective-first using this. `1.0` when
+    /// [`ReadQuery::filter_targets_unique`] holds, since that already proves the filter narrows to
+    /// at most one row; otherwise falls back to [`Filter::selectivity_hint`]'s shape-based guess.
+    /// `0.0` for an unfiltered node. Note this crate's own relation loading doesn't currently
+    /// build a single joined query for nested `include`s (each nested read runs as its own
+    /// batched query, see `process_nested` in the interpreter) — this is exposed for a future or
+    /// connector-specific join-rendering pass to consume.
+    pub fn selectivity_hint(&self) -> f64 {
+        if self.filter_targets_unique() {
+            return 1.0;
+        }
+
+        let filter = match self {
+            ReadQuery::RecordQuery(x) => x.filter.as_ref(),
+            ReadQuery::ManyRecordsQuery(x) => x.args.filter.as_ref(),
+            ReadQuery::RelatedRecordsQuery(x) => x.args.filter.as_ref(),
+            ReadQuery::AggregateRecordsQuery(x) => x.args.filter.as_ref(),
+        };
+
+        filter.map(Filter::selectivity_hint).unwrap_or(0.0)
+    }
+
+    /// Rewrites this query tree into a canonical form by running the passes enabled in `opts`, in
+    /// a fixed order, instead of callers reaching for [`Filter::simplify`], [`Filter::normalize`],
+    /// [`Self::dedup_nested`] and [`Self::fold_redundant_to_one_includes`] individually and having
+    /// to get the order right themselves:
+    ///
+    /// 1. `simplify_filters` — [`Filter::simplify`] on every `filter`/`having` in the tree.
+    /// 2. `canonicalize_filters` — [`Filter::normalize`] on the (already simplified) filters, so
+    ///    two logically-equivalent filters that only differ in operand order compare equal.
+    /// 3. `dedup_order_by` — removes exact-duplicate `ORDER BY` entries from every node's `args`.
+    /// 4. `dedup_nested` — drops structurally-identical duplicate nested reads (see
+    ///    [`Self::dedup_nested`]'s own docs).
+    /// 5. `prune_redundant_includes` — folds together duplicate to-one includes (see
+    ///    [`Self::fold_redundant_to_one_includes`]'s own docs).
+    ///
+    /// Steps 1-3 run top-down over the whole tree first, so that by the time steps 4 and 5 compare
+    /// nodes for equality, filters that are logically the same but were built differently already
+    /// look identical — the later passes catch duplicates the earlier ones would otherwise hide.
+    ///
+    /// A single pass is a fixpoint for this pass set: each of the five rewrites is idempotent on
+    /// its own (documented on the respective method/type), and none of them can re-introduce work
+    /// for one that already ran earlier in the order above. This is *not* a general guarantee for
+    /// any future pass added to [`NormalizeOptions`] — one that could, say, undo a fold done by an
+    /// earlier pass would need this method to loop until a fixpoint instead.
+    ///
+    /// Two rewrites the originating request also named — pushing negations down through filters
+    /// (De Morgan normalization) and rewriting `OR` chains of equality checks into a single `IN` —
+    /// aren't implemented anywhere in this crate yet, so there's no pass to enable for them here.
+    /// [`Filter::normalize`] only canonicalizes operand *order*, it doesn't rewrite negations or
+    /// combine operators. Add them as new [`NormalizeOptions`] fields alongside their
+    /// implementation once one exists, in whichever position of the order above they belong.
+    pub fn normalize(&mut self, opts: NormalizeOptions) {
+        self.normalize_filters_recursive(&opts);
+
+        if opts.dedup_nested {
+            self.dedup_nested();
+        }
+
+        if opts.prune_redundant_includes {
+            self.fold_redundant_to_one_includes();
+        }
+    }
+
+    /// The `simplify_filters`/`canonicalize_filters`/`dedup_order_by` steps of [`Self::normalize`],
+    /// applied to every node in the tree. Split out from [`Self::dedup_nested`] and
+    /// [`Self::fold_redundant_to_one_includes`], which already recurse on their own, so those two
+    /// only need to be invoked once each, from [`Self::normalize`] itself, after every node's
+    /// filters are already in canonical form.
+    fn normalize_filters_recursive(&mut self, opts: &NormalizeOptions) {
+        self.normalize_own_filters(opts);
+
+        for child in self.nested_mut() {
+            child.normalize_filters_recursive(opts);
+        }
+    }
+
+    /// Applies the `simplify_filters`/`canonicalize_filters`/`dedup_order_by` steps of
+    /// [`Self::normalize`] to this node's own `filter`/`having`/`args.order_by`, without touching
+    /// `nested`.
+    fn normalize_own_filters(&mut self, opts: &NormalizeOptions) {
+        fn normalize_filter(filter: Filter, opts: &NormalizeOptions) -> Filter {
+            let filter = if opts.simplify_filters { filter.simplify() } else { filter };
+
+            if opts.canonicalize_filters {
+                filter.normalize()
+            } else {
+                filter
+            }
+        }
+
+        fn dedup_order_by(order_by: &mut Vec<OrderBy>) {
+            let mut seen = HashSet::new();
+            order_by.retain(|ob| seen.insert(ob.clone()));
+        }
+
+        match self {
+            ReadQuery::RecordQuery(x) => {
+                if let Some(filter) = x.filter.take() {
+                    x.filter = Some(normalize_filter(filter, opts));
+                }
+            }
+            ReadQuery::ManyRecordsQuery(x) => {
+                if let Some(filter) = x.args.filter.take() {
+                    x.args.filter = Some(normalize_filter(filter, opts));
+                }
+                if opts.dedup_order_by {
+                    dedup_order_by(&mut x.args.order_by);
+                }
+            }
+            ReadQuery::RelatedRecordsQuery(x) => {
+                if let Some(filter) = x.args.filter.take() {
+                    x.args.filter = Some(normalize_filter(filter, opts));
+                }
+                if opts.dedup_order_by {
+                    dedup_order_by(&mut x.args.order_by);
+                }
+            }
+            ReadQuery::AggregateRecordsQuery(x) => {
+                if let Some(filter) = x.args.filter.take() {
+                    x.args.filter = Some(normalize_filter(filter, opts));
+                }
+                if let Some(having) = x.having.take() {
+                    x.having = Some(normalize_filter(having, opts));
+                }
+                if opts.dedup_order_by {
+                    dedup_order_by(&mut x.args.order_by);
+                }
+            }
+        }
+    }
+}
+
+/// Which passes [`ReadQuery::normalize`] runs. All enabled by default: a caller that wants the
+/// full canonical form (e.g. ahead of a structural hash) can just use `NormalizeOptions::default()`,
+/// and opt out of individual passes when it needs to preserve something they would rewrite (e.g. a
+/// caller that wants to display the filter back to a user in its original, unsimplified shape).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NormalizeOptions {
+    /// Run [`Filter::simplify`] on every filter/`having` in the tree.
+    pub simplify_filters: bool,
+    /// Run [`Filter::normalize`] on every filter/`having` in the tree.
+    pub canonicalize_filters: bool,
+    /// Remove exact-duplicate `ORDER BY` entries from every node's `args.order_by`.
+    pub dedup_order_by: bool,
+    /// Run [`ReadQuery::dedup_nested`].
+    pub dedup_nested: bool,
+    /// Run [`ReadQuery::fold_redundant_to_one_includes`].
+    pub prune_redundant_includes: bool,
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        Self {
+            simplify_filters: true,
+            canonicalize_filters: true,
+            dedup_order_by: true,
+            dedup_nested: true,
+            prune_redundant_includes: true,
+        }
+    }
+}
+
+/// The connection a [`ReadQuery`] node is routed to. Opaque to the query AST: connectors and the
+/// executor decide what a given tag actually maps to (e.g. `Replica("eu-west")` naming a specific
+/// read-replica connection string).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConnectionTag {
+    Primary,
+    Replica(String),
+}
+
+/// The result of [`ReadQuery::tag_connections`]: a tree mirroring the shape of the read query it
+/// was computed from, with each node tagged with the connection it should run against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionPlan {
+    pub node_name: String,
+    pub tag: ConnectionTag,
+    pub nested: Vec<ConnectionPlan>,
+}
+
+fn nested_param_count(nested: &[ReadQuery]) -> usize {
+    nested.iter().map(|q| q.approximate_param_count()).sum()
+}
+
+/// Walks a filter tree that is a top-level `AND` of equalities (as produced for compound-unique
+/// lookups, among others), collecting the database names of every field constrained by an
+/// `Equals` condition. Returns `None`, instead of a partial set, the moment it hits any other
+/// shape (`OR`, `NOT`, a relation/composite filter, a non-equality condition, ...), since those
+/// don't provably narrow the result to a single row on their own.
+fn top_level_equality_fields(filter: &Filter) -> Option<HashSet<String>> {
+    match filter {
+        Filter::And(filters) => filters.iter().try_fold(HashSet::new(), |mut fields, filter| {
+            fields.extend(top_level_equality_fields(filter)?);
+            Some(fields)
+        }),
+        Filter::Scalar(sf) if matches!(sf.condition, ScalarCondition::Equals(_)) => {
+            Some(sf.scalar_fields().into_iter().map(|f| f.db_name().to_owned()).collect())
+        }
+        _ => None,
+    }
+}
+
+/// A dependency edge produced by [`ReadQuery::dependencies`]: `dependent` must run after
+/// `depends_on`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadDependency {
+    pub depends_on: String,
+    pub dependent: String,
+}
+
+fn collect_dependencies(parent: &ReadQuery, edges: &mut Vec<ReadDependency>) {
+    for child in parent.nested() {
+        if child.needs_predecessor() {
+            edges.push(ReadDependency {
+                depends_on: parent.name().to_owned(),
+                dependent: child.name().to_owned(),
+            });
+        }
+
+        collect_dependencies(child, edges);
+    }
 }
 
 impl FilteredQuery for ReadQuery {
@@ -131,6 +926,15 @@ impl ToGraphviz for ReadQuery {
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum QueryOption {
     ThrowOnEmpty,
+    /// The query locks the rows it selects (e.g. `SELECT ... FOR UPDATE`) and therefore must run
+    /// inside the same transaction as whatever relies on that lock.
+    ForUpdate,
+    /// Assemble a `ManyRecordsQuery` result as columns (one vector per selected field) instead of
+    /// rows, via [`prisma_models::ManyRecords::into_columnar`]. Meant for callers (e.g. analytics
+    /// pipelines) that consume columnar batches directly; nested relations aren't representable in
+    /// that shape, so they're silently absent from the columnar result rather than erroring — see
+    /// [`ManyRecordsQuery::wants_columnar`].
+    Columnar,
     Other,
 }
 
@@ -161,6 +965,33 @@ impl QueryOptions {
     }
 }
 
+/// An index hint attached to a `RecordQuery`/`ManyRecordsQuery`, naming an index that exists on
+/// the query's model. Connectors render it as a native index hint where they support one (`FORCE
+/// INDEX` on MySQL, `WITH (INDEX(...))` on MSSQL) and otherwise ignore it, logging a warning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexHint(String);
+
+impl IndexHint {
+    /// The database name of the index this hint forces the connector to use.
+    pub fn index_name(&self) -> &str {
+        &self.0
+    }
+
+    /// Validates that `index_name` is the database name of an index on `model` before wrapping it.
+    fn new(model: &Model, index_name: impl Into<String>) -> QueryGraphBuilderResult<Self> {
+        let index_name = index_name.into();
+
+        if model.index_by_db_name(&index_name).is_none() {
+            return Err(QueryGraphBuilderError::AssertionError(format!(
+                "Cannot force index `{index_name}`: model `{}` has no index with that name.",
+                model.name()
+            )));
+        }
+
+        Ok(Self(index_name))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RecordQuery {
     pub name: String,
@@ -172,6 +1003,17 @@ pub struct RecordQuery {
     pub selection_order: Vec<String>,
     pub aggregation_selections: Vec<RelAggregationSelection>,
     pub options: QueryOptions,
+    pub index_hint: Option<IndexHint>,
+}
+
+impl RecordQuery {
+    /// Attaches an index hint that connectors render where supported, to steer the query planner
+    /// away from a bad plan. Fails if `index_name` isn't the database name of an index defined on
+    /// this query's model.
+    pub fn with_forced_index(mut self, index_name: impl Into<String>) -> QueryGraphBuilderResult<Self> {
+        self.index_hint = Some(IndexHint::new(&self.model, index_name)?);
+        Ok(self)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -185,6 +1027,25 @@ pub struct ManyRecordsQuery {
     pub selection_order: Vec<String>,
     pub aggregation_selections: Vec<RelAggregationSelection>,
     pub options: QueryOptions,
+    pub index_hint: Option<IndexHint>,
+}
+
+impl ManyRecordsQuery {
+    /// Attaches an index hint that connectors render where supported, to steer the query planner
+    /// away from a bad plan. Fails if `index_name` isn't the database name of an index defined on
+    /// this query's model.
+    pub fn with_forced_index(mut self, index_name: impl Into<String>) -> QueryGraphBuilderResult<Self> {
+        self.index_hint = Some(IndexHint::new(&self.model, index_name)?);
+        Ok(self)
+    }
+
+    /// Whether the connector read layer should assemble this query's result as columnar batches
+    /// (see [`QueryOption::Columnar`]) rather than rows. Only true when there's nothing to nest: a
+    /// query with nested relations still executes them the normal, row-oriented way, since
+    /// relations aren't representable in a `ManyRecords`' columnar form.
+    pub fn wants_columnar(&self) -> bool {
+        self.options.contains(QueryOption::Columnar) && self.nested.is_empty()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -201,6 +1062,10 @@ pub struct RelatedRecordsQuery {
     /// Fields and values of the parent to satisfy the relation query without
     /// relying on the parent result passed by the interpreter.
     pub parent_results: Option<Vec<SelectionResult>>,
+
+    /// True when this node is wired into the query graph via a `ProjectedDataDependency` edge
+    /// from a write node, meaning it can only observe correct results once that write has run.
+    pub(crate) depends_on_write: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -234,3 +1099,812 @@ impl FilteredQuery for ManyRecordsQuery {
         self.args.filter = Some(filter)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use connector::ScalarCompare;
+    use std::sync::Arc;
+
+    fn test_model() -> Model {
+        let schema = psl::parse_schema(
+            r#"
+            datasource db {
+              provider = "postgresql"
+              url      = "postgres://"
+            }
+
+            model TestModel {
+              id      Int           @id
+              a       Int
+              b       Int
+              name    String
+              related RelatedModel[]
+
+              @@unique([a, b])
+            }
+
+            model RelatedModel {
+              id           Int       @id
+              testModel    TestModel @relation(fields: [testModelId], references: [id])
+              testModelId  Int
+            }
+            "#,
+        )
+        .unwrap();
+
+        prisma_models::convert(Arc::new(schema)).find_model("TestModel").unwrap()
+    }
+
+    fn related_field(model: &Model) -> RelationFieldRef {
+        model.fields().relation().next().unwrap()
+    }
+
+    fn related_records_query(parent_field: RelationFieldRef, nested: Vec<ReadQuery>) -> ReadQuery {
+        let model = parent_field.related_model();
+        let args = QueryArguments::new(model.clone());
+
+        ReadQuery::RelatedRecordsQuery(RelatedRecordsQuery {
+            name: "test".to_owned(),
+            alias: None,
+            parent_field,
+            args,
+            selected_fields: FieldSelection::new(vec![]),
+            nested,
+            selection_order: Vec::new(),
+            aggregation_selections: Vec::new(),
+            parent_results: None,
+            depends_on_write: false,
+        })
+    }
+
+    fn record_query(model: Model, filter: Filter) -> ReadQuery {
+        ReadQuery::RecordQuery(RecordQuery {
+            name: "test".to_owned(),
+            alias: None,
+            model,
+            filter: Some(filter),
+            selected_fields: FieldSelection::new(vec![]),
+            nested: Vec::new(),
+            selection_order: Vec::new(),
+            aggregation_selections: Vec::new(),
+            options: QueryOptions::none(),
+            index_hint: None,
+        })
+    }
+
+    fn record_query_with_selection(model: Model, selection_order: Vec<String>) -> ReadQuery {
+        ReadQuery::RecordQuery(RecordQuery {
+            name: "test".to_owned(),
+            alias: None,
+            model,
+            filter: None,
+            selected_fields: FieldSelection::new(vec![]),
+            nested: Vec::new(),
+            selection_order,
+            aggregation_selections: Vec::new(),
+            options: QueryOptions::none(),
+            index_hint: None,
+        })
+    }
+
+    fn record_query_with_nested(model: Model, nested: Vec<ReadQuery>) -> ReadQuery {
+        ReadQuery::RecordQuery(RecordQuery {
+            name: "test".to_owned(),
+            alias: None,
+            model,
+            filter: None,
+            selected_fields: FieldSelection::new(vec![]),
+            nested,
+            selection_order: Vec::new(),
+            aggregation_selections: Vec::new(),
+            options: QueryOptions::none(),
+            index_hint: None,
+        })
+    }
+
+    fn many_records_query(model: Model) -> ReadQuery {
+        let args = QueryArguments::new(model.clone());
+
+        ReadQuery::ManyRecordsQuery(ManyRecordsQuery {
+            name: "test".to_owned(),
+            alias: None,
+            model,
+            args,
+            selected_fields: FieldSelection::new(vec![]),
+            nested: Vec::new(),
+            selection_order: Vec::new(),
+            aggregation_selections: Vec::new(),
+            options: QueryOptions::none(),
+            index_hint: None,
+        })
+    }
+
+    fn many_records_query_with_options(model: Model, options: QueryOptions, nested: Vec<ReadQuery>) -> ManyRecordsQuery {
+        let args = QueryArguments::new(model.clone());
+
+        ManyRecordsQuery {
+            name: "test".to_owned(),
+            alias: None,
+            model,
+            args,
+            selected_fields: FieldSelection::new(vec![]),
+            nested,
+            selection_order: Vec::new(),
+            aggregation_selections: Vec::new(),
+            options,
+            index_hint: None,
+        }
+    }
+
+    fn aggregate_query(model: Model) -> ReadQuery {
+        let args = QueryArguments::new(model.clone());
+
+        ReadQuery::AggregateRecordsQuery(AggregateRecordsQuery {
+            name: "test".to_owned(),
+            alias: None,
+            model,
+            selection_order: Vec::new(),
+            args,
+            selectors: Vec::new(),
+            group_by: Vec::new(),
+            having: None,
+        })
+    }
+
+    #[test]
+    fn pk_equality_filter_targets_unique() {
+        let model = test_model();
+        let id = model.fields().find_from_scalar("id").unwrap();
+        let query = record_query(model, id.equals(PrismaValue::Int(1)));
+
+        assert!(query.filter_targets_unique());
+    }
+
+    #[test]
+    fn partial_composite_unique_filter_does_not_target_unique() {
+        let model = test_model();
+        let a = model.fields().find_from_scalar("a").unwrap();
+        let query = record_query(model, a.equals(PrismaValue::Int(1)));
+
+        assert!(!query.filter_targets_unique());
+    }
+
+    #[test]
+    fn non_unique_filter_does_not_target_unique() {
+        let model = test_model();
+        let name = model.fields().find_from_scalar("name").unwrap();
+        let query = record_query(model, name.equals(PrismaValue::String("hello".to_owned())));
+
+        assert!(!query.filter_targets_unique());
+    }
+
+    #[test]
+    fn unique_equality_filter_is_more_selective_than_no_filter() {
+        let model = test_model();
+        let id = model.fields().find_from_scalar("id").unwrap();
+        let filtered = record_query(model.clone(), id.equals(PrismaValue::Int(1)));
+        let unfiltered = record_query(model, Filter::empty());
+
+        assert_eq!(filtered.selectivity_hint(), 1.0);
+        assert!(filtered.selectivity_hint() > unfiltered.selectivity_hint());
+    }
+
+    #[test]
+    fn non_unique_equality_filter_is_more_selective_than_no_filter_but_less_than_unique() {
+        let model = test_model();
+        let name = model.fields().find_from_scalar("name").unwrap();
+        let filtered = record_query(model.clone(), name.equals(PrismaValue::String("alice".to_owned())));
+        let unfiltered = record_query(model, Filter::empty());
+
+        assert!(filtered.selectivity_hint() > unfiltered.selectivity_hint());
+        assert!(filtered.selectivity_hint() < 1.0);
+    }
+
+    #[test]
+    fn wants_columnar_is_false_without_the_option() {
+        let model = test_model();
+        let query = many_records_query_with_options(model, QueryOptions::none(), Vec::new());
+
+        assert!(!query.wants_columnar());
+    }
+
+    #[test]
+    fn wants_columnar_is_true_with_the_option_and_no_nested_relations() {
+        let model = test_model();
+        let query = many_records_query_with_options(model, QueryOption::Columnar.into(), Vec::new());
+
+        assert!(query.wants_columnar());
+    }
+
+    #[test]
+    fn wants_columnar_is_false_with_the_option_but_nested_relations_present() {
+        let model = test_model();
+        let related_field = related_field(&model);
+        let nested = vec![related_records_query(related_field, Vec::new())];
+        let query = many_records_query_with_options(model, QueryOption::Columnar.into(), nested);
+
+        assert!(!query.wants_columnar());
+    }
+
+    #[test]
+    fn add_selected_field_adds_a_new_field() {
+        let model = test_model();
+        let mut query = record_query_with_selection(model, vec!["id".to_owned()]);
+
+        query.add_selected_field("name").unwrap();
+
+        assert!(query.selects_field("name"));
+        assert!(query.returns().unwrap().contains("name"));
+        assert_eq!(query.returns().unwrap().selections().count(), 1);
+    }
+
+    #[test]
+    fn add_selected_field_is_a_noop_for_an_already_selected_field() {
+        let model = test_model();
+        let mut query = record_query_with_selection(model, vec!["name".to_owned()]);
+
+        query.add_selected_field("name").unwrap();
+
+        let ReadQuery::RecordQuery(record_query) = &query else {
+            unreachable!()
+        };
+
+        assert_eq!(record_query.selection_order, vec!["name".to_owned()]);
+        assert_eq!(record_query.selected_fields.selections().count(), 0);
+    }
+
+    #[test]
+    fn add_selected_field_errors_on_a_nonexistent_field() {
+        let model = test_model();
+        let mut query = record_query_with_selection(model, vec!["id".to_owned()]);
+
+        assert!(query.add_selected_field("doesNotExist").is_err());
+    }
+
+    #[test]
+    fn selects_field_is_true_for_a_selected_field() {
+        let model = test_model();
+        let query = record_query_with_selection(model, vec!["name".to_owned()]);
+
+        assert!(query.selects_field("name"));
+    }
+
+    #[test]
+    fn selects_field_is_false_for_an_unselected_field() {
+        let model = test_model();
+        let query = record_query_with_selection(model, vec!["name".to_owned()]);
+
+        assert!(!query.selects_field("a"));
+    }
+
+    #[test]
+    fn selects_field_is_false_for_aggregation_queries() {
+        let model = test_model();
+        let query = aggregate_query(model);
+
+        assert!(!query.selects_field("name"));
+    }
+
+    #[test]
+    fn dedup_nested_collapses_exact_duplicates() {
+        let model = test_model();
+        let parent_field = related_field(&model);
+
+        let mut query = record_query_with_nested(
+            model,
+            vec![
+                related_records_query(parent_field.clone(), vec![]),
+                related_records_query(parent_field, vec![]),
+            ],
+        );
+
+        query.dedup_nested();
+
+        if let ReadQuery::RecordQuery(x) = &query {
+            assert_eq!(x.nested.len(), 1);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn dedup_nested_leaves_differing_selections_alone() {
+        let model = test_model();
+        let parent_field = related_field(&model);
+        let related_id = parent_field.related_model().fields().find_from_scalar("id").unwrap();
+
+        let a = related_records_query(parent_field.clone(), vec![]);
+
+        let mut b = related_records_query(parent_field, vec![]);
+        if let ReadQuery::RelatedRecordsQuery(x) = &mut b {
+            x.selected_fields = FieldSelection::new(vec![related_id.into()]);
+        }
+
+        let mut query = record_query_with_nested(model, vec![a, b]);
+
+        query.dedup_nested();
+
+        if let ReadQuery::RecordQuery(x) = &query {
+            assert_eq!(x.nested.len(), 2);
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn router_by_model_name(replica_model: &'static str) -> impl Fn(&Model) -> ConnectionTag {
+        move |model: &Model| {
+            if model.name() == replica_model {
+                ConnectionTag::Replica("replica".to_owned())
+            } else {
+                ConnectionTag::Primary
+            }
+        }
+    }
+
+    #[test]
+    fn tag_connections_routes_each_node_per_its_own_model() {
+        let model = test_model();
+        let parent_field = related_field(&model);
+        let related_model_name = parent_field.related_model().name().to_owned();
+
+        let query = record_query_with_nested(model, vec![related_records_query(parent_field, vec![])]);
+        let plan = query.tag_connections(&router_by_model_name(&related_model_name));
+
+        assert_eq!(plan.tag, ConnectionTag::Primary);
+        assert_eq!(plan.nested.len(), 1);
+        assert_eq!(plan.nested[0].tag, ConnectionTag::Replica("replica".to_owned()));
+    }
+
+    #[test]
+    fn tag_connections_keeps_a_write_dependent_relation_load_on_its_parents_connection() {
+        let model = test_model();
+        let parent_field = related_field(&model);
+        let related_model_name = parent_field.related_model().name().to_owned();
+
+        let mut nested = related_records_query(parent_field, vec![]);
+        if let ReadQuery::RelatedRecordsQuery(x) = &mut nested {
+            x.depends_on_write = true;
+        }
+
+        let query = record_query_with_nested(model, vec![nested]);
+        // The router would send the related model to the replica, but since the nested read
+        // depends on an earlier write, it must stay on the parent's (primary) connection.
+        let plan = query.tag_connections(&router_by_model_name(&related_model_name));
+
+        assert_eq!(plan.tag, ConnectionTag::Primary);
+        assert_eq!(plan.nested.len(), 1);
+        assert_eq!(plan.nested[0].tag, ConnectionTag::Primary);
+    }
+
+    #[test]
+    fn max_result_rows_computes_a_bound_for_a_fully_bounded_tree() {
+        let model = test_model();
+        let parent_field = related_field(&model);
+
+        let mut nested = related_records_query(parent_field, vec![]);
+        if let ReadQuery::RelatedRecordsQuery(x) = &mut nested {
+            x.args.take = Some(5);
+        }
+
+        let query = record_query_with_nested(model, vec![nested]);
+
+        // The parent (a RecordQuery) always returns at most 1 row, and its nested relation load
+        // returns at most 5 rows for that one row: 1 + 1 * 5.
+        assert_eq!(query.max_result_rows(), Some(6));
+    }
+
+    #[test]
+    fn max_result_rows_is_none_when_a_node_is_unbounded() {
+        let model = test_model();
+        let parent_field = related_field(&model);
+
+        // No `take` set on the nested relation load: it's unbounded.
+        let nested = related_records_query(parent_field, vec![]);
+        let query = record_query_with_nested(model, vec![nested]);
+
+        assert_eq!(query.max_result_rows(), None);
+    }
+
+    #[test]
+    fn fold_redundant_to_one_includes_shares_a_double_to_one_include() {
+        let model = test_model();
+        // The opposite field of the to-many relation field is the to-one side.
+        let to_one_field = related_field(&model).related_field();
+        assert!(!to_one_field.is_list());
+
+        let related_id = to_one_field.related_model().fields().find_from_scalar("id").unwrap();
+        let related_name = to_one_field.related_model().fields().find_from_scalar("name").unwrap();
+
+        let mut a = related_records_query(to_one_field.clone(), vec![]);
+        if let ReadQuery::RelatedRecordsQuery(x) = &mut a {
+            x.selected_fields = FieldSelection::new(vec![related_id.into()]);
+        }
+
+        let mut b = related_records_query(to_one_field, vec![]);
+        if let ReadQuery::RelatedRecordsQuery(x) = &mut b {
+            x.selected_fields = FieldSelection::new(vec![related_name.into()]);
+        }
+
+        let mut query = record_query_with_nested(model, vec![a, b]);
+        query.fold_redundant_to_one_includes();
+
+        if let ReadQuery::RecordQuery(x) = &query {
+            assert_eq!(x.nested.len(), 1);
+
+            if let ReadQuery::RelatedRecordsQuery(rrq) = &x.nested[0] {
+                assert!(rrq.selected_fields.contains("id"));
+                assert!(rrq.selected_fields.contains("name"));
+            } else {
+                unreachable!()
+            }
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn fold_redundant_to_one_includes_leaves_a_to_many_include_separate() {
+        let model = test_model();
+        let to_many_field = related_field(&model);
+        assert!(to_many_field.is_list());
+
+        let mut query = record_query_with_nested(
+            model,
+            vec![
+                related_records_query(to_many_field.clone(), vec![]),
+                related_records_query(to_many_field, vec![]),
+            ],
+        );
+
+        query.fold_redundant_to_one_includes();
+
+        if let ReadQuery::RecordQuery(x) = &query {
+            assert_eq!(x.nested.len(), 2);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn structurally_eq_ignores_the_literal_value_of_a_filter() {
+        let model = test_model();
+        let name = model.fields().find_from_scalar("name").unwrap();
+
+        let a = record_query(model.clone(), name.clone().equals(PrismaValue::String("alice".to_owned())));
+        let b = record_query(model, name.equals(PrismaValue::String("bob".to_owned())));
+
+        assert!(a.structurally_eq(&b));
+    }
+
+    #[test]
+    fn structurally_eq_is_false_for_a_different_operator() {
+        let model = test_model();
+        let name = model.fields().find_from_scalar("name").unwrap();
+
+        let a = record_query(model.clone(), name.clone().equals(PrismaValue::String("alice".to_owned())));
+        let b = record_query(model, name.not_equals(PrismaValue::String("alice".to_owned())));
+
+        assert!(!a.structurally_eq(&b));
+    }
+
+    #[test]
+    fn structurally_eq_is_false_for_a_different_selection() {
+        let model = test_model();
+
+        let a = record_query_with_selection(model.clone(), vec!["name".to_owned()]);
+        let b = record_query_with_selection(model, vec!["id".to_owned()]);
+
+        assert!(!a.structurally_eq(&b));
+    }
+
+    #[test]
+    fn structurally_eq_compares_nested_reads_recursively() {
+        let model = test_model();
+        let parent_field = related_field(&model);
+        let related_id = parent_field.related_model().fields().find_from_scalar("id").unwrap();
+
+        let a = record_query_with_nested(
+            model.clone(),
+            vec![related_records_query(
+                parent_field.clone(),
+                vec![record_query(model.clone(), related_id.clone().equals(PrismaValue::Int(1)))],
+            )],
+        );
+        let b = record_query_with_nested(
+            model.clone(),
+            vec![related_records_query(
+                parent_field,
+                vec![record_query(model, related_id.equals(PrismaValue::Int(2)))],
+            )],
+        );
+
+        assert!(a.structurally_eq(&b));
+    }
+
+    #[test]
+    fn structurally_eq_is_false_when_nesting_differs() {
+        let model = test_model();
+        let parent_field = related_field(&model);
+
+        let a = record_query_with_nested(model.clone(), vec![related_records_query(parent_field, vec![])]);
+        let b = record_query_with_nested(model, vec![]);
+
+        assert!(!a.structurally_eq(&b));
+    }
+
+    #[test]
+    fn as_record_query_matches_only_a_record_query() {
+        let model = test_model();
+        let record = record_query(model.clone(), Filter::empty());
+        let many = many_records_query(model.clone());
+        let related = related_records_query(related_field(&model), vec![]);
+        let aggregate = aggregate_query(model);
+
+        assert!(record.as_record_query().is_some());
+        assert!(many.as_record_query().is_none());
+        assert!(related.as_record_query().is_none());
+        assert!(aggregate.as_record_query().is_none());
+    }
+
+    #[test]
+    fn as_many_query_matches_only_a_many_records_query() {
+        let model = test_model();
+        let record = record_query(model.clone(), Filter::empty());
+        let many = many_records_query(model.clone());
+        let related = related_records_query(related_field(&model), vec![]);
+        let aggregate = aggregate_query(model);
+
+        assert!(many.as_many_query().is_some());
+        assert!(record.as_many_query().is_none());
+        assert!(related.as_many_query().is_none());
+        assert!(aggregate.as_many_query().is_none());
+    }
+
+    #[test]
+    fn as_related_query_matches_only_a_related_records_query() {
+        let model = test_model();
+        let record = record_query(model.clone(), Filter::empty());
+        let many = many_records_query(model.clone());
+        let related = related_records_query(related_field(&model), vec![]);
+        let aggregate = aggregate_query(model);
+
+        assert!(related.as_related_query().is_some());
+        assert!(record.as_related_query().is_none());
+        assert!(many.as_related_query().is_none());
+        assert!(aggregate.as_related_query().is_none());
+    }
+
+    #[test]
+    fn as_aggregate_query_matches_only_an_aggregate_records_query() {
+        let model = test_model();
+        let record = record_query(model.clone(), Filter::empty());
+        let many = many_records_query(model.clone());
+        let related = related_records_query(related_field(&model), vec![]);
+        let aggregate = aggregate_query(model);
+
+        assert!(aggregate.as_aggregate_query().is_some());
+        assert!(record.as_aggregate_query().is_none());
+        assert!(many.as_aggregate_query().is_none());
+        assert!(related.as_aggregate_query().is_none());
+    }
+
+    #[test]
+    fn as_record_query_mut_allows_mutating_the_matched_variant() {
+        let model = test_model();
+        let mut record = record_query(model, Filter::empty());
+
+        record.as_record_query_mut().unwrap().name = "renamed".to_owned();
+
+        assert_eq!(record.as_record_query().unwrap().name, "renamed");
+    }
+
+    #[test]
+    fn prefix_aliases_sets_the_alias_from_name_when_none_was_set() {
+        let model = test_model();
+        let mut record = record_query(model, Filter::empty());
+
+        record.prefix_aliases("batch-0.");
+
+        assert_eq!(record.as_record_query().unwrap().alias.as_deref(), Some("batch-0.test"));
+    }
+
+    #[test]
+    fn prefix_aliases_prepends_to_an_existing_alias() {
+        let model = test_model();
+        let mut record = record_query(model, Filter::empty());
+        record.as_record_query_mut().unwrap().alias = Some("myAlias".to_owned());
+
+        record.prefix_aliases("batch-0.");
+
+        assert_eq!(record.as_record_query().unwrap().alias.as_deref(), Some("batch-0.myAlias"));
+    }
+
+    #[test]
+    fn prefix_aliases_recurses_into_nested_reads() {
+        let model = test_model();
+        let nested = record_query(model.clone(), Filter::empty());
+        let mut parent = record_query_with_nested(model, vec![nested]);
+
+        parent.prefix_aliases("batch-0.");
+
+        assert_eq!(parent.as_record_query().unwrap().alias.as_deref(), Some("batch-0.test"));
+        assert_eq!(
+            parent.as_record_query().unwrap().nested[0]
+                .as_record_query()
+                .unwrap()
+                .alias
+                .as_deref(),
+            Some("batch-0.test")
+        );
+    }
+
+    #[test]
+    fn strip_prefix_restores_the_original_alias() {
+        let model = test_model();
+        let mut record = record_query(model, Filter::empty());
+        record.as_record_query_mut().unwrap().alias = Some("myAlias".to_owned());
+
+        record.prefix_aliases("batch-0.");
+        record.strip_prefix("batch-0.");
+
+        assert_eq!(record.as_record_query().unwrap().alias.as_deref(), Some("myAlias"));
+    }
+
+    #[test]
+    fn strip_prefix_recurses_into_nested_reads() {
+        let model = test_model();
+        let nested = record_query(model.clone(), Filter::empty());
+        let mut parent = record_query_with_nested(model, vec![nested]);
+
+        parent.prefix_aliases("batch-0.");
+        parent.strip_prefix("batch-0.");
+
+        assert_eq!(
+            parent.as_record_query().unwrap().nested[0]
+                .as_record_query()
+                .unwrap()
+                .alias
+                .as_deref(),
+            Some("test")
+        );
+    }
+
+    #[test]
+    fn normalize_simplifies_an_empty_in_filter() {
+        let model = test_model();
+        let name = model.fields().find_from_scalar("name").unwrap();
+        let mut query = record_query(model, name.is_in(Vec::<PrismaValue>::new()));
+
+        query.normalize(NormalizeOptions::default());
+
+        let ReadQuery::RecordQuery(x) = &query else {
+            unreachable!()
+        };
+        assert_eq!(x.filter, Some(Filter::BoolFilter(false)));
+    }
+
+    #[test]
+    fn normalize_leaves_an_empty_in_filter_alone_when_disabled() {
+        let model = test_model();
+        let name = model.fields().find_from_scalar("name").unwrap();
+        let filter = name.is_in(Vec::<PrismaValue>::new());
+        let mut query = record_query(model, filter.clone());
+
+        query.normalize(NormalizeOptions {
+            simplify_filters: false,
+            ..NormalizeOptions::default()
+        });
+
+        let ReadQuery::RecordQuery(x) = &query else {
+            unreachable!()
+        };
+        assert_eq!(x.filter, Some(filter));
+    }
+
+    #[test]
+    fn normalize_canonicalizes_and_operand_order() {
+        let model = test_model();
+        let a = model.fields().find_from_scalar("a").unwrap();
+        let b = model.fields().find_from_scalar("b").unwrap();
+
+        let mut left = record_query(
+            model.clone(),
+            Filter::and(vec![
+                b.clone().equals(PrismaValue::Int(2)),
+                a.clone().equals(PrismaValue::Int(1)),
+            ]),
+        );
+        let mut right = record_query(
+            model,
+            Filter::and(vec![a.equals(PrismaValue::Int(1)), b.equals(PrismaValue::Int(2))]),
+        );
+
+        left.normalize(NormalizeOptions::default());
+        right.normalize(NormalizeOptions::default());
+
+        let ReadQuery::RecordQuery(left) = &left else {
+            unreachable!()
+        };
+        let ReadQuery::RecordQuery(right) = &right else {
+            unreachable!()
+        };
+        assert_eq!(left.filter, right.filter);
+    }
+
+    #[test]
+    fn normalize_dedups_duplicate_order_by_entries() {
+        let model = test_model();
+        let id = model.fields().find_from_scalar("id").unwrap();
+        let mut query = many_records_query(model);
+
+        if let ReadQuery::ManyRecordsQuery(x) = &mut query {
+            x.args.order_by = vec![id.clone().into(), id.into()];
+        }
+
+        query.normalize(NormalizeOptions::default());
+
+        let ReadQuery::ManyRecordsQuery(x) = &query else {
+            unreachable!()
+        };
+        assert_eq!(x.args.order_by.len(), 1);
+    }
+
+    #[test]
+    fn normalize_folds_and_dedups_the_nested_tree() {
+        let model = test_model();
+        let to_one_field = related_field(&model).related_field();
+        let related_id = to_one_field.related_model().fields().find_from_scalar("id").unwrap();
+        let related_name = to_one_field.related_model().fields().find_from_scalar("name").unwrap();
+
+        let mut a = related_records_query(to_one_field.clone(), vec![]);
+        if let ReadQuery::RelatedRecordsQuery(x) = &mut a {
+            x.selected_fields = FieldSelection::new(vec![related_id.into()]);
+        }
+
+        let mut b = related_records_query(to_one_field, vec![]);
+        if let ReadQuery::RelatedRecordsQuery(x) = &mut b {
+            x.selected_fields = FieldSelection::new(vec![related_name.into()]);
+        }
+
+        let mut query = record_query_with_nested(model, vec![a, b]);
+        query.normalize(NormalizeOptions::default());
+
+        let ReadQuery::RecordQuery(x) = &query else {
+            unreachable!()
+        };
+        assert_eq!(x.nested.len(), 1);
+    }
+
+    #[test]
+    fn normalize_is_idempotent() {
+        let model = test_model();
+        let a = model.fields().find_from_scalar("a").unwrap();
+        let b = model.fields().find_from_scalar("b").unwrap();
+        let id = model.fields().find_from_scalar("id").unwrap();
+
+        let to_one_field = related_field(&model).related_field();
+        let mut nested_a = related_records_query(to_one_field.clone(), vec![]);
+        if let ReadQuery::RelatedRecordsQuery(x) = &mut nested_a {
+            x.selected_fields = FieldSelection::new(vec![id.clone().into()]);
+        }
+        let nested_b = related_records_query(to_one_field, vec![]);
+
+        let mut query = record_query_with_nested(model, vec![nested_a, nested_b]);
+        if let ReadQuery::RecordQuery(x) = &mut query {
+            x.filter = Some(Filter::and(vec![
+                b.equals(PrismaValue::Int(2)),
+                a.equals(PrismaValue::Int(1)),
+                Filter::empty(),
+            ]));
+        }
+
+        query.normalize(NormalizeOptions::default());
+        let once = format!("{query:?}");
+
+        query.normalize(NormalizeOptions::default());
+        let twice = format!("{query:?}");
+
+        assert_eq!(once, twice);
+    }
+}
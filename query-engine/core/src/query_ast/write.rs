@@ -1,6 +1,6 @@
 //! Write query AST
 use super::{FilteredNestedMutation, FilteredQuery};
-use crate::{RecordQuery, ToGraphviz};
+use crate::{QueryOptions, RecordQuery, ToGraphviz};
 use connector::{filter::Filter, DatasourceFieldName, NativeUpsert, RecordFilter, WriteArgs};
 use prisma_models::prelude::*;
 use std::collections::HashMap;
@@ -324,6 +324,7 @@ pub struct UpdateManyRecords {
     pub model: Model,
     pub record_filter: RecordFilter,
     pub args: WriteArgs,
+    pub options: QueryOptions,
 }
 
 #[derive(Debug, Clone)]
@@ -336,6 +337,7 @@ pub struct DeleteRecord {
 pub struct DeleteManyRecords {
     pub model: Model,
     pub record_filter: RecordFilter,
+    pub options: QueryOptions,
 }
 
 #[derive(Debug, Clone)]
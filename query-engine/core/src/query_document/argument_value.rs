@@ -81,6 +81,70 @@ impl ArgumentValue {
             ArgumentValue::FieldRef(_) => false,
         }
     }
+
+    /// Appends this value's shape to `out`, for [`crate::Operation::shape_key`]: the same
+    /// structure (variant, `PrismaValue` kind, object keys, list length) but none of the actual
+    /// scalar literals, so two arguments that only differ in which value they carry produce the
+    /// same shape.
+    pub(crate) fn write_shape(&self, out: &mut String) {
+        match self {
+            Self::Scalar(pv) => out.push_str(prisma_value_shape_tag(pv)),
+            Self::FieldRef(obj) => {
+                out.push_str("F{");
+                Self::write_object_shape(obj, out);
+                out.push('}');
+            }
+            Self::Object(obj) => {
+                out.push_str("O{");
+                Self::write_object_shape(obj, out);
+                out.push('}');
+            }
+            Self::List(items) => {
+                out.push_str("L[");
+                out.push_str(&items.len().to_string());
+                out.push(':');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    item.write_shape(out);
+                }
+                out.push(']');
+            }
+        }
+    }
+
+    fn write_object_shape(obj: &ArgumentValueObject, out: &mut String) {
+        for (i, (key, value)) in obj.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push_str(key);
+            out.push(':');
+            value.write_shape(out);
+        }
+    }
+}
+
+/// The shape tag for a leaf [`PrismaValue`], used by [`ArgumentValue::write_shape`]. Identifies the
+/// `PrismaValue` variant only, never its contents, so `Int(1)` and `Int(2)` share a tag but
+/// `Int(1)` and `String("1")` don't.
+fn prisma_value_shape_tag(pv: &PrismaValue) -> &'static str {
+    match pv {
+        PrismaValue::String(_) => "String",
+        PrismaValue::Boolean(_) => "Boolean",
+        PrismaValue::Enum(_) => "Enum",
+        PrismaValue::Int(_) => "Int",
+        PrismaValue::Uuid(_) => "Uuid",
+        PrismaValue::List(_) => "List",
+        PrismaValue::Json(_) => "Json",
+        PrismaValue::Object(_) => "Object",
+        PrismaValue::Null => "Null",
+        PrismaValue::DateTime(_) => "DateTime",
+        PrismaValue::Float(_) => "Float",
+        PrismaValue::BigInt(_) => "BigInt",
+        PrismaValue::Bytes(_) => "Bytes",
+    }
 }
 
 impl From<PrismaValue> for ArgumentValue {
@@ -96,6 +96,15 @@ impl BatchDocument {
     }
 
     /// Checks whether a BatchDocument can be compacted.
+    ///
+    /// Operations don't need identical nested selections to compact together, as long as every
+    /// selection involved is a plain scalar field (see `selections_are_plain_scalars`): the
+    /// merged `findMany` then requests the union of every operation's selection, and each
+    /// operation's own fields are filtered back out of its response afterwards (see
+    /// `CompactedDocument::from_operations`). Operations with a relation load or an argument on a
+    /// selection still need byte-identical nested selections to compact, since two selections
+    /// that share a name but differ in shape (e.g. two different filters on the same relation)
+    /// can't be safely collapsed into just one of them.
     fn can_compact(&self, schema: &QuerySchema) -> bool {
         match self {
             Self::Multi(operations, _) => match operations.split_first() {
@@ -109,15 +118,18 @@ impl BatchDocument {
                         return false;
                     }
 
-                    rest.iter().all(|op| {
-                        op.is_find_unique(schema)
-                            && first.name() == op.name()
-                            && first.nested_selections().len() == op.nested_selections().len()
-                            && first
-                                .nested_selections()
-                                .iter()
-                                .all(|fop| op.nested_selections().contains(fop))
-                    })
+                    let same_name_find_uniques =
+                        rest.iter().all(|op| op.is_find_unique(schema) && first.name() == op.name());
+
+                    same_name_find_uniques
+                        && (Self::selections_are_plain_scalars(operations)
+                            || rest.iter().all(|op| {
+                                first.nested_selections().len() == op.nested_selections().len()
+                                    && first
+                                        .nested_selections()
+                                        .iter()
+                                        .all(|fop| op.nested_selections().contains(fop))
+                            }))
                 }
                 _ => false,
             },
@@ -125,6 +137,18 @@ impl BatchDocument {
         }
     }
 
+    /// Whether every nested selection across every operation is a plain scalar leaf: no
+    /// arguments and no nested selections of its own. Selections shaped like this can be safely
+    /// unioned by name across operations, since a plain scalar field always means the same thing
+    /// regardless of which operation asked for it.
+    fn selections_are_plain_scalars(operations: &[Operation]) -> bool {
+        operations.iter().all(|op| {
+            op.nested_selections()
+                .iter()
+                .all(|sel| sel.arguments().is_empty() && sel.nested_selections().is_empty())
+        })
+    }
+
     pub fn compact(self, schema: &QuerySchema) -> Self {
         match self {
             Self::Multi(operations, _) if self.can_compact(schema) => {
@@ -159,7 +183,10 @@ impl BatchDocumentTransaction {
 #[derive(Debug, Clone)]
 pub struct CompactedDocument {
     pub arguments: Vec<HashMap<String, ArgumentValue>>,
-    pub nested_selection: Vec<String>,
+    /// The nested selection of each original operation, indexed the same way as `arguments` and
+    /// `keys` — the operations that were compacted together into `operation` need not have
+    /// requested the same fields, so each one keeps its own list to filter its response by.
+    pub nested_selection: Vec<Vec<String>>,
     pub operation: Operation,
     pub keys: Vec<String>,
     pub original_query_options: crate::QueryOptions,
@@ -206,10 +233,19 @@ impl CompactedDocument {
                     .trim_end_matches("OrThrow"),
             );
 
-            // Take the nested selection set from the first query. We took care
-            // earlier that all the nested selections are the same in every
-            // query. Otherwise we fail hard here.
-            builder.set_nested_selections(selections[0].nested_selections().to_vec());
+            // Take the union of every query's nested selections, deduped by name, since the
+            // merged findMany has to fetch a superset of whatever any individual query asked
+            // for. Each query's own fields are filtered back out of its response later, using
+            // the per-query `nested_selection` computed below.
+            let mut union_nested_selections: Vec<Selection> = Vec::new();
+            for selection in &selections {
+                for nested in selection.nested_selections() {
+                    if !union_nested_selections.iter().any(|existing| existing.name() == nested.name()) {
+                        union_nested_selections.push(nested.clone());
+                    }
+                }
+            }
+            builder.set_nested_selections(union_nested_selections);
 
             // The query arguments are extracted here. Combine all query
             // arguments from the different queries into a one large argument.
@@ -246,13 +282,13 @@ impl CompactedDocument {
             builder
         };
 
-        // We want to store the original nested selections so we can filter out
-        // the added unique selections from the responses if the original
-        // selection set didn't have them.
-        let nested_selection = selections[0]
-            .nested_selections()
+        // We want to store each original query's own nested selections so we can filter every
+        // response back down to just the fields that particular query asked for (the merged
+        // query above may have selected more, either from other compacted queries or to be able
+        // to match responses back to their request).
+        let nested_selection: Vec<Vec<String>> = selections
             .iter()
-            .map(|s| s.name().to_string())
+            .map(|selection| selection.nested_selections().iter().map(|s| s.name().to_string()).collect())
             .collect();
 
         // Saving the stub of the query name for later use.
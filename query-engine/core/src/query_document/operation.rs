@@ -51,6 +51,96 @@ impl Operation {
             Operation::Write(x) => x.arguments(),
         }
     }
+
+    /// A key identifying this operation's shape: its field/argument/nested-selection structure
+    /// with every literal argument value normalized away, so that two operations built from the
+    /// same client-side query with different argument values (e.g. `findUnique(where: {id: 1})`
+    /// vs. `findUnique(where: {id: 2})`) produce the same key. Intended as the cache key for a
+    /// [`crate::PlanCache`] of compiled `QueryGraph`s, keyed on document shape rather than on the
+    /// full operation (including its literals).
+    ///
+    /// Note this only normalizes values, not structure: two documents that select different fields
+    /// or nest a different number/kind of relations still get different keys, as they should — only
+    /// the actual `QueryGraph` compilation (which the cache is meant to let a caller skip) knows how
+    /// to turn one shape into a different but compatible one (e.g. reusing a superset selection).
+    pub fn shape_key(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str(match self {
+            Self::Read(_) => "R:",
+            Self::Write(_) => "W:",
+        });
+
+        match self {
+            Self::Read(s) => s.write_shape(&mut out),
+            Self::Write(s) => s.write_shape(&mut out),
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArgumentValue;
+    use prisma_models::PrismaValue;
+
+    fn find_unique_by_id(id: i64) -> Operation {
+        Operation::Read(Selection::new(
+            "findUniqueUser",
+            None,
+            vec![(
+                "where".to_owned(),
+                ArgumentValue::object([("id".to_owned(), ArgumentValue::Scalar(PrismaValue::Int(id)))]),
+            )],
+            Vec::new(),
+        ))
+    }
+
+    #[test]
+    fn shape_key_is_the_same_for_two_operations_differing_only_in_a_literal() {
+        assert_eq!(find_unique_by_id(1).shape_key(), find_unique_by_id(2).shape_key());
+    }
+
+    #[test]
+    fn shape_key_differs_for_a_different_argument_type() {
+        let string_id = Operation::Read(Selection::new(
+            "findUniqueUser",
+            None,
+            vec![(
+                "where".to_owned(),
+                ArgumentValue::object([(
+                    "id".to_owned(),
+                    ArgumentValue::Scalar(PrismaValue::String("1".to_owned())),
+                )]),
+            )],
+            Vec::new(),
+        ));
+
+        assert_ne!(find_unique_by_id(1).shape_key(), string_id.shape_key());
+    }
+
+    #[test]
+    fn shape_key_differs_for_a_different_selection() {
+        let with_nested = Operation::Read(Selection::new(
+            "findUniqueUser",
+            None,
+            Vec::new(),
+            vec![Selection::with_name("posts")],
+        ));
+        let without_nested = Operation::Read(Selection::new("findUniqueUser", None, Vec::new(), Vec::new()));
+
+        assert_ne!(with_nested.shape_key(), without_nested.shape_key());
+    }
+
+    #[test]
+    fn shape_key_differs_for_read_vs_write_of_the_same_selection() {
+        let read = Operation::Read(Selection::with_name("user"));
+        let write = Operation::Write(Selection::with_name("user"));
+
+        assert_ne!(read.shape_key(), write.shape_key());
+    }
 }
 
 impl Operation {
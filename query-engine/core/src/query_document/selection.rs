@@ -100,6 +100,35 @@ impl Selection {
     pub fn set_alias(&mut self, alias: Option<String>) {
         self.alias = alias
     }
+
+    /// Appends this selection's shape to `out`, for [`crate::Operation::shape_key`]: field name,
+    /// alias, argument keys/structure and nested selections, but none of the actual argument
+    /// literals. Recurses into `nested_selections` in their existing order.
+    pub(crate) fn write_shape(&self, out: &mut String) {
+        out.push_str(&self.name);
+        out.push('@');
+        out.push_str(self.alias.as_deref().unwrap_or(""));
+
+        out.push('(');
+        for (i, (key, value)) in self.arguments.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push_str(key);
+            out.push(':');
+            value.write_shape(out);
+        }
+        out.push(')');
+
+        out.push('[');
+        for (i, nested) in self.nested_selections.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            nested.write_shape(out);
+        }
+        out.push(']');
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
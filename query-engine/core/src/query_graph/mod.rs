@@ -798,6 +798,7 @@ impl QueryGraph {
                 selection_order: vec![],
                 aggregation_selections: vec![],
                 options: QueryOptions::none(),
+                index_hint: None,
             });
 
             let reload_query = Query::Read(read_query);
@@ -998,3 +999,70 @@ impl ToGraphviz for QueryGraph {
         format!("digraph {{\n{nodes}\n{edges}\n}}")
     }
 }
+
+/// A node in a [`QueryGraph::debug_dump`], mirroring the coloring [`ToGraphviz`] gives it: a
+/// result node feeds the response, a root node is where evaluation starts, and everything else is
+/// an intermediate step.
+#[derive(Debug, serde::Serialize)]
+pub struct DebugNode {
+    pub id: usize,
+    pub label: String,
+    pub is_result: bool,
+    pub is_root: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DebugEdge {
+    pub from: usize,
+    pub to: usize,
+    pub label: String,
+}
+
+/// Structured, serializable form of a [`QueryGraph`], for tooling that wants to inspect query
+/// planning without parsing the Graphviz DOT syntax [`ToGraphviz::to_graphviz`] produces.
+#[derive(Debug, serde::Serialize)]
+pub struct DebugGraph {
+    pub nodes: Vec<DebugNode>,
+    pub edges: Vec<DebugEdge>,
+}
+
+impl QueryGraph {
+    /// Renders this graph's nodes and edges in a structured, JSON-serializable form, for the
+    /// debug query-graph endpoint. Complements [`ToGraphviz::to_graphviz`], which renders the same
+    /// information as a DOT string for direct consumption by Graphviz.
+    pub fn debug_dump(&self) -> DebugGraph {
+        let root_nodes = self.root_nodes();
+
+        let nodes = self
+            .graph
+            .node_indices()
+            .map(|idx| {
+                let node_ref = NodeRef { node_ix: idx };
+                let node = self.graph.node_weight(idx).unwrap().borrow().unwrap();
+
+                DebugNode {
+                    id: idx.index(),
+                    label: node.to_graphviz(),
+                    is_result: self.is_result_node(&node_ref),
+                    is_root: root_nodes.contains(&node_ref),
+                }
+            })
+            .collect();
+
+        let edges = self
+            .graph
+            .edge_references()
+            .map(|edge| {
+                let edge_content = self.graph.edge_weight(edge.id()).unwrap().borrow().unwrap();
+
+                DebugEdge {
+                    from: self.graph.to_index(edge.source()),
+                    to: self.graph.to_index(edge.target()),
+                    label: edge_content.to_string(),
+                }
+            })
+            .collect();
+
+        DebugGraph { nodes, edges }
+    }
+}
@@ -40,7 +40,17 @@ pub enum QueryGraphBuilderError {
 
     RecordNotFound(String),
 
+    /// A single-record update/delete's `where` matched a record by its unique identifier, but one
+    /// or more extra, non-unique conditions in that same `where` (e.g. `{ id: 1, version: 3 }`)
+    /// didn't hold for it — an optimistic-lock check that lost the race, not a missing record.
+    /// Surfaced to clients as `P2037` (`OptimisticLockError`), distinct from [`Self::RecordNotFound`].
+    RecordNotFoundForOptimisticLock { model_name: String, conditions: Vec<String> },
+
     QueryGraphError(QueryGraphError),
+
+    /// A `findMany`/to-many relation load's `take` exceeds the configured maximum, or is
+    /// unbounded while a maximum is configured. `requested` is `None` for the unbounded case.
+    ResultLimitExceeded { requested: Option<i64>, max: i64 },
 }
 
 #[derive(Debug)]
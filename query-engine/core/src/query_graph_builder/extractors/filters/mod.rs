@@ -23,6 +23,20 @@ use std::{borrow::Cow, collections::HashMap, convert::TryInto, str::FromStr};
 
 /// Extracts a filter for a unique selector, i.e. a filter that selects exactly one record.
 pub fn extract_unique_filter(value_map: ParsedInputMap<'_>, model: &Model) -> QueryGraphBuilderResult<Filter> {
+    extract_unique_filter_with_optimistic_lock_info(value_map, model).map(|(filter, _)| filter)
+}
+
+/// Like [`extract_unique_filter`], but additionally returns the names of any extra, non-unique
+/// fields present in `value_map` (e.g. `where: { id: 1, version: 3 }` reports `["version"]`).
+/// Top-level single-record update/delete use this to tell "no such record" apart from "the record
+/// exists, but one of these extra conditions no longer holds" — the latter being how an
+/// optimistic-lock check is expressed with today's `WhereUniqueInput` (see
+/// `write::update::update_record` and `write::delete::delete_record`). Empty when the input only
+/// contained unique/compound-unique fields.
+pub fn extract_unique_filter_with_optimistic_lock_info(
+    value_map: ParsedInputMap<'_>,
+    model: &Model,
+) -> QueryGraphBuilderResult<(Filter, Vec<String>)> {
     let tag = value_map.tag.clone();
     // Partition the input into a map containing only the unique fields and one containing all the other filters
     // so that we can parse them separately and ensure we AND both filters
@@ -33,6 +47,8 @@ pub fn extract_unique_filter(value_map: ParsedInputMap<'_>, model: &Model) -> Qu
                 Ok(field) => field.unique(),
                 Err(_) => utils::resolve_compound_field(field_name, model).is_some(),
             });
+    let extra_conditions: Vec<String> = rest_map.keys().map(|field_name| field_name.to_string()).collect();
+
     let mut unique_map = ParsedInputMap::from(unique_map);
     let mut rest_map = ParsedInputMap::from(rest_map);
     unique_map.set_tag(tag.clone());
@@ -41,7 +57,7 @@ pub fn extract_unique_filter(value_map: ParsedInputMap<'_>, model: &Model) -> Qu
     let unique_filters = internal_extract_unique_filter(unique_map, model)?;
     let rest_filters = extract_filter(rest_map, model)?;
 
-    Ok(Filter::and(vec![unique_filters, rest_filters]))
+    Ok((Filter::and(vec![unique_filters, rest_filters]), extra_conditions))
 }
 
 /// Extracts a filter for a unique selector, i.e. a filter that selects exactly one record.
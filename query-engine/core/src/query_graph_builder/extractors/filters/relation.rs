@@ -10,6 +10,10 @@ pub fn parse(
     field: &RelationFieldRef,
     input: ParsedInputValue<'_>,
 ) -> QueryGraphBuilderResult<Filter> {
+    if filter_key == filters::IS_EMPTY {
+        return Ok(field.is_empty(input.try_into()?));
+    }
+
     let value: Option<ParsedInputMap<'_>> = input.try_into()?;
 
     match (filter_key, value) {
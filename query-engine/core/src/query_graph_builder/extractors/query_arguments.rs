@@ -120,7 +120,10 @@ fn process_order_object(
                         .expect("To-many relation orderBy must be an aggregation ordering.");
 
                     let (sort_order, _) = extract_order_by_args(inner_field_value)?;
-                    Ok(Some(OrderBy::to_many_aggregation(path, sort_order, sort_aggregation)))
+                    // The schema currently only exposes `_count` for to-many orderBy aggregations
+                    // (see `order_by_to_many_aggregate_object_type`), so there's no target field to
+                    // extract yet for `_avg`/`_sum`/`_min`/`_max` here.
+                    Ok(Some(OrderBy::to_many_aggregation(path, sort_order, sort_aggregation, None)))
                 }
 
                 Field::Relation(rf) => {
@@ -151,7 +154,10 @@ fn process_order_object(
                         .expect("To-many composite orderBy must be an aggregation ordering.");
 
                     let (sort_order, _) = extract_order_by_args(inner_field_value)?;
-                    Ok(Some(OrderBy::to_many_aggregation(path, sort_order, sort_aggregation)))
+                    // The schema currently only exposes `_count` for to-many orderBy aggregations
+                    // (see `order_by_to_many_aggregate_object_type`), so there's no target field to
+                    // extract yet for `_avg`/`_sum`/`_min`/`_max` here.
+                    Ok(Some(OrderBy::to_many_aggregation(path, sort_order, sort_aggregation, None)))
                 }
 
                 Field::Composite(cf) => {
@@ -329,22 +335,52 @@ fn extract_compound_cursor_field(
 
 /// Runs final transformations on the QueryArguments.
 fn finalize_arguments(mut args: QueryArguments, model: &Model) -> QueryArguments {
+    // If the filter already pins the result down to at most one record via a unique constraint,
+    // any ordering (explicit or otherwise) is redundant, unless a cursor relies on it to know
+    // which direction to page in.
+    let order_by_is_redundant = args.cursor.is_none()
+        && args
+            .filter
+            .as_ref()
+            .map(|filter| filter.is_unique_restriction())
+            .unwrap_or(false);
+
+    if order_by_is_redundant {
+        args.order_by.clear();
+        return args;
+    }
+
     // Check if the query requires an implicit ordering added to the arguments.
     // An implicit ordering is convenient for deterministic results for take and skip, for cursor it's _required_
     // as a cursor needs a direction to page. We simply take the primary identifier as a default order-by.
-    let add_implicit_ordering =
-        (args.skip.as_ref().map(|skip| *skip > 0).unwrap_or(false) || args.cursor.is_some() || args.take.is_some())
-            && args.order_by.is_empty();
+    //
+    // If an explicit `orderBy` was already provided, it might not be unique (e.g. ordering by a
+    // non-unique field), which would make cursor pagination unstable: two records tied on the
+    // explicit ordering could be paged past or repeated depending on how the database happens to
+    // break the tie. To guarantee a stable order, we append any primary identifier field that
+    // isn't already part of the ordering as a trailing tie-breaker.
+    let requires_implicit_ordering =
+        args.skip.as_ref().map(|skip| *skip > 0).unwrap_or(false) || args.cursor.is_some() || args.take.is_some();
+
+    if requires_implicit_ordering {
+        let already_ordered: Vec<ScalarFieldRef> = args
+            .order_by
+            .iter()
+            .filter_map(|order_by| match order_by {
+                OrderBy::Scalar(by_scalar) if by_scalar.path.is_empty() => Some(by_scalar.field.clone()),
+                _ => None,
+            })
+            .collect();
 
-    if add_implicit_ordering {
         let primary_identifier = model.primary_identifier();
-        let order_bys = primary_identifier.into_iter().map(|f| match f {
+        let missing_pk_order_bys = primary_identifier.into_iter().filter_map(|f| match f {
             // IDs can _only_ contain scalar selections.
-            SelectedField::Scalar(sf) => sf.into(),
+            SelectedField::Scalar(sf) if !already_ordered.contains(&sf) => Some(sf.into()),
+            SelectedField::Scalar(_) => None,
             _ => unreachable!(),
         });
 
-        args.order_by.extend(order_bys);
+        args.order_by.extend(missing_pk_order_bys);
     }
 
     args
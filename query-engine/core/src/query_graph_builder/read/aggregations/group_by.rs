@@ -125,6 +125,12 @@ fn verify_having(having: Option<&Filter>, selectors: &[AggregationSelection]) ->
     }
 }
 
+/// Note that a field wrapped in an aggregation filter (`_sum`, `_avg`, `_count`, etc.) is
+/// intentionally exempt from the "must be in the selection" check below: `collect_aggregate_field_refs`
+/// only surfaces field refs used as comparison values inside the aggregation, not the aggregated
+/// field itself. This is what allows `having: { someField: { _sum: { gt: 10 } } }` to work even when
+/// `someField` isn't part of `by`, rendering a normal `HAVING SUM(someField) > 10` clause.
+///
 /// Collects all flat scalar fields that are used in the having filter.
 fn collect_scalar_fields(filter: &Filter) -> Vec<&ScalarFieldRef> {
     match filter {
@@ -1,7 +1,7 @@
 use prisma_models::Model;
 
 use super::*;
-use crate::ParsedField;
+use crate::{ManyRecordsQuery, ParsedField};
 
 pub(crate) fn find_first(field: ParsedField<'_>, model: Model) -> QueryGraphBuilderResult<ReadQuery> {
     let many_query = many::find_many(field, model)?;
@@ -14,12 +14,128 @@ pub(crate) fn find_first_or_throw(field: ParsedField<'_>, model: Model) -> Query
 }
 
 #[inline]
-fn try_limit_to_one(mut query: ReadQuery) -> QueryGraphBuilderResult<ReadQuery> {
+fn try_limit_to_one(query: ReadQuery) -> QueryGraphBuilderResult<ReadQuery> {
     Ok(match query {
-        ReadQuery::ManyRecordsQuery(ref mut m) if m.args.take.is_none() => {
+        ReadQuery::ManyRecordsQuery(mut m) if m.args.take.is_none() => {
             m.args.take = Some(1);
-            query
+            ReadQuery::ManyRecordsQuery(claim_indexed_order_optimization(m)?)
         }
         _ => query,
     })
 }
+
+/// A `findFirst` with `take: 1` and a single-column, non-relation `orderBy` is really asking for
+/// the min/max row by that column, which a database can answer with an index scan instead of
+/// sorting the whole result set — but only if that column actually leads an index. When it does,
+/// force that index so connectors render the plan the shape implies; otherwise leave the query
+/// untouched, since forcing a non-covering index would be worse than an unconstrained scan.
+fn claim_indexed_order_optimization(query: ManyRecordsQuery) -> QueryGraphBuilderResult<ManyRecordsQuery> {
+    if query.index_hint.is_some() {
+        return Ok(query);
+    }
+
+    let [order_by] = query.args.order_by.as_slice() else {
+        return Ok(query);
+    };
+
+    if order_by.contains_relation_hops() {
+        return Ok(query);
+    }
+
+    let Some(field) = order_by.field() else { return Ok(query) };
+    let Some(index_name) = query.model.index_name_covering_leading_column(&field) else {
+        return Ok(query);
+    };
+
+    query.with_forced_index(index_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QueryOptions;
+    use connector::QueryArguments;
+    use prisma_models::{FieldSelection, OrderBy};
+    use std::sync::Arc;
+
+    fn test_model() -> Model {
+        let schema = psl::parse_schema(
+            r#"
+            datasource db {
+              provider = "postgresql"
+              url      = "postgres://"
+            }
+
+            model TestModel {
+              id      Int    @id
+              indexed Int
+              plain   Int
+
+              @@index([indexed])
+            }
+            "#,
+        )
+        .unwrap();
+
+        prisma_models::convert(Arc::new(schema)).find_model("TestModel").unwrap()
+    }
+
+    fn many_records_query(model: Model, order_by: Vec<OrderBy>) -> ManyRecordsQuery {
+        let mut args = QueryArguments::new(model.clone());
+        args.order_by = order_by;
+
+        ManyRecordsQuery {
+            name: "test".to_owned(),
+            alias: None,
+            model,
+            args,
+            selected_fields: FieldSelection::new(vec![]),
+            nested: Vec::new(),
+            selection_order: Vec::new(),
+            aggregation_selections: Vec::new(),
+            options: QueryOptions::none(),
+            index_hint: None,
+        }
+    }
+
+    #[test]
+    fn indexed_order_column_gets_a_forced_index() {
+        let model = test_model();
+        let field = model.fields().scalar().find(|f| f.name() == "indexed").unwrap();
+        let query = many_records_query(model, vec![field.into()]);
+
+        let query = claim_indexed_order_optimization(query).unwrap();
+
+        assert_eq!(
+            query.index_hint.map(|hint| hint.index_name().to_owned()),
+            Some("TestModel_indexed_idx".to_owned())
+        );
+    }
+
+    #[test]
+    fn unindexed_order_column_is_left_untouched() {
+        let model = test_model();
+        let field = model.fields().scalar().find(|f| f.name() == "plain").unwrap();
+        let query = many_records_query(model, vec![field.into()]);
+
+        let query = claim_indexed_order_optimization(query).unwrap();
+
+        assert!(query.index_hint.is_none());
+    }
+
+    #[test]
+    fn existing_index_hint_is_never_overridden() {
+        let model = test_model();
+        let field = model.fields().scalar().find(|f| f.name() == "indexed").unwrap();
+        let query = many_records_query(model, vec![field.into()])
+            .with_forced_index("TestModel_indexed_idx")
+            .unwrap();
+
+        let query = claim_indexed_order_optimization(query).unwrap();
+
+        assert_eq!(
+            query.index_hint.map(|hint| hint.index_name().to_owned()),
+            Some("TestModel_indexed_idx".to_owned())
+        );
+    }
+}
@@ -16,7 +16,9 @@ fn find_many_with_options(
     model: Model,
     options: QueryOptions,
 ) -> QueryGraphBuilderResult<ReadQuery> {
-    let args = extractors::extract_query_args(field.arguments, &model)?;
+    let mut args = extractors::extract_query_args(field.arguments, &model)?;
+    utils::enforce_take_limit(&args)?;
+    utils::cap_take_for_max_response_rows(&mut args);
     let name = field.name;
     let alias = field.alias;
     let nested_fields = field.nested_fields.unwrap().fields;
@@ -40,5 +42,6 @@ fn find_many_with_options(
         selection_order,
         aggregation_selections,
         options,
+        index_hint: None,
     }))
 }
@@ -48,5 +48,6 @@ fn find_unique_with_options(
         selection_order,
         aggregation_selections,
         options,
+        index_hint: None,
     }))
 }
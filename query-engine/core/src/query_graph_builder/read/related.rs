@@ -8,6 +8,7 @@ pub(crate) fn find_related(
     model: Model,
 ) -> QueryGraphBuilderResult<ReadQuery> {
     let args = extractors::extract_query_args(field.arguments, &model)?;
+    utils::enforce_take_limit(&args)?;
     let name = field.name;
     let alias = field.alias;
     let sub_selections = field.nested_fields.unwrap().fields;
@@ -31,5 +32,6 @@ pub(crate) fn find_related(
         selection_order,
         aggregation_selections,
         parent_results: None,
+        depends_on_write: false,
     }))
 }
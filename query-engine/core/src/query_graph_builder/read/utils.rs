@@ -1,9 +1,81 @@
 use super::*;
 use crate::{ArgumentListLookup, FieldPair, ParsedField, ReadQuery};
-use connector::RelAggregationSelection;
+use connector::{QueryArguments, RelAggregationSelection};
+use once_cell::sync::Lazy;
 use prisma_models::prelude::*;
 use schema::constants::{aggregations::*, args};
 
+/// Overrides the maximum number of records a single `findMany` or to-many relation load may
+/// `take`. `None` (the default) leaves `take` unbounded. Certain deployments cap it to bound
+/// worst-case query cost; for test purposes this can be set with the `QUERY_MAX_TAKE`
+/// environment variable to a small number.
+static MAX_TAKE_OVERRIDE: Lazy<Option<i64>> = Lazy::new(|| {
+    std::env::var("QUERY_MAX_TAKE")
+        .ok()
+        .map(|size| size.parse().expect("QUERY_MAX_TAKE: not a valid size"))
+});
+
+/// Rejects a `take` that would let a `findMany`/to-many relation load return more records than
+/// the configured maximum allows, instead of silently trimming it. An unbounded query (`take`
+/// absent) is rejected the same way once a maximum is configured, since nothing would otherwise
+/// keep its result set under the cap. A no-op when no maximum is configured.
+///
+/// Called from [`super::many::find_many`] and [`super::related::find_related`], which recurse
+/// into nested to-many loads by calling back into each other via [`collect_nested_queries`], so a
+/// relation cannot be used to sidestep the cap.
+pub(crate) fn enforce_take_limit(args: &QueryArguments) -> QueryGraphBuilderResult<()> {
+    enforce_take_limit_with_max(args, *MAX_TAKE_OVERRIDE)
+}
+
+fn enforce_take_limit_with_max(args: &QueryArguments, max: Option<i64>) -> QueryGraphBuilderResult<()> {
+    let Some(max) = max else {
+        return Ok(());
+    };
+
+    match args.take_abs() {
+        Some(requested) if requested <= max => Ok(()),
+        requested => Err(QueryGraphBuilderError::ResultLimitExceeded { requested, max }),
+    }
+}
+
+/// If [`crate::response_ir::MAX_RESPONSE_ROWS`] is configured, tightens this top-level `findMany`'s
+/// `take` (never loosening an already-smaller one) so the connector can never fetch more rows into
+/// memory than the response limit allows. Without this, an unbounded query against a table far
+/// bigger than the limit would fetch every row before `response_ir::check_response_limits` got a
+/// chance to reject the response, defeating the point of the limit. One extra row is kept over the
+/// limit so that check can still tell "exactly at the limit" apart from "more records exist than the
+/// limit allows" and report the latter as `CoreError::QueryLimitExceeded`.
+///
+/// Does nothing when `distinct` is set: a distinct projection has to see every matching row before
+/// it can be applied, so the interpreter turns `take`/`skip` back off at execution time for such
+/// queries anyway (see `InMemoryRecordProcessor::new_from_query_args`) — capping `take` here would
+/// just make that in-memory step see fewer rows than actually match, silently corrupting the result.
+/// `response_ir::check_response_limits` is the only enforcement left for that case.
+///
+/// Only called for the outermost `findMany` ([`super::many::find_many`]), matching
+/// `check_response_limits` only row-counting the outermost list.
+pub(crate) fn cap_take_for_max_response_rows(args: &mut QueryArguments) {
+    cap_take_for_max_response_rows_with_max(args, *crate::response_ir::MAX_RESPONSE_ROWS)
+}
+
+fn cap_take_for_max_response_rows_with_max(args: &mut QueryArguments, max_rows: Option<usize>) {
+    let Some(max_rows) = max_rows else {
+        return;
+    };
+
+    if args.distinct.is_some() {
+        return;
+    }
+
+    let capped = max_rows as i64 + 1;
+
+    args.take = Some(match args.take {
+        Some(take) if take.abs() <= capped => take,
+        Some(take) if take < 0 => -capped,
+        _ => capped,
+    });
+}
+
 pub fn collect_selection_order(from: &[FieldPair<'_>]) -> Vec<String> {
     from.iter()
         .map(|pair| {
@@ -189,3 +261,127 @@ pub fn collect_relation_aggr_selections(
 
     Ok(selections)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn test_model() -> Model {
+        let schema = psl::parse_schema(
+            r#"
+            datasource db {
+              provider = "postgresql"
+              url      = "postgres://"
+            }
+
+            model TestModel {
+              id Int @id
+            }
+            "#,
+        )
+        .unwrap();
+
+        prisma_models::convert(Arc::new(schema)).find_model("TestModel").unwrap()
+    }
+
+    fn args_with_take(take: Option<i64>) -> QueryArguments {
+        let mut args = QueryArguments::new(test_model());
+        args.take = take;
+        args
+    }
+
+    #[test]
+    fn under_cap_take_is_allowed() {
+        let args = args_with_take(Some(10));
+        assert!(enforce_take_limit_with_max(&args, Some(50)).is_ok());
+    }
+
+    #[test]
+    fn over_cap_take_is_rejected() {
+        let args = args_with_take(Some(100));
+
+        let err = enforce_take_limit_with_max(&args, Some(50)).unwrap_err();
+        assert!(matches!(
+            err,
+            QueryGraphBuilderError::ResultLimitExceeded {
+                requested: Some(100),
+                max: 50
+            }
+        ));
+    }
+
+    #[test]
+    fn unbounded_take_is_rejected_once_a_cap_is_configured() {
+        let args = args_with_take(None);
+
+        let err = enforce_take_limit_with_max(&args, Some(50)).unwrap_err();
+        assert!(matches!(
+            err,
+            QueryGraphBuilderError::ResultLimitExceeded { requested: None, max: 50 }
+        ));
+    }
+
+    #[test]
+    fn unbounded_take_is_allowed_when_no_cap_is_configured() {
+        let args = args_with_take(None);
+        assert!(enforce_take_limit_with_max(&args, None).is_ok());
+    }
+
+    #[test]
+    fn negative_take_is_checked_against_its_absolute_value() {
+        let args = args_with_take(Some(-100));
+
+        let err = enforce_take_limit_with_max(&args, Some(50)).unwrap_err();
+        assert!(matches!(
+            err,
+            QueryGraphBuilderError::ResultLimitExceeded {
+                requested: Some(100),
+                max: 50
+            }
+        ));
+    }
+
+    #[test]
+    fn take_under_the_response_row_cap_is_left_alone() {
+        let mut args = args_with_take(Some(10));
+        cap_take_for_max_response_rows_with_max(&mut args, Some(50));
+        assert_eq!(args.take, Some(10));
+    }
+
+    #[test]
+    fn unbounded_take_is_capped_to_one_over_the_response_row_limit() {
+        let mut args = args_with_take(None);
+        cap_take_for_max_response_rows_with_max(&mut args, Some(50));
+        assert_eq!(args.take, Some(51));
+    }
+
+    #[test]
+    fn take_over_the_response_row_cap_is_tightened_to_it() {
+        let mut args = args_with_take(Some(1000));
+        cap_take_for_max_response_rows_with_max(&mut args, Some(50));
+        assert_eq!(args.take, Some(51));
+    }
+
+    #[test]
+    fn negative_take_over_the_cap_is_tightened_keeping_its_sign() {
+        let mut args = args_with_take(Some(-1000));
+        cap_take_for_max_response_rows_with_max(&mut args, Some(50));
+        assert_eq!(args.take, Some(-51));
+    }
+
+    #[test]
+    fn take_is_left_alone_when_no_response_row_limit_is_configured() {
+        let mut args = args_with_take(None);
+        cap_take_for_max_response_rows_with_max(&mut args, None);
+        assert_eq!(args.take, None);
+    }
+
+    #[test]
+    fn distinct_queries_are_never_capped() {
+        let mut args = args_with_take(None);
+        args.distinct = Some(FieldSelection::from(Vec::<ScalarFieldRef>::new()));
+        cap_take_for_max_response_rows_with_max(&mut args, Some(50));
+        assert_eq!(args.take, None);
+    }
+}
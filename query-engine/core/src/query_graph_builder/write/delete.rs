@@ -19,7 +19,8 @@ pub(crate) fn delete_record(
     graph.flag_transactional();
 
     let where_arg = field.arguments.lookup(args::WHERE).unwrap();
-    let filter = extract_unique_filter(where_arg.value.try_into()?, &model)?;
+    let (filter, optimistic_lock_conditions) =
+        extract_unique_filter_with_optimistic_lock_info(where_arg.value.try_into()?, &model)?;
 
     // Prefetch read query for the delete
     let mut read_query = read::find_unique(field, model.clone())?;
@@ -34,17 +35,21 @@ pub(crate) fn delete_record(
     let delete_node = graph.create_node(delete_query);
     utils::insert_emulated_on_delete(graph, query_schema, &model, &read_node, &delete_node)?;
 
+    let model_name = model.name().to_owned();
+
     graph.create_edge(
         &read_node,
         &delete_node,
         QueryGraphDependency::ProjectedDataDependency(
             model.primary_identifier(),
-            Box::new(|delete_node, parent_ids| {
+            Box::new(move |delete_node, parent_ids| {
                 if !parent_ids.is_empty() {
                     Ok(delete_node)
                 } else {
-                    Err(QueryGraphBuilderError::RecordNotFound(
-                        "Record to delete does not exist.".to_owned(),
+                    Err(update::record_not_found_error(
+                        model_name,
+                        optimistic_lock_conditions,
+                        "Record to delete does not exist.",
                     ))
                 }
             }),
@@ -58,10 +63,31 @@ pub(crate) fn delete_record(
 
 /// Creates a top level delete many records query and adds it to the query graph.
 pub fn delete_many_records(
+    graph: &mut QueryGraph,
+    query_schema: &QuerySchema,
+    model: Model,
+    field: ParsedField<'_>,
+) -> QueryGraphBuilderResult<()> {
+    delete_many_records_with_options(graph, query_schema, model, field, QueryOptions::none())
+}
+
+/// Like [`delete_many_records`], but returns a `RecordRequiredButNotFound` error instead of a
+/// count of `0` when the filter matches no record.
+pub fn delete_many_records_or_throw(
+    graph: &mut QueryGraph,
+    query_schema: &QuerySchema,
+    model: Model,
+    field: ParsedField<'_>,
+) -> QueryGraphBuilderResult<()> {
+    delete_many_records_with_options(graph, query_schema, model, field, QueryOption::ThrowOnEmpty.into())
+}
+
+fn delete_many_records_with_options(
     graph: &mut QueryGraph,
     query_schema: &QuerySchema,
     model: Model,
     mut field: ParsedField<'_>,
+    options: QueryOptions,
 ) -> QueryGraphBuilderResult<()> {
     let filter = match field.arguments.lookup(args::WHERE) {
         Some(where_arg) => extract_filter(where_arg.value.try_into()?, &model)?,
@@ -73,6 +99,7 @@ pub fn delete_many_records(
     let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
         model: model.clone(),
         record_filter,
+        options,
     });
 
     let delete_many_node = graph.create_node(Query::Write(delete_many));
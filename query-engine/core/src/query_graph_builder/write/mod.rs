@@ -14,7 +14,7 @@ use super::*;
 
 // Expose top level write operation builder functions.
 pub(crate) use create::{create_many_records, create_record};
-pub(crate) use delete::{delete_many_records, delete_record};
+pub(crate) use delete::{delete_many_records, delete_many_records_or_throw, delete_record};
 pub(crate) use raw::{execute_raw, query_raw};
-pub(crate) use update::{update_many_records, update_record};
+pub(crate) use update::{update_many_records, update_many_records_or_throw, update_record};
 pub(crate) use upsert::upsert_record;
@@ -417,6 +417,10 @@ fn handle_one_to_one(
     Ok(())
 }
 
+/// Builds a nested `CreateManyRecords` node for a to-many relation (`create`/`update { relation: { createMany: { data: [...] } } }`).
+/// The parent's returned identifier is projected into every child record via `ProjectedDataDependency`
+/// + `inject_result_into_all`, so all records are inserted in a single `CreateManyRecords` write rather
+/// than one nested create per item.
 pub fn nested_create_many(
     graph: &mut QueryGraph,
     parent_node: NodeRef,
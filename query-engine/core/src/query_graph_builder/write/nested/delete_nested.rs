@@ -44,6 +44,7 @@ pub fn nested_delete(
         let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
             model: child_model.clone(),
             record_filter: or_filter.clone().into(),
+            options: QueryOptions::none(),
         });
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
@@ -163,6 +164,7 @@ pub fn nested_delete_many(
         let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
             model: child_model.clone(),
             record_filter: RecordFilter::empty(),
+            options: QueryOptions::none(),
         });
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
@@ -11,6 +11,25 @@ use psl::datamodel_connector::ConnectorCapability;
 use schema::{constants::args, QuerySchema};
 use std::convert::TryInto;
 
+/// A single-record update/delete's `where` matched no record. If it also carried extra,
+/// non-unique conditions (e.g. `{ id: 1, version: 3 }`), reports the more specific
+/// [`QueryGraphBuilderError::RecordNotFoundForOptimisticLock`] (`P2037`) instead of `not_found_message`,
+/// since the record most likely exists but failed one of those extra checks.
+pub(crate) fn record_not_found_error(
+    model_name: String,
+    optimistic_lock_conditions: Vec<String>,
+    not_found_message: &str,
+) -> QueryGraphBuilderError {
+    if optimistic_lock_conditions.is_empty() {
+        QueryGraphBuilderError::RecordNotFound(not_found_message.to_string())
+    } else {
+        QueryGraphBuilderError::RecordNotFoundForOptimisticLock {
+            model_name,
+            conditions: optimistic_lock_conditions,
+        }
+    }
+}
+
 /// Creates an update record query and adds it to the query graph, together with it's nested queries and companion read query.
 pub(crate) fn update_record(
     graph: &mut QueryGraph,
@@ -20,7 +39,7 @@ pub(crate) fn update_record(
 ) -> QueryGraphBuilderResult<()> {
     // "where"
     let where_arg: ParsedInputMap<'_> = field.arguments.lookup(args::WHERE).unwrap().value.try_into()?;
-    let filter = extract_unique_filter(where_arg, &model)?;
+    let (filter, optimistic_lock_conditions) = extract_unique_filter_with_optimistic_lock_info(where_arg, &model)?;
 
     // "data"
     let data_argument = field.arguments.lookup(args::DATA).unwrap();
@@ -68,6 +87,7 @@ pub(crate) fn update_record(
         graph.add_result_node(&update_node);
 
         let check_node = graph.create_node(Node::Empty);
+        let model_name = model.name().to_owned();
 
         graph.create_edge(
             &update_node,
@@ -76,8 +96,10 @@ pub(crate) fn update_record(
                 model.primary_identifier(),
                 Box::new(move |read_node, parent_ids| {
                     if parent_ids.is_empty() {
-                        return Err(QueryGraphBuilderError::RecordNotFound(
-                            "Record to update not found.".to_string(),
+                        return Err(record_not_found_error(
+                            model_name,
+                            optimistic_lock_conditions,
+                            "Record to update not found.",
                         ));
                     }
 
@@ -91,6 +113,7 @@ pub(crate) fn update_record(
 
         let read_query = read::find_unique(field, model.clone())?;
         let read_node = graph.create_node(Query::Read(read_query));
+        let model_name = model.name().to_owned();
 
         graph.add_result_node(&read_node);
 
@@ -102,8 +125,10 @@ pub(crate) fn update_record(
                 Box::new(move |mut read_node, mut parent_ids| {
                     let parent_id = match parent_ids.pop() {
                         Some(pid) => Ok(pid),
-                        None => Err(QueryGraphBuilderError::RecordNotFound(
-                            "Record to update not found.".to_string(),
+                        None => Err(record_not_found_error(
+                            model_name,
+                            optimistic_lock_conditions,
+                            "Record to update not found.",
                         )),
                     }?;
 
@@ -122,10 +147,31 @@ pub(crate) fn update_record(
 
 /// Creates an update many record query and adds it to the query graph.
 pub fn update_many_records(
+    graph: &mut QueryGraph,
+    query_schema: &QuerySchema,
+    model: Model,
+    field: ParsedField<'_>,
+) -> QueryGraphBuilderResult<()> {
+    update_many_records_with_options(graph, query_schema, model, field, QueryOptions::none())
+}
+
+/// Like [`update_many_records`], but returns a `RecordRequiredButNotFound` error instead of a
+/// count of `0` when the filter matches no record.
+pub fn update_many_records_or_throw(
+    graph: &mut QueryGraph,
+    query_schema: &QuerySchema,
+    model: Model,
+    field: ParsedField<'_>,
+) -> QueryGraphBuilderResult<()> {
+    update_many_records_with_options(graph, query_schema, model, field, QueryOption::ThrowOnEmpty.into())
+}
+
+fn update_many_records_with_options(
     graph: &mut QueryGraph,
     query_schema: &QuerySchema,
     model: Model,
     mut field: ParsedField<'_>,
+    options: QueryOptions,
 ) -> QueryGraphBuilderResult<()> {
     graph.flag_transactional();
 
@@ -140,14 +186,15 @@ pub fn update_many_records(
     let data_map: ParsedInputMap<'_> = data_argument.value.try_into()?;
 
     if query_schema.relation_mode().uses_foreign_keys() {
-        update_many_record_node(graph, query_schema, filter, model, data_map)?;
+        update_many_record_node(graph, query_schema, filter, model, data_map, options)?;
     } else {
         let pre_read_node = graph.create_node(utils::read_ids_infallible(
             model.clone(),
             model.primary_identifier(),
             filter,
         ));
-        let update_many_node = update_many_record_node(graph, query_schema, Filter::empty(), model.clone(), data_map)?;
+        let update_many_node =
+            update_many_record_node(graph, query_schema, Filter::empty(), model.clone(), data_map, options)?;
 
         utils::insert_emulated_on_update(graph, query_schema, &model, &pre_read_node, &update_many_node)?;
 
@@ -251,6 +298,7 @@ pub fn update_many_record_node<T>(
     filter: T,
     model: Model,
     data_map: ParsedInputMap<'_>,
+    options: QueryOptions,
 ) -> QueryGraphBuilderResult<NodeRef>
 where
     T: Into<Filter>,
@@ -268,6 +316,7 @@ where
         model,
         record_filter,
         args,
+        options,
     };
 
     let update_many_node = graph.create_node(Query::Write(WriteQuery::UpdateManyRecords(update_many)));
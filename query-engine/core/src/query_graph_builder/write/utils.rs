@@ -44,6 +44,7 @@ where
         selection_order: vec![],
         aggregation_selections: vec![],
         options: QueryOptions::none(),
+        index_hint: None,
     });
 
     Query::Read(read_query)
@@ -115,6 +116,7 @@ where
         aggregation_selections: vec![],
         nested: vec![],
         selection_order: vec![],
+        depends_on_write: true,
     })));
 
     graph.create_edge(
@@ -228,6 +230,7 @@ where
         model,
         record_filter,
         args,
+        options: QueryOptions::none(),
     };
 
     graph.create_node(Query::Write(WriteQuery::UpdateManyRecords(ur)))
@@ -528,6 +531,7 @@ pub fn emulate_on_delete_cascade(
     let delete_query = WriteQuery::DeleteManyRecords(DeleteManyRecords {
         model: dependent_model.clone(),
         record_filter: RecordFilter::empty(),
+        options: QueryOptions::none(),
     });
 
     let delete_dependents_node = graph.create_node(Query::Write(delete_query));
@@ -638,6 +642,7 @@ pub fn emulate_on_delete_set_null(
         model: dependent_model.clone(),
         record_filter: RecordFilter::empty(),
         args: WriteArgs::new(child_update_args, crate::executor::get_request_now()),
+        options: QueryOptions::none(),
     });
 
     let set_null_dependents_node = graph.create_node(Query::Write(set_null_query));
@@ -782,6 +787,7 @@ pub fn emulate_on_update_set_null(
         model: dependent_model.clone(),
         record_filter: RecordFilter::empty(),
         args: WriteArgs::new(child_update_args, crate::executor::get_request_now()),
+        options: QueryOptions::none(),
     });
 
     let set_null_dependents_node = graph.create_node(Query::Write(set_null_query));
@@ -1105,6 +1111,7 @@ pub fn emulate_on_update_cascade(
             child_update_args.into_iter().collect(),
             crate::executor::get_request_now(),
         ),
+        options: QueryOptions::none(),
     });
 
     let update_dependents_node = graph.create_node(Query::Write(update_query));
@@ -239,6 +239,7 @@ fn extract_scalar_list_ops(map: ParsedInputMap<'_>) -> QueryGraphBuilderResult<W
     match operation.as_ref() {
         operations::SET => Ok(WriteOperation::scalar_set(pv)),
         operations::PUSH => Ok(WriteOperation::scalar_add(pv)),
+        operations::UNSHIFT => Ok(WriteOperation::scalar_prepend(pv)),
         _ => unreachable!("Invalid scalar list operation"),
     }
 }
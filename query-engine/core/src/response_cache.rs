@@ -0,0 +1,227 @@
+use crate::{Operation, ResponseData};
+use lru::LruCache;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Default bounded per-model capacity for [`ResponseCache`], overridable with the
+/// `RESPONSE_CACHE_SIZE` environment variable. Mirrors `CLOSED_TX_CACHE_SIZE`'s env-var pattern.
+pub static DEFAULT_RESPONSE_CACHE_SIZE: Lazy<usize> = Lazy::new(|| match std::env::var("RESPONSE_CACHE_SIZE") {
+    Ok(size) => size.parse().unwrap_or(1000),
+    Err(_) => 1000,
+});
+
+/// Per-model TTL configuration for [`ResponseCache`]. A model with no entry here is never cached:
+/// the cache is opt-in per model, not a blanket cache-everything toggle.
+#[derive(Debug, Clone)]
+pub struct ResponseCacheConfig {
+    ttls: HashMap<String, Duration>,
+    capacity: usize,
+}
+
+impl ResponseCacheConfig {
+    pub fn new() -> Self {
+        Self {
+            ttls: HashMap::new(),
+            capacity: *DEFAULT_RESPONSE_CACHE_SIZE,
+        }
+    }
+
+    /// Enables caching reads of `model`, evicting an entry `ttl` after it was inserted.
+    pub fn with_model_ttl(mut self, model: impl Into<String>, ttl: Duration) -> Self {
+        self.ttls.insert(model.into(), ttl);
+        self
+    }
+
+    /// Overrides the per-model LRU capacity (default: [`DEFAULT_RESPONSE_CACHE_SIZE`]).
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ttls.is_empty()
+    }
+
+    fn ttl_for(&self, model: &str) -> Option<Duration> {
+        self.ttls.get(model).copied()
+    }
+}
+
+impl Default for ResponseCacheConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct CacheEntry {
+    response: ResponseData,
+    expires_at: Instant,
+}
+
+/// An opt-in, in-memory read cache for query results, keyed on the model being read plus the full
+/// shape *and* literal argument values of the operation. Unlike [`crate::PlanCache`], whose key
+/// (`Operation::shape_key`) deliberately normalizes literals away for plan reuse, a result cache
+/// must key on the actual values: `findUnique(where: {id: 1})` and `findUnique(where: {id: 2})`
+/// must never share a cached response.
+///
+/// Each cache-enabled model gets its own bounded, least-recently-used store with its own TTL, so
+/// traffic to a fast-changing model can't evict cached reads of a slow-changing one. Invalidation
+/// is coarse-grained and explicit: a write to a model drops that model's entire cache via
+/// [`Self::invalidate_model`] rather than reasoning about which cached reads the write could have
+/// affected.
+///
+/// This type only implements the cache itself; wiring it into query execution (checking it before
+/// a read, populating it after, invalidating it after a write) is the caller's responsibility --
+/// see `executor::InterpretingExecutor`.
+#[derive(Default)]
+pub struct ResponseCache {
+    config: ResponseCacheConfig,
+    caches: HashMap<String, LruCache<String, CacheEntry>>,
+}
+
+impl ResponseCache {
+    pub fn new(config: ResponseCacheConfig) -> Self {
+        Self {
+            config,
+            caches: HashMap::new(),
+        }
+    }
+
+    /// Whether `model` is configured to be cached at all. Cheap to call before building a cache key.
+    pub fn is_enabled_for(&self, model: &str) -> bool {
+        self.config.ttl_for(model).is_some()
+    }
+
+    /// Returns the cached response for `operation` against `model`, if present and not yet expired.
+    /// An expired entry is evicted and treated as a miss.
+    pub fn get(&mut self, model: &str, operation: &Operation) -> Option<ResponseData> {
+        let cache = self.caches.get_mut(model)?;
+        let key = cache_key(operation);
+        let expired = cache.peek(&key).is_some_and(|entry| entry.expires_at <= Instant::now());
+
+        if expired {
+            cache.pop(&key);
+            return None;
+        }
+
+        cache.get(&key).map(|entry| entry.response.clone())
+    }
+
+    /// Caches `response` for `operation` against `model`, if the model has a configured TTL.
+    /// A no-op otherwise.
+    pub fn insert(&mut self, model: &str, operation: &Operation, response: ResponseData) {
+        let Some(ttl) = self.config.ttl_for(model) else {
+            return;
+        };
+
+        let capacity = self.config.capacity;
+        let cache = self
+            .caches
+            .entry(model.to_owned())
+            .or_insert_with(|| LruCache::new(capacity));
+
+        cache.put(
+            cache_key(operation),
+            CacheEntry {
+                response,
+                expires_at: Instant::now() + ttl,
+            },
+        );
+    }
+
+    /// Drops every cached read for `model`. Called after a successful write so cached reads can't
+    /// go on serving data the write has made stale.
+    pub fn invalidate_model(&mut self, model: &str) {
+        self.caches.remove(model);
+    }
+}
+
+/// A cache key covering an operation's full shape and literal argument values. See the
+/// [`ResponseCache`] doc comment for why this can't reuse `Operation::shape_key`.
+fn cache_key(operation: &Operation) -> String {
+    format!("{operation:?}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{response_ir::Item, ArgumentValue, Selection};
+    use prisma_models::PrismaValue;
+
+    fn find_by_id(id: i64) -> Operation {
+        Operation::Read(Selection::new(
+            "findUniqueUser",
+            None,
+            vec![(
+                "where".to_owned(),
+                ArgumentValue::object([("id".to_owned(), ArgumentValue::Scalar(PrismaValue::Int(id)))]),
+            )],
+            Vec::new(),
+        ))
+    }
+
+    fn response() -> ResponseData {
+        ResponseData::new("data".to_owned(), Item::Value(PrismaValue::Int(1)))
+    }
+
+    #[test]
+    fn a_model_without_a_configured_ttl_is_never_cached() {
+        let mut cache = ResponseCache::new(ResponseCacheConfig::new());
+        let op = find_by_id(1);
+
+        assert!(!cache.is_enabled_for("User"));
+        cache.insert("User", &op, response());
+
+        assert!(cache.get("User", &op).is_none());
+    }
+
+    #[test]
+    fn insert_then_get_returns_the_cached_response() {
+        let config = ResponseCacheConfig::new().with_model_ttl("User", Duration::from_secs(60));
+        let mut cache = ResponseCache::new(config);
+        let op = find_by_id(1);
+
+        cache.insert("User", &op, response());
+
+        assert_eq!(cache.get("User", &op).map(|r| r.key), Some("data".to_owned()));
+    }
+
+    #[test]
+    fn different_literal_arguments_are_different_cache_entries() {
+        let config = ResponseCacheConfig::new().with_model_ttl("User", Duration::from_secs(60));
+        let mut cache = ResponseCache::new(config);
+
+        cache.insert("User", &find_by_id(1), response());
+
+        assert!(cache.get("User", &find_by_id(2)).is_none());
+        assert!(cache.get("User", &find_by_id(1)).is_some());
+    }
+
+    #[test]
+    fn an_expired_entry_is_treated_as_a_miss() {
+        let config = ResponseCacheConfig::new().with_model_ttl("User", Duration::from_millis(0));
+        let mut cache = ResponseCache::new(config);
+        let op = find_by_id(1);
+
+        cache.insert("User", &op, response());
+        std::thread::sleep(Duration::from_millis(5));
+
+        assert!(cache.get("User", &op).is_none());
+    }
+
+    #[test]
+    fn invalidate_model_clears_only_that_models_cache() {
+        let config = ResponseCacheConfig::new()
+            .with_model_ttl("User", Duration::from_secs(60))
+            .with_model_ttl("Post", Duration::from_secs(60));
+        let mut cache = ResponseCache::new(config);
+
+        cache.insert("User", &find_by_id(1), response());
+        cache.insert("Post", &find_by_id(1), response());
+        cache.invalidate_model("User");
+
+        assert!(cache.get("User", &find_by_id(1)).is_none());
+        assert!(cache.get("Post", &find_by_id(1)).is_some());
+    }
+}
@@ -171,22 +171,43 @@ fn serialize_aggregations(
 
 fn write_rel_aggregation_row(row: &RelAggregationRow, map: &mut HashMap<String, Item>) {
     for result in row.iter() {
-        match result {
-            RelAggregationResult::Count(rf, count) => match map.get_mut(UNDERSCORE_COUNT) {
-                Some(item) => match item {
-                    Item::Map(inner_map) => inner_map.insert(rf.name().to_owned(), Item::Value(count.clone())),
-                    _ => unreachable!(),
-                },
-                None => {
-                    let mut inner_map: Map = Map::new();
-                    inner_map.insert(rf.name().to_owned(), Item::Value(count.clone()));
-                    map.insert(UNDERSCORE_COUNT.to_owned(), Item::Map(inner_map))
-                }
-            },
+        let (aggregation_name, rel_field_name, value) = match result {
+            RelAggregationResult::Count(rf, val) => (UNDERSCORE_COUNT, rf.name().to_owned(), val.clone()),
+            RelAggregationResult::Sum(rf, sf, val) => {
+                (UNDERSCORE_SUM, rel_scalar_field_key(rf.name(), sf.name()), val.clone())
+            }
+            RelAggregationResult::Average(rf, sf, val) => {
+                (UNDERSCORE_AVG, rel_scalar_field_key(rf.name(), sf.name()), val.clone())
+            }
+            RelAggregationResult::Min(rf, sf, val) => {
+                (UNDERSCORE_MIN, rel_scalar_field_key(rf.name(), sf.name()), val.clone())
+            }
+            RelAggregationResult::Max(rf, sf, val) => {
+                (UNDERSCORE_MAX, rel_scalar_field_key(rf.name(), sf.name()), val.clone())
+            }
         };
+
+        match map.get_mut(aggregation_name) {
+            Some(Item::Map(inner_map)) => {
+                inner_map.insert(rel_field_name, Item::Value(value));
+            }
+            Some(_) => unreachable!(),
+            None => {
+                let mut inner_map: Map = Map::new();
+                inner_map.insert(rel_field_name, Item::Value(value));
+                map.insert(aggregation_name.to_owned(), Item::Map(inner_map));
+            }
+        }
     }
 }
 
+/// `_sum`/`_avg`/`_min`/`_max` relation aggregations aggregate a specific scalar field of the
+/// related model, unlike `_count` which just needs the relation field name, so their result key
+/// additionally carries which field was aggregated.
+fn rel_scalar_field_key(relation_field_name: &str, scalar_field_name: &str) -> String {
+    format!("{relation_field_name}.{scalar_field_name}")
+}
+
 fn extract_aggregate_object_type<'a, 'b>(output_type: &'b OutputType<'a>) -> &'b ObjectType<'a> {
     match &output_type.inner {
         InnerOutputType::Object(obj) => obj,
@@ -356,6 +377,10 @@ fn serialize_objects(
                 row.iter()
                     .map(|aggr_result| match aggr_result {
                         RelAggregationResult::Count(_, _) => UNDERSCORE_COUNT.to_owned(),
+                        RelAggregationResult::Sum(_, _, _) => UNDERSCORE_SUM.to_owned(),
+                        RelAggregationResult::Average(_, _, _) => UNDERSCORE_AVG.to_owned(),
+                        RelAggregationResult::Min(_, _, _) => UNDERSCORE_MIN.to_owned(),
+                        RelAggregationResult::Max(_, _, _) => UNDERSCORE_MAX.to_owned(),
                     })
                     .unique()
                     .collect()
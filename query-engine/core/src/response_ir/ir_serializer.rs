@@ -1,8 +1,41 @@
 use super::{internal::serialize_internal, response::*, *};
 use crate::{CoreError, ExpressionResult, QueryResult};
+use once_cell::sync::Lazy;
 use prisma_models::PrismaValue;
 use schema::{OutputField, QuerySchema};
 
+/// Max number of records a single list result (e.g. `findMany`) may contain, overridable with
+/// `PRISMA_MAX_RESPONSE_ROWS`. Unset, or set to `0`, means unlimited, matching this crate's original
+/// behavior. This is an engine-level limit only: there is currently no way to override it for an
+/// individual query.
+///
+/// Enforced twice: [`crate::query_graph_builder::read::utils::cap_take_for_max_response_rows`] bounds
+/// the top-level `take` before the query ever reaches the connector, so a query against a table far
+/// bigger than the limit can't exhaust memory fetching rows that would only be thrown away here. This
+/// module's [`check_response_limits`] then rejects the response with [`CoreError::QueryLimitExceeded`]
+/// if it's still over — which is the only enforcement left for a `distinct` query, since capping
+/// `take` ahead of a `distinct` projection would corrupt it (see that function's doc comment).
+pub(crate) static MAX_RESPONSE_ROWS: Lazy<Option<usize>> = Lazy::new(|| {
+    std::env::var("PRISMA_MAX_RESPONSE_ROWS")
+        .ok()
+        .and_then(|size| size.parse().ok())
+        .filter(|size| *size > 0)
+});
+
+/// Max estimated size, in bytes, of a single serialized response before it's rejected with a
+/// [`CoreError::QueryLimitExceeded`], overridable with `PRISMA_MAX_RESPONSE_BYTES`. Unset, or set to
+/// `0`, means unlimited. Unlike [`MAX_RESPONSE_ROWS`], this only guards the size of the response sent
+/// back over the wire, checked once the full result is already in memory: a serialized row's size
+/// isn't known ahead of fetching it, so there's no equivalent of `take`-capping to push this one
+/// earlier. It does not protect against memory exhaustion while fetching a wide (many-columned or
+/// large-blob) result set.
+static MAX_RESPONSE_BYTES: Lazy<Option<usize>> = Lazy::new(|| {
+    std::env::var("PRISMA_MAX_RESPONSE_BYTES")
+        .ok()
+        .and_then(|size| size.parse().ok())
+        .filter(|size| *size > 0)
+});
+
 #[derive(Debug)]
 pub struct IrSerializer<'a> {
     /// Serialization key for root DataItem
@@ -51,6 +84,8 @@ impl<'a> IrSerializer<'a> {
                     item
                 };
 
+                check_response_limits(&result, self.output_field.name())?;
+
                 Ok(ResponseData::new(self.key.clone(), result))
             }
 
@@ -60,3 +95,56 @@ impl<'a> IrSerializer<'a> {
         }
     }
 }
+
+/// Enforces [`MAX_RESPONSE_ROWS`] and [`MAX_RESPONSE_BYTES`] against a top-level serialized result.
+/// Only the outermost list is row-counted (a `findMany` with nested to-many includes is guarded on
+/// its own row count, not the sum of every nested list), while the byte-size guard walks the whole
+/// tree, since that's what actually determines the size of the response sent over the wire.
+fn check_response_limits(item: &Item, field_name: &str) -> crate::Result<()> {
+    if let (Item::List(list), Some(max_rows)) = (item, *MAX_RESPONSE_ROWS) {
+        if list.len() > max_rows {
+            return Err(CoreError::QueryLimitExceeded(format!(
+                "Query `{field_name}` returned {} records, which exceeds the configured limit of {max_rows} \
+                 (see the PRISMA_MAX_RESPONSE_ROWS environment variable).",
+                list.len()
+            )));
+        }
+    }
+
+    if let Some(max_bytes) = *MAX_RESPONSE_BYTES {
+        let size = estimated_size(item);
+
+        if size > max_bytes {
+            return Err(CoreError::QueryLimitExceeded(format!(
+                "Query `{field_name}` returned a response of approximately {size} bytes, which exceeds the \
+                 configured limit of {max_bytes} (see the PRISMA_MAX_RESPONSE_BYTES environment variable).",
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// A cheap approximation of the size, in bytes, `item` would take up once serialized. Walks the
+/// in-memory `Item` tree rather than actually serializing it, since the shape is only needed to
+/// enforce [`MAX_RESPONSE_BYTES`], not to produce the response itself.
+fn estimated_size(item: &Item) -> usize {
+    match item {
+        Item::Map(map) => map.iter().map(|(key, value)| key.len() + estimated_size(value)).sum(),
+        Item::List(list) => list.into_iter().map(estimated_size).sum(),
+        Item::Value(pv) => prisma_value_size(pv),
+        Item::Json(value) => value.to_string().len(),
+        Item::Ref(item_ref) => estimated_size(item_ref),
+    }
+}
+
+fn prisma_value_size(value: &PrismaValue) -> usize {
+    match value {
+        PrismaValue::String(s) | PrismaValue::Enum(s) | PrismaValue::Json(s) => s.len(),
+        PrismaValue::Bytes(b) => b.len(),
+        PrismaValue::List(values) => values.iter().map(prisma_value_size).sum(),
+        PrismaValue::Object(fields) => fields.iter().map(|(k, v)| k.len() + prisma_value_size(v)).sum(),
+        PrismaValue::Null => 0,
+        _ => std::mem::size_of::<PrismaValue>(),
+    }
+}
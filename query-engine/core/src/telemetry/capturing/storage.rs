@@ -17,3 +17,35 @@ impl From<Settings> for Storage {
         }
     }
 }
+
+impl Storage {
+    /// The statements captured for this request under the "query" log level, in the order they
+    /// were executed, each with its exact text (bound-parameter placeholders, not inlined values)
+    /// and the parameters bound to it.
+    ///
+    /// This is the closest thing this capturing pipeline has to an engine-level EXPLAIN report:
+    /// every quaint/Mongo query issued while handling the request is already captured here (see
+    /// `super::capturer::task::Candidate::is_loggable_query_event`), just not (yet) wired up as a
+    /// substitute for a response's data, nor enriched with each database's own `EXPLAIN` output.
+    /// Doing either needs more than reading captured logs: returning statements *instead of* data
+    /// needs a new operation-level flag reaching the interpreter, and per-statement `EXPLAIN` needs
+    /// a connector-specific primitive (syntax differs across Postgres/MySQL/SQLite/MSSQL, and
+    /// MongoDB has no equivalent) — both are out of scope here.
+    pub fn query_plan(&self) -> Vec<models::QueryPlanEntry> {
+        self.logs
+            .iter()
+            .filter(|log| log.level == "query")
+            .filter_map(|log| {
+                let query = log.attributes.get("query")?.as_str()?.to_owned();
+                let params = log
+                    .attributes
+                    .get("params")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_owned();
+
+                Some(models::QueryPlanEntry { query, params })
+            })
+            .collect()
+    }
+}
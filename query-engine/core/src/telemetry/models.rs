@@ -159,6 +159,15 @@ pub type LogEvent = Event;
 /// metrics are modeled as span events
 pub type MetricEvent = Event;
 
+/// A single database round trip captured from a request's "query" level logs: the exact statement
+/// text (with bound parameter placeholders, e.g. `$1` / `?`, not inlined values) and the parameters
+/// bound to it. See [`super::storage::Storage::query_plan`].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct QueryPlanEntry {
+    pub query: String,
+    pub params: String,
+}
+
 pub type HrTime = [u64; 2];
 
 ///  Take from the otel library on what the format should be for High-Resolution time
@@ -248,6 +248,7 @@ impl TransactionCapable for JsQueryable {
     async fn start_transaction<'a>(
         &'a self,
         isolation: Option<IsolationLevel>,
+        session_context: &[(String, String)],
     ) -> quaint::Result<Box<dyn Transaction + 'a>> {
         let tx = self.driver_proxy.start_transaction().await?;
 
@@ -275,6 +276,10 @@ impl TransactionCapable for JsQueryable {
             }
         }
 
+        for (key, value) in session_context {
+            tx.set_session_context_value(key, value).await?;
+        }
+
         self.server_reset_query(tx.as_ref()).await?;
 
         Ok(tx)
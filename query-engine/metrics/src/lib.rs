@@ -119,7 +119,7 @@ pub fn initialize_metrics() {
 fn initialize_metrics_descriptions() {
     describe_counter!(
         PRISMA_CLIENT_QUERIES_TOTAL,
-        "The total number of Prisma Client queries executed"
+        "The total number of Prisma Client queries executed, labelled by `operation` and `model`"
     );
     describe_counter!(
         PRISMA_DATASOURCE_QUERIES_TOTAL,
@@ -1,5 +1,10 @@
 use crate::{ast, Field};
 
+/// An embedded document type (only meaningful for connectors that support composites, i.e.
+/// MongoDB). Composites are first-class throughout the query engine: `CompositeFieldRef` fields
+/// appear directly in the read/write query ASTs and the `Filter` tree (see the `connector` crate's
+/// `CompositeWriteOperation` and `CompositeCompare`), so connectors select, create, and update
+/// embedded documents structurally rather than treating them as opaque JSON blobs.
 pub type CompositeType = crate::Zipper<ast::CompositeTypeId>;
 
 impl CompositeType {
@@ -45,7 +45,7 @@ impl DefaultKind {
 
     /// Does this match @default(uuid(_))?
     pub fn is_uuid(&self) -> bool {
-        matches!(self, DefaultKind::Expression(generator) if generator.name == "uuid")
+        matches!(self, DefaultKind::Expression(generator) if generator.name.starts_with("uuid("))
     }
 
     pub fn unwrap_single(self) -> PrismaValue {
@@ -115,7 +115,7 @@ impl DefaultValue {
         self.kind.is_now()
     }
 
-    /// Does this match @default(uuid(_))?
+    /// Does this match @default(uuid(_))? Covers both `uuid(4)` and the time-ordered `uuid(7)`.
     pub fn is_uuid(&self) -> bool {
         self.kind.is_uuid()
     }
@@ -184,8 +184,19 @@ impl ValueGenerator {
         ValueGenerator::new("cuid".to_owned(), vec![]).unwrap()
     }
 
-    pub fn new_uuid() -> Self {
-        ValueGenerator::new("uuid".to_owned(), vec![]).unwrap()
+    /// `version` is the UUID version to generate, `4` (the default) or the time-ordered `7`.
+    ///
+    /// Like `cuid()` and `nanoid()`, this is always generated by the query engine rather than
+    /// rendered as a column default: no connector we support ships a `uuid(7)`-shaped function, so
+    /// there is no per-flavour SQL to fall back to.
+    pub fn new_uuid(version: Option<u8>) -> Self {
+        let version = version.unwrap_or(4);
+
+        ValueGenerator::new(
+            format!("uuid({version})"),
+            vec![(None, PrismaValue::Int(version.into()))],
+        )
+        .unwrap()
     }
 
     pub fn new_nanoid(length: Option<u8>) -> Self {
@@ -236,7 +247,8 @@ impl ValueGenerator {
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum ValueGeneratorFn {
-    Uuid,
+    /// The UUID version to generate, `4` or `7`.
+    Uuid(u8),
     Cuid,
     Nanoid(Option<u8>),
     Now,
@@ -249,13 +261,14 @@ impl ValueGeneratorFn {
     fn new(name: &str) -> std::result::Result<Self, String> {
         match name {
             "cuid" => Ok(Self::Cuid),
-            "uuid" => Ok(Self::Uuid),
+            "uuid" => Ok(Self::Uuid(4)),
             "now" => Ok(Self::Now),
             "autoincrement" => Ok(Self::Autoincrement),
             "sequence" => Ok(Self::Autoincrement),
             "dbgenerated" => Ok(Self::DbGenerated),
             "auto" => Ok(Self::Auto),
             name if name.starts_with("nanoid(") => Ok(Self::Nanoid(name[7..name.len() - 1].parse::<u8>().ok())),
+            name if name.starts_with("uuid(") => Ok(Self::Uuid(name[5..name.len() - 1].parse::<u8>().unwrap_or(4))),
             _ => Err(format!("The function {name} is not a known function.")),
         }
     }
@@ -263,7 +276,7 @@ impl ValueGeneratorFn {
     #[cfg(feature = "default_generators")]
     fn invoke(&self) -> Option<PrismaValue> {
         match self {
-            Self::Uuid => Some(Self::generate_uuid()),
+            Self::Uuid(version) => Some(Self::generate_uuid(*version)),
             Self::Cuid => Some(Self::generate_cuid()),
             Self::Nanoid(length) => Some(Self::generate_nanoid(length)),
             Self::Now => Some(Self::generate_now()),
@@ -280,8 +293,14 @@ impl ValueGeneratorFn {
     }
 
     #[cfg(feature = "default_generators")]
-    fn generate_uuid() -> PrismaValue {
-        PrismaValue::Uuid(uuid::Uuid::new_v4())
+    fn generate_uuid(version: u8) -> PrismaValue {
+        let uuid = if version == 7 {
+            uuid::Uuid::now_v7()
+        } else {
+            uuid::Uuid::new_v4()
+        };
+
+        PrismaValue::Uuid(uuid)
     }
 
     #[cfg(feature = "default_generators")]
@@ -336,12 +355,20 @@ mod tests {
 
     #[test]
     fn default_value_is_uuid() {
-        let uuid_default = DefaultValue::new_expression(ValueGenerator::new_uuid());
+        let uuid_default = DefaultValue::new_expression(ValueGenerator::new_uuid(None));
 
         assert!(uuid_default.is_uuid());
         assert!(!uuid_default.is_autoincrement());
     }
 
+    #[test]
+    fn default_value_is_uuid_v7() {
+        let uuid_default = DefaultValue::new_expression(ValueGenerator::new_uuid(Some(7)));
+
+        assert!(uuid_default.is_uuid());
+        assert_eq!(uuid_default.as_expression().unwrap().name(), "uuid(7)");
+    }
+
     #[test]
     fn default_value_is_cuid() {
         let cuid_default = DefaultValue::new_expression(ValueGenerator::new_cuid());
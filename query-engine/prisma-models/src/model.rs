@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use psl::{parser_database::walkers, schema_ast::ast};
+use psl::{datamodel_connector::walker_ext_traits::IndexWalkerExt, parser_database::walkers, schema_ast::ast};
 
 pub type Model = crate::Zipper<ast::ModelId>;
 
@@ -58,6 +58,38 @@ impl Model {
             .filter(|idx| idx.is_unique())
             .filter(|index| !index.fields().any(|f| f.is_unsupported()))
     }
+
+    /// The database names of the fields making up every unique criteria on this model: the
+    /// primary key, if any, and every `@unique`/`@@unique` index. A filter that has an equality
+    /// constraint on every field of one of these sets provably matches at most one record.
+    pub fn unique_criteria_field_names(&self) -> Vec<Vec<String>> {
+        self.walker()
+            .unique_criterias()
+            .map(|criteria| criteria.fields().map(|field| field.database_name().to_owned()).collect())
+            .collect()
+    }
+
+    /// Finds an index on this model by its database constraint name, i.e. the name a connector
+    /// would render or expect in an index hint (`FORCE INDEX`, `WITH (INDEX(...))`, ...).
+    pub fn index_by_db_name(&self, name: &str) -> Option<walkers::IndexWalker<'_>> {
+        let connector = self.dm.schema.connector;
+
+        self.walker()
+            .indexes()
+            .find(|idx| idx.constraint_name(connector) == name)
+    }
+
+    /// The database name of an index whose leading column is `field`, if any. A leading column is
+    /// enough for the database to serve a `WHERE`/`ORDER BY` on that column with an index scan,
+    /// regardless of what other columns the index also covers.
+    pub fn index_name_covering_leading_column(&self, field: &ScalarFieldRef) -> Option<String> {
+        let connector = self.dm.schema.connector;
+
+        self.walker()
+            .indexes()
+            .find(|idx| idx.fields().next().is_some_and(|f| f.database_name() == field.db_name()))
+            .map(|idx| idx.constraint_name(connector).into_owned())
+    }
 }
 
 impl std::fmt::Debug for Model {
@@ -91,11 +91,13 @@ impl OrderBy {
         path: Vec<OrderByHop>,
         sort_order: SortOrder,
         sort_aggregation: SortAggregation,
+        field: Option<ScalarFieldRef>,
     ) -> Self {
         Self::ToManyAggregation(OrderByToManyAggregation {
             path,
             sort_order,
             sort_aggregation,
+            field,
         })
     }
 
@@ -177,6 +179,9 @@ pub struct OrderByToManyAggregation {
     pub path: Vec<OrderByHop>,
     pub sort_order: SortOrder,
     pub sort_aggregation: SortAggregation,
+    /// The scalar field being aggregated, e.g. the `views` in `orderBy: { posts: { _sum: { views: asc } } }`.
+    /// `Count` doesn't aggregate a specific field (`count(*)`) and always leaves this `None`.
+    pub field: Option<ScalarFieldRef>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -124,6 +124,36 @@ impl ManyRecords {
         self.records = self.records.into_iter().unique().collect();
         self
     }
+
+    /// Transposes this row-oriented result into one vector per field, in `field_names` order,
+    /// for callers (e.g. analytics pipelines) that assemble columns rather than rows. Every value
+    /// is carried over verbatim, including `PrismaValue::Null`, so null handling and per-column
+    /// type fidelity match the row-oriented form exactly. Relations aren't represented in
+    /// `ManyRecords` to begin with, so the result is naturally flat: nested selections never
+    /// appear in the output regardless of what the original query requested.
+    pub fn into_columnar(self) -> ColumnarRecords {
+        let mut columns: Vec<Vec<PrismaValue>> = vec![Vec::with_capacity(self.records.len()); self.field_names.len()];
+
+        for record in self.records {
+            for (column, value) in columns.iter_mut().zip(record.values) {
+                column.push(value);
+            }
+        }
+
+        ColumnarRecords {
+            field_names: self.field_names,
+            columns,
+        }
+    }
+}
+
+/// A columnar transposition of a [`ManyRecords`]: one vector per field in `field_names` order,
+/// each holding that field's value from every record, in the same row order the original result
+/// had. See [`ManyRecords::into_columnar`].
+#[derive(Debug, Clone, Default)]
+pub struct ColumnarRecords {
+    pub field_names: Vec<String>,
+    pub columns: Vec<Vec<PrismaValue>>,
 }
 
 impl From<(Vec<Vec<PrismaValue>>, &FieldSelection)> for ManyRecords {
@@ -214,3 +244,59 @@ impl Record {
         self.parent_id = Some(parent_id);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_columnar_transposes_the_row_oriented_output() {
+        let field_names = vec!["id".to_string(), "name".to_string(), "age".to_string()];
+
+        let mut records = ManyRecords::new(field_names.clone());
+        records.push(Record::new(vec![
+            PrismaValue::Int(1),
+            PrismaValue::String("Alice".to_string()),
+            PrismaValue::Null,
+        ]));
+        records.push(Record::new(vec![
+            PrismaValue::Int(2),
+            PrismaValue::String("Bob".to_string()),
+            PrismaValue::Int(42),
+        ]));
+
+        let rows: Vec<Vec<PrismaValue>> = records.records.iter().map(|record| record.values.clone()).collect();
+        let columnar = records.into_columnar();
+
+        assert_eq!(columnar.field_names, field_names);
+        assert_eq!(
+            columnar.columns,
+            vec![
+                vec![PrismaValue::Int(1), PrismaValue::Int(2)],
+                vec![
+                    PrismaValue::String("Alice".to_string()),
+                    PrismaValue::String("Bob".to_string())
+                ],
+                vec![PrismaValue::Null, PrismaValue::Int(42)],
+            ]
+        );
+
+        // The columnar form transposed back should reproduce the original row-oriented output.
+        for (row_index, row) in rows.iter().enumerate() {
+            for (col_index, value) in row.iter().enumerate() {
+                assert_eq!(&columnar.columns[col_index][row_index], value);
+            }
+        }
+    }
+
+    #[test]
+    fn into_columnar_of_an_empty_result_has_one_empty_column_per_field() {
+        let field_names = vec!["id".to_string()];
+        let records = ManyRecords::new(field_names.clone());
+
+        let columnar = records.into_columnar();
+
+        assert_eq!(columnar.field_names, field_names);
+        assert_eq!(columnar.columns, vec![Vec::<PrismaValue>::new()]);
+    }
+}
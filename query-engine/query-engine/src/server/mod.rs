@@ -7,7 +7,10 @@ use opentelemetry::trace::TraceContextExt;
 use opentelemetry::{global, propagation::Extractor};
 use query_core::helpers::*;
 use query_core::telemetry::capturing::TxTraceExt;
-use query_core::{telemetry, ExtendedTransactionUserFacingError, TransactionOptions, TxId};
+use query_core::{
+    telemetry, ExtendedTransactionUserFacingError, QueryDocument, QueryGraphBuilder, ToGraphviz, TransactionOptions,
+    TxId,
+};
 use request_handlers::{dmmf, render_graphql_schema, RequestBody, RequestHandler};
 use serde::Serialize;
 use serde_json::json;
@@ -60,6 +63,13 @@ pub(crate) async fn routes(cx: Arc<PrismaContext>, req: Request<Body>) -> Result
         return metrics_handler(cx, req).await;
     }
 
+    if req.method() == Method::POST
+        && req.uri().path() == "/debug/query-graph"
+        && cx.enabled_features.contains(Feature::DebugMode)
+    {
+        return debug_query_graph_handler(cx, req).await;
+    }
+
     let mut res = match (req.method(), req.uri().path()) {
         (&Method::POST, "/") => request_handler(cx, req).await?,
         (&Method::GET, "/") if cx.enabled_features.contains(Feature::Playground) => playground_handler(),
@@ -249,6 +259,50 @@ async fn metrics_handler(cx: Arc<PrismaContext>, req: Request<Body>) -> Result<R
     Ok(response)
 }
 
+/// Builds the query graph for a single operation and returns it as a Graphviz DOT string plus a
+/// structured JSON form, without executing it. Only enabled in [`Feature::DebugMode`], as this
+/// bypasses the executor entirely and is meant for troubleshooting query planning, not for use by
+/// a real client.
+async fn debug_query_graph_handler(cx: Arc<PrismaContext>, req: Request<Body>) -> Result<Response<Body>, hyper::Error> {
+    let body_start = req.into_body();
+    let full_body = hyper::body::to_bytes(body_start).await?;
+
+    let operation = match RequestBody::try_from_slice(full_body.as_ref(), cx.engine_protocol())
+        .map_err(|e| e.to_string())
+        .and_then(|body| body.into_doc(cx.query_schema()).map_err(|e| e.to_string()))
+    {
+        Ok(QueryDocument::Single(operation)) => operation,
+        Ok(QueryDocument::Multi(_)) => {
+            return Ok(build_json_response(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                &json!({ "error": "The debug query-graph endpoint only supports a single operation, not a batch." }),
+            ))
+        }
+        Err(e) => {
+            return Ok(build_json_response(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                &json!({ "error": e }),
+            ))
+        }
+    };
+
+    let query_schema = cx.query_schema().clone();
+    let response = match QueryGraphBuilder::new(query_schema.as_ref()).build(operation) {
+        Ok((graph, _serializer)) => json!({
+            "dot": graph.to_graphviz(),
+            "graph": graph.debug_dump(),
+        }),
+        Err(e) => {
+            return Ok(build_json_response(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                &json!({ "error": format!("{e:?}") }),
+            ))
+        }
+    };
+
+    Ok(build_json_response(StatusCode::OK, &response))
+}
+
 /// Sadly the routing doesn't make it obvious what the transaction routes are:
 /// POST /transaction/start -> start a transaction
 /// POST /transaction/{tx_id}/commit -> commit a transaction
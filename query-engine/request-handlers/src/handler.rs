@@ -43,7 +43,10 @@ impl<'a> RequestHandler<'a> {
     pub async fn handle(&self, body: RequestBody, tx_id: Option<TxId>, trace_id: Option<String>) -> PrismaResponse {
         tracing::debug!("Incoming GraphQL query: {:?}", &body);
 
-        match body.into_doc(self.query_schema) {
+        let parse_span = tracing::info_span!("prisma:engine:parse_query");
+        let parsed = parse_span.in_scope(|| body.into_doc(self.query_schema));
+
+        match parsed {
             Ok(QueryDocument::Single(query)) => self.handle_single(query, tx_id, trace_id).await,
             Ok(QueryDocument::Multi(batch)) => match batch.compact(self.query_schema) {
                 BatchDocument::Multi(batch, transaction) => {
@@ -114,7 +117,7 @@ impl<'a> RequestHandler<'a> {
         let throw_on_empty = document.throw_on_empty();
         let keys: Vec<String> = document.keys;
         let arguments = document.arguments;
-        let nested_selection = document.nested_selection;
+        let nested_selections = document.nested_selection;
 
         match AssertUnwindSafe(self.handle_request(document.operation, tx_id, trace_id))
             .catch_unwind()
@@ -154,7 +157,8 @@ impl<'a> RequestHandler<'a> {
 
                 let results: Vec<GQLResponse> = arguments
                     .into_iter()
-                    .map(|args| {
+                    .zip(nested_selections)
+                    .map(|(args, nested_selection)| {
                         let mut responses = GQLResponse::with_capacity(1);
                         // This is step 5 of the comment above.
                         // Copying here is mandatory due to some of the queries
@@ -162,12 +166,13 @@ impl<'a> RequestHandler<'a> {
                         // batch. We need to give the same answer for both of them.
                         match Self::find_original_result_from_args(&args_to_results, &args) {
                             Some(result) => {
-                                // Filter out all the keys not selected in the
-                                // original query.
-                                let result: IndexMap<String, Item> = result
-                                    .clone()
-                                    .into_iter()
-                                    .filter(|(k, _)| nested_selection.contains(k))
+                                // Rebuild the response in this particular original query's own
+                                // field order, picking only the fields it selected (other
+                                // compacted queries may have selected more, or the same fields
+                                // in a different order).
+                                let result: IndexMap<String, Item> = nested_selection
+                                    .iter()
+                                    .filter_map(|key| result.get(key).map(|value| (key.clone(), value.clone())))
                                     .collect();
 
                                 responses.insert_data(&singular_name, Item::Map(result));
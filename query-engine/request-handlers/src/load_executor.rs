@@ -1,8 +1,9 @@
 use psl::{builtin_connectors::*, Datasource, PreviewFeatures};
-use query_core::{executor::InterpretingExecutor, Connector, QueryExecutor};
+use query_core::{executor::InterpretingExecutor, Connector, QueryExecutor, ResponseCacheConfig};
 use sql_query_connector::*;
 use std::collections::HashMap;
 use std::env;
+use std::time::Duration;
 use tracing::trace;
 use url::Url;
 
@@ -110,7 +111,36 @@ fn executor_for<T>(connector: T, force_transactions: bool) -> Box<dyn QueryExecu
 where
     T: Connector + Send + Sync + 'static,
 {
-    Box::new(InterpretingExecutor::new(connector, force_transactions))
+    let executor = InterpretingExecutor::new(connector, force_transactions);
+
+    match response_cache_config_from_env() {
+        Some(config) => Box::new(executor.with_response_cache(config)),
+        None => Box::new(executor),
+    }
+}
+
+/// Builds an opt-in [`ResponseCacheConfig`] from the `RESPONSE_CACHE_MODELS` environment variable,
+/// a comma-separated list of `Model=ttl_seconds` pairs (e.g. `RESPONSE_CACHE_MODELS=Country=300,Currency=3600`).
+/// Returns `None` (cache disabled, matching the executor's default) if the variable is unset,
+/// empty, or contains no valid pair.
+fn response_cache_config_from_env() -> Option<ResponseCacheConfig> {
+    let raw = env::var("RESPONSE_CACHE_MODELS").ok()?;
+    let mut config = ResponseCacheConfig::new();
+    let mut has_entries = false;
+
+    for pair in raw.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let Some((model, ttl_secs)) = pair.split_once('=') else {
+            continue;
+        };
+        let Ok(ttl_secs) = ttl_secs.trim().parse() else {
+            continue;
+        };
+
+        config = config.with_model_ttl(model.trim(), Duration::from_secs(ttl_secs));
+        has_entries = true;
+    }
+
+    has_entries.then_some(config)
 }
 
 #[cfg(feature = "mongodb")]
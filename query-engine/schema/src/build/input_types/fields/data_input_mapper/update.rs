@@ -79,7 +79,15 @@ impl DataInputFieldMapper for UpdateDataInputFieldMapper {
             if ctx.has_capability(ConnectorCapability::EnumArrayPush) {
                 let map_scalar_type = map_scalar_input_type(ctx, type_identifier, false);
                 object_fields.push(
-                    input_field(operations::PUSH, vec![map_scalar_type, list_input_type.clone()], None).optional(),
+                    input_field(
+                        operations::PUSH,
+                        vec![map_scalar_type.clone(), list_input_type.clone()],
+                        None,
+                    )
+                    .optional(),
+                );
+                object_fields.push(
+                    input_field(operations::UNSHIFT, vec![map_scalar_type, list_input_type.clone()], None).optional(),
                 )
             }
             object_fields
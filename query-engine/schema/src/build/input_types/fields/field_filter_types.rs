@@ -73,6 +73,7 @@ fn to_many_relation_filter_object(ctx: &'_ QuerySchema, rf: RelationFieldRef) ->
             simple_input_field(filters::EVERY, InputType::object(related_input_type.clone()), None).optional(),
             simple_input_field(filters::SOME, InputType::object(related_input_type.clone()), None).optional(),
             simple_input_field(filters::NONE, InputType::object(related_input_type), None).optional(),
+            simple_input_field(filters::IS_EMPTY, InputType::boolean(), None).optional(),
         ]
     });
     object
@@ -260,6 +261,10 @@ fn full_scalar_filter_type(
 
             TypeIdentifier::Boolean => equality_filters(mapped_scalar_type.clone(), nullable).collect(),
 
+            // `equals`/`in`/`notIn` (plus the unconditional `not` pushed below and the null check
+            // carried by `nullable`) is the full filter surface for Bytes: binding to hex/base64 on
+            // SQL and to BinData on Mongo happens uniformly for every scalar type via
+            // `ScalarFieldRef::value`/`into_bson`, so there is nothing Bytes-specific to add here.
             TypeIdentifier::Bytes | TypeIdentifier::Enum(_) => equality_filters(mapped_scalar_type.clone(), nullable)
                 .chain(inclusion_filters(ctx, mapped_scalar_type.clone(), nullable))
                 .collect(),
@@ -30,6 +30,8 @@ pub(crate) fn mutation_fields(ctx: &QuerySchema) -> Vec<FieldFn> {
 
         field!(update_many_field, model);
         field!(delete_many_field, model);
+        field!(update_many_or_throw_field, model);
+        field!(delete_many_or_throw_field, model);
     }
 
     if ctx.enable_raw_queries && ctx.has_capability(ConnectorCapability::SqlQueryRaw) {
@@ -165,6 +167,40 @@ fn update_many_field(ctx: &QuerySchema, model: Model) -> OutputField<'_> {
     )
 }
 
+/// Builds an update many mutation field that throws if no record matched the filter (e.g.
+/// updateManyUsersOrThrow), mirroring `updateManyUsers` otherwise.
+fn update_many_or_throw_field(ctx: &QuerySchema, model: Model) -> OutputField<'_> {
+    let field_name = format!("updateMany{}OrThrow", model.name());
+    let cloned_model = model.clone();
+
+    field(
+        field_name,
+        move || arguments::update_many_arguments(ctx, cloned_model),
+        OutputType::object(objects::affected_records_object_type()),
+        Some(QueryInfo {
+            model: Some(model.id),
+            tag: QueryTag::UpdateManyOrThrow,
+        }),
+    )
+}
+
+/// Builds a delete many mutation field that throws if no record matched the filter (e.g.
+/// deleteManyUsersOrThrow), mirroring `deleteManyUsers` otherwise.
+fn delete_many_or_throw_field(ctx: &QuerySchema, model: Model) -> OutputField<'_> {
+    let field_name = format!("deleteMany{}OrThrow", model.name());
+    let cloned_model = model.clone();
+
+    field(
+        field_name,
+        move || arguments::delete_many_arguments(ctx, cloned_model),
+        OutputType::object(objects::affected_records_object_type()),
+        Some(QueryInfo {
+            model: Some(model.id),
+            tag: QueryTag::DeleteManyOrThrow,
+        }),
+    )
+}
+
 /// Builds an upsert mutation field (e.g. upsertUser) for given model.
 fn upsert_item_field(ctx: &QuerySchema, model: Model) -> OutputField<'_> {
     let cloned_model = model.clone();
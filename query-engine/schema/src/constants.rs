@@ -40,6 +40,7 @@ pub mod operations {
 
     // scalar lists and composites
     pub const PUSH: &str = "push";
+    pub const UNSHIFT: &str = "unshift";
     pub const UNSET: &str = "unset";
 
     // numbers
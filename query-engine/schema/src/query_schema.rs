@@ -90,6 +90,10 @@ impl QuerySchema {
         capabilities.iter().any(|c| self.connector.has_capability(*c))
     }
 
+    /// `FullTextSearchWithoutIndex` (Postgres, computed on the fly with `to_tsvector`/`to_tsquery`)
+    /// and `FullTextSearchWithIndex` (MySQL, backed by a `@@fulltext` index and `MATCH ... AGAINST`)
+    /// both enable the `search` filter and `_relevance` orderBy the same way from the client's
+    /// perspective, they just differ in what the connector needs on the database side.
     pub(crate) fn can_full_text_search(&self) -> bool {
         self.has_feature(PreviewFeature::FullTextSearch)
             && (self.has_capability(ConnectorCapability::FullTextSearchWithoutIndex)
@@ -192,8 +196,10 @@ pub enum QueryTag {
     CreateMany,
     UpdateOne,
     UpdateMany,
+    UpdateManyOrThrow,
     DeleteOne,
     DeleteMany,
+    DeleteManyOrThrow,
     UpsertOne,
     Aggregate,
     GroupBy,
@@ -217,8 +223,10 @@ impl fmt::Display for QueryTag {
             Self::CreateMany => "createMany",
             Self::UpdateOne => "updateOne",
             Self::UpdateMany => "updateMany",
+            Self::UpdateManyOrThrow => "updateManyOrThrow",
             Self::DeleteOne => "deleteOne",
             Self::DeleteMany => "deleteMany",
+            Self::DeleteManyOrThrow => "deleteManyOrThrow",
             Self::UpsertOne => "upsertOne",
             Self::Aggregate => "aggregate",
             Self::GroupBy => "groupBy",
@@ -245,8 +253,10 @@ impl From<&str> for QueryTag {
             "createMany" => Self::CreateMany,
             "updateOne" => Self::UpdateOne,
             "updateMany" => Self::UpdateMany,
+            "updateManyOrThrow" => Self::UpdateManyOrThrow,
             "deleteOne" => Self::DeleteOne,
             "deleteMany" => Self::DeleteMany,
+            "deleteManyOrThrow" => Self::DeleteManyOrThrow,
             "upsertOne" => Self::UpsertOne,
             "aggregate" => Self::Aggregate,
             "groupBy" => Self::GroupBy,
@@ -117,7 +117,7 @@ pub(crate) async fn apply_script(
     connector.flavour.apply_migration_script(migration_name, script).await
 }
 
-fn render_raw_sql(
+pub(crate) fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlFlavour + Send + Sync),
     schemas: MigrationPair<&SqlSchema>,
@@ -126,6 +126,12 @@ fn render_raw_sql(
         SqlMigrationStep::AlterSequence(sequence_ids, changes) => {
             renderer.render_alter_sequence(*sequence_ids, *changes, schemas)
         }
+        SqlMigrationStep::AlterColumnStorage(column_ids, storage) => {
+            vec![renderer.render_alter_column_storage(schemas.walk(*column_ids), *storage)]
+        }
+        SqlMigrationStep::AlterColumnCompression(column_ids, compression) => {
+            vec![renderer.render_alter_column_compression(schemas.walk(*column_ids), *compression)]
+        }
         SqlMigrationStep::AlterPrimaryKey(table_id) => renderer.render_alter_primary_key(schemas.walk(*table_id)),
         SqlMigrationStep::AlterEnum(alter_enum) => renderer.render_alter_enum(alter_enum, schemas),
         SqlMigrationStep::RedefineTables(redefine_tables) => renderer.render_redefine_tables(redefine_tables, schemas),
@@ -137,7 +143,14 @@ fn render_raw_sql(
         SqlMigrationStep::CreateTable { table_id } => {
             let table = schemas.next.walk(*table_id);
 
-            vec![renderer.render_create_table(table)]
+            let mut stmts = vec![renderer.render_create_table(table)];
+            stmts.extend(renderer.render_table_grants(table));
+            stmts
+        }
+        SqlMigrationStep::CreateTableAsSelect(create_table_as_select) => {
+            let table = schemas.next.walk(create_table_as_select.table_id);
+
+            vec![renderer.render_create_table_as_select(table, &create_table_as_select.select_query)]
         }
         SqlMigrationStep::DropTable { table_id } => {
             let table = schemas.previous.walk(*table_id);
@@ -9,7 +9,7 @@ mod sqlite;
 
 pub(crate) use mssql::MssqlFlavour;
 pub(crate) use mysql::MysqlFlavour;
-pub(crate) use postgres::PostgresFlavour;
+pub(crate) use postgres::{IdentifierCasing, PostgresFlavour};
 pub(crate) use sqlite::SqliteFlavour;
 
 use crate::{
@@ -145,6 +145,17 @@ pub(crate) trait SqlFlavour:
 
     fn describe_schema(&mut self, namespaces: Option<Namespaces>) -> BoxFuture<'_, ConnectorResult<SqlSchema>>;
 
+    /// A cheap, stable fingerprint of the live database schema, for drift detection: comparing two
+    /// fingerprints taken over time tells you whether the schema shape changed, without doing a
+    /// full diff. See [`crate::schema_fingerprint`] for exactly what goes into it and why it's
+    /// stable across cosmetic, flavour-specific type spelling differences.
+    fn schema_fingerprint(&mut self, namespaces: Option<Namespaces>) -> BoxFuture<'_, ConnectorResult<String>> {
+        Box::pin(async move {
+            let schema = self.describe_schema(namespaces).await?;
+            Ok(crate::schema_fingerprint::compute(&schema))
+        })
+    }
+
     /// Drop the database.
     fn drop_database(&mut self) -> BoxFuture<'_, ConnectorResult<()>>;
 
@@ -283,6 +294,15 @@ pub(crate) trait SqlFlavour:
     /// If this is ever a problem, considering returning an indicator of success.
     fn set_preview_features(&mut self, preview_features: BitFlags<psl::PreviewFeature>);
 
+    /// Whether DDL should be rendered idempotently (`CREATE TABLE IF NOT EXISTS`, `DROP TABLE IF
+    /// EXISTS`, ...) where this flavour supports it, falling back to bare DDL otherwise. Off by
+    /// default. Useful for operators manually re-running a migration that was interrupted
+    /// partway through.
+    fn idempotent_ddl(&self) -> bool;
+
+    /// See [`SqlFlavour::idempotent_ddl`].
+    fn set_idempotent_ddl(&mut self, idempotent: bool);
+
     /// Table to store applied migrations.
     fn migrations_table(&self) -> Table<'static> {
         crate::MIGRATIONS_TABLE_NAME.into()
@@ -27,11 +27,15 @@ impl Params {
 
 pub(crate) struct MssqlFlavour {
     state: State,
+    idempotent_ddl: bool,
 }
 
 impl Default for MssqlFlavour {
     fn default() -> Self {
-        MssqlFlavour { state: State::Initial }
+        MssqlFlavour {
+            state: State::Initial,
+            idempotent_ddl: false,
+        }
     }
 }
 
@@ -404,6 +408,14 @@ impl SqlFlavour for MssqlFlavour {
         }
     }
 
+    fn idempotent_ddl(&self) -> bool {
+        self.idempotent_ddl
+    }
+
+    fn set_idempotent_ddl(&mut self, idempotent: bool) {
+        self.idempotent_ddl = idempotent;
+    }
+
     fn sql_schema_from_migration_history<'a>(
         &'a mut self,
         migrations: &'a [MigrationDirectory],
@@ -28,11 +28,15 @@ struct Params {
 
 pub(crate) struct MysqlFlavour {
     state: State,
+    idempotent_ddl: bool,
 }
 
 impl Default for MysqlFlavour {
     fn default() -> Self {
-        MysqlFlavour { state: State::Initial }
+        MysqlFlavour {
+            state: State::Initial,
+            idempotent_ddl: false,
+        }
     }
 }
 
@@ -68,6 +72,14 @@ impl MysqlFlavour {
 }
 
 impl SqlFlavour for MysqlFlavour {
+    fn idempotent_ddl(&self) -> bool {
+        self.idempotent_ddl
+    }
+
+    fn set_idempotent_ddl(&mut self, idempotent: bool) {
+        self.idempotent_ddl = idempotent;
+    }
+
     fn acquire_lock(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         with_connection(&mut self.state, |params, _, connection| async move {
             // We do not acquire advisory locks on PlanetScale instances.
@@ -31,6 +31,36 @@ type State = super::State<Params, (BitFlags<Circumstances>, Connection)>;
 struct Params {
     connector_params: ConnectorParams,
     url: PostgresUrl,
+    identifier_casing: IdentifierCasing,
+}
+
+/// Controls how the Postgres renderer quotes identifiers (tables, columns, indexes, ...) it emits.
+///
+/// Postgres folds unquoted identifiers to lowercase, so a database created by hand-written,
+/// unquoted SQL only ever has lowercase names. Prisma always double-quotes by default, which is
+/// exact but can look inconsistent next to such a database. Set via the `identifierCasing` query
+/// parameter on the connection string (`identifierCasing=foldLowercaseUnquoted`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum IdentifierCasing {
+    /// Always double-quote identifiers, regardless of casing. The default, and the only
+    /// historical behaviour.
+    #[default]
+    AlwaysQuote,
+    /// Render identifiers unquoted when they're already valid, all-lowercase Postgres identifiers.
+    /// Anything else (mixed case, reserved words, special characters) is still quoted, so
+    /// switching to this mode never changes which object a rendered identifier refers to.
+    FoldLowercaseUnquoted,
+}
+
+fn identifier_casing_from_url(url: &Url) -> IdentifierCasing {
+    match url
+        .query_pairs()
+        .find(|(k, _)| k == "identifierCasing")
+        .map(|(_, v)| v.into_owned())
+    {
+        Some(v) if v.eq_ignore_ascii_case("foldLowercaseUnquoted") => IdentifierCasing::FoldLowercaseUnquoted,
+        _ => IdentifierCasing::AlwaysQuote,
+    }
 }
 
 /// The specific provider that was requested by the user.
@@ -47,6 +77,7 @@ pub(crate) enum PostgresProvider {
 pub(crate) struct PostgresFlavour {
     state: State,
     provider: PostgresProvider,
+    idempotent_ddl: bool,
 }
 
 impl Default for PostgresFlavour {
@@ -66,6 +97,7 @@ impl PostgresFlavour {
         PostgresFlavour {
             state: State::Initial,
             provider: PostgresProvider::PostgreSql,
+            idempotent_ddl: false,
         }
     }
 
@@ -73,6 +105,7 @@ impl PostgresFlavour {
         PostgresFlavour {
             state: State::Initial,
             provider: PostgresProvider::CockroachDb,
+            idempotent_ddl: false,
         }
     }
 
@@ -80,6 +113,7 @@ impl PostgresFlavour {
         PostgresFlavour {
             state: State::Initial,
             provider: PostgresProvider::Unspecified,
+            idempotent_ddl: false,
         }
     }
 
@@ -101,9 +135,44 @@ impl PostgresFlavour {
     pub(crate) fn schema_name(&self) -> &str {
         self.state.params().map(|p| p.url.schema()).unwrap_or("public")
     }
+
+    /// Postgres 12+ skips the full-table validation scan for `SET NOT NULL` if a `NOT NULL`-
+    /// entailing `CHECK` constraint on the column has already been validated, so promoting a
+    /// column to `NOT NULL` can be done with a cheap, mostly-online `ADD CONSTRAINT ... NOT VALID`
+    /// + `VALIDATE CONSTRAINT` dance instead of locking the table for a full scan.
+    pub(crate) fn can_validate_not_null_with_check_constraint(&self) -> bool {
+        !self.is_cockroachdb()
+            && self
+                .circumstances()
+                .map(|c| c.contains(Circumstances::CanValidateNotNullWithCheck))
+                .unwrap_or(false)
+    }
+
+    /// Postgres 14+ (and not CockroachDB) lets a column declare its own TOAST compression method
+    /// via `SET COMPRESSION`, instead of always using the `default_toast_compression` GUC.
+    pub(crate) fn can_set_column_compression(&self) -> bool {
+        !self.is_cockroachdb()
+            && self
+                .circumstances()
+                .map(|c| c.contains(Circumstances::CanSetColumnCompression))
+                .unwrap_or(false)
+    }
+
+    /// The identifier casing strategy configured for this connection (see [`IdentifierCasing`]).
+    pub(crate) fn identifier_casing(&self) -> IdentifierCasing {
+        self.state.params().map(|p| p.identifier_casing).unwrap_or_default()
+    }
 }
 
 impl SqlFlavour for PostgresFlavour {
+    fn idempotent_ddl(&self) -> bool {
+        self.idempotent_ddl
+    }
+
+    fn set_idempotent_ddl(&mut self, idempotent: bool) {
+        self.idempotent_ddl = idempotent;
+    }
+
     fn acquire_lock(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         with_connection(self, move |params, circumstances, connection| async move {
             // They do not support advisory locking:
@@ -364,10 +433,15 @@ impl SqlFlavour for PostgresFlavour {
             .parse()
             .map_err(ConnectorError::url_parse_error)?;
         disable_postgres_statement_cache(&mut url)?;
+        let identifier_casing = identifier_casing_from_url(&url);
         let connection_string = url.to_string();
         let url = PostgresUrl::new(url).map_err(ConnectorError::url_parse_error)?;
         connector_params.connection_string = connection_string;
-        let params = Params { connector_params, url };
+        let params = Params {
+            connector_params,
+            url,
+            identifier_casing,
+        };
         self.state.set_params(params);
         Ok(())
     }
@@ -535,6 +609,12 @@ pub(crate) enum Circumstances {
     IsCockroachDb,
     CockroachWithPostgresNativeTypes, // FIXME: we should really break and remove this
     CanPartitionTables,
+    /// Postgres 12+: `SET NOT NULL` can skip its validation scan when a validated `CHECK`
+    /// constraint already proves the column has no `NULL`s.
+    CanValidateNotNullWithCheck,
+    /// Postgres 14+: columns can be given an explicit compression method (`SET COMPRESSION`),
+    /// choosing between `pglz` and the faster, less dense `lz4`. Not available on CockroachDB.
+    CanSetColumnCompression,
 }
 
 fn disable_postgres_statement_cache(url: &mut Url) -> ConnectorResult<()> {
@@ -615,8 +695,18 @@ where
                             if db_is_cockroach {
                                 circumstances |= Circumstances::IsCockroachDb;
                                 connection.raw_cmd(COCKROACHDB_PRELUDE, &params.url).await?;
-                            } else if version_num >= 100000 {
-                                circumstances |= Circumstances:: CanPartitionTables;
+                            } else {
+                                if version_num >= 100000 {
+                                    circumstances |= Circumstances::CanPartitionTables;
+                                }
+
+                                if version_num >= 120000 {
+                                    circumstances |= Circumstances::CanValidateNotNullWithCheck;
+                                }
+
+                                if version_num >= 140000 {
+                                    circumstances |= Circumstances::CanSetColumnCompression;
+                                }
                             }
                         }
                         None => {
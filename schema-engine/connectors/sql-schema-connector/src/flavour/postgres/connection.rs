@@ -67,6 +67,10 @@ impl Connection {
             describer_circumstances |= describer::Circumstances::CanPartitionTables;
         }
 
+        if circumstances.contains(super::Circumstances::CanSetColumnCompression) {
+            describer_circumstances |= describer::Circumstances::CanUseColumnCompression;
+        }
+
         let namespaces_vec = Namespaces::to_vec(namespaces, String::from(params.url.schema()));
         let namespaces_str: Vec<&str> = namespaces_vec.iter().map(AsRef::as_ref).collect();
 
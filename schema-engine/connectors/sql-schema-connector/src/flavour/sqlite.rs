@@ -18,11 +18,15 @@ struct Params {
 
 pub(crate) struct SqliteFlavour {
     state: State,
+    idempotent_ddl: bool,
 }
 
 impl Default for SqliteFlavour {
     fn default() -> Self {
-        SqliteFlavour { state: State::Initial }
+        SqliteFlavour {
+            state: State::Initial,
+            idempotent_ddl: false,
+        }
     }
 }
 
@@ -33,6 +37,14 @@ impl std::fmt::Debug for SqliteFlavour {
 }
 
 impl SqlFlavour for SqliteFlavour {
+    fn idempotent_ddl(&self) -> bool {
+        self.idempotent_ddl
+    }
+
+    fn set_idempotent_ddl(&mut self, idempotent: bool) {
+        self.idempotent_ddl = idempotent;
+    }
+
     fn acquire_lock(&mut self) -> BoxFuture<'_, ConnectorResult<()>> {
         self.raw_cmd("PRAGMA main.locking_mode=EXCLUSIVE")
     }
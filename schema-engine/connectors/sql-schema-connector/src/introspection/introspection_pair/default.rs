@@ -41,8 +41,20 @@ impl<'a> DefaultValuePair<'a> {
                     .sequences
                     .binary_search_by_key(&name, |s| &s.name)
                     .unwrap();
+                let sequence = &connector_data.sequences[sequence_idx];
 
-                Some(DefaultKind::Sequence(&connector_data.sequences[sequence_idx]))
+                // A sequence owned by this exact column is just this column's autoincrement, not
+                // a standalone sequence the user declared with `@default(sequence(...))`.
+                let table_id = match self.next.refine() {
+                    Either::Left(col) => Some(col.table().id),
+                    Either::Right(_) => None,
+                };
+
+                if table_id.is_some_and(|id| sequence.is_owned_by(id, self.next.name())) {
+                    Some(DefaultKind::Autoincrement)
+                } else {
+                    Some(DefaultKind::Sequence(sequence))
+                }
             }
             (_, sql::ColumnTypeFamily::Int | sql::ColumnTypeFamily::BigInt) if self.next.is_autoincrement() => {
                 Some(DefaultKind::Autoincrement)
@@ -54,6 +66,10 @@ impl<'a> DefaultValuePair<'a> {
                 Some(DefaultKind::DbGenerated(default_string.as_deref()))
             }
 
+            // PSL has no syntax for generated columns, so we surface them the same way we
+            // surface any other default we can't fully round-trip: as an opaque `dbgenerated()`.
+            (Some(sql::DefaultKind::Generated(expr, _)), _) => Some(DefaultKind::DbGenerated(Some(expr))),
+
             (Some(sql::DefaultKind::Now), sql::ColumnTypeFamily::DateTime) => Some(DefaultKind::Now),
 
             (Some(sql::DefaultKind::Value(PrismaValue::Null)), _) => Some(DefaultKind::Constant(&"null")),
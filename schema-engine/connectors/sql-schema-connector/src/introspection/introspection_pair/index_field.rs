@@ -82,6 +82,8 @@ impl<'a> IndexFieldPair<'a> {
             sql::postgres::SQLOperatorClassKind::JsonbOps => Some(IndexOps::Managed("JsonbOps")),
             sql::postgres::SQLOperatorClassKind::JsonbPathOps => Some(IndexOps::Managed("JsonbPathOps")),
             sql::postgres::SQLOperatorClassKind::ArrayOps => Some(IndexOps::Managed("ArrayOps")),
+            sql::postgres::SQLOperatorClassKind::GinTrgmOps => Some(IndexOps::Managed("GinTrgmOps")),
+            sql::postgres::SQLOperatorClassKind::GistTrgmOps => Some(IndexOps::Managed("GistTrgmOps")),
             sql::postgres::SQLOperatorClassKind::TextOps => Some(IndexOps::Managed("TextOps")),
             sql::postgres::SQLOperatorClassKind::BitMinMaxOps => Some(IndexOps::Managed("BitMinMaxOps")),
             sql::postgres::SQLOperatorClassKind::VarBitMinMaxOps => Some(IndexOps::Managed("VarBitMinMaxOps")),
@@ -65,7 +65,7 @@ impl<'a> ModelPair<'a> {
 
     /// The names of check constraints for this model.
     pub(crate) fn check_constraints(self) -> impl Iterator<Item = &'a str> {
-        self.next.check_constraints()
+        self.next.check_constraints().map(|constraint| constraint.name.as_str())
     }
 
     /// Whether the model has exclusion constraints.
@@ -60,7 +60,7 @@ pub(super) fn generate_warnings(model: ModelPair<'_>, warnings: &mut Warnings) {
     for constraint in model.check_constraints() {
         warnings.check_constraints.push(generators::ModelAndConstraint {
             model: model.name().to_string(),
-            constraint: constraint.to_string(),
+            constraint: constraint.to_owned(),
         })
     }
 
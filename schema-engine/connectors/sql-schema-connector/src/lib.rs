@@ -8,6 +8,7 @@ mod error;
 mod flavour;
 mod introspection;
 mod migration_pair;
+mod schema_fingerprint;
 mod sql_destructive_change_checker;
 mod sql_migration;
 mod sql_migration_persistence;
@@ -27,6 +28,24 @@ use std::{future, sync::Arc};
 
 const MIGRATIONS_TABLE_NAME: &str = "_prisma_migrations";
 
+/// One table's contribution to a [`SqlSchemaConnector::render_diff_annotated`] result.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TableDiff {
+    /// The table's name. Taken from the previous side whenever the table existed before the
+    /// change (a dropped or altered table), and from the next side only for a created table (a
+    /// table rename shows up as its own name on each side, since it's really a drop-and-recreate
+    /// as far as this diff is concerned).
+    pub table_name: String,
+    /// The full `CREATE TABLE` for the table as it was before the change, or `None` if the table
+    /// didn't exist yet (it's being created).
+    pub previous_ddl: Option<String>,
+    /// The full `CREATE TABLE` for the table as it is after the change, or `None` if the table no
+    /// longer exists (it's being dropped).
+    pub next_ddl: Option<String>,
+    /// The statements the plain diff would render for this table's change.
+    pub delta: Vec<String>,
+}
+
 /// The top-level SQL migration connector.
 pub struct SqlSchemaConnector {
     flavour: Box<dyn SqlFlavour + Send + Sync + 'static>,
@@ -97,6 +116,11 @@ impl SqlSchemaConnector {
         self.flavour.describe_schema(namespaces)
     }
 
+    /// Made public for tests.
+    pub fn schema_fingerprint(&mut self, namespaces: Option<Namespaces>) -> BoxFuture<'_, ConnectorResult<String>> {
+        self.flavour.schema_fingerprint(namespaces)
+    }
+
     /// For tests
     pub async fn query_raw(
         &mut self,
@@ -124,6 +148,82 @@ impl SqlSchemaConnector {
         self.flavour.set_params(params)
     }
 
+    /// Render idempotent DDL (`CREATE TABLE IF NOT EXISTS`, `DROP TABLE IF EXISTS`, ...) wherever
+    /// the connected database supports it, falling back to bare DDL where it doesn't. Off by
+    /// default.
+    pub fn set_idempotent_ddl(&mut self, idempotent: bool) {
+        self.flavour.set_idempotent_ddl(idempotent)
+    }
+
+    /// Renders a best-effort "down" migration: the SQL to go from `to` back to `from`. Computed by
+    /// running the same differ as [`Self::diff`] with the arguments swapped, so it's exactly as
+    /// accurate (and limited) as the forward migration it inverts. A forward step that isn't
+    /// reversible without losing data (e.g. a dropped column, whose values can't be brought back)
+    /// still renders its structural inverse — the column comes back empty — and the fact that it's
+    /// lossy is surfaced the same way it would be for a forward migration: as a `Warnings:` comment
+    /// block at the top of the script, via [`Self::render_script`].
+    pub fn render_down(&self, from: DatabaseSchema, to: DatabaseSchema) -> ConnectorResult<String> {
+        let down_migration = self.diff(to, from);
+        let diagnostics = self.pure_check(&down_migration);
+        self.render_script(&down_migration, &diagnostics)
+    }
+
+    /// Diffs `from` against `to` like [`Self::diff`] does, but for every table whose definition
+    /// changed, additionally renders the table's full `CREATE TABLE` DDL on both sides of the
+    /// change, so a reviewer can see the complete before/after instead of only the delta.
+    ///
+    /// Scoped to tables for now: enums, views and extensions still only show up as part of the
+    /// plain diff, since they don't have an equally central "one full `CREATE`" rendering to
+    /// contrast.
+    pub fn render_diff_annotated(&self, from: DatabaseSchema, to: DatabaseSchema) -> ConnectorResult<Vec<TableDiff>> {
+        let migration = self.diff(from, to);
+        let migration: &SqlMigration = migration.downcast_ref();
+        let schemas = MigrationPair::new(&migration.before, &migration.after);
+        let flavour = self.flavour();
+
+        let mut diffs = Vec::new();
+
+        for step in &migration.steps {
+            let table_ids: Vec<MigrationPair<Option<sql::TableId>>> = match step {
+                SqlMigrationStep::CreateTable { table_id } => vec![MigrationPair::new(None, Some(*table_id))],
+                SqlMigrationStep::DropTable { table_id } => vec![MigrationPair::new(Some(*table_id), None)],
+                SqlMigrationStep::AlterTable(alter_table) => {
+                    vec![alter_table.table_ids.map(Some)]
+                }
+                SqlMigrationStep::RedefineTables(redefines) => redefines
+                    .iter()
+                    .map(|redefine| redefine.table_ids.map(Some))
+                    .collect(),
+                _ => Vec::new(),
+            };
+
+            if table_ids.is_empty() {
+                continue;
+            }
+
+            let delta = apply_migration::render_raw_sql(step, flavour, schemas);
+
+            for ids in table_ids {
+                let previous_ddl = ids.previous.map(|id| flavour.render_create_table(schemas.previous.walk(id)));
+                let next_ddl = ids.next.map(|id| flavour.render_create_table(schemas.next.walk(id)));
+                let table_name = ids
+                    .previous
+                    .map(|id| schemas.previous.walk(id).name().to_owned())
+                    .or_else(|| ids.next.map(|id| schemas.next.walk(id).name().to_owned()))
+                    .expect("a table diff always has a previous or a next table id");
+
+                diffs.push(TableDiff {
+                    table_name,
+                    previous_ddl,
+                    next_ddl,
+                    delta: delta.clone(),
+                });
+            }
+        }
+
+        Ok(diffs)
+    }
+
     async fn db_schema_from_diff_target(
         &mut self,
         target: DiffTarget<'_>,
@@ -134,6 +234,7 @@ impl SqlSchemaConnector {
             DiffTarget::Datamodel(schema) => {
                 let schema = psl::parse_schema(schema).map_err(ConnectorError::new_schema_parser_error)?;
                 self.flavour.check_schema_features(&schema)?;
+                sql_schema_calculator::validate_native_types(&schema, self.flavour.as_ref())?;
                 Ok(sql_schema_calculator::calculate_sql_schema(
                     &schema,
                     self.flavour.as_ref(),
@@ -419,3 +520,97 @@ async fn best_effort_reset_impl(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WITHOUT_NAME: &str = r#"
+        datasource db {
+          provider = "postgresql"
+          url      = "postgres://"
+        }
+
+        model TestModel {
+          id Int @id
+        }
+    "#;
+
+    const WITH_NAME: &str = r#"
+        datasource db {
+          provider = "postgresql"
+          url      = "postgres://"
+        }
+
+        model TestModel {
+          id   Int @id
+          name String
+        }
+    "#;
+
+    fn erased_schema(connector: &SqlSchemaConnector, datamodel: &str) -> DatabaseSchema {
+        let schema = psl::parse_schema(datamodel).unwrap();
+        DatabaseSchema::from(sql_schema_calculator::calculate_sql_schema(&schema, connector.flavour.as_ref()))
+    }
+
+    #[test]
+    fn render_down_of_an_add_column_is_a_drop_column() {
+        let connector = SqlSchemaConnector::new_postgres();
+        let from = erased_schema(&connector, WITHOUT_NAME);
+        let to = erased_schema(&connector, WITH_NAME);
+
+        // The forward migration (`from` -> `to`) adds `name`; its down migration should drop it.
+        let down = connector.render_down(from, to).unwrap();
+
+        assert!(down.to_uppercase().contains("DROP COLUMN"), "{down}");
+    }
+
+    #[test]
+    fn render_down_flags_the_dropped_column_as_lossy() {
+        let connector = SqlSchemaConnector::new_postgres();
+        let from = erased_schema(&connector, WITHOUT_NAME);
+        let to = erased_schema(&connector, WITH_NAME);
+
+        // Reversing an added column means dropping it again, which is a destructive change: the
+        // resulting script should carry the same `Warnings:` comment block a forward drop would.
+        let down = connector.render_down(from, to).unwrap();
+
+        assert!(down.contains("Warnings"), "{down}");
+        assert!(down.contains("name"), "{down}");
+    }
+
+    #[test]
+    fn render_diff_annotated_includes_both_table_ddls_and_the_delta_for_a_changed_table() {
+        let connector = SqlSchemaConnector::new_postgres();
+        let from = erased_schema(&connector, WITHOUT_NAME);
+        let to = erased_schema(&connector, WITH_NAME);
+
+        let diffs = connector.render_diff_annotated(from, to).unwrap();
+
+        assert_eq!(diffs.len(), 1);
+        let diff = &diffs[0];
+
+        assert_eq!(diff.table_name, "TestModel");
+
+        let previous_ddl = diff.previous_ddl.as_ref().unwrap();
+        assert!(previous_ddl.to_uppercase().contains("CREATE TABLE"), "{previous_ddl}");
+        assert!(!previous_ddl.contains("name"), "{previous_ddl}");
+
+        let next_ddl = diff.next_ddl.as_ref().unwrap();
+        assert!(next_ddl.to_uppercase().contains("CREATE TABLE"), "{next_ddl}");
+        assert!(next_ddl.contains("name"), "{next_ddl}");
+
+        assert!(diff.delta.iter().any(|stmt| stmt.to_uppercase().contains("ADD COLUMN")));
+    }
+
+    #[test]
+    fn render_diff_annotated_is_empty_when_nothing_changed() {
+        let connector = SqlSchemaConnector::new_postgres();
+        let from = erased_schema(&connector, WITHOUT_NAME);
+        let to = erased_schema(&connector, WITHOUT_NAME);
+
+        let diffs = connector.render_diff_annotated(from, to).unwrap();
+
+        assert!(diffs.is_empty(), "{diffs:?}");
+    }
+}
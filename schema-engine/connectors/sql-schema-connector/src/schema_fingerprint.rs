@@ -0,0 +1,208 @@
+//! Computes a cheap, stable fingerprint of a described database schema, for drift detection: a
+//! hash of a normalized textual representation of its tables, columns, indexes and foreign keys.
+//! Comparing two fingerprints taken over time tells you whether the schema shape changed, without
+//! doing a full diff.
+//!
+//! The representation deliberately leaves out [`sql_schema_describer::ColumnType::full_data_type`],
+//! the raw type spelling the database reports (e.g. Postgres' `int4` vs `integer`), and hashes the
+//! already-normalized [`sql_schema_describer::ColumnTypeFamily`]/[`sql_schema_describer::ColumnArity`]
+//! instead, so cosmetic spelling differences for the same underlying type don't change the
+//! fingerprint. Check constraint expressions go through the same treatment, via
+//! [`sql_schema_describer::normalize_check_constraint_expression`], so a database echoing the same
+//! `CHECK` clause back with different whitespace or parenthesization doesn't look like a change —
+//! this is also how an adopted legacy schema with pre-existing check constraints avoids being
+//! flagged as drifted on introspection alone. Fingerprints aren't meant to be compared across
+//! flavours: the same logical schema on two different databases need not fingerprint the same.
+
+use sha2::{Digest, Sha256};
+use sql_schema_describer::SqlSchema;
+use std::fmt::Write as _;
+
+/// Computes the fingerprint of `schema`, as a hex-encoded SHA-256 digest of its normalized
+/// representation.
+pub(crate) fn compute(schema: &SqlSchema) -> String {
+    let mut repr = String::new();
+    render(schema, &mut repr);
+
+    let mut hasher = Sha256::new();
+    hasher.update(repr.as_bytes());
+
+    let digest: [u8; 32] = hasher.finalize().into();
+    let mut hex = String::with_capacity(digest.len() * 2);
+
+    for byte in digest {
+        write!(hex, "{byte:02x}").unwrap();
+    }
+
+    hex
+}
+
+fn render(schema: &SqlSchema, out: &mut String) {
+    let mut tables: Vec<_> = schema.table_walkers().collect();
+    tables.sort_by_key(|t| (t.namespace().unwrap_or_default(), t.name()));
+
+    for table in tables {
+        writeln!(out, "table {:?}.{:?}", table.namespace().unwrap_or_default(), table.name()).unwrap();
+
+        let mut columns: Vec<_> = table.columns().collect();
+        columns.sort_by_key(|c| c.name());
+
+        for column in columns {
+            writeln!(
+                out,
+                "  column {:?} family={:?} arity={:?} autoincrement={} default={:?}",
+                column.name(),
+                column.column_type_family(),
+                column.arity(),
+                column.is_autoincrement(),
+                column.default().map(|d| d.kind()),
+            )
+            .unwrap();
+        }
+
+        let mut indexes: Vec<_> = table.indexes().collect();
+        indexes.sort_by_key(|i| i.name().to_owned());
+
+        for index in indexes {
+            writeln!(
+                out,
+                "  index {:?} type={:?} columns={:?}",
+                index.name(),
+                index.index_type(),
+                index.column_names().collect::<Vec<_>>(),
+            )
+            .unwrap();
+        }
+
+        let mut check_constraints: Vec<_> = table
+            .check_constraints()
+            .map(|c| (c.name.as_str(), sql_schema_describer::normalize_check_constraint_expression(&c.definition)))
+            .collect();
+        check_constraints.sort_unstable();
+
+        for (name, expr) in check_constraints {
+            writeln!(out, "  check {name:?} expr={expr:?}").unwrap();
+        }
+
+        let mut foreign_keys: Vec<_> = table.foreign_keys().collect();
+        foreign_keys.sort_by_key(|fk| {
+            (
+                fk.constrained_columns().map(|c| c.name().to_owned()).collect::<Vec<_>>(),
+                fk.referenced_table_name().to_owned(),
+            )
+        });
+
+        for fk in foreign_keys {
+            writeln!(
+                out,
+                "  fk columns={:?} references={:?}.{:?} on_delete={:?} on_update={:?}",
+                fk.constrained_columns().map(|c| c.name()).collect::<Vec<_>>(),
+                fk.referenced_table().namespace().unwrap_or_default(),
+                fk.referenced_table_name(),
+                fk.on_delete_action(),
+                fk.on_update_action(),
+            )
+            .unwrap();
+        }
+    }
+
+    let mut enums: Vec<_> = schema.enum_walkers().collect();
+    enums.sort_by_key(|e| (e.namespace().unwrap_or_default(), e.name()));
+
+    for r#enum in enums {
+        writeln!(
+            out,
+            "enum {:?}.{:?} values={:?}",
+            r#enum.namespace().unwrap_or_default(),
+            r#enum.name(),
+            r#enum.values().collect::<Vec<_>>(),
+        )
+        .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sql_schema_describer::{ColumnArity, ColumnType, ColumnTypeFamily, SqlSchema};
+
+    fn schema_with_columns(columns: &[(&str, &str)]) -> SqlSchema {
+        let mut schema = SqlSchema::default();
+        let namespace_id = schema.push_namespace("public".to_owned());
+        let table_id = schema.push_table("User".to_owned(), namespace_id, None);
+
+        for (name, full_data_type) in columns {
+            let tpe = ColumnType::with_full_data_type(
+                ColumnTypeFamily::Int,
+                ColumnArity::Required,
+                (*full_data_type).to_owned(),
+            );
+
+            schema.push_table_column(
+                table_id,
+                sql_schema_describer::Column {
+                    name: (*name).to_owned(),
+                    tpe,
+                    auto_increment: false,
+                    description: None,
+                },
+            );
+        }
+
+        schema
+    }
+
+    #[test]
+    fn fingerprint_is_stable_across_repeated_calls() {
+        let schema = schema_with_columns(&[("id", "int4")]);
+
+        assert_eq!(compute(&schema), compute(&schema));
+    }
+
+    #[test]
+    fn fingerprint_ignores_raw_type_spelling() {
+        let int4 = schema_with_columns(&[("id", "int4")]);
+        let integer = schema_with_columns(&[("id", "integer")]);
+
+        assert_eq!(compute(&int4), compute(&integer));
+    }
+
+    #[test]
+    fn fingerprint_changes_when_a_column_is_added() {
+        let before = schema_with_columns(&[("id", "int4")]);
+        let after = schema_with_columns(&[("id", "int4"), ("name", "text")]);
+
+        assert_ne!(compute(&before), compute(&after));
+    }
+
+    fn schema_with_check_constraint(definition: &str) -> SqlSchema {
+        let mut schema = schema_with_columns(&[("id", "int4")]);
+        let table_id = schema.table_walkers().next().unwrap().id;
+
+        schema.push_check_constraint(
+            table_id,
+            sql_schema_describer::CheckConstraint {
+                name: "User_id_check".to_owned(),
+                definition: definition.to_owned(),
+            },
+        );
+
+        schema
+    }
+
+    #[test]
+    fn fingerprint_ignores_check_constraint_expression_spelling() {
+        let tight = schema_with_check_constraint("(id > 0)");
+        let spaced = schema_with_check_constraint("  ( id  >   0 )  ");
+
+        assert_eq!(compute(&tight), compute(&spaced));
+    }
+
+    #[test]
+    fn fingerprint_changes_when_a_check_constraint_expression_changes() {
+        let before = schema_with_check_constraint("(id > 0)");
+        let after = schema_with_check_constraint("(id > 1)");
+
+        assert_ne!(compute(&before), compute(&after));
+    }
+}
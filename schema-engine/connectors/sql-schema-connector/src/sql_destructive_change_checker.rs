@@ -247,6 +247,15 @@ impl SqlSchemaConnector {
                     let table = schemas.previous.walk(*table_id);
                     self.check_table_drop(table.name(), table.namespace(), &mut plan, step_index);
                 }
+                SqlMigrationStep::CreateTableAsSelect(create_table_as_select) => {
+                    let table = schemas.next.walk(create_table_as_select.table_id);
+                    plan.push_warning(
+                        SqlMigrationWarningCheck::CreateTableAsSelect {
+                            table: table.name().to_owned(),
+                        },
+                        step_index,
+                    );
+                }
                 SqlMigrationStep::CreateIndex {
                     table_id: (Some(_), _),
                     index_id,
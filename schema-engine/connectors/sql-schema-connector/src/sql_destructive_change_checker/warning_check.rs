@@ -45,6 +45,9 @@ pub(super) enum SqlMigrationWarningCheck {
         enm: String,
         values: Vec<String>,
     },
+    CreateTableAsSelect {
+        table: String,
+    },
 }
 
 impl Check for SqlMigrationWarningCheck {
@@ -161,6 +164,7 @@ impl Check for SqlMigrationWarningCheck {
             SqlMigrationWarningCheck::UniqueConstraintAddition { table, columns } =>
                 Some(format!("A unique constraint covering the columns `[{columns}]` on the table `{table}` will be added. If there are existing duplicate values, this will fail.", table = table, columns = columns.join(","))),
             SqlMigrationWarningCheck::EnumValueRemoval { enm, values } =>  Some(format!("The values [{values}] on the enum `{enm}` will be removed. If these variants are still used in the database, this will fail.", enm = enm, values = values.join(","))),
+            SqlMigrationWarningCheck::CreateTableAsSelect { table } => Some(format!("The `{table}` table will be created and populated from the result of a query. This is a data-carrying migration step.")),
 
         }
     }
@@ -64,6 +64,8 @@ impl SqlMigration {
             let idx = idx as u32;
             match step {
                 SqlMigrationStep::AlterSequence(_, _) => (),
+                SqlMigrationStep::AlterColumnStorage(_, _) => (),
+                SqlMigrationStep::AlterColumnCompression(_, _) => (),
                 SqlMigrationStep::CreateSchema(_) => (), // todo
                 SqlMigrationStep::DropView(drop_view) => {
                     drift_items.insert((
@@ -122,6 +124,9 @@ impl SqlMigration {
                 SqlMigrationStep::CreateTable { .. } => {
                     drift_items.insert((DriftType::AddedTable, "", idx));
                 }
+                SqlMigrationStep::CreateTableAsSelect(_) => {
+                    drift_items.insert((DriftType::AddedTable, "", idx));
+                }
                 SqlMigrationStep::RedefineTables(redefines) => {
                     for redefine in redefines {
                         drift_items.insert((
@@ -228,6 +233,8 @@ impl SqlMigration {
 
             match &self.steps[*step_idx as usize] {
                 SqlMigrationStep::AlterSequence(_, _) => {}
+                SqlMigrationStep::AlterColumnStorage(_, _) => {}
+                SqlMigrationStep::AlterColumnCompression(_, _) => {}
                 SqlMigrationStep::DropView(_) => {}
                 SqlMigrationStep::DropUserDefinedType(_) => {}
                 SqlMigrationStep::CreateEnum(enum_id) => {
@@ -349,6 +356,11 @@ impl SqlMigration {
                     out.push_str(self.schemas().next.walk(*table_id).name());
                     out.push('\n');
                 }
+                SqlMigrationStep::CreateTableAsSelect(create_table_as_select) => {
+                    out.push_str("  - ");
+                    out.push_str(self.schemas().next.walk(create_table_as_select.table_id).name());
+                    out.push_str(" (populated from an existing query)\n");
+                }
                 SqlMigrationStep::RedefineTables(_) => {}
                 SqlMigrationStep::RenameForeignKey { foreign_key_id } => {
                     let fks = self.schemas().walk(*foreign_key_id);
@@ -453,6 +465,8 @@ pub(crate) enum SqlMigrationStep {
     CreateExtension(CreateExtension),
     AlterExtension(AlterExtension),
     AlterSequence(MigrationPair<u32>, SequenceChanges),
+    AlterColumnStorage(MigrationPair<TableColumnId>, postgres::ColumnStorage),
+    AlterColumnCompression(MigrationPair<TableColumnId>, postgres::ColumnCompression),
     DropView(DropView),
     DropUserDefinedType(DropUserDefinedType),
     CreateEnum(sql_schema_describer::EnumId),
@@ -481,6 +495,9 @@ pub(crate) enum SqlMigrationStep {
     CreateTable {
         table_id: TableId,
     },
+    // Data-carrying table creation: the table is populated from the result of a query instead of
+    // starting out empty. Order matters the same way as `CreateTable`.
+    CreateTableAsSelect(CreateTableAsSelect),
     RedefineTables(Vec<RedefineTable>),
     // Order matters: we must create indexes after ALTER TABLEs because the indexes can be
     // on fields that are dropped/created there.
@@ -509,6 +526,8 @@ impl SqlMigrationStep {
     pub(crate) fn description(&self) -> &'static str {
         match self {
             SqlMigrationStep::AddForeignKey { .. } => "AddForeignKey",
+            SqlMigrationStep::AlterColumnStorage(_, _) => "AlterColumnStorage",
+            SqlMigrationStep::AlterColumnCompression(_, _) => "AlterColumnCompression",
             SqlMigrationStep::AlterEnum(_) => "AlterEnum",
             SqlMigrationStep::AlterPrimaryKey(_) => "AlterPrimaryKey",
             SqlMigrationStep::AlterSequence(_, _) => "AlterSequence",
@@ -517,6 +536,7 @@ impl SqlMigrationStep {
             SqlMigrationStep::CreateIndex { .. } => "CreateIndex",
             SqlMigrationStep::CreateSchema { .. } => "CreateSchema",
             SqlMigrationStep::CreateTable { .. } => "CreateTable",
+            SqlMigrationStep::CreateTableAsSelect(_) => "CreateTableAsSelect",
             SqlMigrationStep::DropEnum(_) => "DropEnum",
             SqlMigrationStep::DropForeignKey { .. } => "DropForeignKey",
             SqlMigrationStep::DropIndex { .. } => "DropIndex",
@@ -636,6 +656,15 @@ impl AlterEnum {
     }
 }
 
+/// A `CREATE TABLE ... AS SELECT` (or `SELECT ... INTO` on MSSQL) step. `table_id` points at the
+/// already fully described table in the next schema; `select_query` is the query it is populated
+/// from, and is rendered as-is by the flavours.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct CreateTableAsSelect {
+    pub table_id: TableId,
+    pub select_query: String,
+}
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) struct RedefineTable {
     pub added_columns: Vec<TableColumnId>,
@@ -670,6 +699,7 @@ pub(crate) enum SequenceChange {
     Start = 1 << 2,
     Cache = 1 << 3,
     Increment = 1 << 4,
+    Cycle = 1 << 5,
 }
 
 fn render_primary_key_column_names(table: TableWalker<'_>, out: &mut String) {
@@ -25,7 +25,10 @@ use crate::{
 };
 use sql_schema_describer::{
     self as sql,
-    walkers::{EnumWalker, ForeignKeyWalker, IndexWalker, TableWalker, UserDefinedTypeWalker, ViewWalker},
+    postgres::{ColumnCompression, ColumnStorage},
+    walkers::{
+        EnumWalker, ForeignKeyWalker, IndexWalker, TableColumnWalker, TableWalker, UserDefinedTypeWalker, ViewWalker,
+    },
     SqlSchema,
 };
 
@@ -49,6 +52,25 @@ pub(crate) trait SqlRenderer {
         unreachable!("unreachable render_alter_sequence");
     }
 
+    /// Render an `ALTER TABLE ... ALTER COLUMN ... SET STORAGE` step. Postgres-only.
+    fn render_alter_column_storage(
+        &self,
+        _columns: MigrationPair<TableColumnWalker<'_>>,
+        _storage: ColumnStorage,
+    ) -> String {
+        unreachable!("unreachable render_alter_column_storage")
+    }
+
+    /// Render an `ALTER TABLE ... ALTER COLUMN ... SET COMPRESSION` step. Postgres-only, and only
+    /// ever called on a connection where `PostgresFlavour::can_set_column_compression()` is true.
+    fn render_alter_column_compression(
+        &self,
+        _columns: MigrationPair<TableColumnWalker<'_>>,
+        _compression: ColumnCompression,
+    ) -> String {
+        unreachable!("unreachable render_alter_column_compression")
+    }
+
     fn render_rename_index(&self, _indexes: MigrationPair<IndexWalker<'_>>) -> Vec<String> {
         unreachable!("unreachable render_alter_index")
     }
@@ -70,6 +92,17 @@ pub(crate) trait SqlRenderer {
     /// Render a table creation with the provided table name.
     fn render_create_table_as(&self, table: TableWalker<'_>, table_name: QuotedWithPrefix<&str>) -> String;
 
+    /// Render a data-carrying table creation step: `table` is populated from the result of
+    /// `select_query` (`CREATE TABLE ... AS SELECT` on Postgres/MySQL/SQLite, `SELECT ... INTO` on
+    /// MSSQL) instead of starting out empty.
+    fn render_create_table_as_select(&self, table: TableWalker<'_>, select_query: &str) -> String;
+
+    /// Render the `GRANT`s (`@@grant` in the datamodel) declared on a freshly created table.
+    /// Connectors without a grant system (SQLite) leave this as a no-op.
+    fn render_table_grants(&self, _table: TableWalker<'_>) -> Vec<String> {
+        Vec::new()
+    }
+
     fn render_drop_and_recreate_index(&self, _indexes: MigrationPair<IndexWalker<'_>>) -> Vec<String> {
         unreachable!("unreachable render_drop_and_recreate_index")
     }
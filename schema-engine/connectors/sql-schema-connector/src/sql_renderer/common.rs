@@ -1,21 +1,57 @@
+use crate::flavour::IdentifierCasing;
 use sql_schema_describer::{walkers::TableColumnWalker, *};
 use std::fmt::{Display, Write as _};
 
 pub(super) const SQL_INDENTATION: &str = "    ";
 
+/// Render a `CREATE TABLE ... AS SELECT` statement, for the flavours that support the syntax
+/// directly (Postgres, MySQL, SQLite). MSSQL has no such syntax and renders `SELECT ... INTO`
+/// instead; see `mssql_renderer::render_create_table_as_select`.
+pub(super) fn render_create_table_as_select_stmt(table_name: &dyn Display, select_query: &str) -> String {
+    format!("CREATE TABLE {table_name} AS {select_query}")
+}
+
+/// Insert `IF NOT EXISTS` right after the first occurrence of `keyword` in `sql`, when
+/// `idempotent` is true, otherwise return `sql` unchanged. `keyword` must be the literal token
+/// (e.g. `"CREATE TABLE "` or `"INDEX "`) that the clause goes after.
+pub(super) fn make_create_idempotent(sql: String, keyword: &str, idempotent: bool) -> String {
+    if idempotent {
+        sql.replacen(keyword, &format!("{keyword}IF NOT EXISTS "), 1)
+    } else {
+        sql
+    }
+}
+
+/// Insert `IF EXISTS` right after the first occurrence of `keyword` in `sql`, when `idempotent` is
+/// true, otherwise return `sql` unchanged. See [`make_create_idempotent`].
+pub(super) fn make_drop_idempotent(sql: String, keyword: &str, idempotent: bool) -> String {
+    if idempotent {
+        sql.replacen(keyword, &format!("{keyword}IF EXISTS "), 1)
+    } else {
+        sql
+    }
+}
+
 /// A quoted identifier with an optional schema prefix.
 #[derive(Clone, Copy)]
 pub(crate) struct QuotedWithPrefix<T>(pub(crate) Option<Quoted<T>>, pub(crate) Quoted<T>);
 
 impl QuotedWithPrefix<&str> {
-    pub(crate) fn pg_new<'a>(namespace: Option<&'a str>, name: &'a str) -> QuotedWithPrefix<&'a str> {
-        QuotedWithPrefix(namespace.map(Quoted::postgres_ident), Quoted::postgres_ident(name))
+    pub(crate) fn pg_new<'a>(
+        namespace: Option<&'a str>,
+        name: &'a str,
+        casing: IdentifierCasing,
+    ) -> QuotedWithPrefix<&'a str> {
+        QuotedWithPrefix(
+            namespace.map(|ns| Quoted::postgres_ident_with_casing(ns, casing)),
+            Quoted::postgres_ident_with_casing(name, casing),
+        )
     }
 
-    pub(crate) fn pg_from_table_walker(table: TableWalker<'_>) -> QuotedWithPrefix<&str> {
+    pub(crate) fn pg_from_table_walker(table: TableWalker<'_>, casing: IdentifierCasing) -> QuotedWithPrefix<&str> {
         QuotedWithPrefix(
-            table.namespace().map(Quoted::postgres_ident),
-            Quoted::postgres_ident(table.name()),
+            table.namespace().map(|ns| Quoted::postgres_ident_with_casing(ns, casing)),
+            Quoted::postgres_ident_with_casing(table.name(), casing),
         )
     }
 }
@@ -39,6 +75,10 @@ pub(crate) enum Quoted<T> {
     Single(T),
     Backticks(T),
     SquareBrackets(T),
+    /// Rendered without surrounding quotes at all. Only ever produced for identifiers that are
+    /// already valid, unquoted-safe lowercase Postgres identifiers, so this can never change what
+    /// object the rendered SQL refers to (see [`IdentifierCasing`]).
+    Bare(T),
 }
 
 impl<T> Quoted<T> {
@@ -75,6 +115,36 @@ impl<T> Quoted<T> {
     }
 }
 
+impl<T> Quoted<T>
+where
+    T: AsRef<str>,
+{
+    /// Quotes a Postgres identifier according to the connector's [`IdentifierCasing`]: under
+    /// `AlwaysQuote`, always double-quotes, exactly like [`Quoted::postgres_ident`]. Under
+    /// `FoldLowercaseUnquoted`, identifiers that are already valid lowercase, unquoted-safe
+    /// Postgres identifiers are rendered bare (to match hand-written schemas that rely on
+    /// Postgres's own folding); anything else still falls back to quoting, so switching strategy
+    /// never changes which object a rendered identifier refers to.
+    pub(crate) fn postgres_ident_with_casing(name: T, casing: IdentifierCasing) -> Quoted<T> {
+        match casing {
+            IdentifierCasing::AlwaysQuote => Quoted::Double(name),
+            IdentifierCasing::FoldLowercaseUnquoted if is_unquoted_safe_postgres_ident(name.as_ref()) => {
+                Quoted::Bare(name)
+            }
+            IdentifierCasing::FoldLowercaseUnquoted => Quoted::Double(name),
+        }
+    }
+}
+
+/// True if `name` is a valid Postgres identifier that Postgres would fold to itself when left
+/// unquoted, i.e. it is already all lowercase and doesn't need quoting for any other reason.
+fn is_unquoted_safe_postgres_ident(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    matches!(chars.next(), Some(c) if c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
 impl<T> Display for Quoted<T>
 where
     T: Display,
@@ -85,6 +155,7 @@ where
             Quoted::Single(inner) => write!(f, "'{inner}'"),
             Quoted::Backticks(inner) => write!(f, "`{inner}`"),
             Quoted::SquareBrackets(inner) => write!(f, "[{inner}]"),
+            Quoted::Bare(inner) => write!(f, "{inner}"),
         }
     }
 }
@@ -190,3 +261,56 @@ pub(super) fn render_step(f: &mut dyn FnMut(&mut StepRenderer)) -> Vec<String> {
     f(&mut renderer);
     renderer.stmts
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_create_table_as_select_stmt_postgres() {
+        let table_name = QuotedWithPrefix::pg_new(Some("public"), "new_table", IdentifierCasing::AlwaysQuote);
+        let stmt = render_create_table_as_select_stmt(&table_name, "SELECT * FROM \"public\".\"old_table\"");
+
+        assert_eq!(
+            stmt,
+            "CREATE TABLE \"public\".\"new_table\" AS SELECT * FROM \"public\".\"old_table\""
+        );
+    }
+
+    #[test]
+    fn render_create_table_as_select_stmt_mysql() {
+        let table_name = Quoted::mysql_ident("new_table");
+        let stmt = render_create_table_as_select_stmt(&table_name, "SELECT * FROM `old_table`");
+
+        assert_eq!(stmt, "CREATE TABLE `new_table` AS SELECT * FROM `old_table`");
+    }
+
+    #[test]
+    fn render_create_table_as_select_stmt_sqlite() {
+        let table_name = Quoted::sqlite_ident("new_table");
+        let stmt = render_create_table_as_select_stmt(&table_name, "SELECT * FROM \"old_table\"");
+
+        assert_eq!(stmt, "CREATE TABLE \"new_table\" AS SELECT * FROM \"old_table\"");
+    }
+
+    #[test]
+    fn make_create_idempotent_inserts_if_not_exists_when_idempotent() {
+        let sql = make_create_idempotent("CREATE TABLE \"a\" (\"id\" INTEGER)".to_owned(), "CREATE TABLE ", true);
+
+        assert_eq!(sql, "CREATE TABLE IF NOT EXISTS \"a\" (\"id\" INTEGER)");
+    }
+
+    #[test]
+    fn make_create_idempotent_leaves_sql_untouched_when_not_idempotent() {
+        let sql = make_create_idempotent("CREATE TABLE \"a\" (\"id\" INTEGER)".to_owned(), "CREATE TABLE ", false);
+
+        assert_eq!(sql, "CREATE TABLE \"a\" (\"id\" INTEGER)");
+    }
+
+    #[test]
+    fn make_drop_idempotent_inserts_if_exists_when_idempotent() {
+        let sql = make_drop_idempotent("DROP TABLE \"a\"".to_owned(), "TABLE ", true);
+
+        assert_eq!(sql, "DROP TABLE IF EXISTS \"a\"");
+    }
+}
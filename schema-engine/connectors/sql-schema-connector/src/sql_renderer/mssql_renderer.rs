@@ -29,6 +29,15 @@ impl MssqlFlavour {
     fn render_column(&self, column: sql::TableColumnWalker<'_>) -> String {
         let column_name = Quoted::mssql_ident(column.name());
 
+        if let Some(sql::DefaultKind::Generated(expr, strategy)) = column.default().map(|d| d.kind()) {
+            let persisted = match strategy {
+                sql::GeneratedColumnStrategy::Stored => " PERSISTED",
+                sql::GeneratedColumnStrategy::Virtual => "",
+            };
+
+            return format!("{column_name} AS ({expr}){persisted}");
+        }
+
         let r#type = render_column_type(column);
         let nullability = render_nullability(column);
 
@@ -136,7 +145,7 @@ impl SqlRenderer for MssqlFlavour {
 
         let columns = columns.join(", ");
 
-        match index.index_type() {
+        let create_index = match index.index_type() {
             sql::IndexType::Unique => {
                 let constraint_name = Quoted::mssql_ident(index.name());
 
@@ -146,11 +155,47 @@ impl SqlRenderer for MssqlFlavour {
                 format!("CREATE {clustering}INDEX {index_name} ON {table_reference}({columns})",)
             }
             sql::IndexType::Fulltext | sql::IndexType::PrimaryKey => unreachable!(),
+        };
+
+        if self.idempotent_ddl() {
+            wrap_if_index_not_exists(&table_reference.to_string(), index.name(), &create_index)
+        } else {
+            create_index
         }
     }
 
     fn render_create_table(&self, table: sql::TableWalker<'_>) -> String {
-        self.render_create_table_as(table, self.table_name(table))
+        let create_table = self.render_create_table_as(table, self.table_name(table));
+
+        if self.idempotent_ddl() {
+            wrap_if_table_not_exists(&self.table_name(table).to_string(), &create_table)
+        } else {
+            create_table
+        }
+    }
+
+    fn render_create_table_as_select(&self, table: sql::TableWalker<'_>, select_query: &str) -> String {
+        let table_name = self.table_name(table);
+
+        render_select_into_stmt(&table_name, select_query)
+    }
+
+    fn render_table_grants(&self, table: sql::TableWalker<'_>) -> Vec<String> {
+        let table_name = self.table_name(table);
+
+        table
+            .grants()
+            .map(|grant| {
+                let privileges = grant
+                    .privileges
+                    .iter()
+                    .map(|p| p.to_uppercase())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("GRANT {privileges} ON {table_name} TO {}", Quoted::mssql_ident(&grant.role))
+            })
+            .collect()
     }
 
     fn render_create_table_as(&self, table: sql::TableWalker<'_>, table_name: QuotedWithPrefix<&str>) -> String {
@@ -242,16 +287,17 @@ impl SqlRenderer for MssqlFlavour {
 
     fn render_drop_index(&self, index: sql::IndexWalker<'_>) -> String {
         let ext: &MssqlSchemaExt = index.schema.downcast_connector_data();
+        let if_exists = if self.idempotent_ddl() { "IF EXISTS " } else { "" };
 
         if ext.index_is_a_constraint(index.id) {
             format!(
-                "ALTER TABLE {} DROP CONSTRAINT {}",
+                "ALTER TABLE {} DROP CONSTRAINT {if_exists}{}",
                 self.table_name(index.table()),
                 Quoted::mssql_ident(index.name()),
             )
         } else {
             format!(
-                "DROP INDEX {} ON {}",
+                "DROP INDEX {if_exists}{} ON {}",
                 Quoted::mssql_ident(index.name()),
                 self.table_name(index.table())
             )
@@ -405,7 +451,12 @@ impl SqlRenderer for MssqlFlavour {
     }
 
     fn render_drop_table(&self, namespace: Option<&str>, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote_with_schema(namespace, table_name))]
+        let if_exists = if self.idempotent_ddl() { "IF EXISTS " } else { "" };
+
+        vec![format!(
+            "DROP TABLE {if_exists}{}",
+            self.quote_with_schema(namespace, table_name)
+        )]
     }
 
     fn render_drop_view(&self, view: sql::ViewWalker<'_>) -> String {
@@ -539,5 +590,89 @@ fn render_default(default: &sql::DefaultValue) -> Cow<'_, str> {
         sql::DefaultKind::Value(PrismaValue::Boolean(val)) => Cow::from(if *val { "1" } else { "0" }),
         sql::DefaultKind::Value(val) => val.to_string().into(),
         sql::DefaultKind::Sequence(_) | sql::DefaultKind::UniqueRowid => unreachable!(),
+        // Computed columns are rendered as `AS (expr) [PERSISTED]` in `render_column`, never as a `DEFAULT`.
+        sql::DefaultKind::Generated(_, _) => unreachable!(),
+    }
+}
+
+/// MSSQL has no `CREATE TABLE ... AS SELECT`; `SELECT ... INTO` is the equivalent, so we splice
+/// the target table into the caller's query right before its `FROM` clause.
+fn render_select_into_stmt(table_name: &dyn std::fmt::Display, select_query: &str) -> String {
+    let from_offset = select_query
+        .to_uppercase()
+        .find(" FROM ")
+        .expect("a CREATE TABLE AS SELECT query must contain a FROM clause");
+
+    format!(
+        "{} INTO {table_name} {}",
+        &select_query[..from_offset],
+        &select_query[from_offset..]
+    )
+}
+
+/// MSSQL has no `CREATE TABLE IF NOT EXISTS`; wrap the statement in an existence check instead.
+fn wrap_if_table_not_exists(table_name: &str, create_table: &str) -> String {
+    formatdoc!(
+        r#"
+        IF NOT EXISTS (SELECT * FROM sys.objects WHERE object_id = OBJECT_ID(N'{table_name}') AND type = N'U')
+        BEGIN
+        {create_table}
+        END"#
+    )
+}
+
+/// MSSQL has no `CREATE INDEX IF NOT EXISTS`; wrap the statement in an existence check instead.
+fn wrap_if_index_not_exists(table_name: &str, index_name: &str, create_index: &str) -> String {
+    formatdoc!(
+        r#"
+        IF NOT EXISTS (SELECT * FROM sys.indexes WHERE name = N'{index_name}' AND object_id = OBJECT_ID(N'{table_name}'))
+        BEGIN
+        {create_index}
+        END"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_select_into_stmt_splices_the_table_before_from() {
+        let stmt = render_select_into_stmt(&"[dbo].[new_table]", "SELECT [id], [name] FROM [dbo].[old_table]");
+
+        assert_eq!(
+            stmt,
+            "SELECT [id], [name] INTO [dbo].[new_table] FROM [dbo].[old_table]"
+        );
+    }
+
+    #[test]
+    fn wrap_if_table_not_exists_wraps_in_an_existence_check() {
+        let wrapped = wrap_if_table_not_exists("[dbo].[a]", "CREATE TABLE [dbo].[a] (\n    [id] INT NOT NULL\n)");
+
+        assert_eq!(
+            wrapped,
+            "IF NOT EXISTS (SELECT * FROM sys.objects WHERE object_id = OBJECT_ID(N'[dbo].[a]') AND type = N'U')\n\
+             BEGIN\n\
+             CREATE TABLE [dbo].[a] (\n    [id] INT NOT NULL\n)\n\
+             END"
+        );
+    }
+
+    #[test]
+    fn wrap_if_index_not_exists_wraps_in_an_existence_check() {
+        let wrapped = wrap_if_index_not_exists(
+            "[dbo].[a]",
+            "a_idx",
+            "CREATE INDEX [a_idx] ON [dbo].[a]([id])",
+        );
+
+        assert_eq!(
+            wrapped,
+            "IF NOT EXISTS (SELECT * FROM sys.indexes WHERE name = N'a_idx' AND object_id = OBJECT_ID(N'[dbo].[a]'))\n\
+             BEGIN\n\
+             CREATE INDEX [a_idx] ON [dbo].[a]([id])\n\
+             END"
+        );
     }
 }
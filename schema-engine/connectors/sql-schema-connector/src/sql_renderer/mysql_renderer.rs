@@ -193,6 +193,8 @@ impl SqlRenderer for MysqlFlavour {
         )
     }
 
+    // MySQL has no `CREATE INDEX IF NOT EXISTS`, so this always renders bare DDL regardless of
+    // `self.idempotent_ddl()`.
     fn render_create_index(&self, index: IndexWalker<'_>) -> String {
         ddl::CreateIndex {
             r#type: match index.index_type() {
@@ -296,6 +298,8 @@ impl SqlRenderer for MysqlFlavour {
         )
     }
 
+    // MySQL has no `DROP INDEX IF EXISTS`, so this always renders bare DDL regardless of
+    // `self.idempotent_ddl()`.
     fn render_drop_index(&self, index: IndexWalker<'_>) -> String {
         sql_ddl::mysql::DropIndex {
             table_name: index.table().name().into(),
@@ -305,11 +309,16 @@ impl SqlRenderer for MysqlFlavour {
     }
 
     fn render_drop_table(&self, _namespace: Option<&str>, table_name: &str) -> Vec<String> {
+        let idempotent = self.idempotent_ddl();
+
         render_step(&mut |step| {
             step.render_statement(&mut |stmt| {
-                stmt.push_display(&sql_ddl::mysql::DropTable {
+                let drop_table = sql_ddl::mysql::DropTable {
                     table_name: table_name.into(),
-                })
+                }
+                .to_string();
+
+                stmt.push_str(&make_drop_idempotent(drop_table, "TABLE ", idempotent))
             })
         })
     }
@@ -329,7 +338,33 @@ impl SqlRenderer for MysqlFlavour {
     }
 
     fn render_create_table(&self, table: TableWalker<'_>) -> String {
-        self.render_create_table_as(table, QuotedWithPrefix(None, Quoted::mysql_ident(table.name())))
+        let create_table = self.render_create_table_as(table, QuotedWithPrefix(None, Quoted::mysql_ident(table.name())));
+
+        make_create_idempotent(create_table, "CREATE TABLE ", self.idempotent_ddl())
+    }
+
+    fn render_create_table_as_select(&self, table: TableWalker<'_>, select_query: &str) -> String {
+        let table_name = Quoted::mysql_ident(table.name());
+
+        render_create_table_as_select_stmt(&table_name, select_query)
+    }
+
+    fn render_table_grants(&self, table: TableWalker<'_>) -> Vec<String> {
+        let table_name = Quoted::mysql_ident(table.name());
+
+        table
+            .grants()
+            .map(|grant| {
+                let privileges = grant
+                    .privileges
+                    .iter()
+                    .map(|p| p.to_uppercase())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("GRANT {privileges} ON {table_name} TO {}", Quoted::mysql_string(&grant.role))
+            })
+            .collect()
     }
 
     fn render_drop_view(&self, view: ViewWalker<'_>) -> String {
@@ -516,5 +551,7 @@ fn render_default<'a>(column: TableColumnWalker<'a>, default: &'a DefaultValue)
         }
         DefaultKind::Value(val) => val.to_string().into(),
         DefaultKind::DbGenerated(None) | DefaultKind::Sequence(_) | DefaultKind::UniqueRowid => unreachable!(),
+        // Generated columns are a Postgres-only concept for now.
+        DefaultKind::Generated(_, _) => unreachable!(),
     }
 }
@@ -14,7 +14,7 @@ use sql_ddl::{
     IndexColumn, SortOrder,
 };
 use sql_schema_describer::{
-    postgres::{PostgresSchemaExt, SqlIndexAlgorithm},
+    postgres::{ColumnCompression, ColumnStorage, PostgresSchemaExt, SqlIndexAlgorithm},
     walkers::*,
     ColumnArity, ColumnTypeFamily, DefaultKind, DefaultValue, ForeignKeyAction, PrismaValue, SQLSortOrder, SqlSchema,
 };
@@ -22,9 +22,16 @@ use std::borrow::Cow;
 
 impl PostgresFlavour {
     fn render_column(&self, column: TableColumnWalker<'_>) -> String {
-        let column_name = Quoted::postgres_ident(column.name());
+        let column_name = self.quote(column.name());
         let tpe_str = render_column_type(column, self);
         let nullability_str = render_nullability(column);
+
+        if let Some(DefaultKind::Generated(expr, _)) = column.default().map(|d| d.kind()) {
+            return format!(
+                "{SQL_INDENTATION}{column_name} {tpe_str}{nullability_str} GENERATED ALWAYS AS ({expr}) STORED",
+            );
+        }
+
         let default_str = column
             .default()
             .map(|d| render_default(d.inner(), &render_column_type(column, self)))
@@ -54,7 +61,7 @@ impl SqlRenderer for PostgresFlavour {
         render_step(&mut |step| {
             step.render_statement(&mut |stmt| {
                 stmt.push_str("ALTER SEQUENCE ");
-                stmt.push_display(&Quoted::postgres_ident(&prev_seq.name));
+                stmt.push_display(&self.quote(&prev_seq.name));
 
                 if changes.0.contains(SequenceChange::MinValue) {
                     stmt.push_str(" MINVALUE ");
@@ -80,6 +87,10 @@ impl SqlRenderer for PostgresFlavour {
                     stmt.push_str(" CACHE ");
                     stmt.push_display(&next_seq.cache_size);
                 }
+
+                if changes.0.contains(SequenceChange::Cycle) {
+                    stmt.push_str(if next_seq.cycle { " CYCLE" } else { " NO CYCLE" });
+                }
             })
         })
     }
@@ -91,7 +102,7 @@ impl SqlRenderer for PostgresFlavour {
         render_step(&mut |step| {
             step.render_statement(&mut |stmt| {
                 stmt.push_str("CREATE EXTENSION IF NOT EXISTS ");
-                stmt.push_display(&Quoted::postgres_ident(&extension.name));
+                stmt.push_display(&self.quote(&extension.name));
 
                 if !extension.version.is_empty() || !extension.schema.is_empty() {
                     stmt.push_str(" WITH");
@@ -99,12 +110,12 @@ impl SqlRenderer for PostgresFlavour {
 
                 if !extension.schema.is_empty() {
                     stmt.push_str(" SCHEMA ");
-                    stmt.push_display(&Quoted::postgres_ident(&extension.schema));
+                    stmt.push_display(&self.quote(&extension.schema));
                 }
 
                 if !extension.version.is_empty() {
                     stmt.push_str(" VERSION ");
-                    stmt.push_display(&Quoted::postgres_ident(&extension.version));
+                    stmt.push_display(&self.quote(&extension.version));
                 }
             })
         })
@@ -117,7 +128,7 @@ impl SqlRenderer for PostgresFlavour {
         render_step(&mut |step| {
             step.render_statement(&mut |stmt| {
                 stmt.push_str("DROP EXTENSION ");
-                stmt.push_display(&Quoted::postgres_ident(&extension.name));
+                stmt.push_display(&self.quote(&extension.name));
             })
         })
     }
@@ -133,15 +144,15 @@ impl SqlRenderer for PostgresFlavour {
                 render_step(&mut |step| match change {
                     ExtensionChange::AlterVersion => step.render_statement(&mut |stmt| {
                         stmt.push_str("ALTER EXTENSION ");
-                        stmt.push_display(&Quoted::postgres_ident(&extensions.previous.name));
+                        stmt.push_display(&self.quote(&extensions.previous.name));
                         stmt.push_str(" UPDATE TO ");
-                        stmt.push_display(&Quoted::postgres_ident(&extensions.next.version));
+                        stmt.push_display(&self.quote(&extensions.next.version));
                     }),
                     ExtensionChange::AlterSchema => step.render_statement(&mut |stmt| {
                         stmt.push_str("ALTER EXTENSION ");
-                        stmt.push_display(&Quoted::postgres_ident(&extensions.previous.name));
+                        stmt.push_display(&self.quote(&extensions.previous.name));
                         stmt.push_str(" SET SCHEMA ");
-                        stmt.push_display(&Quoted::postgres_ident(&extensions.next.schema));
+                        stmt.push_display(&self.quote(&extensions.next.schema));
                     }),
                 })
             })
@@ -149,17 +160,20 @@ impl SqlRenderer for PostgresFlavour {
     }
 
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str> {
-        Quoted::postgres_ident(name)
+        Quoted::postgres_ident_with_casing(name, self.identifier_casing())
     }
 
     fn render_add_foreign_key(&self, foreign_key: ForeignKeyWalker<'_>) -> String {
         ddl::AlterTable {
-            table_name: &QuotedWithPrefix::pg_from_table_walker(foreign_key.table()),
+            table_name: &QuotedWithPrefix::pg_from_table_walker(foreign_key.table(), self.identifier_casing()),
             clauses: vec![ddl::AlterTableClause::AddForeignKey(ddl::ForeignKey {
                 constrained_columns: foreign_key.constrained_columns().map(|c| c.name().into()).collect(),
                 referenced_columns: foreign_key.referenced_columns().map(|c| c.name().into()).collect(),
                 constraint_name: foreign_key.constraint_name().map(From::from),
-                referenced_table: &QuotedWithPrefix::pg_from_table_walker(foreign_key.referenced_table()),
+                referenced_table: &QuotedWithPrefix::pg_from_table_walker(
+                    foreign_key.referenced_table(),
+                    self.identifier_casing(),
+                ),
                 on_delete: Some(match foreign_key.on_delete_action() {
                     ForeignKeyAction::Cascade => ddl::ForeignKeyAction::Cascade,
                     ForeignKeyAction::NoAction => ddl::ForeignKeyAction::NoAction,
@@ -187,7 +201,7 @@ impl SqlRenderer for PostgresFlavour {
         // - Only one value can be added in a single transaction until postgres 11.
         if self.is_cockroachdb() {
             render_step(&mut |step| {
-                render_cockroach_alter_enum(alter_enum, schemas, step);
+                render_cockroach_alter_enum(alter_enum, schemas, step, self);
             })
         } else {
             let flavour = self;
@@ -199,14 +213,18 @@ impl SqlRenderer for PostgresFlavour {
         render_step(&mut |step| {
             step.render_statement(&mut |stmt| {
                 stmt.push_str("ALTER TABLE ");
-                stmt.push_display(&QuotedWithPrefix::pg_from_table_walker(tables.previous));
+                stmt.push_display(&QuotedWithPrefix::pg_from_table_walker(
+                    tables.previous,
+                    self.identifier_casing(),
+                ));
                 stmt.push_str(" ALTER PRIMARY KEY USING COLUMNS (");
+                let casing = self.identifier_casing();
                 let column_names = tables
                     .next
                     .primary_key()
                     .unwrap() // safe because there is a primary key to alter
                     .column_names()
-                    .map(Quoted::postgres_ident);
+                    .map(move |name| Quoted::postgres_ident_with_casing(name, casing));
                 stmt.join(", ", column_names);
                 stmt.push_str(")");
             })
@@ -217,13 +235,14 @@ impl SqlRenderer for PostgresFlavour {
         render_step(&mut |step| {
             step.render_statement(&mut |stmt| {
                 let previous_table = indexes.previous.table();
-                let index_previous_name = QuotedWithPrefix::pg_new(previous_table.namespace(), indexes.previous.name());
+                let index_previous_name =
+                    QuotedWithPrefix::pg_new(previous_table.namespace(), indexes.previous.name(), self.identifier_casing());
                 stmt.push_str("ALTER INDEX ");
                 stmt.push_str(&index_previous_name.to_string());
                 stmt.push_str(" RENAME TO ");
                 // Postgres assumes we use the same schema as the previous name's, so we're not
                 // allowed to qualify this identifier.
-                stmt.push_display(&Quoted::postgres_ident(indexes.next.name()));
+                stmt.push_display(&self.quote(indexes.next.name()));
             })
         })
     }
@@ -239,12 +258,12 @@ impl SqlRenderer for PostgresFlavour {
             match change {
                 TableChange::DropPrimaryKey => lines.push(format!(
                     "DROP CONSTRAINT {}",
-                    Quoted::postgres_ident(tables.previous.primary_key().unwrap().name())
+                    self.quote(tables.previous.primary_key().unwrap().name())
                 )),
                 TableChange::RenamePrimaryKey => lines.push(format!(
                     "RENAME CONSTRAINT {} TO {}",
-                    Quoted::postgres_ident(tables.previous.primary_key().unwrap().name()),
-                    Quoted::postgres_ident(tables.next.primary_key().unwrap().name())
+                    self.quote(tables.previous.primary_key().unwrap().name()),
+                    self.quote(tables.next.primary_key().unwrap().name())
                 )),
                 TableChange::AddPrimaryKey => lines.push({
                     let named = match tables.next.primary_key().map(|pk| pk.name()) {
@@ -314,7 +333,7 @@ impl SqlRenderer for PostgresFlavour {
             for line in lines {
                 out.push(format!(
                     "ALTER TABLE {} {}",
-                    QuotedWithPrefix::pg_from_table_walker(tables.previous),
+                    QuotedWithPrefix::pg_from_table_walker(tables.previous, self.identifier_casing()),
                     line
                 ))
             }
@@ -323,7 +342,7 @@ impl SqlRenderer for PostgresFlavour {
         } else {
             let alter_table = format!(
                 "ALTER TABLE {} {}",
-                QuotedWithPrefix::pg_new(tables.previous.namespace(), tables.previous.name()),
+                QuotedWithPrefix::pg_new(tables.previous.namespace(), tables.previous.name(), self.identifier_casing()),
                 lines.join(",\n")
             );
 
@@ -339,7 +358,7 @@ impl SqlRenderer for PostgresFlavour {
         render_step(&mut |step| {
             step.render_statement(&mut |stmt| {
                 stmt.push_str("CREATE TYPE ");
-                stmt.push_display(&QuotedWithPrefix::pg_new(enm.namespace(), enm.name()));
+                stmt.push_display(&QuotedWithPrefix::pg_new(enm.namespace(), enm.name(), self.identifier_casing()));
                 stmt.push_str(" AS ENUM (");
                 let mut values = enm.values().peekable();
                 while let Some(value) = values.next() {
@@ -356,10 +375,10 @@ impl SqlRenderer for PostgresFlavour {
     fn render_create_index(&self, index: IndexWalker<'_>) -> String {
         let pg_ext: &PostgresSchemaExt = index.schema.downcast_connector_data();
 
-        ddl::CreateIndex {
+        let create_index = ddl::CreateIndex {
             index_name: index.name().into(),
             is_unique: index.is_unique(),
-            table_reference: &QuotedWithPrefix::pg_from_table_walker(index.table()),
+            table_reference: &QuotedWithPrefix::pg_from_table_walker(index.table(), self.identifier_casing()),
             using: Some(match pg_ext.index_algorithm(index.id) {
                 SqlIndexAlgorithm::BTree => ddl::IndexAlgorithm::BTree,
                 SqlIndexAlgorithm::Hash => ddl::IndexAlgorithm::Hash,
@@ -381,34 +400,97 @@ impl SqlRenderer for PostgresFlavour {
                 })
                 .collect(),
         }
-        .to_string()
+        .to_string();
+
+        // Indexes live in the same tablespace as their table unless told otherwise; we don't yet
+        // support pinning an index to a tablespace different from its table's.
+        let create_index = match pg_ext.table_tablespace(index.table().id) {
+            Some(tablespace) => format!("{create_index} TABLESPACE {}", Quoted::postgres_ident(tablespace)),
+            None => create_index,
+        };
+
+        make_create_idempotent(create_index, "INDEX ", self.idempotent_ddl())
     }
 
     fn render_create_namespace(&self, ns: sql_schema_describer::NamespaceWalker<'_>) -> String {
-        format!("CREATE SCHEMA IF NOT EXISTS {}", Quoted::postgres_ident(ns.name()))
+        format!("CREATE SCHEMA IF NOT EXISTS {}", self.quote(ns.name()))
     }
 
     fn render_create_table(&self, table: TableWalker<'_>) -> String {
-        self.render_create_table_as(table, QuotedWithPrefix::pg_from_table_walker(table))
+        let create_table = self.render_create_table_as(
+            table,
+            QuotedWithPrefix::pg_from_table_walker(table, self.identifier_casing()),
+        );
+
+        make_create_idempotent(create_table, "CREATE TABLE ", self.idempotent_ddl())
+    }
+
+    fn render_create_table_as_select(&self, table: TableWalker<'_>, select_query: &str) -> String {
+        let table_name = QuotedWithPrefix::pg_from_table_walker(table, self.identifier_casing());
+
+        render_create_table_as_select_stmt(&table_name, select_query)
+    }
+
+    fn render_table_grants(&self, table: TableWalker<'_>) -> Vec<String> {
+        let table_name = QuotedWithPrefix::pg_from_table_walker(table, self.identifier_casing());
+
+        table
+            .grants()
+            .map(|grant| {
+                let privileges = grant
+                    .privileges
+                    .iter()
+                    .map(|p| p.to_uppercase())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(
+                    "GRANT {privileges} ON {table_name} TO {}",
+                    Quoted::postgres_ident(&grant.role)
+                )
+            })
+            .collect()
     }
 
     fn render_create_table_as(&self, table: TableWalker<'_>, table_name: QuotedWithPrefix<&str>) -> String {
-        let columns: String = table.columns().map(|column| self.render_column(column)).join(",\n");
+        let pg_ext: &PostgresSchemaExt = table.schema.downcast_connector_data();
+        let parent = pg_ext.inherits(table.id).map(|id| table.walk(id));
+
+        let columns: String = table
+            .columns()
+            // Columns inherited from the parent are already part of the child's storage; only
+            // the columns declared locally need to be listed.
+            .filter(|column| !parent.is_some_and(|parent| parent.column(column.name()).is_some()))
+            .map(|column| self.render_column(column))
+            .join(",\n");
 
         let pk = if let Some(pk) = table.primary_key() {
-            let named_constraint = format!("CONSTRAINT {} ", Quoted::postgres_ident(pk.name()));
+            let named_constraint = format!("CONSTRAINT {} ", self.quote(pk.name()));
 
             format!(
                 ",\n\n{}{}PRIMARY KEY ({})",
                 SQL_INDENTATION,
                 named_constraint,
-                pk.columns().map(|col| Quoted::postgres_ident(col.name())).join(",")
+                pk.columns().map(|col| self.quote(col.name())).join(",")
             )
         } else {
             String::new()
         };
 
-        format!("CREATE TABLE {table_name} (\n{columns}{pk}\n)")
+        let inherits = match parent {
+            Some(parent) => format!(
+                " INHERITS ({})",
+                QuotedWithPrefix::pg_from_table_walker(parent, self.identifier_casing())
+            ),
+            None => String::new(),
+        };
+
+        let tablespace = match pg_ext.table_tablespace(table.id) {
+            Some(tablespace) => format!(" TABLESPACE {}", Quoted::postgres_ident(tablespace)),
+            None => String::new(),
+        };
+
+        format!("CREATE TABLE {table_name} (\n{columns}{pk}\n){inherits}{tablespace}")
     }
 
     fn render_drop_enum(&self, dropped_enum: EnumWalker<'_>) -> Vec<String> {
@@ -425,24 +507,31 @@ impl SqlRenderer for PostgresFlavour {
         format!(
             "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
             table = PostgresIdentifier::new(foreign_key.table().namespace(), foreign_key.table().name()),
-            constraint_name = Quoted::postgres_ident(foreign_key.constraint_name().unwrap()),
+            constraint_name = self.quote(foreign_key.constraint_name().unwrap()),
         )
     }
 
     fn render_drop_index(&self, index: IndexWalker<'_>) -> String {
-        ddl::DropIndex {
+        let drop_index = ddl::DropIndex {
             index_name: PostgresIdentifier::new(index.table().namespace(), index.name()),
         }
-        .to_string()
+        .to_string();
+
+        make_drop_idempotent(drop_index, "INDEX ", self.idempotent_ddl())
     }
 
     fn render_drop_table(&self, namespace: Option<&str>, table_name: &str) -> Vec<String> {
+        let idempotent = self.idempotent_ddl();
+
         render_step(&mut |step| {
             step.render_statement(&mut |stmt| {
-                stmt.push_display(&ddl::DropTable {
+                let drop_table = ddl::DropTable {
                     table_name: PostgresIdentifier::new(namespace, table_name),
                     cascade: false,
-                })
+                }
+                .to_string();
+
+                stmt.push_str(&make_drop_idempotent(drop_table, "TABLE ", idempotent))
             })
         })
     }
@@ -460,9 +549,10 @@ impl SqlRenderer for PostgresFlavour {
         for redefine_table in tables {
             let tables = schemas.walk(redefine_table.table_ids);
             let temporary_table_name = format!("_prisma_new_{}", &tables.next.name());
-            let quoted_temporary_table = QuotedWithPrefix(
-                tables.next.namespace().map(Quoted::postgres_ident),
-                Quoted::postgres_ident(&temporary_table_name),
+            let quoted_temporary_table = QuotedWithPrefix::pg_new(
+                tables.next.namespace(),
+                &temporary_table_name,
+                self.identifier_casing(),
             );
             result.push(self.render_create_table_as(tables.next, quoted_temporary_table));
 
@@ -470,7 +560,7 @@ impl SqlRenderer for PostgresFlavour {
                 .column_pairs
                 .iter()
                 .map(|(column_ids, _, _)| schemas.walk(*column_ids).next.name())
-                .map(|c| Quoted::postgres_ident(c).to_string())
+                .map(|c| self.quote(c).to_string())
                 .collect();
 
             let table = tables.previous.name();
@@ -511,8 +601,8 @@ impl SqlRenderer for PostgresFlavour {
     fn render_rename_table(&self, namespace: Option<&str>, name: &str, new_name: &str) -> String {
         format!(
             "ALTER TABLE {} RENAME TO {}",
-            QuotedWithPrefix::pg_new(namespace, name),
-            Quoted::postgres_ident(new_name)
+            QuotedWithPrefix::pg_new(namespace, name, self.identifier_casing()),
+            self.quote(new_name)
         )
     }
 
@@ -523,21 +613,61 @@ impl SqlRenderer for PostgresFlavour {
     fn render_rename_foreign_key(&self, fks: MigrationPair<ForeignKeyWalker<'_>>) -> String {
         format!(
             r#"ALTER TABLE {table} RENAME CONSTRAINT {previous} TO {next}"#,
-            table = QuotedWithPrefix::pg_from_table_walker(fks.previous.table()),
+            table = QuotedWithPrefix::pg_from_table_walker(fks.previous.table(), self.identifier_casing()),
             previous = self.quote(fks.previous.constraint_name().unwrap()),
             next = self.quote(fks.next.constraint_name().unwrap()),
         )
     }
+
+    fn render_alter_column_storage(
+        &self,
+        columns: MigrationPair<TableColumnWalker<'_>>,
+        storage: ColumnStorage,
+    ) -> String {
+        format!(
+            r#"ALTER TABLE {table} ALTER COLUMN {column} SET STORAGE {storage}"#,
+            table = QuotedWithPrefix::pg_from_table_walker(columns.next.table(), self.identifier_casing()),
+            column = self.quote(columns.next.name()),
+            storage = storage.as_sql(),
+        )
+    }
+
+    /// Only ever called when `can_set_column_compression()` gated this step at diff time, so we
+    /// don't need (and can't cleanly express through this trait's `String`-returning methods) a
+    /// separate rejection path here: on servers or flavours that don't support per-column
+    /// compression, no step is ever produced in the first place.
+    fn render_alter_column_compression(
+        &self,
+        columns: MigrationPair<TableColumnWalker<'_>>,
+        compression: ColumnCompression,
+    ) -> String {
+        format!(
+            r#"ALTER TABLE {table} ALTER COLUMN {column} SET COMPRESSION {compression}"#,
+            table = QuotedWithPrefix::pg_from_table_walker(columns.next.table(), self.identifier_casing()),
+            column = self.quote(columns.next.name()),
+            compression = compression.as_sql(),
+        )
+    }
 }
 
 fn render_column_type(col: TableColumnWalker<'_>, flavour: &PostgresFlavour) -> Cow<'static, str> {
     let t = col.column_type();
     if let Some(enm) = col.column_type_family_as_enum() {
-        let name = QuotedWithPrefix::pg_new(enm.namespace(), enm.name());
+        let name = QuotedWithPrefix::pg_new(enm.namespace(), enm.name(), flavour.identifier_casing());
         let arity = if t.arity.is_list() { "[]" } else { "" };
         return format!("{name}{arity}").into();
     }
 
+    let pg_ext: &PostgresSchemaExt = col.schema.downcast_connector_data();
+    if let Some(domain) = pg_ext.get_domain_for_column(col.id) {
+        let arity = if t.arity.is_list() { "[]" } else { "" };
+        return format!(
+            "{}{arity}",
+            Quoted::postgres_ident_with_casing(&domain.name, flavour.identifier_casing())
+        )
+        .into();
+    }
+
     if let ColumnTypeFamily::Unsupported(description) = &t.family {
         return format!("{}{}", description, if t.arity.is_list() { "[]" } else { "" }).into();
     }
@@ -687,8 +817,8 @@ fn render_alter_column(
     flavour: &PostgresFlavour,
 ) {
     let steps = expand_alter_column(columns, column_changes);
-    let table_name = QuotedWithPrefix::pg_from_table_walker(columns.previous.table());
-    let column_name = Quoted::postgres_ident(columns.previous.name());
+    let table_name = QuotedWithPrefix::pg_from_table_walker(columns.previous.table(), flavour.identifier_casing());
+    let column_name = Quoted::postgres_ident_with_casing(columns.previous.name(), flavour.identifier_casing());
 
     let alter_column_prefix = format!("ALTER COLUMN {column_name}");
 
@@ -702,7 +832,10 @@ fn render_alter_column(
                     let sequence_is_still_used = columns.next.schema.walk_table_columns().any(|column| matches!(column.default().map(|d| d.kind()), Some(DefaultKind::Sequence(other_sequence)) if other_sequence == sequence_name) && !column.is_same_column(columns.next));
 
                     if !sequence_is_still_used {
-                        after_statements.push(format!("DROP SEQUENCE {}", Quoted::postgres_ident(sequence_name)));
+                        after_statements.push(format!(
+                            "DROP SEQUENCE {}",
+                            Quoted::postgres_ident_with_casing(sequence_name, flavour.identifier_casing())
+                        ));
                     }
                 }
             }
@@ -712,7 +845,38 @@ fn render_alter_column(
                 render_default(&new_default, &render_column_type(columns.next, flavour))
             )),
             PostgresAlterColumn::DropNotNull => clauses.push(format!("{} DROP NOT NULL", &alter_column_prefix)),
-            PostgresAlterColumn::SetNotNull => clauses.push(format!("{} SET NOT NULL", &alter_column_prefix)),
+            PostgresAlterColumn::SetNotNull => {
+                // If the column is gaining a default value in the same migration, backfill any
+                // existing NULLs with it first so `SET NOT NULL` doesn't fail outright.
+                if let Some(default) = columns.next.default() {
+                    if let DefaultKind::Value(_) | DefaultKind::Now = default.kind() {
+                        let rendered_default = render_default(default.inner(), &render_column_type(columns.next, flavour));
+
+                        before_statements.push(format!(
+                            "UPDATE {table_name} SET {column_name} = {rendered_default} WHERE {column_name} IS NULL"
+                        ));
+                    }
+                }
+
+                if flavour.can_validate_not_null_with_check_constraint() {
+                    // `ADD CONSTRAINT ... NOT VALID` is instant. `VALIDATE CONSTRAINT` does the
+                    // scan, but only takes a `SHARE UPDATE EXCLUSIVE` lock, so reads and writes
+                    // keep working while it runs. Once validated, PG12+'s `SET NOT NULL` recognizes
+                    // the constraint and skips its own scan, so the final `ALTER TABLE` is fast.
+                    let check_name = Quoted::postgres_ident_with_casing(
+                        format!("{}_not_null_check", columns.next.name()),
+                        flavour.identifier_casing(),
+                    );
+
+                    before_statements.push(format!(
+                        "ALTER TABLE {table_name} ADD CONSTRAINT {check_name} CHECK ({column_name} IS NOT NULL) NOT VALID"
+                    ));
+                    before_statements.push(format!("ALTER TABLE {table_name} VALIDATE CONSTRAINT {check_name}"));
+                    after_statements.push(format!("ALTER TABLE {table_name} DROP CONSTRAINT {check_name}"));
+                }
+
+                clauses.push(format!("{} SET NOT NULL", &alter_column_prefix));
+            }
             PostgresAlterColumn::SetType => clauses.push(format!(
                 "{} SET DATA TYPE {}",
                 &alter_column_prefix,
@@ -726,7 +890,9 @@ fn render_alter_column(
                 let sequence_name = format!(
                     "{namespace}{table_name}_{column_name}_seq",
                     namespace = match columns.next.table().namespace() {
-                        Some(namespace) => format!("{}.", Quoted::postgres_ident(namespace)),
+                        Some(namespace) => {
+                            format!("{}.", Quoted::postgres_ident_with_casing(namespace, flavour.identifier_casing()))
+                        }
                         None => String::from(""),
                     },
                     table_name = columns.next.table().name(),
@@ -866,7 +1032,9 @@ fn render_default<'a>(default: &'a DefaultValue, full_data_type: &str) -> Cow<'a
         DefaultKind::Now => "CURRENT_TIMESTAMP".into(),
         DefaultKind::Value(value) => render_constant_default(value, full_data_type),
         DefaultKind::UniqueRowid => "unique_rowid()".into(),
-        DefaultKind::Sequence(_) | DefaultKind::DbGenerated(None) => Default::default(),
+        // None of these becomes a `DEFAULT`; `Generated` is rendered as
+        // `GENERATED ALWAYS AS (...) STORED` in `render_column` instead.
+        DefaultKind::Sequence(_) | DefaultKind::DbGenerated(None) | DefaultKind::Generated(_, _) => Default::default(),
     }
 }
 
@@ -884,7 +1052,8 @@ fn render_postgres_alter_enum(
                     "ALTER TYPE {enum_name} ADD VALUE {value}",
                     enum_name = QuotedWithPrefix::pg_new(
                         schemas.walk(alter_enum.id).previous.namespace(),
-                        schemas.walk(alter_enum.id).previous.name()
+                        schemas.walk(alter_enum.id).previous.name(),
+                        flavour.identifier_casing()
                     ),
                     value = Quoted::postgres_string(created_value)
                 )
@@ -913,7 +1082,7 @@ fn render_postgres_alter_enum(
     let mut stmts = Vec::with_capacity(10);
 
     let temporary_enum_name = format!("{}_new", &enums.next.name());
-    let tmp_name = QuotedWithPrefix::pg_new(enums.next.namespace(), temporary_enum_name.as_str());
+    let tmp_name = QuotedWithPrefix::pg_new(enums.next.namespace(), temporary_enum_name.as_str(), flavour.identifier_casing());
     let tmp_old_name = format!("{}_old", &enums.previous.name());
 
     stmts.push("BEGIN".to_string());
@@ -936,8 +1105,8 @@ fn render_postgres_alter_enum(
 
             let drop_default = format!(
                 r#"ALTER TABLE {table_name} ALTER COLUMN {column_name} DROP DEFAULT"#,
-                table_name = QuotedWithPrefix::pg_from_table_walker(column.table()),
-                column_name = Quoted::postgres_ident(column.name()),
+                table_name = QuotedWithPrefix::pg_from_table_walker(column.table(), flavour.identifier_casing()),
+                column_name = Quoted::postgres_ident_with_casing(column.name(), flavour.identifier_casing()),
             );
 
             stmts.push(drop_default);
@@ -957,8 +1126,8 @@ fn render_postgres_alter_enum(
                 "ALTER TABLE {table_name} \
                             ALTER COLUMN {column_name} TYPE {tmp_name}{array} \
                                 USING ({column_name}::text::{tmp_name}{array})",
-                table_name = QuotedWithPrefix::pg_from_table_walker(column.table()),
-                column_name = Quoted::postgres_ident(column.name()),
+                table_name = QuotedWithPrefix::pg_from_table_walker(column.table(), flavour.identifier_casing()),
+                column_name = Quoted::postgres_ident_with_casing(column.name(), flavour.identifier_casing()),
                 array = array,
             );
 
@@ -970,8 +1139,8 @@ fn render_postgres_alter_enum(
     {
         let sql = format!(
             "ALTER TYPE {enum_name} RENAME TO {tmp_old_name}",
-            enum_name = QuotedWithPrefix::pg_new(enums.previous.namespace(), enums.previous.name()),
-            tmp_old_name = Quoted::postgres_ident(&tmp_old_name)
+            enum_name = QuotedWithPrefix::pg_new(enums.previous.namespace(), enums.previous.name(), flavour.identifier_casing()),
+            tmp_old_name = Quoted::postgres_ident_with_casing(&tmp_old_name, flavour.identifier_casing())
         );
 
         stmts.push(sql);
@@ -981,7 +1150,7 @@ fn render_postgres_alter_enum(
     {
         let sql = format!(
             "ALTER TYPE {tmp_name} RENAME TO {enum_name}",
-            enum_name = Quoted::postgres_ident(enums.next.name())
+            enum_name = Quoted::postgres_ident_with_casing(enums.next.name(), flavour.identifier_casing())
         );
 
         stmts.push(sql)
@@ -1012,8 +1181,12 @@ fn render_postgres_alter_enum(
 
             let set_default = format!(
                 "ALTER TABLE {table_name} ALTER COLUMN {column_name} SET DEFAULT {default}",
-                table_name = QuotedWithPrefix::pg_new(columns.previous.table().namespace(), table_name),
-                column_name = Quoted::postgres_ident(&column_name),
+                table_name = QuotedWithPrefix::pg_new(
+                    columns.previous.table().namespace(),
+                    table_name,
+                    flavour.identifier_casing()
+                ),
+                column_name = Quoted::postgres_ident_with_casing(&column_name, flavour.identifier_casing()),
                 default = default_str,
             );
 
@@ -1030,12 +1203,13 @@ fn render_cockroach_alter_enum(
     alter_enum: &AlterEnum,
     schemas: MigrationPair<&SqlSchema>,
     renderer: &mut StepRenderer,
+    flavour: &PostgresFlavour,
 ) {
     let enums = schemas.walk(alter_enum.id);
     let mut prefix = String::new();
     prefix.push_str("ALTER TYPE ");
     prefix.push_str(
-        QuotedWithPrefix::pg_new(enums.previous.namespace(), enums.previous.name())
+        QuotedWithPrefix::pg_new(enums.previous.namespace(), enums.previous.name(), flavour.identifier_casing())
             .to_string()
             .as_str(),
     );
@@ -1056,9 +1230,9 @@ fn render_cockroach_alter_enum(
     for (col, _) in defaults_to_drop {
         renderer.render_statement(&mut |stmt| {
             stmt.push_str("ALTER TABLE ");
-            stmt.push_display(&QuotedWithPrefix::pg_from_table_walker(col.table()));
+            stmt.push_display(&QuotedWithPrefix::pg_from_table_walker(col.table(), flavour.identifier_casing()));
             stmt.push_str(" ALTER COLUMN ");
-            stmt.push_display(&Quoted::postgres_ident(col.name()));
+            stmt.push_display(&Quoted::postgres_ident_with_casing(col.name(), flavour.identifier_casing()));
             stmt.push_str(" DROP DEFAULT");
         })
     }
@@ -1124,6 +1298,10 @@ fn render_column_identity_str(column: TableColumnWalker<'_>, flavour: &PostgresF
         options.push(format!("MAXVALUE {}", sequence.max_value))
     }
 
+    if sequence.cycle {
+        options.push("CYCLE".to_owned())
+    }
+
     if options.is_empty() {
         String::from(" GENERATED BY DEFAULT AS IDENTITY")
     } else {
@@ -44,6 +44,8 @@ impl SqlRenderer for SqliteFlavour {
             columns = columns.join(", ")
         );
 
+        let index_create = make_create_idempotent(index_create, "INDEX ", self.idempotent_ddl());
+
         if index.name().starts_with("sqlite_") {
             formatdoc!(
                 "Pragma writable_schema=1;
@@ -100,7 +102,15 @@ impl SqlRenderer for SqliteFlavour {
     }
 
     fn render_create_table(&self, table: TableWalker<'_>) -> String {
-        self.render_create_table_as(table, QuotedWithPrefix(None, Quoted::sqlite_ident(table.name())))
+        let create_table = self.render_create_table_as(table, QuotedWithPrefix(None, Quoted::sqlite_ident(table.name())));
+
+        make_create_idempotent(create_table, "CREATE TABLE ", self.idempotent_ddl())
+    }
+
+    fn render_create_table_as_select(&self, table: TableWalker<'_>, select_query: &str) -> String {
+        let table_name = Quoted::sqlite_ident(table.name());
+
+        render_create_table_as_select_stmt(&table_name, select_query)
     }
 
     fn render_create_table_as(&self, table: TableWalker<'_>, table_name: QuotedWithPrefix<&str>) -> String {
@@ -153,7 +163,9 @@ impl SqlRenderer for SqliteFlavour {
     }
 
     fn render_drop_index(&self, index: IndexWalker<'_>) -> String {
-        format!("DROP INDEX {}", self.quote(index.name()))
+        let drop_index = format!("DROP INDEX {}", self.quote(index.name()));
+
+        make_drop_idempotent(drop_index, "INDEX ", self.idempotent_ddl())
     }
 
     fn render_drop_and_recreate_index(&self, indexes: MigrationPair<IndexWalker<'_>>) -> Vec<String> {
@@ -168,13 +180,16 @@ impl SqlRenderer for SqliteFlavour {
         // to a non-existent model. There appears to be no other way to deal with cyclic
         // dependencies in the dropping order of tables in the presence of foreign key
         // constraints on SQLite.
+        let idempotent = self.idempotent_ddl();
+
         render_step(&mut |step| {
             step.render_statement(&mut |stmt| {
                 stmt.push_str("PRAGMA foreign_keys=off");
             });
             step.render_statement(&mut |stmt| {
-                stmt.push_str("DROP TABLE ");
-                stmt.push_display(&Quoted::sqlite_ident(table_name));
+                let drop_table = format!("DROP TABLE {}", Quoted::sqlite_ident(table_name));
+
+                stmt.push_str(&make_drop_idempotent(drop_table, "TABLE ", idempotent))
             });
             step.render_statement(&mut |stmt| {
                 stmt.push_str("PRAGMA foreign_keys=on");
@@ -345,5 +360,7 @@ fn render_default(default: &DefaultValue) -> Cow<'_, str> {
         DefaultKind::Value(PrismaValue::DateTime(val)) => Quoted::sqlite_string(val).to_string().into(),
         DefaultKind::Value(val) => val.to_string().into(),
         DefaultKind::DbGenerated(None) | DefaultKind::Sequence(_) | DefaultKind::UniqueRowid => unreachable!(),
+        // Generated columns are a Postgres-only concept for now.
+        DefaultKind::Generated(_, _) => unreachable!(),
     }
 }
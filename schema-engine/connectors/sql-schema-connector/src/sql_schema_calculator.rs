@@ -12,9 +12,28 @@ use psl::{
     },
     ValidatedSchema,
 };
+use schema_connector::ConnectorResult;
 use sql_schema_describer::{self as sql, PrismaValue};
 use std::collections::HashMap;
 
+/// Validate the native type arguments of every scalar field against the bounds the connected
+/// database documents for them (e.g. `VarChar(0)`, `Decimal(100, 200)`), before any DDL is
+/// calculated from the schema. See [`SqlSchemaCalculatorFlavour::validate_native_type`].
+pub(crate) fn validate_native_types(datamodel: &ValidatedSchema, flavour: &dyn SqlFlavour) -> ConnectorResult<()> {
+    let connector = flavour.datamodel_connector();
+
+    for field in datamodel.db.walk_models().flat_map(|model| model.scalar_fields()) {
+        let Some(scalar_type) = field.scalar_type() else { continue };
+        let native_type = field
+            .native_type_instance(connector)
+            .unwrap_or_else(|| connector.default_native_type_for_scalar_type(&scalar_type));
+
+        flavour.validate_native_type(field, &native_type)?;
+    }
+
+    Ok(())
+}
+
 pub(crate) fn calculate_sql_schema(datamodel: &ValidatedSchema, flavour: &dyn SqlFlavour) -> SqlDatabaseSchema {
     let mut schema = SqlDatabaseSchema::default();
 
@@ -68,6 +87,19 @@ fn push_model_tables(ctx: &mut Context<'_>) {
         }
 
         push_model_indexes(model, table_id, ctx);
+        push_model_grants(model, table_id, ctx);
+    }
+}
+
+fn push_model_grants(model: ModelWalker<'_>, table_id: sql::TableId, ctx: &mut Context<'_>) {
+    for grant in model.grants() {
+        ctx.schema.describer_schema.push_table_grant(
+            table_id,
+            sql::TableGrant {
+                role: grant.role().to_owned(),
+                privileges: grant.privileges().map(|p| p.to_owned()).collect(),
+            },
+        );
     }
 }
 
@@ -99,9 +131,11 @@ fn push_model_indexes(model: ModelWalker<'_>, table_id: sql::TableId, ctx: &mut
     for index in model.indexes() {
         let constraint_name = index.constraint_name(ctx.flavour.datamodel_connector()).into_owned();
         let index_id = if index.is_unique() {
-            ctx.schema
-                .describer_schema
-                .push_unique_constraint(table_id, constraint_name)
+            ctx.schema.describer_schema.push_unique_constraint(
+                table_id,
+                constraint_name,
+                index.mapped_name().is_none(),
+            )
         } else if index.is_fulltext() {
             ctx.schema
                 .describer_schema
@@ -267,7 +301,10 @@ fn push_relation_tables(ctx: &mut Context<'_>) {
                 "{}_AB_unique",
                 table_name.chars().take(max_identifier_length - 10).collect::<String>()
             );
-            let index_id = ctx.schema.describer_schema.push_unique_constraint(table_id, index_name);
+            let index_id = ctx
+                .schema
+                .describer_schema
+                .push_unique_constraint(table_id, index_name, true);
             ctx.schema.describer_schema.push_index_column(sql::IndexColumn {
                 index_id,
                 column_id: column_a_id,
@@ -3,7 +3,11 @@ mod mysql;
 mod postgres;
 mod sqlite;
 
-use psl::parser_database::{ast::FieldArity, walkers::*};
+use psl::{
+    datamodel_connector::NativeTypeInstance,
+    parser_database::{ast::FieldArity, walkers::*},
+};
+use schema_connector::ConnectorResult;
 use sql_schema_describer::{self as sql, ColumnArity, ColumnType, ColumnTypeFamily};
 
 pub(crate) trait SqlSchemaCalculatorFlavour {
@@ -39,4 +43,17 @@ pub(crate) trait SqlSchemaCalculatorFlavour {
     }
 
     fn push_connector_data(&self, _context: &mut super::Context<'_>) {}
+
+    /// Validate a scalar field's native type arguments (e.g. `VarChar(0)`, `Decimal(100, 200)`)
+    /// against the bounds the connected database actually documents for them. Called once per
+    /// scalar field while calculating the SQL schema, so out-of-range arguments are rejected with
+    /// a clear error before any DDL is generated and sent to the database. The default
+    /// implementation accepts every argument.
+    fn validate_native_type(
+        &self,
+        _field: ScalarFieldWalker<'_>,
+        _native_type: &NativeTypeInstance,
+    ) -> ConnectorResult<()> {
+        Ok(())
+    }
 }
@@ -1,9 +1,14 @@
 use super::SqlSchemaCalculatorFlavour;
 use crate::flavour::{MssqlFlavour, SqlFlavour};
 use psl::{
-    datamodel_connector::walker_ext_traits::{DefaultValueExt, IndexWalkerExt},
+    builtin_connectors::{MsSqlType, MsSqlTypeParameter},
+    datamodel_connector::{
+        walker_ext_traits::{DefaultValueExt, IndexWalkerExt},
+        NativeTypeInstance,
+    },
     parser_database::walkers::*,
 };
+use schema_connector::{ConnectorError, ConnectorResult};
 use sql_schema_describer::{
     mssql::{IndexBits, MssqlSchemaExt},
     ForeignKeyAction,
@@ -51,4 +56,43 @@ impl SqlSchemaCalculatorFlavour for MssqlFlavour {
 
         context.schema.describer_schema.set_connector_data(Box::new(data));
     }
+
+    fn validate_native_type(
+        &self,
+        _field: ScalarFieldWalker<'_>,
+        native_type: &NativeTypeInstance,
+    ) -> ConnectorResult<()> {
+        let native_type: &MsSqlType = native_type.downcast_ref();
+
+        match native_type {
+            MsSqlType::Decimal(Some((precision, scale))) if scale > precision => Err(ConnectorError::from_msg(format!(
+                "The scale of the `Decimal({precision}, {scale})` native type must not be larger than its precision."
+            ))),
+            MsSqlType::Decimal(Some((precision, _))) if *precision == 0 || *precision > 38 => Err(ConnectorError::from_msg(
+                format!("The precision of the `Decimal({precision}, _)` native type must be between 1 and 38."),
+            )),
+            MsSqlType::Decimal(Some((_, scale))) if *scale > 38 => Err(ConnectorError::from_msg(format!(
+                "The scale of the `Decimal(_, {scale})` native type must be between 0 and 38."
+            ))),
+            MsSqlType::NVarChar(Some(MsSqlTypeParameter::Number(0)))
+            | MsSqlType::VarChar(Some(MsSqlTypeParameter::Number(0)))
+            | MsSqlType::VarBinary(Some(MsSqlTypeParameter::Number(0))) => Err(ConnectorError::from_msg(
+                "The length argument of the native type must be greater than 0.".to_owned(),
+            )),
+            MsSqlType::NVarChar(Some(MsSqlTypeParameter::Number(p))) if *p > 4000 => Err(ConnectorError::from_msg(
+                "The length of the `NVarChar` native type can range from 1 to 4000. For larger sizes, use `Max`."
+                    .to_owned(),
+            )),
+            MsSqlType::VarChar(Some(MsSqlTypeParameter::Number(p)))
+            | MsSqlType::VarBinary(Some(MsSqlTypeParameter::Number(p)))
+                if *p > 8000 =>
+            {
+                Err(ConnectorError::from_msg(
+                    "The length of the `VarChar`/`VarBinary` native type can range from 1 to 8000. For larger sizes, use `Max`."
+                        .to_owned(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
 }
@@ -1,5 +1,11 @@
 use super::{super::Context, SqlSchemaCalculatorFlavour};
 use crate::flavour::MysqlFlavour;
+use psl::{
+    builtin_connectors::MySqlType,
+    datamodel_connector::NativeTypeInstance,
+    parser_database::walkers::ScalarFieldWalker,
+};
+use schema_connector::{ConnectorError, ConnectorResult};
 
 impl SqlSchemaCalculatorFlavour for MysqlFlavour {
     fn calculate_enums(&self, ctx: &mut Context<'_>) {
@@ -23,4 +29,31 @@ impl SqlSchemaCalculatorFlavour for MysqlFlavour {
             }
         }
     }
+
+    fn validate_native_type(
+        &self,
+        _field: ScalarFieldWalker<'_>,
+        native_type: &NativeTypeInstance,
+    ) -> ConnectorResult<()> {
+        let native_type: &MySqlType = native_type.downcast_ref();
+
+        match native_type {
+            MySqlType::Decimal(Some((precision, scale))) if scale > precision => Err(ConnectorError::from_msg(format!(
+                "The scale of the `Decimal({precision}, {scale})` native type must not be larger than its precision."
+            ))),
+            MySqlType::Decimal(Some((precision, _))) if *precision == 0 || *precision > 65 => Err(ConnectorError::from_msg(
+                format!("The precision of the `Decimal({precision}, _)` native type must be between 1 and 65."),
+            )),
+            MySqlType::Decimal(Some((_, scale))) if *scale > 30 => Err(ConnectorError::from_msg(format!(
+                "The scale of the `Decimal(_, {scale})` native type must be between 0 and 30."
+            ))),
+            MySqlType::VarChar(length) if *length > 65_535 => Err(ConnectorError::from_msg(format!(
+                "The length of the `VarChar({length})` native type must be between 0 and 65,535."
+            ))),
+            MySqlType::Char(length) if *length > 255 => Err(ConnectorError::from_msg(format!(
+                "The length of the `Char({length})` native type must be between 0 and 255."
+            ))),
+            _ => Ok(()),
+        }
+    }
 }
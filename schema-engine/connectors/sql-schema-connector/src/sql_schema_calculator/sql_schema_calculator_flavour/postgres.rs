@@ -2,10 +2,13 @@ use super::{super::Context, SqlSchemaCalculatorFlavour};
 use crate::flavour::{PostgresFlavour, SqlFlavour};
 use either::Either;
 use psl::{
-    builtin_connectors::{cockroach_datamodel_connector::SequenceFunction, PostgresDatasourceProperties},
-    datamodel_connector::walker_ext_traits::IndexWalkerExt,
-    parser_database::{IndexAlgorithm, OperatorClass},
+    builtin_connectors::{
+        cockroach_datamodel_connector::SequenceFunction, CockroachType, PostgresDatasourceProperties, PostgresType,
+    },
+    datamodel_connector::{walker_ext_traits::IndexWalkerExt, NativeTypeInstance},
+    parser_database::{walkers::ScalarFieldWalker, IndexAlgorithm, OperatorClass},
 };
+use schema_connector::{ConnectorError, ConnectorResult};
 use sql::postgres::DatabaseExtension;
 use sql_schema_describer::{self as sql, postgres::PostgresSchemaExt};
 
@@ -37,6 +40,50 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
         }
     }
 
+    fn validate_native_type(
+        &self,
+        _field: ScalarFieldWalker<'_>,
+        native_type: &NativeTypeInstance,
+    ) -> ConnectorResult<()> {
+        if self.is_cockroachdb() {
+            let native_type: &CockroachType = native_type.downcast_ref();
+            match native_type {
+                CockroachType::Decimal(Some((precision, scale))) if scale > precision => {
+                    Err(ConnectorError::from_msg(format!(
+                        "The scale of the `Decimal({precision}, {scale})` native type must not be larger than its precision."
+                    )))
+                }
+                CockroachType::Decimal(Some((precision, _))) if *precision == 0 || *precision > 1000 => {
+                    Err(ConnectorError::from_msg(format!(
+                        "The precision of the `Decimal({precision}, _)` native type must be between 1 and 1000."
+                    )))
+                }
+                CockroachType::String(Some(0)) | CockroachType::Char(Some(0)) => Err(ConnectorError::from_msg(
+                    "The length argument of the native type must be greater than 0.".to_owned(),
+                )),
+                _ => Ok(()),
+            }
+        } else {
+            let native_type: &PostgresType = native_type.downcast_ref();
+            match native_type {
+                PostgresType::Decimal(Some((precision, scale))) if scale > precision => {
+                    Err(ConnectorError::from_msg(format!(
+                        "The scale of the `Decimal({precision}, {scale})` native type must not be larger than its precision."
+                    )))
+                }
+                PostgresType::Decimal(Some((precision, _))) if *precision == 0 || *precision > 1000 => {
+                    Err(ConnectorError::from_msg(format!(
+                        "The precision of the `Decimal({precision}, _)` native type must be between 1 and 1000."
+                    )))
+                }
+                PostgresType::VarChar(Some(0)) | PostgresType::Char(Some(0)) => Err(ConnectorError::from_msg(
+                    "The length argument of the native type must be greater than 0.".to_owned(),
+                )),
+                _ => Ok(()),
+            }
+        }
+    }
+
     fn push_connector_data(&self, context: &mut super::super::Context<'_>) {
         let mut postgres_ext = PostgresSchemaExt::default();
         let db = &context.datamodel.db;
@@ -149,6 +196,10 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
                     sequence.increment_by = increment;
                 }
 
+                if let Some(cycle) = sequence_details.cycle {
+                    sequence.cycle = cycle;
+                }
+
                 if let Some(r#virtual) = sequence_details.r#virtual {
                     sequence.r#virtual = r#virtual;
                 }
@@ -178,6 +229,14 @@ fn convert_opclass(opclass: OperatorClass, algo: Option<IndexAlgorithm>) -> sql:
             kind: sql::postgres::SQLOperatorClassKind::JsonbPathOps,
             is_default: false,
         },
+        OperatorClass::GinTrgmOps => sql::postgres::SQLOperatorClass {
+            kind: sql::postgres::SQLOperatorClassKind::GinTrgmOps,
+            is_default: false,
+        },
+        OperatorClass::GistTrgmOps => sql::postgres::SQLOperatorClass {
+            kind: sql::postgres::SQLOperatorClassKind::GistTrgmOps,
+            is_default: false,
+        },
         OperatorClass::ArrayOps => sql::postgres::SQLOperatorClass {
             kind: sql::postgres::SQLOperatorClassKind::ArrayOps,
             is_default: true,
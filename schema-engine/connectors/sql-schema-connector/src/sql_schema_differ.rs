@@ -16,7 +16,10 @@ use crate::{
     SqlFlavour,
 };
 use column::ColumnTypeChange;
-use sql_schema_describer::{walkers::ForeignKeyWalker, IndexId, TableColumnId};
+use sql_schema_describer::{
+    walkers::{ForeignKeyWalker, IndexWalker},
+    IndexId, TableColumnId,
+};
 use std::{borrow::Cow, collections::HashSet};
 use table::TableDiffer;
 
@@ -39,6 +42,8 @@ pub(crate) fn calculate_steps(
 
     flavour.push_enum_steps(&mut steps, &db);
     flavour.push_alter_sequence_steps(&mut steps, &db);
+    flavour.push_alter_column_storage_steps(&mut steps, &db);
+    flavour.push_alter_column_compression_steps(&mut steps, &db);
 
     steps.sort();
 
@@ -115,10 +120,9 @@ fn push_altered_table_steps(steps: &mut Vec<SqlMigrationStep>, db: &DifferDataba
         push_alter_primary_key(&table, steps);
 
         // Indexes.
-        for i in table
-            .index_pairs()
-            .filter(|pair| db.flavour.index_should_be_renamed(*pair))
-        {
+        for i in table.index_pairs().filter(|pair| {
+            !preserves_introspected_constraint_name(*pair) && db.flavour.index_should_be_renamed(*pair)
+        }) {
             let index: MigrationPair<IndexId> = i.map(|i| i.id);
 
             let step = if db.flavour.can_rename_index() {
@@ -168,6 +172,15 @@ fn push_altered_table_steps(steps: &mut Vec<SqlMigrationStep>, db: &DifferDataba
     }
 }
 
+/// A name-only difference on a unique constraint pair should not trigger a rename when `previous`
+/// carries a name we know is real (introspected, or explicitly `map`ped) and `next` only carries a
+/// name generated from the naming convention: it means the incoming schema has no opinion on the
+/// constraint's name, so the existing one is preserved rather than clobbered with the generated
+/// one. A rename still happens when `next` explicitly chooses a different name.
+fn preserves_introspected_constraint_name(pair: MigrationPair<IndexWalker<'_>>) -> bool {
+    pair.previous.is_unique() && !pair.previous.has_default_name() && pair.next.has_default_name()
+}
+
 fn dropped_columns(differ: &TableDiffer<'_, '_>, changes: &mut Vec<TableChange>) {
     for column in differ.dropped_columns() {
         changes.push(TableChange::DropColumn { column_id: column.id })
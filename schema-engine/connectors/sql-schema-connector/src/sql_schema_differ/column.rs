@@ -108,6 +108,15 @@ fn defaults_match(cols: MigrationPair<TableColumnWalker<'_>>, flavour: &dyn SqlF
         (Some(DefaultKind::DbGenerated(Some(prev))), Some(DefaultKind::DbGenerated(Some(next)))) => {
             (prev.eq_ignore_ascii_case(next)) && names_match
         }
+
+        // A generated (computed) column expression is not a default, so it never matches a
+        // literal, function, or absent default, and it can only match another generated
+        // expression that's textually the same.
+        (Some(DefaultKind::Generated(prev, prev_strategy)), Some(DefaultKind::Generated(next, next_strategy))) => {
+            prev.eq_ignore_ascii_case(next) && prev_strategy == next_strategy && names_match
+        }
+        (Some(DefaultKind::Generated(_, _)), _) | (_, Some(DefaultKind::Generated(_, _))) => false,
+
         (_, Some(DefaultKind::DbGenerated(_))) => false,
         (_, Some(DefaultKind::Sequence(_))) => true,
     }
@@ -342,5 +342,13 @@ pub(crate) fn extensions_match(previous: ExtensionWalker<'_>, next: ExtensionWal
 }
 
 fn enums_match(previous: &EnumWalker<'_>, next: &EnumWalker<'_>) -> bool {
-    previous.name() == next.name() && previous.namespace() == next.namespace()
+    previous.name() == next.name() && namespaces_match(previous.namespace(), next.namespace())
+}
+
+// An enum without an explicit namespace (no `@@schema`/multiSchema) calculates to `None`, while the
+// same enum coming back from introspection always resolves to the default namespace (e.g. `Some("public")`
+// on Postgres). Treat a missing namespace on either side as a wildcard so such enums are not seen as
+// having moved schemas.
+fn namespaces_match(previous: Option<&str>, next: Option<&str>) -> bool {
+    previous == next || previous.is_none() || next.is_none()
 }
@@ -50,6 +50,18 @@ pub(crate) trait SqlSchemaDifferFlavour {
     /// Push AlterSequence steps.
     fn push_alter_sequence_steps(&self, _steps: &mut Vec<SqlMigrationStep>, _db: &DifferDatabase<'_>) {}
 
+    /// Push AlterColumnStorage steps. Only ever produced when diffing two
+    /// introspected database schemas: the storage mode is not representable
+    /// in the Prisma schema, so a schema calculated from a `ValidatedSchema`
+    /// never carries an opinion on it, and no steps are generated in that
+    /// case, leaving the existing storage mode untouched.
+    fn push_alter_column_storage_steps(&self, _steps: &mut Vec<SqlMigrationStep>, _db: &DifferDatabase<'_>) {}
+
+    /// Push AlterColumnCompression steps. Only ever produced when diffing two introspected
+    /// database schemas, for the same reason as [`Self::push_alter_column_storage_steps`]: the
+    /// compression method isn't representable in the Prisma schema.
+    fn push_alter_column_compression_steps(&self, _steps: &mut Vec<SqlMigrationStep>, _db: &DifferDatabase<'_>) {}
+
     /// Push AlterExtension steps.
     fn push_extension_steps(&self, _steps: &mut Vec<SqlMigrationStep>, _db: &DifferDatabase<'_>) {}
 
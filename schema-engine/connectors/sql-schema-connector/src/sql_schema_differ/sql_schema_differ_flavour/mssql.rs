@@ -48,8 +48,14 @@ impl SqlSchemaDifferFlavour for MssqlFlavour {
             })
             .map(|t| t.table_ids());
 
+        let computed_column_changed = db
+            .table_pairs()
+            .filter(|tables| tables.column_pairs().any(computed_column_changed))
+            .map(|t| t.table_ids());
+
         db.tables_to_redefine = autoincrement_changed
             .chain(all_columns_of_the_table_gets_dropped)
+            .chain(computed_column_changed)
             .collect();
     }
 
@@ -107,6 +113,26 @@ impl SqlSchemaDifferFlavour for MssqlFlavour {
     }
 }
 
+/// MSSQL has no `ALTER COLUMN` for computed columns: changing the expression or its
+/// `PERSISTED` state requires dropping and re-adding the column. The table redefine
+/// machinery already knows how to do that safely, so we reuse it here too.
+fn computed_column_changed(columns: MigrationPair<sql::TableColumnWalker<'_>>) -> bool {
+    use sql_schema_describer::DefaultKind;
+
+    let kinds = columns.map(|c| c.default().map(|d| d.kind()));
+
+    match kinds.into_tuple() {
+        // An empty `dbgenerated()` in the datamodel means "leave whatever is in the database
+        // alone", so it's compatible with any actual column, computed or not.
+        (_, Some(DefaultKind::DbGenerated(None))) => false,
+        (Some(DefaultKind::Generated(prev, prev_strategy)), Some(DefaultKind::Generated(next, next_strategy))) => {
+            !prev.eq_ignore_ascii_case(next) || prev_strategy != next_strategy
+        }
+        (Some(DefaultKind::Generated(_, _)), _) | (_, Some(DefaultKind::Generated(_, _))) => true,
+        _ => false,
+    }
+}
+
 fn family_change_riskyness(previous: &ColumnTypeFamily, next: &ColumnTypeFamily) -> Option<ColumnTypeChange> {
     match (previous, next) {
         (prev, next) if prev == next => None,
@@ -15,8 +15,10 @@ use psl::builtin_connectors::{CockroachType, PostgresType};
 use regex::RegexSet;
 use sql_schema_describer::{
     postgres::PostgresSchemaExt,
-    walkers::{IndexWalker, TableColumnWalker},
+    walkers::{IndexWalker, TableColumnWalker, TableWalker},
+    TableId,
 };
+use std::collections::BTreeSet;
 
 /// These can be tables or views, depending on the PostGIS version. In both cases, they should be ignored.
 static POSTGIS_TABLES_OR_VIEWS: Lazy<RegexSet> = Lazy::new(|| {
@@ -148,6 +150,10 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
                 changes |= SequenceChange::Increment;
             }
 
+            if prev.cycle != next.cycle {
+                changes |= SequenceChange::Cycle;
+            }
+
             if !changes.is_empty() {
                 steps.push(SqlMigrationStep::AlterSequence(
                     pair.map(|p| p.0 as u32),
@@ -157,6 +163,50 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
         }
     }
 
+    fn push_alter_column_storage_steps(&self, steps: &mut Vec<SqlMigrationStep>, db: &DifferDatabase<'_>) {
+        let schemas: MigrationPair<&PostgresSchemaExt> =
+            db.schemas.map(|schema| schema.describer_schema.downcast_connector_data());
+
+        for columns in db.all_column_pairs() {
+            let storage = schemas.zip(columns).map(|(ext, column_id)| ext.column_storage(column_id));
+
+            // The storage mode is not representable in the Prisma schema, so a schema
+            // calculated from the datamodel never has an opinion on it (`next` is `None`).
+            // Only emit a step when we are diffing two introspected schemas and the next
+            // one explicitly changes the storage mode.
+            if let Some(next) = storage.next {
+                if storage.previous != Some(next) {
+                    steps.push(SqlMigrationStep::AlterColumnStorage(columns, next));
+                }
+            }
+        }
+    }
+
+    fn push_alter_column_compression_steps(&self, steps: &mut Vec<SqlMigrationStep>, db: &DifferDatabase<'_>) {
+        // Older servers have no notion of column compression at all, and CockroachDB doesn't
+        // support it either: leave any (impossible) metadata on those connections untouched.
+        if !self.can_set_column_compression() {
+            return;
+        }
+
+        let schemas: MigrationPair<&PostgresSchemaExt> =
+            db.schemas.map(|schema| schema.describer_schema.downcast_connector_data());
+
+        for columns in db.all_column_pairs() {
+            let compression = schemas.zip(columns).map(|(ext, column_id)| ext.column_compression(column_id));
+
+            // The compression method is not representable in the Prisma schema, so a schema
+            // calculated from the datamodel never has an opinion on it (`next` is `None`). Only
+            // emit a step when we are diffing two introspected schemas and the next one
+            // explicitly changes the compression method.
+            if let Some(next) = compression.next {
+                if compression.previous != Some(next) {
+                    steps.push(SqlMigrationStep::AlterColumnCompression(columns, next));
+                }
+            }
+        }
+    }
+
     fn indexes_match(&self, a: IndexWalker<'_>, b: IndexWalker<'_>) -> bool {
         let columns_previous = a.columns();
         let columns_next = b.columns();
@@ -195,23 +245,56 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
     }
 
     fn set_tables_to_redefine(&self, db: &mut DifferDatabase<'_>) {
-        if !self.is_cockroachdb() {
-            return;
-        }
+        let mut tables_to_redefine: BTreeSet<MigrationPair<TableId>> = BTreeSet::new();
 
-        let id_gets_dropped = db
-            .table_pairs()
-            .filter(|tables| {
-                tables.column_pairs().any(|columns| {
-                    let type_change = self.column_type_change(columns);
-                    let is_id = columns.previous.is_single_primary_key();
+        if self.is_cockroachdb() {
+            tables_to_redefine.extend(
+                db.table_pairs()
+                    .filter(|tables| {
+                        tables.column_pairs().any(|columns| {
+                            let type_change = self.column_type_change(columns);
+                            let is_id = columns.previous.is_single_primary_key();
+
+                            is_id && matches!(type_change, Some(ColumnTypeChange::NotCastable))
+                        }) || tables.dropped_columns().any(|col| col.is_single_primary_key())
+                    })
+                    .map(|t| t.table_ids()),
+            );
+        }
 
-                    is_id && matches!(type_change, Some(ColumnTypeChange::NotCastable))
-                }) || tables.dropped_columns().any(|col| col.is_single_primary_key())
-            })
-            .map(|t| t.table_ids());
+        // Postgres has no `ALTER TABLE ... INHERIT newparent` shortcut that safely re-parents a
+        // table without risking duplicated or missing inherited columns, so a changed `INHERITS`
+        // parent is treated like an unsupported type change: the table gets rebuilt from scratch.
+        tables_to_redefine.extend(
+            db.table_pairs()
+                .filter(|tables| inheritance_parent_changed(tables.tables))
+                .map(|t| t.table_ids()),
+        );
+
+        // Postgres has no `ALTER COLUMN ... SET GENERATED ALWAYS AS (...)`: a generated column's
+        // expression can only be changed by dropping and re-adding the column. The table redefine
+        // machinery already knows how to do that safely, so we reuse it here too.
+        tables_to_redefine.extend(
+            db.table_pairs()
+                .filter(|tables| tables.column_pairs().any(|columns| generated_column_changed(columns)))
+                .map(|t| t.table_ids()),
+        );
+
+        // Domains are unmanaged database objects, so we never emit `ALTER DOMAIN`. If a column's
+        // domain type was redefined (base type or `CHECK` constraint changed) between the two
+        // schemas being diffed, the only safe way to pick that up is to drop and re-add the
+        // column, which the table redefine machinery already does for us.
+        tables_to_redefine.extend(
+            db.table_pairs()
+                .filter(|tables| {
+                    tables
+                        .column_pairs()
+                        .any(|columns| domain_definition_changed(columns))
+                })
+                .map(|t| t.table_ids()),
+        );
 
-        db.tables_to_redefine = id_gets_dropped.collect();
+        db.tables_to_redefine = tables_to_redefine;
     }
 
     fn string_matches_bytes(&self, string: &str, bytes: &[u8]) -> bool {
@@ -290,6 +373,61 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
     }
 }
 
+/// True if the table's declared `INHERITS` parent is different between the two schema versions.
+/// A table gaining or losing inheritance entirely is not considered a change here, only an
+/// existing parent being swapped for another one.
+fn inheritance_parent_changed(tables: MigrationPair<TableWalker<'_>>) -> bool {
+    let pg_ext: MigrationPair<&PostgresSchemaExt> = tables.map(|t| t.schema.downcast_connector_data());
+    let parents = tables.zip(pg_ext).map(|(t, ext)| ext.inherits(t.id));
+
+    match parents.into_tuple() {
+        (Some(previous_parent), Some(next_parent)) => {
+            let previous = tables.previous.walk(previous_parent);
+            let next = tables.next.walk(next_parent);
+
+            previous.namespace() != next.namespace() || previous.name() != next.name()
+        }
+        _ => false,
+    }
+}
+
+fn generated_column_changed(columns: MigrationPair<TableColumnWalker<'_>>) -> bool {
+    use sql_schema_describer::DefaultKind;
+
+    let kinds = columns.map(|c| c.default().map(|d| d.kind()));
+
+    match kinds.into_tuple() {
+        // An empty `dbgenerated()` in the datamodel means "leave whatever is in the database
+        // alone", so it's compatible with any actual column, generated or not.
+        (_, Some(DefaultKind::DbGenerated(None))) => false,
+        (Some(DefaultKind::Generated(prev, _)), Some(DefaultKind::Generated(next, _))) => {
+            !prev.eq_ignore_ascii_case(next)
+        }
+        (Some(DefaultKind::Generated(_, _)), _) | (_, Some(DefaultKind::Generated(_, _))) => true,
+        _ => false,
+    }
+}
+
+/// True if a column's domain type was added, removed, or redefined (different base type or
+/// `CHECK` constraint) between the two schema versions.
+fn domain_definition_changed(columns: MigrationPair<TableColumnWalker<'_>>) -> bool {
+    let pg_ext: MigrationPair<&PostgresSchemaExt> = columns.map(|c| c.schema.downcast_connector_data());
+    let domains = columns
+        .zip(pg_ext)
+        .map(|(c, ext)| ext.get_domain_for_column(c.id));
+
+    match domains.into_tuple() {
+        (None, None) => false,
+        (Some(prev), Some(next)) => {
+            prev.namespace_id != next.namespace_id
+                || prev.name != next.name
+                || prev.base_type != next.base_type
+                || prev.constraint != next.constraint
+        }
+        _ => true,
+    }
+}
+
 fn cockroach_column_type_change(columns: MigrationPair<TableColumnWalker<'_>>) -> Option<ColumnTypeChange> {
     use ColumnTypeChange::*;
 
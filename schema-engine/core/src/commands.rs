@@ -14,7 +14,7 @@ pub use diagnose_migration_history::{
     DiagnoseMigrationHistoryInput, DiagnoseMigrationHistoryOutput, DriftDiagnostic, HistoryDiagnostic,
 };
 
-pub use apply_migrations::apply_migrations;
+pub use apply_migrations::{apply_migrations, apply_migrations_with_progress, MigrationApplyProgress};
 pub use create_migration::create_migration;
 pub use dev_diagnostic::dev_diagnostic;
 pub use diagnose_migration_history::diagnose_migration_history;
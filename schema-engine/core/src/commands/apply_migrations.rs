@@ -3,14 +3,42 @@ use schema_connector::{
     migrations_directory::{error_on_changed_provider, list_migrations, MigrationDirectory},
     ConnectorError, MigrationRecord, Namespaces, PersistenceNotInitializedError, SchemaConnector,
 };
-use std::{path::Path, time::Instant};
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
 use tracing::Instrument;
 use user_facing_errors::schema_engine::FoundFailedMigrations;
 
+/// A progress event emitted by [`apply_migrations_with_progress`] around the application of each
+/// unapplied migration, so tooling can render progress through a long migration history instead
+/// of waiting on the batch as a whole.
+pub enum MigrationApplyProgress<'a> {
+    Started { migration_name: &'a str },
+    Finished { migration_name: &'a str, duration: Duration },
+}
+
 pub async fn apply_migrations(
     input: ApplyMigrationsInput,
     connector: &mut dyn SchemaConnector,
     namespaces: Option<Namespaces>,
+) -> CoreResult<ApplyMigrationsOutput> {
+    apply_migrations_with_progress(input, connector, namespaces, |_| {}).await
+}
+
+/// Like [`apply_migrations`], but invokes `on_progress` immediately before and after each
+/// migration is applied, with the migration name and (on completion) how long it took. Each
+/// migration is applied as a single script through the connector's `apply_script`, exactly as
+/// [`apply_migrations`] does. This function opens no transaction around a migration, so whether the
+/// statements a failing migration already ran are rolled back is up to that connector's
+/// `apply_script` and the database. `on_progress`'s `Finished` event fires only after a migration
+/// actually succeeds, and a failure surfaces the failing migration's name through the returned
+/// error the same way [`apply_migrations`] does.
+pub async fn apply_migrations_with_progress(
+    input: ApplyMigrationsInput,
+    connector: &mut dyn SchemaConnector,
+    namespaces: Option<Namespaces>,
+    mut on_progress: impl FnMut(MigrationApplyProgress<'_>),
 ) -> CoreResult<ApplyMigrationsOutput> {
     let start = Instant::now();
 
@@ -46,32 +74,29 @@ pub async fn apply_migrations(
     let mut applied_migration_names: Vec<String> = Vec::with_capacity(unapplied_migrations.len());
 
     for unapplied_migration in unapplied_migrations {
+        let migration_name = unapplied_migration.migration_name();
+        let migration_start = Instant::now();
+        on_progress(MigrationApplyProgress::Started { migration_name });
+
         let fut = async {
             let script = unapplied_migration
                 .read_migration_script()
                 .map_err(ConnectorError::from)?;
 
-            tracing::info!(
-                script = script.as_str(),
-                "Applying `{}`",
-                unapplied_migration.migration_name()
-            );
+            tracing::info!(script = script.as_str(), "Applying `{}`", migration_name);
 
             let migration_id = connector
                 .migration_persistence()
-                .record_migration_started(unapplied_migration.migration_name(), &script)
+                .record_migration_started(migration_name, &script)
                 .await?;
 
-            match connector
-                .apply_script(unapplied_migration.migration_name(), &script)
-                .await
-            {
+            match connector.apply_script(migration_name, &script).await {
                 Ok(()) => {
                     tracing::debug!("Successfully applied the script.");
                     let p = connector.migration_persistence();
                     p.record_successful_step(&migration_id).await?;
                     p.record_migration_finished(&migration_id).await?;
-                    applied_migration_names.push(unapplied_migration.migration_name().to_owned());
+                    applied_migration_names.push(migration_name.to_owned());
                     Ok(())
                 }
                 Err(err) => {
@@ -90,9 +115,14 @@ pub async fn apply_migrations(
         };
         fut.instrument(tracing::info_span!(
             "Applying migration",
-            migration_name = unapplied_migration.migration_name(),
+            migration_name = migration_name,
         ))
-        .await?
+        .await?;
+
+        on_progress(MigrationApplyProgress::Finished {
+            migration_name,
+            duration: migration_start.elapsed(),
+        });
     }
 
     Ok(ApplyMigrationsOutput {
@@ -357,6 +357,23 @@ impl<'a> TableAssertion<'a> {
         self
     }
 
+    #[track_caller]
+    pub fn assert_inherits(self, parent_table_name: &str) -> Self {
+        let pg_ext: &PostgresSchemaExt = self.table.schema.downcast_connector_data();
+        let parent = pg_ext.inherits(self.table.id).map(|id| self.table.walk(id).name());
+
+        assert_eq!(
+            parent,
+            Some(parent_table_name),
+            "Assertion failed. Expected table {} to inherit from {}, found {:?}.",
+            self.table.name(),
+            parent_table_name,
+            parent,
+        );
+
+        self
+    }
+
     pub fn assert_column_count(self, n: usize) -> Self {
         let columns_count = self.table.columns().count();
 
@@ -628,6 +645,47 @@ impl<'a> ColumnAssertion<'a> {
         self
     }
 
+    pub fn assert_generated(self) -> Self {
+        let found = self.column.default();
+
+        assert!(
+            matches!(found.map(|d| d.kind()), Some(DefaultKind::Generated(_, _))),
+            "Assertion failed. Expected `{}` to be a generated column, found {:?}",
+            self.column.name(),
+            found.map(|d| d.kind())
+        );
+
+        self
+    }
+
+    pub fn assert_domain_name(self, expected: &str) -> Self {
+        let pg_ext: &PostgresSchemaExt = self.column.schema.downcast_connector_data();
+        let found = pg_ext.get_domain_for_column(self.column.id).map(|d| d.name.as_str());
+
+        assert!(
+            found == Some(expected),
+            "Assertion failed. Expected `{}` to have domain type `{}`, found {:?}",
+            self.column.name(),
+            expected,
+            found
+        );
+
+        self
+    }
+
+    pub fn assert_not_generated(self) -> Self {
+        let found = self.column.default();
+
+        assert!(
+            !matches!(found.map(|d| d.kind()), Some(DefaultKind::Generated(_, _))),
+            "Assertion failed. Expected `{}` not to be a generated column, found {:?}",
+            self.column.name(),
+            found.map(|d| d.kind())
+        );
+
+        self
+    }
+
     pub fn assert_enum_default(self, expected: &str) -> Self {
         let default = self.column.default().unwrap();
 
@@ -1,5 +1,9 @@
 use indoc::{formatdoc, indoc};
 use pretty_assertions::assert_eq;
+use schema_core::{
+    commands::{apply_migrations_with_progress, MigrationApplyProgress},
+    json_rpc::types::ApplyMigrationsInput,
+};
 use sql_migration_tests::test_api::*;
 use std::io::Write;
 use user_facing_errors::{schema_engine::ApplyMigrationError, UserFacingError};
@@ -463,6 +467,145 @@ fn migrations_should_fail_on_an_uninitialized_nonempty_database(api: TestApi) {
     );
 }
 
+#[test_connector]
+fn apply_migrations_with_progress_reports_each_migration_in_order(mut api: TestApi) {
+    let dm1 = api.datamodel_with_provider(
+        r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#,
+    );
+
+    let migrations_directory = api.create_migrations_directory();
+
+    api.create_migration("initial", &dm1, &migrations_directory).send_sync();
+
+    let dm2 = api.datamodel_with_provider(
+        r#"
+        model Cat {
+            id          Int @id
+            name        String
+            fluffiness  Float
+        }
+    "#,
+    );
+
+    api.create_migration("second-migration", &dm2, &migrations_directory)
+        .send_sync();
+
+    let input = ApplyMigrationsInput {
+        migrations_directory_path: migrations_directory.path().to_str().unwrap().to_owned(),
+    };
+
+    let mut events = Vec::new();
+    let output = tok(apply_migrations_with_progress(input, &mut api.connector, None, |progress| {
+        let event = match progress {
+            MigrationApplyProgress::Started { migration_name } => format!("started {migration_name}"),
+            MigrationApplyProgress::Finished { migration_name, .. } => format!("finished {migration_name}"),
+        };
+        events.push(event);
+    }))
+    .unwrap();
+
+    assert_eq!(
+        output.applied_migration_names.len(),
+        2,
+        "expected both migrations to be applied"
+    );
+
+    assert_eq!(
+        events,
+        vec![
+            "started initial".to_owned(),
+            "finished initial".to_owned(),
+            "started second-migration".to_owned(),
+            "finished second-migration".to_owned(),
+        ]
+    );
+}
+
+#[test_connector]
+fn apply_migrations_resumes_from_the_first_unapplied_migration_after_a_failure(api: TestApi) {
+    let dm1 = api.datamodel_with_provider(
+        r#"
+        model Cat {
+            id      Int @id
+            name    String
+        }
+    "#,
+    );
+
+    let migrations_directory = api.create_migrations_directory();
+
+    api.create_migration("01-initial", &dm1, &migrations_directory)
+        .send_sync();
+
+    let dm2 = api.datamodel_with_provider(
+        r#"
+        model Cat {
+            id          Int @id
+            name        String
+            fluffiness  Float
+        }
+    "#,
+    );
+
+    let second = api
+        .create_migration("02-second", &dm2, &migrations_directory)
+        .send_sync();
+    let second_migration_name = second.output().generated_migration_name.clone().unwrap();
+    let second_script_path = second.migration_script_path();
+    let second_migration_script = std::fs::read_to_string(&second_script_path).unwrap();
+    second.modify_migration(|contents| contents.push_str("\nSELECT (^.^)_n;\n"));
+
+    let dm3 = api.datamodel_with_provider(
+        r#"
+        model Cat {
+            id          Int @id
+            name        String
+            fluffiness  Float
+            age         Int?
+        }
+    "#,
+    );
+
+    api.create_migration("03-third", &dm3, &migrations_directory)
+        .send_sync();
+
+    // The second migration's script is broken, so the batch fails there.
+    api.apply_migrations(&migrations_directory).send_unwrap_err();
+
+    let mut migrations = tok(api.migration_persistence().list_migrations()).unwrap().unwrap();
+
+    assert_eq!(
+        migrations.len(),
+        2,
+        "The third migration must not have been attempted."
+    );
+    migrations.remove(1).assert_migration_name("02-second").assert_failed();
+    migrations.remove(0).assert_migration_name("01-initial").assert_success();
+
+    // An operator inspects the failure, fixes the script and marks the failed step rolled back so
+    // the applier will retry it, rather than being stuck refusing to apply any further migration.
+    std::fs::write(&second_script_path, second_migration_script).unwrap();
+    api.mark_migration_rolled_back(&second_migration_name).send();
+
+    // Resuming must skip the already-applied first migration and continue from the second.
+    api.apply_migrations(&migrations_directory)
+        .send_sync()
+        .assert_applied_migrations(&["02-second", "03-third"]);
+
+    let migrations = tok(api.migration_persistence().list_migrations()).unwrap().unwrap();
+
+    assert_eq!(
+        migrations.iter().filter(|m| m.migration_name.ends_with("01-initial")).count(),
+        1,
+        "The first migration must not have been reapplied."
+    );
+}
+
 // Reference for the tables created by PostGIS: https://postgis.net/docs/manual-1.4/ch04.html#id418599
 #[test_connector(tags(Postgres))]
 fn migrations_should_succeed_on_an_uninitialized_nonempty_database_with_postgis_tables(api: TestApi) {
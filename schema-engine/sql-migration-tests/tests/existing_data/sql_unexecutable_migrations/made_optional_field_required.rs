@@ -44,6 +44,62 @@ fn making_an_optional_field_required_with_data_without_a_default_is_unexecutable
         .assert_single_row(|row| row.assert_text_value("id", "abc").assert_text_value("name", "george"));
 }
 
+// On Postgres, promoting the column backfills existing NULLs with the new default before setting
+// `NOT NULL`, so the forced migration succeeds instead of failing at the database level.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn making_an_optional_field_required_with_data_with_a_default_backfills_on_postgres(api: TestApi) {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            name String
+            age Int?
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm1).send().assert_green();
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("name", "george")
+        .result_raw();
+
+    api.insert("Test")
+        .value("id", "def")
+        .value("name", "X Æ A-12")
+        .value("age", 7i64)
+        .result_raw();
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            name String
+            age Int @default(84)
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm2).force(true).send();
+
+    api.assert_schema().assert_table("Test", |table| {
+        table.assert_column("age", |column| {
+            column
+                .assert_is_required()
+                .assert_default(Some(DefaultValue::value(84)))
+        })
+    });
+
+    let rows = api.dump_table("Test");
+
+    assert_eq!(
+        rows.into_iter()
+            .map(|row| row.into_iter().collect::<Vec<Value>>())
+            .collect::<Vec<_>>(),
+        &[
+            &[Value::text("abc"), Value::text("george"), Value::int32(84)],
+            &[Value::text("def"), Value::text("X Æ A-12"), Value::int32(7)],
+        ]
+    );
+}
+
 #[test_connector(tags(Sqlite))]
 fn making_an_optional_field_required_with_data_with_a_default_works(api: TestApi) {
     let dm1 = r#"
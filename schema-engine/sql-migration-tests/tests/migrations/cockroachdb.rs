@@ -1083,6 +1083,70 @@ fn alter_sequence(api: TestApi) {
     api.schema_push(schema2).send().assert_green().assert_no_steps();
 }
 
+#[test_connector(tags(CockroachDb))]
+fn sequences_with_cycle_can_be_created(api: TestApi) {
+    let dm = r#"
+        datasource test {
+            provider = "cockroachdb"
+            url = env("TEST_DATABASE_URL")
+        }
+
+        model Test {
+            Id Int @id @default(sequence(cache: 4, cycle: true))
+        }
+    "#;
+
+    api.schema_push(dm).send().assert_green();
+    api.schema_push(dm).send().assert_green().assert_no_steps();
+
+    let sql = expect![[r#"
+        -- CreateTable
+        CREATE TABLE "Test" (
+            "Id" INT4 NOT NULL GENERATED BY DEFAULT AS IDENTITY (CACHE 4 CYCLE),
+
+            CONSTRAINT "Test_pkey" PRIMARY KEY ("Id")
+        );
+    "#]];
+    api.expect_sql_for_schema(dm, &sql);
+}
+
+#[test_connector(tags(CockroachDb))]
+fn alter_sequence_cycle(api: TestApi) {
+    let schema1 = r#"
+        datasource db {
+            provider = "cockroachdb"
+            url = env("TEST_DATABASE_URL")
+        }
+
+        model Test {
+            Id Int @id @default(sequence(cycle: false))
+        }
+    "#;
+
+    let schema2 = r#"
+        datasource db {
+            provider = "cockroachdb"
+            url = env("TEST_DATABASE_URL")
+        }
+
+        model Test {
+            Id Int @id @default(sequence(cycle: true))
+        }
+    "#;
+
+    api.schema_push(schema1)
+        .send()
+        .assert_green()
+        .assert_has_executed_steps();
+    api.schema_push(schema1).send().assert_green().assert_no_steps();
+
+    api.schema_push(schema2)
+        .send()
+        .assert_green()
+        .assert_has_executed_steps();
+    api.schema_push(schema2).send().assert_green().assert_no_steps();
+}
+
 // https://github.com/prisma/prisma/issues/13842
 #[test_connector(tags(CockroachDb))]
 fn mapped_enum_defaults_must_work(api: TestApi) {
@@ -316,3 +316,88 @@ fn changing_all_referenced_columns_of_foreign_key_works(api: TestApi) {
 
     api.schema_push_w_datasource(dm2).send().assert_green();
 }
+
+// Two tables with mutual foreign keys can't both be created with the FK inlined in `CREATE
+// TABLE`, since whichever table is created first would reference a table that doesn't exist yet.
+// The differ must create both tables first, then add the foreign keys with separate `ADD
+// CONSTRAINT` steps.
+#[test_connector(exclude(Vitess))]
+fn mutually_referencing_tables_can_be_created(api: TestApi) {
+    let dm = r#"
+        model A {
+            id    Int @id
+            b_id  Int? @unique
+            b     B?  @relation("Rel1", fields: [b_id], references: [id])
+            b_owner B? @relation("Rel2")
+        }
+
+        model B {
+            id    Int @id
+            a_id  Int? @unique
+            a     A?  @relation("Rel2", fields: [a_id], references: [id])
+            a_owner A? @relation("Rel1")
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green();
+
+    api.assert_schema().assert_table("A", |table| {
+        table
+            .assert_foreign_keys_count(1)
+            .assert_fk_on_columns(&["b_id"], |fk| fk.assert_references("B", &["id"]))
+    });
+
+    api.assert_schema().assert_table("B", |table| {
+        table
+            .assert_foreign_keys_count(1)
+            .assert_fk_on_columns(&["a_id"], |fk| fk.assert_references("A", &["id"]))
+    });
+}
+
+// Same as `mutually_referencing_tables_can_be_created`, but with a cycle spanning three tables
+// (A -> B -> C -> A) instead of two tables referencing each other directly.
+#[test_connector(exclude(Vitess))]
+fn three_table_foreign_key_cycle_can_be_created(api: TestApi) {
+    let dm = r#"
+        model A {
+            id   Int @id
+            b_id Int? @unique
+            b    B?  @relation(fields: [b_id], references: [id])
+            c    C?
+        }
+
+        model B {
+            id   Int @id
+            c_id Int? @unique
+            c    C?  @relation(fields: [c_id], references: [id])
+            a    A?
+        }
+
+        model C {
+            id   Int @id
+            a_id Int? @unique
+            a    A?  @relation(fields: [a_id], references: [id])
+            b    B?
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green();
+
+    api.assert_schema().assert_table("A", |table| {
+        table
+            .assert_foreign_keys_count(1)
+            .assert_fk_on_columns(&["b_id"], |fk| fk.assert_references("B", &["id"]))
+    });
+
+    api.assert_schema().assert_table("B", |table| {
+        table
+            .assert_foreign_keys_count(1)
+            .assert_fk_on_columns(&["c_id"], |fk| fk.assert_references("C", &["id"]))
+    });
+
+    api.assert_schema().assert_table("C", |table| {
+        table
+            .assert_foreign_keys_count(1)
+            .assert_fk_on_columns(&["a_id"], |fk| fk.assert_references("A", &["id"]))
+    });
+}
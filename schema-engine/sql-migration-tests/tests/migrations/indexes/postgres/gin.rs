@@ -222,6 +222,65 @@ fn from_jsonb_ops_to_jsonb_path_ops(api: TestApi) {
     });
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn gin_trgm_ops(api: TestApi) {
+    let dm = r#"
+        model A {
+          id   Int     @id @default(autoincrement())
+          data String?
+
+          @@index([data(ops: GinTrgmOps)], type: Gin)
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green();
+
+    api.assert_schema().assert_table("A", |table| {
+        table
+            .assert_has_column("data")
+            .assert_index_on_columns(&["data"], |idx| {
+                idx.assert_algorithm(SqlIndexAlgorithm::Gin)
+                    .assert_column("data", |attrs| attrs.assert_ops(SQLOperatorClassKind::GinTrgmOps))
+            })
+    });
+
+    api.schema_push_w_datasource(dm).send().assert_no_steps();
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn from_gin_trgm_ops_to_gist_trgm_ops_recreates_the_index(api: TestApi) {
+    let dm = r#"
+        model A {
+          id   Int     @id @default(autoincrement())
+          data String?
+
+          @@index([data(ops: GinTrgmOps)], type: Gin)
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green();
+
+    let dm = r#"
+        model A {
+          id   Int     @id @default(autoincrement())
+          data String?
+
+          @@index([data(ops: GistTrgmOps)], type: Gist)
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green();
+
+    api.assert_schema().assert_table("A", |table| {
+        table
+            .assert_has_column("data")
+            .assert_index_on_columns(&["data"], |idx| {
+                idx.assert_algorithm(SqlIndexAlgorithm::Gist)
+                    .assert_column("data", |attrs| attrs.assert_ops(SQLOperatorClassKind::GistTrgmOps))
+            })
+    });
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb, Postgres9))]
 fn compound_index_with_different_ops(api: TestApi) {
     let dm = r#"
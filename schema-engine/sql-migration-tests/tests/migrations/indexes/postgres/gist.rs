@@ -63,6 +63,31 @@ fn gist_inet_ops(api: TestApi) {
     api.schema_push_w_datasource(dm).send().assert_no_steps();
 }
 
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn gist_trgm_ops(api: TestApi) {
+    let dm = r#"
+        model A {
+          id   Int     @id @default(autoincrement())
+          data String?
+
+          @@index([data(ops: GistTrgmOps)], type: Gist)
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green();
+
+    api.assert_schema().assert_table("A", |table| {
+        table
+            .assert_has_column("data")
+            .assert_index_on_columns(&["data"], |idx| {
+                idx.assert_algorithm(SqlIndexAlgorithm::Gist)
+                    .assert_column("data", |attrs| attrs.assert_ops(SQLOperatorClassKind::GistTrgmOps))
+            })
+    });
+
+    api.schema_push_w_datasource(dm).send().assert_no_steps();
+}
+
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 fn gist_raw_ops(api: TestApi) {
     let dm = r#"
@@ -346,3 +346,60 @@ fn float_columns(api: TestApi) {
     api.schema_push(schema).send().assert_green();
     api.schema_push(schema).send().assert_green().assert_no_steps();
 }
+
+#[test_connector(tags(Mssql))]
+fn persisted_computed_columns_are_introspected(api: TestApi) {
+    let schema = api.schema_name();
+
+    api.raw_cmd(&format!(
+        r#"
+            CREATE TABLE [{schema}].[table] (
+                [id] NVARCHAR(1000) NOT NULL,
+                [hereBeDragons] AS ('this row ID is: ' + [id]) PERSISTED,
+                CONSTRAINT [table_pkey] PRIMARY KEY CLUSTERED ([id])
+            )
+        "#
+    ));
+
+    api.assert_schema().assert_table("table", |table| {
+        table.assert_column("hereBeDragons", |col| col.assert_generated())
+    });
+}
+
+#[test_connector(tags(Mssql))]
+fn changing_a_computed_column_expression_requires_a_rebuild(mut api: TestApi) {
+    let schema = api.schema_name();
+
+    api.raw_cmd(&format!(
+        r#"
+            CREATE TABLE [{schema}].[table] (
+                [id] NVARCHAR(1000) NOT NULL,
+                [hereBeDragons] AS ('this row ID is: ' + [id]) PERSISTED,
+                CONSTRAINT [table_pkey] PRIMARY KEY CLUSTERED ([id])
+            )
+        "#
+    ));
+
+    let previous = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    api.raw_cmd(&format!(r#"ALTER TABLE [{schema}].[table] DROP COLUMN [hereBeDragons]"#));
+    api.raw_cmd(&format!(
+        r#"ALTER TABLE [{schema}].[table] ADD [hereBeDragons] AS ('row: ' + [id]) PERSISTED"#
+    ));
+
+    let next = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    let migration = api.connector.diff(previous, next);
+    let script = api.connector.render_script(&migration, &Default::default()).unwrap();
+
+    assert!(
+        script.to_uppercase().contains("DROP TABLE") || script.contains("_prisma_new_table"),
+        "expected the migration to rebuild the `table` table, got:\n{script}"
+    );
+}
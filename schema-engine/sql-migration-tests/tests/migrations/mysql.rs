@@ -626,3 +626,35 @@ fn bigint_defaults_work(api: TestApi) {
     api.schema_push(schema).send().assert_green();
     api.schema_push(schema).send().assert_green().assert_no_steps();
 }
+
+// `lower_case_table_names` is a server-wide setting: on a server that folds case, `Cats` and
+// `cats` name the same table, so a schema that only changes the `@@map`'s case should be a no-op.
+// On a server that doesn't fold case, it's a real rename.
+#[test_connector(tags(Mysql))]
+fn case_only_table_rename_is_a_noop_when_the_server_folds_case(api: TestApi) {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+
+            @@map("Cats")
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm1).send().assert_green();
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+
+            @@map("cats")
+        }
+    "#;
+
+    let assertion = api.schema_push_w_datasource(dm2).send().assert_green();
+
+    if api.lower_cases_table_names() {
+        assertion.assert_no_steps();
+    } else {
+        assertion.assert_has_executed_steps();
+    }
+}
@@ -1,4 +1,5 @@
 mod extensions;
+mod inheritance;
 mod introspection;
 mod multi_schema;
 
@@ -39,6 +40,163 @@ fn enums_can_be_dropped_on_postgres(api: TestApi) {
     api.assert_schema().assert_has_no_enum("CatMood");
 }
 
+#[test_connector(tags(Postgres), preview_features("multiSchema"), namespaces("cats"))]
+fn schema_qualified_enums_are_rendered_and_reintrospection_is_a_no_op(api: TestApi) {
+    let datasource = api.datasource_block_with(&[("schemas", r#"["cats"]"#)]);
+    let generator = api.generator_block();
+
+    let dm = format!(
+        r#"
+        {datasource}
+
+        {generator}
+
+        model Cat {{
+            id String @id
+            name String
+            mood CatMood
+
+            @@schema("cats")
+        }}
+
+        enum CatMood {{
+            ANGRY
+            HUNGRY
+            CUDDLY
+
+            @@schema("cats")
+        }}
+    "#
+    );
+
+    api.schema_push(&dm).send().assert_green().assert_has_executed_steps();
+    api.assert_schema()
+        .assert_enum("CatMood", |r#enum| r#enum.assert_namespace("cats"));
+
+    // Applying the same schema again must be a no-op: introspecting the enum back always resolves
+    // its namespace to `Some("cats")`, and that must compare equal to the namespace calculated
+    // from the very same `@@schema("cats")` attribute, not be seen as a schema move.
+    api.schema_push(&dm).send().assert_green().assert_no_steps();
+}
+
+#[test_connector(tags(Postgres))]
+fn grants_are_rendered_when_a_table_is_created(api: TestApi) {
+    let schema = r#"
+        datasource db {
+          provider = "postgresql"
+          url = "postgres://"
+        }
+
+        model Cat {
+            id Int @id
+
+            @@grant(role: "app_user", privileges: ["select", "insert"])
+        }
+    "#;
+
+    let expected_sql = expect![[r#"
+        -- CreateTable
+        CREATE TABLE "Cat" (
+            "id" INTEGER NOT NULL,
+
+            CONSTRAINT "Cat_pkey" PRIMARY KEY ("id")
+        );
+        GRANT SELECT, INSERT ON "Cat" TO "app_user";
+    "#]];
+
+    api.expect_sql_for_schema(schema, &expected_sql);
+}
+
+#[test_connector(tags(Postgres))]
+fn grant_changes_on_an_already_existing_table_are_not_yet_diffed(api: TestApi) {
+    // Known gap: grants are only (re-)emitted when a table's own `CreateTable` step fires
+    // (first creation, or a full table rebuild). We don't introspect real grants from the
+    // database, so the differ has no way to notice that `@@grant` changed on a table that is
+    // otherwise unmodified, and no migration step is generated for it. Widening the differ to
+    // cover this case would require trusting hand-written introspection queries against
+    // `pg_catalog`/`information_schema` across three connectors with no way to verify them here.
+    let dm1 = r#"
+        datasource db {
+          provider = "postgresql"
+          url = "postgres://"
+        }
+
+        model Cat {
+            id Int @id
+
+            @@grant(role: "app_user", privileges: ["select"])
+        }
+    "#;
+
+    api.schema_push(dm1).send().assert_green().assert_has_executed_steps();
+
+    let dm2 = r#"
+        datasource db {
+          provider = "postgresql"
+          url = "postgres://"
+        }
+
+        model Cat {
+            id Int @id
+
+            @@grant(role: "app_user", privileges: ["select", "insert"])
+        }
+    "#;
+
+    api.schema_push(dm2).send().assert_green().assert_no_steps();
+}
+
+#[test_connector(tags(Postgres))]
+fn triggers_survive_an_unrelated_migration_to_the_same_table(mut api: TestApi) {
+    let dm1 = r#"
+        datasource db {
+          provider = "postgresql"
+          url = "postgres://"
+        }
+
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.schema_push(dm1).send().assert_green().assert_has_executed_steps();
+
+    api.raw_cmd(&format!(
+        "CREATE FUNCTION \"{schema}\".\"notify_cat_change\"() RETURNS trigger AS $$
+            BEGIN
+                RETURN NEW;
+            END;
+        $$ LANGUAGE plpgsql",
+        schema = api.schema_name(),
+    ));
+
+    api.raw_cmd(&format!(
+        "CREATE TRIGGER \"cat_changed\" AFTER INSERT ON \"{schema}\".\"Cat\"
+            FOR EACH ROW EXECUTE FUNCTION \"{schema}\".\"notify_cat_change\"()",
+        schema = api.schema_name(),
+    ));
+
+    let dm2 = r#"
+        datasource db {
+          provider = "postgresql"
+          url = "postgres://"
+        }
+
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    api.schema_push(dm2).send().assert_green().assert_has_executed_steps();
+
+    let schema = api.assert_schema().into_schema();
+    let table = schema.table_walker("Cat").unwrap();
+    let trigger_names: Vec<_> = table.triggers().map(|trigger| trigger.name.as_str()).collect();
+
+    assert_eq!(trigger_names, vec!["cat_changed"]);
+}
+
 // Reference for the tables created by PostGIS: https://postgis.net/docs/manual-1.4/ch04.html#id418599
 #[test_connector(tags(Postgres))]
 fn existing_postgis_tables_must_not_be_migrated(api: TestApi) {
@@ -401,6 +559,70 @@ fn foreign_key_renaming_to_default_works(api: TestApi) {
     api.schema_push(target_schema).send().assert_green().assert_no_steps();
 }
 
+// exclude: CockroachDB does not expose `pg_depend`-based sequence ownership the same way.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn autoincrement_sequence_is_owned_by_its_column(api: TestApi) {
+    let dm = r#"
+        model User {
+            id Int @id @default(autoincrement())
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm).send().assert_green();
+
+    let schema = api.schema_name();
+    let owned = api.query_raw(
+        &format!(
+            r#"
+            SELECT 1
+            FROM pg_depend dep
+            JOIN pg_class seq ON seq.oid = dep.objid AND seq.relkind = 'S'
+            JOIN pg_class tbl ON tbl.oid = dep.refobjid
+            JOIN pg_namespace ns ON ns.oid = tbl.relnamespace
+            JOIN pg_attribute att ON att.attrelid = dep.refobjid AND att.attnum = dep.refobjsubid
+            WHERE dep.deptype = 'a' AND ns.nspname = '{schema}' AND tbl.relname = 'User' AND att.attname = 'id'
+            "#
+        ),
+        &[],
+    );
+
+    assert_eq!(1, owned.len());
+}
+
+// exclude: CockroachDB does not expose `pg_depend`-based sequence ownership the same way.
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn dropping_an_autoincrement_column_drops_its_owned_sequence(api: TestApi) {
+    let dm1 = r#"
+        model User {
+            id   Int @id
+            rank Int @default(autoincrement())
+        }
+    "#;
+
+    let dm2 = r#"
+        model User {
+            id Int @id
+        }
+    "#;
+
+    api.schema_push_w_datasource(dm1).send().assert_green();
+
+    let schema = api.schema_name();
+    let sequences_before = api.query_raw(
+        &format!("SELECT 1 FROM information_schema.sequences WHERE sequence_schema = '{schema}'"),
+        &[],
+    );
+    assert_eq!(1, sequences_before.len());
+
+    api.schema_push_w_datasource(dm2).send().assert_green();
+
+    let sequences_after = api.query_raw(
+        &format!("SELECT 1 FROM information_schema.sequences WHERE sequence_schema = '{schema}'"),
+        &[],
+    );
+    assert_eq!(0, sequences_after.len());
+}
+
 // exclude: enum migrations work differently on cockroachdb, there is no migration
 #[test_connector(tags(Postgres), exclude(CockroachDb))]
 fn failing_enum_migrations_should_not_be_partially_applied(api: TestApi) {
@@ -735,3 +957,376 @@ fn dbgenerated_on_generated_columns_is_idempotent(api: TestApi) {
 
     api.schema_push(schema).send().assert_green().assert_no_steps();
 }
+
+#[test_connector(tags(Postgres12), exclude(CockroachDb))]
+fn generated_columns_are_distinguished_from_function_defaults(api: TestApi) {
+    let sql = r#"
+        CREATE TABLE "table" (
+         "id" TEXT NOT NULL,
+         "hereBeDragons" TEXT NOT NULL GENERATED ALWAYS AS ('this row ID is: '::text || "id") STORED,
+         "createdAt" TIMESTAMP NOT NULL DEFAULT now(),
+
+         CONSTRAINT "table_pkey" PRIMARY KEY ("id")
+        );
+    "#;
+
+    api.raw_cmd(sql);
+
+    api.assert_schema().assert_table("table", |table| {
+        table
+            .assert_column("hereBeDragons", |col| col.assert_generated())
+            .assert_column("createdAt", |col| col.assert_not_generated())
+    });
+}
+
+#[test_connector(tags(Postgres12), exclude(CockroachDb))]
+fn changing_a_generated_column_expression_requires_a_rebuild(mut api: TestApi) {
+    api.raw_cmd(
+        r#"
+        CREATE TABLE "table" (
+         "id" TEXT NOT NULL,
+         "hereBeDragons" TEXT NOT NULL GENERATED ALWAYS AS ('this row ID is: '::text || "id") STORED,
+
+         CONSTRAINT "table_pkey" PRIMARY KEY ("id")
+        );
+    "#,
+    );
+
+    let previous = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    api.raw_cmd(r#"ALTER TABLE "table" DROP COLUMN "hereBeDragons""#);
+    api.raw_cmd(
+        r#"ALTER TABLE "table" ADD COLUMN "hereBeDragons" TEXT NOT NULL GENERATED ALWAYS AS ('row: '::text || "id") STORED"#,
+    );
+
+    let next = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    let migration = api.connector.diff(previous, next);
+    let script = api.connector.render_script(&migration, &Default::default()).unwrap();
+
+    assert!(
+        script.contains("_prisma_new_table") || script.to_uppercase().contains("DROP TABLE"),
+        "expected the migration to rebuild the `table` table, got:\n{script}"
+    );
+}
+
+#[test_connector(tags(Postgres12), exclude(CockroachDb))]
+fn promoting_a_nullable_column_to_not_null_validates_via_a_check_constraint(mut api: TestApi) {
+    api.raw_cmd(
+        r#"
+        CREATE TABLE "table" (
+            "id" TEXT NOT NULL,
+            "age" INTEGER,
+
+            CONSTRAINT "table_pkey" PRIMARY KEY ("id")
+        );
+    "#,
+    );
+
+    let previous = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    api.raw_cmd(r#"ALTER TABLE "table" ALTER COLUMN "age" SET NOT NULL"#);
+
+    let next = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    let migration = api.connector.diff(previous, next);
+    let script = api.connector.render_script(&migration, &Default::default()).unwrap();
+
+    assert!(
+        script.contains("NOT VALID") && script.to_uppercase().contains("VALIDATE CONSTRAINT"),
+        "expected the migration to validate a CHECK constraint before promoting to NOT NULL, got:\n{script}"
+    );
+}
+
+#[test_connector(tags(Postgres12), exclude(CockroachDb))]
+fn columns_using_a_domain_type_are_not_flattened_to_their_base_type(api: TestApi) {
+    api.raw_cmd(r#"CREATE DOMAIN "positiveInt" AS INTEGER CHECK (VALUE > 0)"#);
+
+    api.raw_cmd(
+        r#"
+        CREATE TABLE "table" (
+            "id" TEXT NOT NULL,
+            "quantity" "positiveInt" NOT NULL,
+
+            CONSTRAINT "table_pkey" PRIMARY KEY ("id")
+        );
+    "#,
+    );
+
+    api.assert_schema().assert_table("table", |table| {
+        table.assert_column("quantity", |col| col.assert_domain_name("positiveInt"))
+    });
+}
+
+#[test_connector(tags(Postgres12), exclude(CockroachDb))]
+fn reintrospecting_a_domain_typed_column_is_idempotent(mut api: TestApi) {
+    api.raw_cmd(r#"CREATE DOMAIN "positiveInt" AS INTEGER CHECK (VALUE > 0)"#);
+
+    api.raw_cmd(
+        r#"
+        CREATE TABLE "table" (
+            "id" TEXT NOT NULL,
+            "quantity" "positiveInt" NOT NULL,
+
+            CONSTRAINT "table_pkey" PRIMARY KEY ("id")
+        );
+    "#,
+    );
+
+    let previous = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    let next = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    let migration = api.connector.diff(previous, next);
+    let script = api.connector.render_script(&migration, &Default::default()).unwrap();
+
+    assert!(script.is_empty(), "expected a no-op diff, got:\n{script}");
+}
+
+#[test_connector(tags(Postgres12), exclude(CockroachDb))]
+fn changing_a_domain_constraint_requires_a_rebuild(mut api: TestApi) {
+    api.raw_cmd(r#"CREATE DOMAIN "positiveInt" AS INTEGER CHECK (VALUE > 0)"#);
+
+    api.raw_cmd(
+        r#"
+        CREATE TABLE "table" (
+            "id" TEXT NOT NULL,
+            "quantity" "positiveInt" NOT NULL,
+
+            CONSTRAINT "table_pkey" PRIMARY KEY ("id")
+        );
+    "#,
+    );
+
+    let previous = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    api.raw_cmd(r#"ALTER DOMAIN "positiveInt" DROP CONSTRAINT "positiveInt_check""#);
+    api.raw_cmd(r#"ALTER DOMAIN "positiveInt" ADD CONSTRAINT "positiveInt_check" CHECK (VALUE >= 0)"#);
+
+    let next = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    let migration = api.connector.diff(previous, next);
+    let script = api.connector.render_script(&migration, &Default::default()).unwrap();
+
+    assert!(
+        script.contains("_prisma_new_table") || script.to_uppercase().contains("DROP TABLE"),
+        "expected the migration to rebuild the `table` table, got:\n{script}"
+    );
+}
+
+#[test_connector(tags(Postgres12), exclude(CockroachDb))]
+fn reintrospecting_a_column_storage_mode_is_idempotent(mut api: TestApi) {
+    api.raw_cmd(
+        r#"
+        CREATE TABLE "table" (
+            "id" TEXT NOT NULL,
+            "content" TEXT NOT NULL,
+
+            CONSTRAINT "table_pkey" PRIMARY KEY ("id")
+        );
+    "#,
+    );
+
+    api.raw_cmd(r#"ALTER TABLE "table" ALTER COLUMN "content" SET STORAGE EXTERNAL"#);
+
+    let previous = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    let next = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    let migration = api.connector.diff(previous, next);
+    let script = api.connector.render_script(&migration, &Default::default()).unwrap();
+
+    assert!(script.is_empty(), "expected a no-op diff, got:\n{script}");
+}
+
+#[test_connector(tags(Postgres12), exclude(CockroachDb))]
+fn changing_a_column_storage_mode_produces_an_alter_column_statement(mut api: TestApi) {
+    api.raw_cmd(
+        r#"
+        CREATE TABLE "table" (
+            "id" TEXT NOT NULL,
+            "content" TEXT NOT NULL,
+
+            CONSTRAINT "table_pkey" PRIMARY KEY ("id")
+        );
+    "#,
+    );
+
+    let previous = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    api.raw_cmd(r#"ALTER TABLE "table" ALTER COLUMN "content" SET STORAGE EXTERNAL"#);
+
+    let next = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    let migration = api.connector.diff(previous, next);
+    let script = api.connector.render_script(&migration, &Default::default()).unwrap();
+
+    assert!(
+        script.to_uppercase().contains("SET STORAGE EXTERNAL"),
+        "expected the migration to alter the column's storage mode, got:\n{script}"
+    );
+}
+
+#[test_connector(tags(Postgres14), exclude(CockroachDb))]
+fn changing_a_column_compression_method_produces_an_alter_column_statement(mut api: TestApi) {
+    api.raw_cmd(
+        r#"
+        CREATE TABLE "table" (
+            "id" TEXT NOT NULL,
+            "content" TEXT NOT NULL,
+
+            CONSTRAINT "table_pkey" PRIMARY KEY ("id")
+        );
+    "#,
+    );
+
+    let previous = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    api.raw_cmd(r#"ALTER TABLE "table" ALTER COLUMN "content" SET COMPRESSION lz4"#);
+
+    let next = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    let migration = api.connector.diff(previous, next);
+    let script = api.connector.render_script(&migration, &Default::default()).unwrap();
+
+    assert!(
+        script.to_uppercase().contains("SET COMPRESSION LZ4"),
+        "expected the migration to alter the column's compression method, got:\n{script}"
+    );
+}
+
+#[test_connector(tags(Postgres13), exclude(CockroachDb))]
+fn a_column_compression_method_change_is_ignored_on_servers_that_predate_it(mut api: TestApi) {
+    // Postgres 13 has no `pg_attribute.attcompression` column at all, so a compression method set
+    // by hand on a newer server can't even be introspected here — there is nothing to diff, and
+    // certainly nothing to reject: the feature simply doesn't exist yet on this connection.
+    api.raw_cmd(
+        r#"
+        CREATE TABLE "table" (
+            "id" TEXT NOT NULL,
+            "content" TEXT NOT NULL,
+
+            CONSTRAINT "table_pkey" PRIMARY KEY ("id")
+        );
+    "#,
+    );
+
+    let previous = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    let next = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    let migration = api.connector.diff(previous, next);
+    let script = api.connector.render_script(&migration, &Default::default()).unwrap();
+
+    assert!(script.is_empty(), "expected a no-op diff, got:\n{script}");
+}
+
+#[test_connector(tags(Postgres))]
+fn adopting_a_legacy_schema_preserves_custom_unique_constraint_names(mut api: TestApi) {
+    api.raw_cmd(
+        r#"
+        CREATE TABLE "User" (
+            "id" INTEGER NOT NULL,
+            "email" TEXT NOT NULL,
+
+            CONSTRAINT "User_pkey" PRIMARY KEY ("id"),
+            CONSTRAINT "legacy_email_ops_constraint" UNIQUE ("email")
+        );
+        "#,
+    );
+
+    let target_schema = r#"
+        datasource db {
+            provider = "postgresql"
+            url      = env("TEST_DATABASE_URL")
+        }
+
+        model User {
+            id    Int    @id
+            email String @unique
+        }
+    "#;
+
+    let migration = api.connector_diff(
+        DiffTarget::Database,
+        DiffTarget::Datamodel(SourceFile::new_static(target_schema)),
+        None,
+    );
+
+    assert!(
+        migration.is_empty(),
+        "expected the introspected constraint name to be preserved, got:\n{migration}"
+    );
+
+    let target_schema_with_column_set_change = r#"
+        datasource db {
+            provider = "postgresql"
+            url      = env("TEST_DATABASE_URL")
+        }
+
+        model User {
+            id       Int    @id
+            email    String
+            username String @unique
+        }
+    "#;
+
+    let migration = api.connector_diff(
+        DiffTarget::Database,
+        DiffTarget::Datamodel(SourceFile::new_static(target_schema_with_column_set_change)),
+        None,
+    );
+
+    assert!(
+        migration.to_uppercase().contains("USERNAME"),
+        "expected a genuine column-set change to still produce a step, got:\n{migration}"
+    );
+}
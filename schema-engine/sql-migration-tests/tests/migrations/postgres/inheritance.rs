@@ -0,0 +1,41 @@
+use schema_core::schema_connector::DiffTarget;
+use sql_migration_tests::test_api::*;
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn an_inheriting_table_is_introspected_with_its_parent(api: TestApi) {
+    api.raw_cmd("CREATE TABLE parent (id INTEGER PRIMARY KEY, name TEXT NOT NULL)");
+    api.raw_cmd("CREATE TABLE child (extra TEXT) INHERITS (parent)");
+
+    api.assert_schema()
+        .assert_table("child", |table| table.assert_inherits("parent"));
+}
+
+#[test_connector(tags(Postgres), exclude(CockroachDb))]
+fn changing_the_inheritance_parent_requires_a_rebuild(mut api: TestApi) {
+    api.raw_cmd("CREATE TABLE parent_a (id INTEGER PRIMARY KEY)");
+    api.raw_cmd("CREATE TABLE parent_b (id INTEGER PRIMARY KEY)");
+    api.raw_cmd("CREATE TABLE child (extra TEXT) INHERITS (parent_a)");
+
+    let previous = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    api.raw_cmd("ALTER TABLE child NO INHERIT parent_a");
+    api.raw_cmd("ALTER TABLE child INHERIT parent_b");
+
+    let next = tok(api
+        .connector
+        .database_schema_from_diff_target(DiffTarget::Database, None, None))
+    .unwrap();
+
+    let migration = api.connector.diff(previous, next);
+    let script = api.connector.render_script(&migration, &Default::default()).unwrap();
+
+    // A changed `INHERITS` parent cannot be expressed as a plain `ALTER TABLE`. The table must be
+    // rebuilt instead, so the rendered script recreates it rather than issuing an in-place change.
+    assert!(
+        script.contains("_prisma_new_child") || script.to_uppercase().contains("DROP TABLE"),
+        "expected the migration to rebuild the `child` table, got:\n{script}"
+    );
+}
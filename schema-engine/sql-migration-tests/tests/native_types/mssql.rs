@@ -2198,3 +2198,17 @@ fn typescript_starter_schema_with_different_native_types_is_idempotent(api: Test
         .assert_green()
         .assert_no_steps();
 }
+
+#[test_connector(tags(Mssql))]
+fn varchar_with_a_zero_length_is_rejected(api: TestApi) {
+    let dm = r#"
+        model Post {
+            id    Int    @id @default(autoincrement())
+            title String @db.VarChar(0)
+        }
+    "#;
+
+    let error = api.schema_push_w_datasource(dm).send_unwrap_err().to_string();
+
+    assert!(error.contains("length argument of the native type must be greater than 0"));
+}
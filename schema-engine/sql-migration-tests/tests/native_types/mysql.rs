@@ -1057,3 +1057,18 @@ fn time_is_idempotent(api: TestApi) {
         .assert_green()
         .assert_no_steps();
 }
+
+#[test_connector(tags(Mysql))]
+fn decimal_with_scale_larger_than_precision_is_rejected(api: TestApi) {
+    let dm = r#"
+        model Post {
+            id     Int     @id @default(autoincrement())
+            amount Decimal @db.Decimal(2, 10)
+        }
+    "#;
+
+    // Caught by the datamodel parser already, before schema calculation is ever reached.
+    let error = api.schema_push_w_datasource(dm).send_unwrap_err().to_string();
+
+    assert!(error.contains("The scale must not be larger than the precision"));
+}
@@ -79,7 +79,12 @@ pub struct SqlSchema {
     /// All columns of indexes.
     index_columns: Vec<IndexColumn>,
     /// Check constraints for every table.
-    check_constraints: Vec<(TableId, String)>,
+    check_constraints: Vec<(TableId, CheckConstraint)>,
+    /// Grants declared on every table, via `@@grant` in the datamodel.
+    table_grants: Vec<(TableId, TableGrant)>,
+    /// Triggers found on every table. Introspection-only: never diffed, so a trigger the
+    /// migration engine doesn't manage is left alone rather than dropped.
+    triggers: Vec<(TableId, Trigger)>,
     /// The schema's views,
     views: Vec<View>,
     /// The schema's columns that are in views.
@@ -247,6 +252,7 @@ impl SqlSchema {
             table_id,
             index_name,
             tpe: IndexType::Fulltext,
+            has_default_name: false,
         });
         id
     }
@@ -258,6 +264,7 @@ impl SqlSchema {
             table_id,
             index_name,
             tpe: IndexType::Normal,
+            has_default_name: false,
         });
         id
     }
@@ -283,21 +290,41 @@ impl SqlSchema {
             table_id,
             index_name,
             tpe: IndexType::PrimaryKey,
+            has_default_name: false,
         });
         id
     }
 
-    /// Add a unique constraint/index to the schema.
-    pub fn push_unique_constraint(&mut self, table_id: TableId, index_name: String) -> IndexId {
+    /// Add a unique constraint/index to the schema. `has_default_name` should be `true` when
+    /// `index_name` was generated from the naming convention rather than explicitly chosen;
+    /// introspected constraints always pass `false`, since the name discovered in the database is
+    /// never a guess.
+    pub fn push_unique_constraint(&mut self, table_id: TableId, index_name: String, has_default_name: bool) -> IndexId {
         let id = IndexId(self.indexes.len() as u32);
         self.indexes.push(Index {
             table_id,
             index_name,
             tpe: IndexType::Unique,
+            has_default_name,
         });
         id
     }
 
+    /// Declare that `table_id` should carry the given grant.
+    pub fn push_table_grant(&mut self, table_id: TableId, grant: TableGrant) {
+        self.table_grants.push((table_id, grant));
+    }
+
+    /// Declare that `table_id` carries the given trigger, as found by introspection.
+    pub fn push_table_trigger(&mut self, table_id: TableId, trigger: Trigger) {
+        self.triggers.push((table_id, trigger));
+    }
+
+    /// Declare that `table_id` carries the given check constraint, as found by introspection.
+    pub fn push_check_constraint(&mut self, table_id: TableId, constraint: CheckConstraint) {
+        self.check_constraints.push((table_id, constraint));
+    }
+
     pub fn push_index_column(&mut self, column: IndexColumn) -> IndexColumnId {
         let id = IndexColumnId(self.index_columns.len() as u32);
         self.index_columns.push(column);
@@ -561,6 +588,93 @@ struct Index {
     table_id: TableId,
     index_name: String,
     tpe: IndexType,
+    /// True if `index_name` was generated from the naming convention rather than explicitly
+    /// chosen (via `map:` in the datamodel, or discovered as-is by introspection). Used by the
+    /// differ to avoid renaming a constraint whose real name it doesn't actually know is meant to
+    /// change.
+    has_default_name: bool,
+}
+
+/// A `GRANT` a table should carry, declared via `@@grant` in the datamodel.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct TableGrant {
+    /// The role the privileges are granted to.
+    pub role: String,
+    /// The privileges granted to the role, e.g. `["select", "insert"]`.
+    pub privileges: Vec<String>,
+}
+
+/// A `CHECK` constraint found by introspection.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct CheckConstraint {
+    /// The constraint's name.
+    pub name: String,
+    /// The constraint's expression, exactly as reported by the database (e.g.
+    /// `pg_get_constraintdef` on Postgres, `information_schema.check_constraints.check_clause` on
+    /// MySQL).
+    pub definition: String,
+}
+
+impl CheckConstraint {
+    /// Whether `self` and `other` describe the same constraint, ignoring spelling differences in
+    /// the expression (runs of whitespace, spaces just inside parentheses) that don't change its
+    /// meaning; redundant parentheses themselves still count as a difference. Used to
+    /// tell a real definition change apart from the database merely echoing the same expression
+    /// back differently, so introspecting an unchanged schema twice doesn't look like a diff.
+    pub fn is_equivalent_to(&self, other: &CheckConstraint) -> bool {
+        self.name == other.name
+            && normalize_check_constraint_expression(&self.definition)
+                == normalize_check_constraint_expression(&other.definition)
+    }
+}
+
+/// Normalizes a `CHECK` constraint expression for comparison: collapses runs of whitespace to a
+/// single space and drops the spaces immediately inside a pair of parentheses, without attempting
+/// to understand the expression's grammar. This is enough to absorb the cosmetic differences
+/// databases introduce when echoing an expression back (extra spaces, reformatted parenthesized
+/// groups), while still treating an actual change in the expression as a difference.
+pub fn normalize_check_constraint_expression(expr: &str) -> String {
+    let collapsed = expr.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut out = String::with_capacity(collapsed.len());
+
+    for c in collapsed.chars() {
+        if c == '(' {
+            out.push(c);
+            continue;
+        }
+
+        if c == ' ' && out.ends_with('(') {
+            continue;
+        }
+
+        if c == ')' {
+            while out.ends_with(' ') {
+                out.pop();
+            }
+        }
+
+        out.push(c);
+    }
+
+    out
+}
+
+/// A database trigger found by introspection. Not managed by the migration engine: it is never
+/// diffed, so a table recreation only ever touches tables the differ decided to recreate, and
+/// existing triggers on tables that are otherwise unchanged are left in the database untouched.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct Trigger {
+    /// The trigger's name.
+    pub name: String,
+    /// When the trigger fires relative to the event, e.g. `"BEFORE"`, `"AFTER"`, `"INSTEAD OF"`.
+    pub timing: String,
+    /// The event(s) that fire the trigger, e.g. `"INSERT"`, or `"INSERT, UPDATE"` on connectors
+    /// that allow a single trigger to fire on more than one event.
+    pub event: String,
+    /// A connector-specific rendering of the trigger: the full `CREATE TRIGGER` statement from
+    /// `pg_get_triggerdef` (Postgres), the trigger's action statement (MySQL), or the trigger's own
+    /// definition text (MSSQL).
+    pub definition: String,
 }
 
 /// A stored procedure (like, the function inside your database).
@@ -822,6 +936,20 @@ pub enum DefaultKind {
     UniqueRowid,
     /// An unrecognized Default Value
     DbGenerated(Option<String>),
+    /// A generated (computed) column expression, e.g. Postgres' `GENERATED ALWAYS AS (...) STORED`
+    /// or MSSQL's `AS (...) PERSISTED`. Unlike `DbGenerated`, this is not a default at all: the
+    /// column has no value of its own and is always recomputed from the expression, so it must
+    /// never be rendered as `DEFAULT`.
+    Generated(String, GeneratedColumnStrategy),
+}
+
+/// Whether a [`DefaultKind::Generated`] column's value is materialized on disk or recomputed on
+/// every read. Postgres generated columns are always `Stored`; MSSQL computed columns are
+/// `Virtual` unless declared `PERSISTED`.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum GeneratedColumnStrategy {
+    Stored,
+    Virtual,
 }
 
 impl DefaultValue {
@@ -829,6 +957,10 @@ impl DefaultValue {
         Self::new(DefaultKind::DbGenerated(Some(val.into())))
     }
 
+    pub fn generated(val: impl Into<String>, strategy: GeneratedColumnStrategy) -> Self {
+        Self::new(DefaultKind::Generated(val.into(), strategy))
+    }
+
     pub fn constraint_name(&self) -> Option<&str> {
         self.constraint_name.as_deref()
     }
@@ -880,6 +1012,11 @@ impl DefaultValue {
         matches!(self.kind, DefaultKind::DbGenerated(_))
     }
 
+    /// True if this is a generated (computed) column expression rather than a true default.
+    pub fn is_generated(&self) -> bool {
+        matches!(self.kind, DefaultKind::Generated(_, _))
+    }
+
     pub fn unique_rowid() -> Self {
         Self::new(DefaultKind::UniqueRowid)
     }
@@ -926,4 +1063,48 @@ mod tests {
 
         assert_eq!(unquote_string("heh "), "heh ");
     }
+
+    #[test]
+    fn check_constraint_expression_normalization_ignores_whitespace_and_paren_spacing() {
+        assert_eq!(
+            normalize_check_constraint_expression("(id > 0)"),
+            normalize_check_constraint_expression("  ( id  >   0 )  ")
+        );
+    }
+
+    #[test]
+    fn check_constraint_expression_normalization_still_detects_real_changes() {
+        assert_ne!(
+            normalize_check_constraint_expression("(id > 0)"),
+            normalize_check_constraint_expression("(id > 1)")
+        );
+    }
+
+    #[test]
+    fn check_constraints_with_equivalent_expressions_are_equivalent() {
+        let a = CheckConstraint {
+            name: "check_id".to_owned(),
+            definition: "(id > 0)".to_owned(),
+        };
+        let b = CheckConstraint {
+            name: "check_id".to_owned(),
+            definition: "  ( id  >   0 )  ".to_owned(),
+        };
+
+        assert!(a.is_equivalent_to(&b));
+    }
+
+    #[test]
+    fn check_constraints_with_different_expressions_are_not_equivalent() {
+        let a = CheckConstraint {
+            name: "check_id".to_owned(),
+            definition: "(id > 0)".to_owned(),
+        };
+        let b = CheckConstraint {
+            name: "check_id".to_owned(),
+            definition: "(id > 1)".to_owned(),
+        };
+
+        assert!(!a.is_equivalent_to(&b));
+    }
 }
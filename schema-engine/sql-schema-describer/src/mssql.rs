@@ -2,8 +2,8 @@
 
 use crate::{
     getters::Getter, ids::*, parsers::Parser, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue,
-    DescriberError, DescriberErrorKind, DescriberResult, ForeignKeyAction, IndexColumn, Procedure, SQLSortOrder,
-    SqlMetadata, SqlSchema, UserDefinedType, View,
+    DescriberError, DescriberErrorKind, DescriberResult, ForeignKeyAction, GeneratedColumnStrategy, IndexColumn,
+    Procedure, SQLSortOrder, SqlMetadata, SqlSchema, Trigger, UserDefinedType, View,
 };
 use either::Either;
 use enumflags2::BitFlags;
@@ -135,6 +135,7 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
         self.get_all_indices(&mut mssql_ext, &table_names, &mut sql_schema)
             .await?;
         self.get_foreign_keys(&table_names, &mut sql_schema).await?;
+        self.get_triggers(&table_names, &mut sql_schema).await?;
 
         self.get_procedures(&mut sql_schema).await?;
         self.get_user_defined_types(&mut sql_schema).await?;
@@ -282,10 +283,14 @@ impl<'a> SqlSchemaDescriber<'a> {
                 convert(int, CASE
                     WHEN c.system_type_id IN (40, 41, 42, 43, 58, 61) THEN NULL
                     ELSE ODBCSCALE(c.system_type_id, c.scale) END) AS numeric_scale,
-                OBJECT_SCHEMA_NAME(c.object_id) AS namespace
+                OBJECT_SCHEMA_NAME(c.object_id) AS namespace,
+                cc.definition                                                   AS computed_definition,
+                cc.is_persisted                                                 AS computed_is_persisted
             FROM sys.columns c
                     INNER JOIN sys.objects obj ON c.object_id = obj.object_id
                     INNER JOIN sys.types typ ON c.user_type_id = typ.user_type_id
+                    LEFT JOIN sys.computed_columns cc
+                        ON cc.object_id = c.object_id AND cc.column_id = c.column_id
             WHERE obj.is_ms_shipped = 0
             ORDER BY table_name, COLUMNPROPERTY(c.object_id, c.name, 'ordinal');
         "#};
@@ -331,64 +336,76 @@ impl<'a> SqlSchemaDescriber<'a> {
 
             let auto_increment = col.get_expect_bool("is_identity");
 
-            let default = match col.get("column_default") {
-                None => None,
-                Some(param_value) => match param_value.to_string() {
+            let computed_definition = col.get_string("computed_definition");
+
+            let default = if let Some(expr) = computed_definition {
+                let strategy = if col.get_expect_bool("computed_is_persisted") {
+                    GeneratedColumnStrategy::Stored
+                } else {
+                    GeneratedColumnStrategy::Virtual
+                };
+
+                Some(DefaultValue::generated(expr, strategy))
+            } else {
+                match col.get("column_default") {
                     None => None,
-                    Some(x) if x == "(NULL)" => None,
-                    Some(x) if DEFAULT_SHARED_CONSTRAINT.is_match(&x) => None,
-                    Some(default_string) => {
-                        let default_string = DEFAULT_NON_STRING
-                            .captures_iter(&default_string)
-                            .next()
-                            .or_else(|| DEFAULT_STRING.captures_iter(&default_string).next())
-                            .or_else(|| DEFAULT_DB_GEN.captures_iter(&default_string).next())
-                            .map(|cap| cap[1].to_string())
-                            .ok_or_else(|| format!("Couldn't parse default value: `{default_string}`"))
-                            .unwrap();
-
-                        let mut default = match tpe.family {
-                            ColumnTypeFamily::Int => match Self::parse_int(&default_string) {
-                                Some(int_value) => DefaultValue::value(int_value),
-                                None => DefaultValue::db_generated(default_string),
-                            },
-                            ColumnTypeFamily::BigInt => match Self::parse_big_int(&default_string) {
-                                Some(int_value) => DefaultValue::value(int_value),
-                                None => DefaultValue::db_generated(default_string),
-                            },
-                            ColumnTypeFamily::Float => match Self::parse_float(&default_string) {
-                                Some(float_value) => DefaultValue::value(float_value),
-                                None => DefaultValue::db_generated(default_string),
-                            },
-                            ColumnTypeFamily::Decimal => match Self::parse_float(&default_string) {
-                                Some(float_value) => DefaultValue::value(float_value),
-                                None => DefaultValue::db_generated(default_string),
-                            },
-                            ColumnTypeFamily::Boolean => match Self::parse_int(&default_string) {
-                                Some(PrismaValue::Int(1)) => DefaultValue::value(PrismaValue::Boolean(true)),
-                                Some(PrismaValue::Int(0)) => DefaultValue::value(PrismaValue::Boolean(false)),
-                                _ => DefaultValue::db_generated(default_string),
-                            },
-                            ColumnTypeFamily::String => DefaultValue::value(default_string.replace("''", "'")),
-                            //todo check other now() definitions
-                            ColumnTypeFamily::DateTime => match default_string.as_str() {
-                                "getdate()" => DefaultValue::now(),
-                                _ => DefaultValue::db_generated(default_string),
-                            },
-                            ColumnTypeFamily::Binary => DefaultValue::db_generated(default_string),
-                            ColumnTypeFamily::Json => DefaultValue::db_generated(default_string),
-                            ColumnTypeFamily::Uuid => DefaultValue::db_generated(default_string),
-                            ColumnTypeFamily::Unsupported(_) => DefaultValue::db_generated(default_string),
-                            ColumnTypeFamily::Enum(_) => unreachable!("No enums in MSSQL"),
-                        };
-
-                        if let Some(name) = col.get_string("constraint_name") {
-                            default.set_constraint_name(name);
+                    Some(param_value) => match param_value.to_string() {
+                        None => None,
+                        Some(x) if x == "(NULL)" => None,
+                        Some(x) if DEFAULT_SHARED_CONSTRAINT.is_match(&x) => None,
+                        Some(default_string) => {
+                            let default_string = DEFAULT_NON_STRING
+                                .captures_iter(&default_string)
+                                .next()
+                                .or_else(|| DEFAULT_STRING.captures_iter(&default_string).next())
+                                .or_else(|| DEFAULT_DB_GEN.captures_iter(&default_string).next())
+                                .map(|cap| cap[1].to_string())
+                                .ok_or_else(|| format!("Couldn't parse default value: `{default_string}`"))
+                                .unwrap();
+
+                            let mut default = match tpe.family {
+                                ColumnTypeFamily::Int => match Self::parse_int(&default_string) {
+                                    Some(int_value) => DefaultValue::value(int_value),
+                                    None => DefaultValue::db_generated(default_string),
+                                },
+                                ColumnTypeFamily::BigInt => match Self::parse_big_int(&default_string) {
+                                    Some(int_value) => DefaultValue::value(int_value),
+                                    None => DefaultValue::db_generated(default_string),
+                                },
+                                ColumnTypeFamily::Float => match Self::parse_float(&default_string) {
+                                    Some(float_value) => DefaultValue::value(float_value),
+                                    None => DefaultValue::db_generated(default_string),
+                                },
+                                ColumnTypeFamily::Decimal => match Self::parse_float(&default_string) {
+                                    Some(float_value) => DefaultValue::value(float_value),
+                                    None => DefaultValue::db_generated(default_string),
+                                },
+                                ColumnTypeFamily::Boolean => match Self::parse_int(&default_string) {
+                                    Some(PrismaValue::Int(1)) => DefaultValue::value(PrismaValue::Boolean(true)),
+                                    Some(PrismaValue::Int(0)) => DefaultValue::value(PrismaValue::Boolean(false)),
+                                    _ => DefaultValue::db_generated(default_string),
+                                },
+                                ColumnTypeFamily::String => DefaultValue::value(default_string.replace("''", "'")),
+                                //todo check other now() definitions
+                                ColumnTypeFamily::DateTime => match default_string.as_str() {
+                                    "getdate()" => DefaultValue::now(),
+                                    _ => DefaultValue::db_generated(default_string),
+                                },
+                                ColumnTypeFamily::Binary => DefaultValue::db_generated(default_string),
+                                ColumnTypeFamily::Json => DefaultValue::db_generated(default_string),
+                                ColumnTypeFamily::Uuid => DefaultValue::db_generated(default_string),
+                                ColumnTypeFamily::Unsupported(_) => DefaultValue::db_generated(default_string),
+                                ColumnTypeFamily::Enum(_) => unreachable!("No enums in MSSQL"),
+                            };
+
+                            if let Some(name) = col.get_string("constraint_name") {
+                                default.set_constraint_name(name);
+                            }
+
+                            Some(default)
                         }
-
-                        Some(default)
-                    }
-                },
+                    },
+                }
             };
 
             let column = Column {
@@ -510,7 +527,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 let id = if is_pk {
                     sql_schema.push_primary_key(table_id, index_name)
                 } else if is_unique {
-                    sql_schema.push_unique_constraint(table_id, index_name)
+                    sql_schema.push_unique_constraint(table_id, index_name, false)
                 } else {
                     sql_schema.push_index(table_id, index_name)
                 };
@@ -789,6 +806,65 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(())
     }
 
+    /// Introspect the triggers declared on the tables in scope. Informational only: the differ
+    /// never looks at `SqlSchema::triggers`, so a trigger the migration engine doesn't manage is
+    /// never dropped just because the table it lives on is otherwise unchanged.
+    async fn get_triggers(
+        &self,
+        table_ids: &IndexMap<(String, String), TableId>,
+        sql_schema: &mut SqlSchema,
+    ) -> DescriberResult<()> {
+        let sql = indoc! {r#"
+            SELECT
+                SCHEMA_NAME(tbl.schema_id)  AS namespace,
+                tbl.name                    AS table_name,
+                trig.name                   AS trigger_name,
+                trig.is_instead_of_trigger  AS is_instead_of_trigger,
+                STRING_AGG(te.type_desc, ', ') AS events,
+                OBJECT_DEFINITION(trig.object_id) AS definition
+            FROM sys.triggers AS trig
+                INNER JOIN sys.tables AS tbl
+                    ON trig.parent_id = tbl.object_id
+                INNER JOIN sys.trigger_events AS te
+                    ON te.object_id = trig.object_id
+            WHERE trig.is_ms_shipped = 0
+            GROUP BY tbl.schema_id, tbl.name, trig.name, trig.is_instead_of_trigger, trig.object_id
+            ORDER BY namespace, table_name, trigger_name
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[]).await?;
+
+        for row in rows {
+            let namespace = row.get_expect_string("namespace");
+            let table_name = row.get_expect_string("table_name");
+            let trigger_name = row.get_expect_string("trigger_name");
+            let is_instead_of = row.get_expect_bool("is_instead_of_trigger");
+            let events = row.get_expect_string("events");
+            let definition = row.get_expect_string("definition");
+
+            let table_id = match table_ids.get(&(namespace, table_name)) {
+                Some(id) => *id,
+                None => continue,
+            };
+
+            let timing = if is_instead_of { "INSTEAD OF" } else { "AFTER" }.to_owned();
+
+            sql_schema.push_table_trigger(
+                table_id,
+                Trigger {
+                    name: trigger_name,
+                    timing,
+                    event: events,
+                    definition,
+                },
+            );
+        }
+
+        sql_schema.triggers.sort_by_key(|(id, _)| *id);
+
+        Ok(())
+    }
+
     fn get_column_type(
         &self,
         data_type: &str,
@@ -88,6 +88,7 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
         sql_schema.table_columns.reserve(table_names.len());
 
         self.get_constraints(&table_names, &mut sql_schema).await?;
+        self.get_triggers(&table_names, &mut sql_schema).await?;
 
         Self::get_all_columns(&table_names, self.conn, schema, &mut sql_schema, &flavour).await?;
         push_foreign_keys(schema, &table_names, &mut sql_schema, self.conn).await?;
@@ -185,7 +186,7 @@ async fn push_indexes(
             let index_id = if is_pk {
                 sql_schema.push_primary_key(table_id, String::new())
             } else if is_unique {
-                sql_schema.push_unique_constraint(table_id, index_name)
+                sql_schema.push_unique_constraint(table_id, index_name, false)
             } else if is_fulltext {
                 sql_schema.push_fulltext_index(table_id, index_name)
             } else {
@@ -751,6 +752,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             let table_name = row.get_expect_string("table_name");
             let constraint_name = row.get_expect_string("constraint_name");
             let constraint_type = row.get_expect_string("constraint_type");
+            let constraint_definition = row.get_string("constraint_definition").unwrap_or_default();
 
             let table_id = match table_names.get(&table_name) {
                 Some(id) => *id,
@@ -758,7 +760,13 @@ impl<'a> SqlSchemaDescriber<'a> {
             };
 
             if constraint_type.as_str() == "check" {
-                sql_schema.check_constraints.push((table_id, constraint_name));
+                sql_schema.check_constraints.push((
+                    table_id,
+                    CheckConstraint {
+                        name: constraint_name,
+                        definition: constraint_definition,
+                    },
+                ));
             }
         }
 
@@ -767,6 +775,46 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(())
     }
 
+    /// Introspect the triggers declared on the tables in scope. Informational only: the differ
+    /// never looks at `SqlSchema::triggers`, so a trigger the migration engine doesn't manage is
+    /// never dropped just because the table it lives on is otherwise unchanged.
+    async fn get_triggers(
+        &self,
+        table_names: &IndexMap<String, TableId>,
+        sql_schema: &mut SqlSchema,
+    ) -> DescriberResult<()> {
+        let sql = include_str!("mysql/triggers_query.sql");
+
+        let rows = self.conn.query_raw(sql, &[]).await?;
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+            let trigger_name = row.get_expect_string("trigger_name");
+            let timing = row.get_expect_string("timing");
+            let event = row.get_expect_string("event");
+            let definition = row.get_expect_string("definition");
+
+            let table_id = match table_names.get(&table_name) {
+                Some(id) => *id,
+                None => continue,
+            };
+
+            sql_schema.push_table_trigger(
+                table_id,
+                Trigger {
+                    name: trigger_name,
+                    timing,
+                    event,
+                    definition,
+                },
+            );
+        }
+
+        sql_schema.triggers.sort_by_key(|(id, _)| *id);
+
+        Ok(())
+    }
+
     fn extract_precision(input: &str) -> Option<u32> {
         static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r".*\(([1-9])\)").unwrap());
         RE.captures(input)
@@ -48,6 +48,11 @@ pub struct Sequence {
     pub cache_size: i64,
     /// Whether the sequence is a cockroachdb virtual sequence
     pub r#virtual: bool,
+    /// The table and column this sequence is `OWNED BY` (`pg_depend` deptype `a`), if any. A
+    /// `SERIAL`-like column's automatically created sequence is owned by that column, so it is
+    /// dropped along with it; a `None` here means the sequence is standalone, e.g. shared between
+    /// columns or created independently of any column.
+    pub owned_by: Option<(TableId, String)>,
 }
 
 // We impl default manually to align with database defaults.
@@ -63,10 +68,18 @@ impl Default for Sequence {
             cycle: false,
             cache_size: 1,
             r#virtual: false,
+            owned_by: None,
         }
     }
 }
 
+impl Sequence {
+    /// Whether this sequence is `OWNED BY` the given column.
+    pub fn is_owned_by(&self, table_id: TableId, column_name: &str) -> bool {
+        matches!(&self.owned_by, Some((owner_table, owner_column)) if *owner_table == table_id && owner_column == column_name)
+    }
+}
+
 #[derive(PartialEq, Debug, Clone, Copy)]
 pub enum SqlIndexAlgorithm {
     BTree,
@@ -109,6 +122,9 @@ pub enum Circumstances {
     Cockroach,
     CockroachWithPostgresNativeTypes, // TODO: this is a temporary workaround
     CanPartitionTables,
+    /// Postgres 14+: `pg_attribute.attcompression` exists, so the column-compression query clause
+    /// can be included without breaking older servers where the column doesn't exist.
+    CanUseColumnCompression,
 }
 
 pub struct SqlSchemaDescriber<'a> {
@@ -153,10 +169,120 @@ pub struct PostgresSchemaExt {
     pub constraint_options: HashMap<Constraint, BitFlags<ConstraintOption>>,
     pub table_options: Vec<BTreeMap<String, String>>,
     pub exclude_constraints: Vec<(TableId, String)>,
+    /// Tables explicitly pinned to a tablespace other than the database's default, as
+    /// `(table, tablespace name)` pairs. A table with no entry here uses whatever tablespace is
+    /// the database default, which we don't track since it isn't a per-table property.
+    /// Introspection-only: never diffed, so a table whose tablespace changed outside of a
+    /// migration is left alone rather than moved, and there is no way to request a tablespace
+    /// from the Prisma schema.
+    pub table_tablespaces: Vec<(TableId, String)>,
     /// The schema's sequences.
     pub sequences: Vec<Sequence>,
     /// The extensions included in the schema(s).
     extensions: Vec<DatabaseExtension>,
+    /// `CREATE TABLE child () INHERITS (parent)` relationships, as `(child, parent)` pairs.
+    /// Sorted by child `TableId`.
+    pub table_inherits: Vec<(TableId, TableId)>,
+    /// The schema's domains (`CREATE DOMAIN name AS base_type CHECK (...)`).
+    pub domains: Vec<Domain>,
+    /// Columns whose declared type is a domain, as `(column, index into domains)` pairs.
+    /// Sorted by `TableColumnId`.
+    domain_columns: Vec<(TableColumnId, usize)>,
+    /// Columns whose TOAST storage mode (`ALTER COLUMN ... SET STORAGE`) was explicitly changed
+    /// away from their type's default. Sorted by `TableColumnId`. Columns using their type's
+    /// default storage are not recorded here.
+    column_storage: Vec<(TableColumnId, ColumnStorage)>,
+    /// Columns with an explicit TOAST compression method (`ALTER COLUMN ... SET COMPRESSION`,
+    /// Postgres 14+). Sorted by `TableColumnId`. Columns using the server's
+    /// `default_toast_compression` are not recorded here. Empty on servers older than Postgres 14,
+    /// since `pg_attribute.attcompression` doesn't exist there.
+    column_compression: Vec<(TableColumnId, ColumnCompression)>,
+}
+
+/// A column's TOAST storage strategy, as set by `ALTER TABLE ... ALTER COLUMN ... SET STORAGE`.
+/// <https://www.postgresql.org/docs/current/storage-toast.html>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColumnStorage {
+    /// `PLAIN`: prevents both compression and out-of-line storage. The oldest strategy, only used
+    /// for types that don't support TOAST at all (e.g. `int4`).
+    Plain,
+    /// `EXTERNAL`: allows out-of-line storage but not compression. Trades compression overhead
+    /// for faster substring access on large values, which is why we tune it on some text columns.
+    External,
+    /// `EXTENDED`: allows both compression and out-of-line storage. The default for most
+    /// variable-length types (e.g. `text`, `bytea`).
+    Extended,
+    /// `MAIN`: allows compression but not out-of-line storage, except as a last resort.
+    Main,
+}
+
+impl ColumnStorage {
+    fn from_attstorage(code: &str) -> Option<Self> {
+        match code {
+            "p" => Some(Self::Plain),
+            "e" => Some(Self::External),
+            "x" => Some(Self::Extended),
+            "m" => Some(Self::Main),
+            _ => None,
+        }
+    }
+
+    /// The keyword rendered after `SET STORAGE` in an `ALTER TABLE ... ALTER COLUMN` statement.
+    pub fn as_sql(self) -> &'static str {
+        match self {
+            Self::Plain => "PLAIN",
+            Self::External => "EXTERNAL",
+            Self::Extended => "EXTENDED",
+            Self::Main => "MAIN",
+        }
+    }
+}
+
+/// A column's TOAST compression method, as set by `ALTER TABLE ... ALTER COLUMN ... SET
+/// COMPRESSION`. Postgres 14+ only.
+/// <https://www.postgresql.org/docs/current/sql-altertable.html>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColumnCompression {
+    /// `pglz`: the original TOAST compression method, available on every version.
+    Pglz,
+    /// `lz4`: faster to (de)compress than `pglz` at the cost of a somewhat lower compression
+    /// ratio, which is why it's worth calling out explicitly for large text/jsonb columns.
+    Lz4,
+}
+
+impl ColumnCompression {
+    fn from_attcompression(code: &str) -> Option<Self> {
+        match code {
+            "p" => Some(Self::Pglz),
+            "l" => Some(Self::Lz4),
+            _ => None,
+        }
+    }
+
+    /// The keyword rendered after `COMPRESSION` in a column definition or `ALTER TABLE ... ALTER
+    /// COLUMN ... SET COMPRESSION` statement.
+    pub fn as_sql(self) -> &'static str {
+        match self {
+            Self::Pglz => "pglz",
+            Self::Lz4 => "lz4",
+        }
+    }
+}
+
+/// A Postgres domain: a named, constrained alias for a base type. Domains are never created,
+/// altered or dropped by Prisma — they are treated as pre-existing, unmanaged database objects,
+/// the same way user-defined types are on MSSQL. We only need to recognize them so that columns
+/// declared with a domain type keep their declared type name instead of being flattened to the
+/// domain's base type.
+#[derive(Clone, Debug)]
+pub struct Domain {
+    pub namespace_id: NamespaceId,
+    pub name: String,
+    /// The domain's underlying base type, as a raw SQL type name (e.g. `text`).
+    pub base_type: String,
+    /// The domain's `CHECK` constraint expression(s), without the `CHECK (...)` wrapper, joined
+    /// with `AND` when a domain declares more than one.
+    pub constraint: Option<String>,
 }
 
 impl PostgresSchemaExt {
@@ -237,6 +363,15 @@ impl PostgresSchemaExt {
         }
     }
 
+    /// The tablespace a table is explicitly pinned to, if any. `None` means the table uses the
+    /// database's default tablespace.
+    pub fn table_tablespace(&self, id: TableId) -> Option<&str> {
+        self.table_tablespaces
+            .iter()
+            .find(|(table_id, _)| *table_id == id)
+            .map(|(_, name)| name.as_str())
+    }
+
     pub fn non_default_foreign_key_constraint_deferring(&self, id: ForeignKeyId) -> bool {
         match self.constraint_options.get(&Constraint::ForeignKey(id)) {
             Some(opts) => opts.contains(ConstraintOption::Deferrable) || opts.contains(ConstraintOption::Deferred),
@@ -265,6 +400,47 @@ impl PostgresSchemaExt {
             .binary_search_by_key(&id, |(id, _)| *id)
             .is_ok()
     }
+
+    /// The table this table declares as its `INHERITS (...)` parent, if any.
+    pub fn inherits(&self, table_id: TableId) -> Option<TableId> {
+        self.table_inherits
+            .binary_search_by_key(&table_id, |(child, _)| *child)
+            .ok()
+            .map(|idx| self.table_inherits[idx].1)
+    }
+
+    /// The domain a column is declared with, if its type is a domain rather than a base type.
+    pub fn get_domain_for_column(&self, column_id: TableColumnId) -> Option<&Domain> {
+        let idx = self
+            .domain_columns
+            .binary_search_by_key(&column_id, |(id, _)| *id)
+            .ok()?;
+        Some(&self.domains[self.domain_columns[idx].1])
+    }
+
+    /// The column's TOAST storage mode, if it was explicitly changed away from its type's
+    /// default. `None` means the column uses whatever storage its type defaults to.
+    pub fn column_storage(&self, column_id: TableColumnId) -> Option<ColumnStorage> {
+        let idx = self.column_storage.binary_search_by_key(&column_id, |(id, _)| *id).ok()?;
+        Some(self.column_storage[idx].1)
+    }
+
+    /// The column's explicit TOAST compression method, if it was set away from the server's
+    /// `default_toast_compression`. `None` means the column uses whatever that default is, or that
+    /// the server predates Postgres 14 and has no notion of per-column compression at all.
+    pub fn column_compression(&self, column_id: TableColumnId) -> Option<ColumnCompression> {
+        let idx = self
+            .column_compression
+            .binary_search_by_key(&column_id, |(id, _)| *id)
+            .ok()?;
+        Some(self.column_compression[idx].1)
+    }
+
+    fn find_domain(&self, namespace_id: NamespaceId, name: &str) -> Option<usize> {
+        self.domains
+            .iter()
+            .position(|d| d.namespace_id == namespace_id && d.name == name)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -283,6 +459,10 @@ pub enum SQLOperatorClassKind {
     JsonbPathOps,
     /// GIN + array type
     ArrayOps,
+    /// GIN + text type, requires the `pg_trgm` extension
+    GinTrgmOps,
+    /// GiST + text type, requires the `pg_trgm` extension
+    GistTrgmOps,
     /// SP-GiST + text type
     TextOps,
     /// BRIN + bit
@@ -402,6 +582,8 @@ impl From<&str> for SQLOperatorClassKind {
     fn from(s: &str) -> Self {
         match s {
             "array_ops" => SQLOperatorClassKind::ArrayOps,
+            "gin_trgm_ops" => SQLOperatorClassKind::GinTrgmOps,
+            "gist_trgm_ops" => SQLOperatorClassKind::GistTrgmOps,
             "jsonb_ops" => SQLOperatorClassKind::JsonbOps,
             "text_ops" => SQLOperatorClassKind::TextOps,
             "bit_minmax_ops" => SQLOperatorClassKind::BitMinMaxOps,
@@ -469,6 +651,8 @@ impl AsRef<str> for SQLOperatorClassKind {
             SQLOperatorClassKind::JsonbOps => "jsonb_ops",
             SQLOperatorClassKind::JsonbPathOps => "jsonb_path_ops",
             SQLOperatorClassKind::ArrayOps => "array_ops",
+            SQLOperatorClassKind::GinTrgmOps => "gin_trgm_ops",
+            SQLOperatorClassKind::GistTrgmOps => "gist_trgm_ops",
             SQLOperatorClassKind::TextOps => "text_ops",
             SQLOperatorClassKind::BitMinMaxOps => "bit_minmax_ops",
             SQLOperatorClassKind::VarBitMinMaxOps => "varbit_minmax_ops",
@@ -561,9 +745,14 @@ impl<'a> super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'a> {
 
         // order matters
         self.get_constraints(&table_names, &mut sql_schema, &mut pg_ext).await?;
+        self.get_triggers(&table_names, &mut sql_schema).await?;
         self.get_views(&mut sql_schema).await?;
         self.get_enums(&mut sql_schema).await?;
-        self.get_columns(&mut sql_schema).await?;
+        self.get_domains(&sql_schema, &mut pg_ext).await?;
+        // Sequences (and their column ownership) are described before columns so that
+        // `get_columns` can tell an owned, `SERIAL`-style sequence default from a standalone one.
+        self.get_sequences(&sql_schema, &mut pg_ext).await?;
+        self.get_columns(&mut sql_schema, &mut pg_ext).await?;
         self.get_foreign_keys(&table_names, &mut pg_ext, &mut sql_schema)
             .await?;
         self.get_indices(&table_names, &mut pg_ext, &mut sql_schema).await?;
@@ -571,11 +760,14 @@ impl<'a> super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'a> {
         self.get_procedures(&mut sql_schema).await?;
         self.get_extensions(&mut pg_ext).await?;
 
-        //Todo(matthias) understand this
-        self.get_sequences(&sql_schema, &mut pg_ext).await?;
+        self.get_table_inheritance(&sql_schema, &mut pg_ext).await?;
         // Make sure the vectors we use binary search on are sorted.
         pg_ext.indexes.sort_by_key(|(id, _)| *id);
         pg_ext.opclasses.sort_by_key(|(id, _)| *id);
+        pg_ext.table_inherits.sort_by_key(|(child, _)| *child);
+        pg_ext.domain_columns.sort_by_key(|(id, _)| *id);
+        pg_ext.column_storage.sort_by_key(|(id, _)| *id);
+        pg_ext.column_compression.sort_by_key(|(id, _)| *id);
 
         sql_schema.connector_data = connector_data::ConnectorData {
             data: Some(Box::new(pg_ext)),
@@ -733,6 +925,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 row.get_expect_bool("is_partition"),
                 row.get_expect_bool("has_subclass"),
                 row.get_expect_bool("has_row_level_security"),
+                row.get_string("tablespace"),
                 row.get_string("description"),
             ));
 
@@ -741,7 +934,9 @@ impl<'a> SqlSchemaDescriber<'a> {
 
         let mut map = IndexMap::default();
 
-        for (table_name, namespace, is_partition, has_subclass, has_row_level_security, description) in names {
+        for (table_name, namespace, is_partition, has_subclass, has_row_level_security, tablespace, description) in
+            names
+        {
             let cloned_name = table_name.clone();
 
             let partition = if is_partition {
@@ -774,6 +969,10 @@ impl<'a> SqlSchemaDescriber<'a> {
                 description,
             );
 
+            if let Some(tablespace) = tablespace {
+                pg_ext.table_tablespaces.push((id, tablespace));
+            }
+
             map.insert(constraints_key, id);
         }
 
@@ -828,10 +1027,13 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(())
     }
 
-    async fn get_columns(&self, sql_schema: &mut SqlSchema) -> DescriberResult<()> {
+    async fn get_columns(&self, sql_schema: &mut SqlSchema, pg_ext: &mut PostgresSchemaExt) -> DescriberResult<()> {
         let namespaces = &sql_schema.namespaces;
         let mut table_defaults = Vec::new();
         let mut view_defaults = Vec::new();
+        let mut table_domains: Vec<(TableId, Option<usize>)> = Vec::new();
+        let mut table_storage: Vec<(TableId, Option<ColumnStorage>)> = Vec::new();
+        let mut table_compression: Vec<(TableId, Option<ColumnCompression>)> = Vec::new();
 
         let is_visible_clause = if self.is_cockroach() {
             " AND info.is_hidden = 'NO'"
@@ -839,6 +1041,14 @@ impl<'a> SqlSchemaDescriber<'a> {
             ""
         };
 
+        // `pg_attribute.attcompression` was only added in Postgres 14, so selecting it
+        // unconditionally would break introspection against older servers.
+        let compression_column = if self.circumstances.contains(Circumstances::CanUseColumnCompression) {
+            ", att.attcompression AS column_compression"
+        } else {
+            ""
+        };
+
         let sql = format!(
             r#"
             SELECT
@@ -854,10 +1064,17 @@ impl<'a> SqlSchemaDescriber<'a> {
                 info.udt_schema as type_schema_name,
                 info.udt_name as full_data_type,
                 pg_get_expr(attdef.adbin, attdef.adrelid) AS column_default,
+                attdef.adgenerated AS column_generated,
                 info.is_nullable,
                 info.is_identity,
                 info.character_maximum_length,
-                col_description(att.attrelid, ordinal_position) AS description
+                col_description(att.attrelid, ordinal_position) AS description,
+                declared_type.typtype AS declared_type_kind,
+                declared_type.typname AS declared_type_name,
+                declared_type_ns.nspname AS declared_type_namespace,
+                att.attstorage AS column_storage,
+                declared_type.typstorage AS type_storage_default
+                {compression_column}
             FROM information_schema.columns info
             JOIN pg_attribute att ON att.attname = info.column_name
             JOIN (
@@ -866,10 +1083,12 @@ impl<'a> SqlSchemaDescriber<'a> {
                  JOIN pg_namespace on pg_namespace.oid = pg_class.relnamespace
                  AND pg_namespace.nspname = ANY ( $1 )
                  WHERE reltype > 0
-                ) as oid on oid.oid = att.attrelid 
+                ) as oid on oid.oid = att.attrelid
                   AND relname = info.table_name
                   AND namespace = info.table_schema
             LEFT OUTER JOIN pg_attrdef attdef ON attdef.adrelid = att.attrelid AND attdef.adnum = att.attnum AND table_schema = namespace
+            LEFT OUTER JOIN pg_type declared_type ON declared_type.oid = att.atttypid
+            LEFT OUTER JOIN pg_namespace declared_type_ns ON declared_type_ns.oid = declared_type.typnamespace
             WHERE table_schema = ANY ( $1 ) {is_visible_clause}
             ORDER BY namespace, table_name, ordinal_position;
         "#
@@ -909,15 +1128,75 @@ impl<'a> SqlSchemaDescriber<'a> {
                 get_column_type_postgresql(&col, sql_schema)
             };
 
+            if let Either::Left(table_id) = container_id {
+                let domain_idx = col
+                    .get_string("declared_type_kind")
+                    .filter(|kind| kind == "d")
+                    .and_then(|_| {
+                        let declared_type_namespace = col.get_string("declared_type_namespace")?;
+                        let declared_type_name = col.get_string("declared_type_name")?;
+                        let namespace_id = sql_schema.get_namespace_id(&declared_type_namespace)?;
+                        pg_ext.find_domain(namespace_id, &declared_type_name)
+                    });
+
+                table_domains.push((table_id, domain_idx));
+
+                let storage = col.get_string("column_storage").and_then(|s| ColumnStorage::from_attstorage(&s));
+                let default_storage = col
+                    .get_string("type_storage_default")
+                    .and_then(|s| ColumnStorage::from_attstorage(&s));
+
+                // Only record the storage mode when it was explicitly changed away from the
+                // type's own default: that's the only case `SET STORAGE` could have produced.
+                let non_default_storage = match (storage, default_storage) {
+                    (Some(storage), Some(default_storage)) if storage != default_storage => Some(storage),
+                    _ => None,
+                };
+
+                table_storage.push((table_id, non_default_storage));
+
+                // A blank `attcompression` means "use `default_toast_compression`", which is the
+                // same "not explicitly set" case as an absent column on pre-14 servers.
+                let compression = col
+                    .get_string("column_compression")
+                    .filter(|s| !s.is_empty())
+                    .and_then(|s| ColumnCompression::from_attcompression(&s));
+
+                table_compression.push((table_id, compression));
+            }
+
+            let is_generated_column = col.get_string("column_generated").as_deref() == Some("s");
+
             let default = col
                 .get("column_default")
                 .and_then(|raw_default_value| raw_default_value.to_string())
-                .and_then(|raw_default_value| get_default_value(&raw_default_value, &tpe));
+                .and_then(|raw_default_value| {
+                    if is_generated_column {
+                        Some(DefaultValue::generated(raw_default_value, GeneratedColumnStrategy::Stored))
+                    } else {
+                        get_default_value(&raw_default_value, &tpe)
+                    }
+                });
 
             let description = col.get_string("description");
 
+            // A `nextval()` default only makes a column auto-incrementing if the sequence behind
+            // it is actually `OWNED BY` that column, i.e. it was created for this column (by
+            // `SERIAL` or by us). A `nextval()` default pointing at a standalone sequence — shared
+            // between columns, or created independently — isn't an autoincrement in that sense.
+            let owns_sequence = match (container_id, default.as_ref().map(|d| &d.kind)) {
+                (Either::Left(table_id), Some(DefaultKind::Sequence(seq_name))) => pg_ext
+                    .get_sequence(seq_name)
+                    .map(|(_, seq)| seq.is_owned_by(table_id, &name))
+                    .unwrap_or(false),
+                // Views have no `ALTER SEQUENCE ... OWNED BY` of their own; keep the previous,
+                // more permissive behaviour for their columns.
+                (Either::Right(_), Some(DefaultKind::Sequence(_))) => true,
+                _ => false,
+            };
+
             let auto_increment = is_identity
-                || matches!(default.as_ref().map(|d| &d.kind), Some(DefaultKind::Sequence(_)))
+                || owns_sequence
                 || (self.is_cockroach()
                     && matches!(
                         default.as_ref().map(|d| &d.kind),
@@ -958,6 +1237,9 @@ impl<'a> SqlSchemaDescriber<'a> {
 
         table_defaults.sort_by_key(|(table_id, _)| *table_id);
         view_defaults.sort_by_key(|(view_id, _)| *view_id);
+        table_domains.sort_by_key(|(table_id, _)| *table_id);
+        table_storage.sort_by_key(|(table_id, _)| *table_id);
+        table_compression.sort_by_key(|(table_id, _)| *table_id);
 
         for (i, (_, default)) in table_defaults.into_iter().enumerate() {
             if let Some(default) = default {
@@ -965,6 +1247,24 @@ impl<'a> SqlSchemaDescriber<'a> {
             }
         }
 
+        for (i, (_, domain_idx)) in table_domains.into_iter().enumerate() {
+            if let Some(domain_idx) = domain_idx {
+                pg_ext.domain_columns.push((TableColumnId(i as u32), domain_idx));
+            }
+        }
+
+        for (i, (_, storage)) in table_storage.into_iter().enumerate() {
+            if let Some(storage) = storage {
+                pg_ext.column_storage.push((TableColumnId(i as u32), storage));
+            }
+        }
+
+        for (i, (_, compression)) in table_compression.into_iter().enumerate() {
+            if let Some(compression) = compression {
+                pg_ext.column_compression.push((TableColumnId(i as u32), compression));
+            }
+        }
+
         for (i, (_, default)) in view_defaults.into_iter().enumerate() {
             if let Some(default) = default {
                 sql_schema.push_view_default_value(ViewColumnId(i as u32), default);
@@ -1228,6 +1528,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             let table_name = row.get_expect_string("table_name");
             let constraint_name = row.get_expect_string("constraint_name");
             let constraint_type = row.get_expect_char("constraint_type");
+            let constraint_definition = row.get_expect_string("constraint_definition");
 
             let table_id = match table_names.get(&(namespace, table_name)) {
                 Some(id) => *id,
@@ -1236,7 +1537,13 @@ impl<'a> SqlSchemaDescriber<'a> {
 
             match constraint_type {
                 'c' => {
-                    sql_schema.check_constraints.push((table_id, constraint_name));
+                    sql_schema.check_constraints.push((
+                        table_id,
+                        CheckConstraint {
+                            name: constraint_name,
+                            definition: constraint_definition,
+                        },
+                    ));
                 }
                 'x' => {
                     pg_ext.exclude_constraints.push((table_id, constraint_name));
@@ -1251,6 +1558,49 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(())
     }
 
+    /// Introspect the triggers declared on the tables in scope. This is informational only: the
+    /// differ never looks at `SqlSchema::triggers`, so a trigger the migration engine doesn't
+    /// manage is never dropped just because the table it lives on is otherwise unchanged.
+    async fn get_triggers(
+        &self,
+        table_names: &IndexMap<(String, String), TableId>,
+        sql_schema: &mut SqlSchema,
+    ) -> DescriberResult<()> {
+        let namespaces = &sql_schema.namespaces;
+        let sql = include_str!("postgres/triggers_query.sql");
+
+        let rows = self.conn.query_raw(sql, &[Value::array(namespaces)]).await?;
+
+        for row in rows {
+            let namespace = row.get_expect_string("namespace");
+            let table_name = row.get_expect_string("table_name");
+            let trigger_name = row.get_expect_string("trigger_name");
+            let trigger_type = row.get_expect_i64("trigger_type");
+            let definition = row.get_expect_string("definition");
+
+            let table_id = match table_names.get(&(namespace, table_name)) {
+                Some(id) => *id,
+                None => continue,
+            };
+
+            let (timing, event) = decode_pg_trigger_type(trigger_type);
+
+            sql_schema.push_table_trigger(
+                table_id,
+                Trigger {
+                    name: trigger_name,
+                    timing,
+                    event,
+                    definition,
+                },
+            );
+        }
+
+        sql_schema.triggers.sort_by_key(|(id, _)| *id);
+
+        Ok(())
+    }
+
     async fn get_indices(
         &self,
         table_ids: &IndexMap<(String, String), TableId>,
@@ -1330,24 +1680,202 @@ impl<'a> SqlSchemaDescriber<'a> {
         };
 
         let rows = self.conn.query_raw(sql, &[Value::array(namespaces)]).await?;
-        let sequences = rows.into_iter().map(|seq| Sequence {
-            namespace_id: sql_schema
-                .get_namespace_id(&seq.get_expect_string("namespace"))
-                .unwrap(),
-            name: seq.get_expect_string("sequence_name"),
-            start_value: seq.get_expect_i64("start_value"),
-            min_value: seq.get_expect_i64("min_value"),
-            max_value: seq.get_expect_i64("max_value"),
-            increment_by: seq.get_expect_i64("increment_by"),
-            cycle: seq.get_expect_bool("cycle"),
-            cache_size: seq.get_expect_i64("cache_size"),
-            r#virtual: false,
+        let ownership = self.get_sequence_ownership(namespaces).await?;
+
+        let sequences = rows.into_iter().map(|seq| {
+            let namespace = seq.get_expect_string("namespace");
+            let name = seq.get_expect_string("sequence_name");
+            let owned_by = ownership
+                .get(&(namespace.clone(), name.clone()))
+                .and_then(|(owner_namespace, owner_table, owner_column)| {
+                    sql_schema
+                        .table_walker_ns(owner_namespace, owner_table)
+                        .map(|table| (table.id, owner_column.clone()))
+                });
+
+            Sequence {
+                namespace_id: sql_schema.get_namespace_id(&namespace).unwrap(),
+                name,
+                start_value: seq.get_expect_i64("start_value"),
+                min_value: seq.get_expect_i64("min_value"),
+                max_value: seq.get_expect_i64("max_value"),
+                increment_by: seq.get_expect_i64("increment_by"),
+                cycle: seq.get_expect_bool("cycle"),
+                cache_size: seq.get_expect_i64("cache_size"),
+                r#virtual: false,
+                owned_by,
+            }
         });
         postgres_ext.sequences.extend(sequences);
 
         Ok(())
     }
 
+    /// A sequence created for a `SERIAL`-like column (or explicitly via
+    /// `ALTER SEQUENCE ... OWNED BY`) carries an automatic dependency (`pg_depend.deptype = 'a'`)
+    /// on that column, keyed by `(sequence_namespace, sequence_name) -> (owner_namespace,
+    /// owner_table, owner_column)`. A sequence absent from this map is standalone: shared between
+    /// columns, or created independently of any column.
+    async fn get_sequence_ownership(
+        &self,
+        namespaces: &[String],
+    ) -> DescriberResult<HashMap<(String, String), (String, String, String)>> {
+        let sql = r#"
+            SELECT
+                seq_ns.nspname AS sequence_namespace,
+                seq.relname AS sequence_name,
+                tbl_ns.nspname AS owner_namespace,
+                tbl.relname AS owner_table,
+                att.attname AS owner_column
+            FROM pg_depend dep
+            JOIN pg_class seq ON seq.oid = dep.objid AND seq.relkind = 'S'
+            JOIN pg_namespace seq_ns ON seq_ns.oid = seq.relnamespace
+            JOIN pg_class tbl ON tbl.oid = dep.refobjid
+            JOIN pg_namespace tbl_ns ON tbl_ns.oid = tbl.relnamespace
+            JOIN pg_attribute att ON att.attrelid = dep.refobjid AND att.attnum = dep.refobjsubid
+            WHERE dep.deptype = 'a'
+                AND dep.classid = 'pg_class'::regclass
+                AND dep.refclassid = 'pg_class'::regclass
+                AND seq_ns.nspname = ANY ( $1 )
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[Value::array(namespaces)]).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                (
+                    (
+                        row.get_expect_string("sequence_namespace"),
+                        row.get_expect_string("sequence_name"),
+                    ),
+                    (
+                        row.get_expect_string("owner_namespace"),
+                        row.get_expect_string("owner_table"),
+                        row.get_expect_string("owner_column"),
+                    ),
+                )
+            })
+            .collect())
+    }
+
+    /// Populates legacy `CREATE TABLE child () INHERITS (parent)` relationships from `pg_inherits`.
+    /// Declarative partitioning (`relispartition`) is tracked separately via [`TableProperties`]
+    /// and is not represented here.
+    async fn get_table_inheritance(
+        &self,
+        sql_schema: &SqlSchema,
+        postgres_ext: &mut PostgresSchemaExt,
+    ) -> DescriberResult<()> {
+        if self.is_cockroach() {
+            return Ok(());
+        }
+
+        let namespaces = &sql_schema.namespaces;
+
+        let sql = r#"
+            SELECT
+                child_ns.nspname AS child_namespace,
+                child.relname AS child_name,
+                parent_ns.nspname AS parent_namespace,
+                parent.relname AS parent_name
+            FROM pg_inherits
+            INNER JOIN pg_class AS child ON child.oid = pg_inherits.inhrelid
+            INNER JOIN pg_namespace AS child_ns ON child_ns.oid = child.relnamespace
+            INNER JOIN pg_class AS parent ON parent.oid = pg_inherits.inhparent
+            INNER JOIN pg_namespace AS parent_ns ON parent_ns.oid = parent.relnamespace
+            WHERE child.relispartition = 'f'
+              AND child_ns.nspname = ANY ( $1 )
+              AND parent_ns.nspname = ANY ( $1 )
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[Value::array(namespaces)]).await?;
+
+        for row in rows.into_iter() {
+            let child = sql_schema.table_walker_ns(
+                &row.get_expect_string("child_namespace"),
+                &row.get_expect_string("child_name"),
+            );
+            let parent = sql_schema.table_walker_ns(
+                &row.get_expect_string("parent_namespace"),
+                &row.get_expect_string("parent_name"),
+            );
+
+            if let (Some(child), Some(parent)) = (child, parent) {
+                postgres_ext.table_inherits.push((child.id, parent.id));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_domains(&self, sql_schema: &SqlSchema, pg_ext: &mut PostgresSchemaExt) -> DescriberResult<()> {
+        // CockroachDB does not support domains.
+        if self.is_cockroach() {
+            return Ok(());
+        }
+
+        let namespaces = &sql_schema.namespaces;
+
+        let sql = r#"
+            SELECT
+                n.nspname AS namespace,
+                t.typname AS name,
+                bt.typname AS base_type,
+                pg_get_constraintdef(c.oid) AS constraint_def
+            FROM pg_type t
+            JOIN pg_namespace n ON n.oid = t.typnamespace
+            JOIN pg_type bt ON bt.oid = t.typbasetype
+            LEFT JOIN pg_constraint c ON c.contypid = t.oid
+            WHERE t.typtype = 'd'
+              AND n.nspname = ANY ( $1 )
+            ORDER BY n.nspname, t.typname
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[Value::array(namespaces)]).await?;
+        let mut domains: BTreeMap<(NamespaceId, String, String), Vec<String>> = BTreeMap::new();
+
+        for row in rows.into_iter() {
+            let namespace = row.get_expect_string("namespace");
+            let namespace_id = match sql_schema.get_namespace_id(&namespace) {
+                Some(id) => id,
+                None => continue,
+            };
+            let name = row.get_expect_string("name");
+            let base_type = row.get_expect_string("base_type");
+
+            let checks = domains.entry((namespace_id, name, base_type)).or_default();
+
+            if let Some(constraint_def) = row.get_string("constraint_def") {
+                // `pg_get_constraintdef` renders the full `CHECK (...)` clause; we only keep the expression.
+                let expression = constraint_def
+                    .strip_prefix("CHECK (")
+                    .and_then(|def| def.strip_suffix(')'))
+                    .unwrap_or(&constraint_def)
+                    .to_string();
+
+                checks.push(expression);
+            }
+        }
+
+        for ((namespace_id, name, base_type), checks) in domains {
+            let constraint = if checks.is_empty() {
+                None
+            } else {
+                Some(checks.join(" AND "))
+            };
+
+            pg_ext.domains.push(Domain {
+                namespace_id,
+                name,
+                base_type,
+                constraint,
+            });
+        }
+
+        Ok(())
+    }
+
     async fn get_enums(&self, sql_schema: &mut SqlSchema) -> DescriberResult<()> {
         let namespaces = &sql_schema.namespaces;
 
@@ -1390,6 +1918,42 @@ impl<'a> SqlSchemaDescriber<'a> {
     }
 }
 
+/// Decode `pg_trigger.tgtype`, a bitmask, into a human-readable timing (`BEFORE`/`AFTER`/`INSTEAD
+/// OF`) and a comma-separated list of the events that fire the trigger. See the bit layout at
+/// <https://www.postgresql.org/docs/current/catalog-pg-trigger.html>.
+fn decode_pg_trigger_type(tgtype: i64) -> (String, String) {
+    const BEFORE: i64 = 1 << 1;
+    const INSERT: i64 = 1 << 2;
+    const DELETE: i64 = 1 << 3;
+    const UPDATE: i64 = 1 << 4;
+    const TRUNCATE: i64 = 1 << 5;
+    const INSTEAD: i64 = 1 << 6;
+
+    let timing = if tgtype & INSTEAD != 0 {
+        "INSTEAD OF"
+    } else if tgtype & BEFORE != 0 {
+        "BEFORE"
+    } else {
+        "AFTER"
+    };
+
+    let mut events = Vec::new();
+    if tgtype & INSERT != 0 {
+        events.push("INSERT");
+    }
+    if tgtype & UPDATE != 0 {
+        events.push("UPDATE");
+    }
+    if tgtype & DELETE != 0 {
+        events.push("DELETE");
+    }
+    if tgtype & TRUNCATE != 0 {
+        events.push("TRUNCATE");
+    }
+
+    (timing.to_owned(), events.join(", "))
+}
+
 fn group_next_index<T>(result_rows: &mut Vec<ResultRow>, index_rows: &mut Peekable<T>)
 where
     T: Iterator<Item = ResultRow>,
@@ -1470,7 +2034,7 @@ fn index_from_row(
             let index_id = if is_primary_key {
                 sql_schema.push_primary_key(table_id, index_name)
             } else if is_unique {
-                sql_schema.push_unique_constraint(table_id, index_name)
+                sql_schema.push_unique_constraint(table_id, index_name, false)
             } else {
                 sql_schema.push_index(table_id, index_name)
             };
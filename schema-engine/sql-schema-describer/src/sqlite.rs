@@ -527,7 +527,7 @@ async fn push_indexes(
 
     for (index_name, unique, columns) in indexes {
         let index_id = if unique {
-            schema.push_unique_constraint(table_id, index_name)
+            schema.push_unique_constraint(table_id, index_name, false)
         } else {
             schema.push_index(table_id, index_name)
         };
@@ -7,7 +7,7 @@ pub use view_default::ViewDefaultValueWalker;
 
 use either::Either;
 
-use crate::{DefaultKind, DefaultValue, TableDefaultValueId, ViewDefaultValueId, Walker};
+use crate::{DefaultKind, DefaultValue, GeneratedColumnStrategy, TableDefaultValueId, ViewDefaultValueId, Walker};
 
 /// Traverse default value.
 pub type DefaultValueWalker<'a> = Walker<'a, Either<TableDefaultValueId, ViewDefaultValueId>>;
@@ -57,6 +57,19 @@ impl<'a> DefaultValueWalker<'a> {
         matches!(self.kind(), DefaultKind::DbGenerated(_))
     }
 
+    /// True if this is a generated (computed) column expression rather than a true default.
+    pub fn is_generated(&self) -> bool {
+        matches!(self.kind(), DefaultKind::Generated(_, _))
+    }
+
+    /// If this is a generated (computed) column, return its expression and persistence strategy.
+    pub fn as_generated(self) -> Option<(&'a str, GeneratedColumnStrategy)> {
+        match self.kind() {
+            DefaultKind::Generated(expr, strategy) => Some((expr, *strategy)),
+            _ => None,
+        }
+    }
+
     /// The value kind enumerator
     pub fn kind(self) -> &'a DefaultKind {
         &self.value().kind
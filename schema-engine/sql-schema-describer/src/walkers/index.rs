@@ -39,6 +39,12 @@ impl<'a> IndexWalker<'a> {
         matches!(self.get().tpe, IndexType::Unique)
     }
 
+    /// True if the index's name was generated from the naming convention rather than explicitly
+    /// chosen or discovered as-is by introspection.
+    pub fn has_default_name(self) -> bool {
+        self.get().has_default_name
+    }
+
     /// The name of the index.
     pub fn name(self) -> &'a str {
         &self.get().index_name
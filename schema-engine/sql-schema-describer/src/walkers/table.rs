@@ -118,14 +118,30 @@ impl<'a> TableWalker<'a> {
             .is_ok()
     }
 
-    /// The check constraint names for the table.
-    pub fn check_constraints(self) -> impl ExactSizeIterator<Item = &'a str> {
+    /// The check constraints for the table.
+    pub fn check_constraints(self) -> impl ExactSizeIterator<Item = &'a crate::CheckConstraint> {
         let low = self.schema.check_constraints.partition_point(|(id, _)| *id < self.id);
         let high = self.schema.check_constraints[low..].partition_point(|(id, _)| *id <= self.id);
 
         self.schema.check_constraints[low..low + high]
             .iter()
-            .map(|(_, name)| name.as_str())
+            .map(|(_, constraint)| constraint)
+    }
+
+    /// The grants (`@@grant`) declared on the table.
+    pub fn grants(self) -> impl ExactSizeIterator<Item = &'a crate::TableGrant> {
+        let low = self.schema.table_grants.partition_point(|(id, _)| *id < self.id);
+        let high = self.schema.table_grants[low..].partition_point(|(id, _)| *id <= self.id);
+
+        self.schema.table_grants[low..low + high].iter().map(|(_, grant)| grant)
+    }
+
+    /// The triggers found on the table by introspection.
+    pub fn triggers(self) -> impl ExactSizeIterator<Item = &'a crate::Trigger> {
+        let low = self.schema.triggers.partition_point(|(id, _)| *id < self.id);
+        let high = self.schema.triggers[low..].partition_point(|(id, _)| *id <= self.id);
+
+        self.schema.triggers[low..low + high].iter().map(|(_, trigger)| trigger)
     }
 
     /// Description (comment) of the table.
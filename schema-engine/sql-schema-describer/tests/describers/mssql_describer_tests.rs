@@ -679,6 +679,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
                     ),
                     index_name: "thepk",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
@@ -950,6 +951,7 @@ fn multiple_schemas_with_same_table_names_are_described(api: TestApi) {
                     ),
                     index_name: "Table_0_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -957,6 +959,7 @@ fn multiple_schemas_with_same_table_names_are_described(api: TestApi) {
                     ),
                     index_name: "Table_0_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
@@ -1315,6 +1318,7 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                     ),
                     index_name: "Table_0_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -1322,6 +1326,7 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                     ),
                     index_name: "Table_0_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -1329,6 +1334,7 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                     ),
                     index_name: "Table_1_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -1336,6 +1342,7 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                     ),
                     index_name: "Table_1_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -1343,6 +1350,7 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                     ),
                     index_name: "Table_2_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
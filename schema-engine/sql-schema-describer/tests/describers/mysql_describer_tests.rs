@@ -850,6 +850,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                     ),
                     index_name: "",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
@@ -1676,6 +1677,7 @@ fn all_mariadb_column_types_must_work(api: TestApi) {
                     ),
                     index_name: "",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
@@ -2505,6 +2507,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                     ),
                     index_name: "",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
@@ -2765,6 +2768,7 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
                     ),
                     index_name: "",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -2772,6 +2776,7 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
                     ),
                     index_name: "user_id",
                     tpe: Normal,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -2779,6 +2784,7 @@ fn constraints_from_other_databases_should_not_be_introspected(api: TestApi) {
                     ),
                     index_name: "",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
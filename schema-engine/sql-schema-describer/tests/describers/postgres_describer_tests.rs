@@ -979,6 +979,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                     ),
                     index_name: "User_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
@@ -1449,6 +1450,7 @@ fn escaped_quotes_in_string_defaults_must_be_unescaped(api: TestApi) {
                     ),
                     index_name: "string_defaults_test_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
@@ -2011,6 +2013,7 @@ fn multiple_schemas_with_same_table_names_are_described(api: TestApi) {
                     ),
                     index_name: "Table_0_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -2018,6 +2021,7 @@ fn multiple_schemas_with_same_table_names_are_described(api: TestApi) {
                     ),
                     index_name: "Table_0_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
@@ -2443,6 +2447,7 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                     ),
                     index_name: "Table_0_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -2450,6 +2455,7 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                     ),
                     index_name: "Table_1_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -2457,6 +2463,7 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                     ),
                     index_name: "Table_0_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -2464,6 +2471,7 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                     ),
                     index_name: "Table_1_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -2471,6 +2479,7 @@ fn multiple_schemas_with_same_foreign_key_are_described(api: TestApi) {
                     ),
                     index_name: "Table_2_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
@@ -2829,6 +2838,7 @@ fn multiple_schemas_are_described(api: TestApi) {
                     ),
                     index_name: "Table_0_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -2836,6 +2846,7 @@ fn multiple_schemas_are_described(api: TestApi) {
                     ),
                     index_name: "Index_0",
                     tpe: Normal,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -2843,6 +2854,7 @@ fn multiple_schemas_are_described(api: TestApi) {
                     ),
                     index_name: "Table_1_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -2850,6 +2862,7 @@ fn multiple_schemas_are_described(api: TestApi) {
                     ),
                     index_name: "Table_2_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -2857,6 +2870,7 @@ fn multiple_schemas_are_described(api: TestApi) {
                     ),
                     index_name: "Index_1",
                     tpe: Normal,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -2864,6 +2878,7 @@ fn multiple_schemas_are_described(api: TestApi) {
                     ),
                     index_name: "Table_3_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
@@ -312,6 +312,7 @@ fn multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi) {
                     ),
                     index_name: "indexes_test_pkey",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -319,6 +320,7 @@ fn multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi) {
                     ),
                     index_name: "my_idx",
                     tpe: Unique,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -326,6 +328,7 @@ fn multi_field_indexes_must_be_inferred_in_the_right_order(api: TestApi) {
                     ),
                     index_name: "my_idx2",
                     tpe: Normal,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
@@ -186,6 +186,7 @@ fn sqlite_column_types_must_work(api: TestApi) {
                     ),
                     index_name: "",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [
@@ -614,6 +615,7 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                     ),
                     index_name: "",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
                 Index {
                     table_id: TableId(
@@ -621,6 +623,7 @@ fn broken_relations_are_filtered_out(api: TestApi) {
                     ),
                     index_name: "",
                     tpe: PrimaryKey,
+                    has_default_name: false,
                 },
             ],
             index_columns: [